@@ -60,6 +60,7 @@ fn main() {
                     lotr.time,
                     lotr.src_id.clone(),
                     [("type".to_string(), Prop::Str("Character".to_string()))],
+                    None,
                 )
                 .expect("Failed to add vertex");
 
@@ -67,6 +68,7 @@ fn main() {
                     lotr.time,
                     lotr.dst_id.clone(),
                     [("type".to_string(), Prop::Str("Character".to_string()))],
+                    None,
                 )
                 .expect("Failed to add vertex");
 
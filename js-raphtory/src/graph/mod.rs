@@ -115,12 +115,12 @@ impl Graph {
         match JsVertex::try_from(id)? {
             JsVertex::Str(vertex) => self
                 .mutable_graph()
-                .add_vertex(t, vertex, rust_props)
+                .add_vertex(t, vertex, rust_props, None)
                 .map(|v| v.into())
                 .map_err(JSError),
             JsVertex::Number(vertex) => self
                 .mutable_graph()
-                .add_vertex(t, vertex, rust_props)
+                .add_vertex(t, vertex, rust_props, None)
                 .map(|v| v.into())
                 .map_err(JSError),
         }
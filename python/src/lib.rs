@@ -72,6 +72,10 @@ fn raphtory(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         weakly_connected_components,
         algorithm_module
     )?)?;
+    algorithm_module.add_function(wrap_pyfunction!(
+        strongly_connected_components,
+        algorithm_module
+    )?)?;
     algorithm_module.add_function(wrap_pyfunction!(
         global_temporal_three_node_motif,
         algorithm_module
@@ -80,6 +84,14 @@ fn raphtory(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         local_temporal_three_node_motifs,
         algorithm_module
     )?)?;
+    algorithm_module.add_function(wrap_pyfunction!(
+        temporal_motif_count,
+        algorithm_module
+    )?)?;
+    algorithm_module.add_function(wrap_pyfunction!(
+        temporal_motif_instances,
+        algorithm_module
+    )?)?;
     algorithm_module.add_function(wrap_pyfunction!(hits, algorithm_module)?)?;
     algorithm_module.add_function(wrap_pyfunction!(balance, algorithm_module)?)?;
 
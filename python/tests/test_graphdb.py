@@ -1045,6 +1045,34 @@ def test_connected_components():
     assert (actual.get('1') == 1)
 
 
+def test_strongly_connected_components():
+    g = Graph()
+    g.add_edge(3, 1, 2, {})
+    g.add_edge(2, 2, 3, {})
+    g.add_edge(1, 3, 1, {})
+    g.add_edge(4, 3, 4, {})
+    actual = algorithms.strongly_connected_components(g)
+    assert (actual.get_all() == {'1': 1, '2': 1, '3': 1, '4': 4})
+    temporal = algorithms.strongly_connected_components(g, temporal=True)
+    assert (temporal.get_all() == {'1': 1, '2': 2, '3': 2, '4': 4})
+
+
+def test_temporal_motif():
+    g = Graph()
+    g.add_edge(1, 1, 2, {"amount": 10})
+    g.add_edge(2, 2, 3, {"amount": 5})
+    g.add_edge(3, 3, 1, {"amount": 20})
+    g.add_edge(4, 1, 2, {"amount": 1})
+    triangle = [(0, 1), (1, 2), (2, 0)]
+    assert algorithms.temporal_motif_count(g, triangle, 5) == 2
+    assert algorithms.temporal_motif_instances(g, triangle, 5) == [
+        (['1', '2', '3'], [1, 2, 3]),
+        (['2', '3', '1'], [2, 3, 4]),
+    ]
+    filters = [[("amount", ">=", 10)], [("amount", "<", 10)]]
+    assert algorithms.temporal_motif_count(g, [(0, 1), (1, 2)], 10, filters=filters) == 2
+
+
 def test_algo_result():
     g = gen_graph()
 
@@ -1100,6 +1128,38 @@ def test_page_rank():
     assert (actual.get_all() == expected)
 
 
+def test_weighted_page_rank():
+    g = Graph()
+    g.add_edge(1, 1, 2, {"weight": 3.0})
+    g.add_edge(2, 1, 2, {"weight": 2.0})
+    g.add_edge(3, 1, 3, {"weight": 1.0})
+    g.add_edge(4, 2, 3, {"weight": 2.0})
+    g.add_edge(5, 3, 1, {"weight": 4.0})
+    g.add_edge(6, 3, 4, {"weight": 1.0})
+
+    actual = algorithms.pagerank(g, iter_count=1000, max_diff=1e-10, weight="weight")
+    expected = {'1': 0.28653, '2': 0.26567, '3': 0.32913, '4': 0.11867}
+    assert {k: round(v, 5) for k, v in actual.get_all().items()} == expected
+
+    actual = algorithms.pagerank(g, iter_count=1000, max_diff=1e-10, damping_factor=0.5, personalization={1: 1.0})
+    expected = {'1': 0.58182, '2': 0.14545, '3': 0.21818, '4': 0.05455}
+    assert {k: round(v, 5) for k, v in actual.get_all().items()} == expected
+
+
+def test_weighted_page_rank_integer_weights():
+    g = Graph()
+    g.add_edge(1, 1, 2, {"weight": 3})
+    g.add_edge(2, 1, 2, {"weight": 2})
+    g.add_edge(3, 1, 3, {"weight": 1})
+    g.add_edge(4, 2, 3, {"weight": 2})
+    g.add_edge(5, 3, 1, {"weight": 4})
+    g.add_edge(6, 3, 4, {"weight": 1})
+
+    actual = algorithms.pagerank(g, iter_count=1000, max_diff=1e-10, weight="weight")
+    expected = {'1': 0.28653, '2': 0.26567, '3': 0.32913, '4': 0.11867}
+    assert {k: round(v, 5) for k, v in actual.get_all().items()} == expected
+
+
 def test_temporal_reachability():
     g = gen_graph()
 
@@ -70,7 +70,7 @@ pub fn run_ingestion_benchmarks<F>(
         |b: &mut Bencher| {
             b.iter_batched_ref(
                 || (make_graph(), time_sample()),
-                |(g, t): &mut (Graph, i64)| g.add_vertex(*t, 0, NO_PROPS),
+                |(g, t): &mut (Graph, i64)| g.add_vertex(*t, 0, NO_PROPS, None),
                 BatchSize::SmallInput,
             )
         },
@@ -82,7 +82,7 @@ pub fn run_ingestion_benchmarks<F>(
         |b: &mut Bencher| {
             b.iter_batched_ref(
                 || (make_graph(), index_sample()),
-                |(g, v): &mut (Graph, u64)| g.add_vertex(0, *v, NO_PROPS),
+                |(g, v): &mut (Graph, u64)| g.add_vertex(0, *v, NO_PROPS, None),
                 BatchSize::SmallInput,
             )
         },
@@ -22,11 +22,16 @@ pub enum UrlDecodeError {
     },
 }
 
+/// Encode a graph as url-safe base64 for sending over GraphQL
+///
+/// This uses the raw bincode of the graph and not the versioned file format, so it is only
+/// meant for transport between a client and server running the same release.
 pub fn url_encode_graph<G: Into<MaterializedGraph>>(graph: G) -> Result<String, GraphError> {
     let g: MaterializedGraph = graph.into();
     Ok(BASE64_URL_SAFE_NO_PAD.encode(bincode::serialize(&g)?))
 }
 
+/// Decode a graph produced by [`url_encode_graph`]
 pub fn url_decode_graph<T: AsRef<[u8]>>(graph: T) -> Result<MaterializedGraph, UrlDecodeError> {
     Ok(bincode::deserialize(
         &BASE64_URL_SAFE_NO_PAD.decode(graph)?,
@@ -48,10 +53,20 @@ mod graphql_test {
     async fn search_for_gandalf_query() {
         let graph = Graph::new();
         graph
-            .add_vertex(0, "Gandalf", [("kind".to_string(), Prop::str("wizard"))])
+            .add_vertex(
+                0,
+                "Gandalf",
+                [("kind".to_string(), Prop::str("wizard"))],
+                None,
+            )
             .expect("Could not add vertex!");
         graph
-            .add_vertex(0, "Frodo", [("kind".to_string(), Prop::str("Hobbit"))])
+            .add_vertex(
+                0,
+                "Frodo",
+                [("kind".to_string(), Prop::str("Hobbit"))],
+                None,
+            )
             .expect("Could not add vertex!");
 
         let graphs = HashMap::from([("lotr".to_string(), graph.into_dynamic())]);
@@ -89,7 +104,7 @@ mod graphql_test {
     async fn basic_query() {
         let graph = Graph::new();
         graph
-            .add_vertex(0, 11, NO_PROPS)
+            .add_vertex(0, 11, NO_PROPS, None)
             .expect("Could not add vertex!");
 
         let graphs = HashMap::from([("lotr".to_string(), graph.into_dynamic())]);
@@ -127,13 +142,13 @@ mod graphql_test {
     #[tokio::test]
     async fn query_nodefilter() {
         let graph = Graph::new();
-        if let Err(err) = graph.add_vertex(0, "gandalf", NO_PROPS) {
+        if let Err(err) = graph.add_vertex(0, "gandalf", NO_PROPS, None) {
             panic!("Could not add vertex! {:?}", err);
         }
-        if let Err(err) = graph.add_vertex(0, "bilbo", NO_PROPS) {
+        if let Err(err) = graph.add_vertex(0, "bilbo", NO_PROPS, None) {
             panic!("Could not add vertex! {:?}", err);
         }
-        if let Err(err) = graph.add_vertex(0, "frodo", NO_PROPS) {
+        if let Err(err) = graph.add_vertex(0, "frodo", NO_PROPS, None) {
             panic!("Could not add vertex! {:?}", err);
         }
 
@@ -196,16 +211,74 @@ mod graphql_test {
         );
     }
 
+    #[tokio::test]
+    async fn query_type_property() {
+        let graph = Graph::new();
+        graph
+            .add_vertex(0, "gandalf", [("type", Prop::str("wizard"))], None)
+            .expect("Could not add vertex!");
+        graph
+            .add_vertex(0, "bilbo", [("type", Prop::str("hobbit"))], None)
+            .expect("Could not add vertex!");
+        graph
+            .add_vertex(0, "frodo", [("type", Prop::str("hobbit"))], None)
+            .expect("Could not add vertex!");
+
+        let graphs = HashMap::from([("lotr".to_string(), graph.into_dynamic())]);
+        let data = Data::from_map(graphs);
+
+        let schema = App::create_schema().data(data).finish().unwrap();
+
+        let query = r#"
+        {
+          graph(name: "lotr") {
+            nodes(filter: { nodeType: { eq: "hobbit" } }) {
+              name
+              nodeType
+            }
+            schema {
+              nodes {
+                typeName
+              }
+            }
+          }
+        }
+        "#;
+
+        let req = Request::new(query);
+        let res = schema.execute(req).await;
+        let data = res.data.into_json().unwrap();
+
+        assert_eq!(
+            data,
+            json!({
+                "graph": {
+                    "nodes": [
+                        { "name": "bilbo", "nodeType": "hobbit" },
+                        { "name": "frodo", "nodeType": "hobbit" }
+                    ],
+                    "schema": {
+                        "nodes": [
+                            { "typeName": "wizard" },
+                            { "typeName": "hobbit" }
+                        ]
+                    }
+                }
+            }),
+        );
+    }
+
     #[tokio::test]
     async fn query_properties() {
         let graph = Graph::new();
-        if let Err(err) = graph.add_vertex(0, "gandalf", NO_PROPS) {
+        if let Err(err) = graph.add_vertex(0, "gandalf", NO_PROPS, None) {
             panic!("Could not add vertex! {:?}", err);
         }
         if let Err(err) = graph.add_vertex(
             0,
             "bilbo",
             [("food".to_string(), Prop::Str("lots".to_string()))],
+            None,
         ) {
             panic!("Could not add vertex! {:?}", err);
         }
@@ -213,6 +286,7 @@ mod graphql_test {
             0,
             "frodo",
             [("food".to_string(), Prop::Str("some".to_string()))],
+            None,
         ) {
             panic!("Could not add vertex! {:?}", err);
         }
@@ -288,10 +362,10 @@ mod graphql_test {
         g0.save_to_file(f0).unwrap();
 
         let g1 = Graph::new();
-        g1.add_vertex(0, 1, NO_PROPS).unwrap();
+        g1.add_vertex(0, 1, NO_PROPS, None).unwrap();
 
         let g2 = Graph::new();
-        g2.add_vertex(0, 2, NO_PROPS).unwrap();
+        g2.add_vertex(0, 2, NO_PROPS, None).unwrap();
 
         let data = Data::default();
         let schema = App::create_schema().data(data).finish().unwrap();
@@ -386,7 +460,7 @@ mod graphql_test {
     #[tokio::test]
     async fn test_graph_injection() {
         let g = Graph::new();
-        g.add_vertex(0, 1, NO_PROPS).unwrap();
+        g.add_vertex(0, 1, NO_PROPS, None).unwrap();
         let tmp_file = tempfile::NamedTempFile::new().unwrap();
         let path = tmp_file.path();
         g.save_to_file(path).unwrap();
@@ -436,7 +510,7 @@ mod graphql_test {
     #[tokio::test]
     async fn test_graph_send_receive_base64() {
         let g = Graph::new();
-        g.add_vertex(0, 1, NO_PROPS).unwrap();
+        g.add_vertex(0, 1, NO_PROPS, None).unwrap();
 
         let graph_str = url_encode_graph(g.clone()).unwrap();
 
@@ -486,4 +560,118 @@ mod graphql_test {
         let graph_roundtrip = url_decode_graph(graph_encoded).unwrap().into_dynamic();
         assert_eq!(g, graph_roundtrip);
     }
+
+    #[tokio::test]
+    async fn personalized_pagerank_query() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, [("weight", 3.0)], None).unwrap();
+        graph.add_edge(1, 2, 1, [("weight", 1.0)], None).unwrap();
+        graph.add_edge(2, 2, 3, [("weight", 1.0)], None).unwrap();
+
+        let graphs = HashMap::from([("test".to_string(), graph.into_dynamic())]);
+        let data = data::Data::from_map(graphs);
+        let schema = App::create_schema().data(data).finish().unwrap();
+
+        let query = r#"
+        {
+          graph(name: "test") {
+            algorithms {
+              pagerank(iterCount: 100, weight: "weight", dampingFactor: 0.5, seeds: ["1"]) {
+                name
+                rank
+              }
+            }
+          }
+        }
+        "#;
+        let res = schema.execute(Request::new(query)).await;
+        assert_eq!(res.errors.len(), 0);
+        let res_json = res.data.into_json().unwrap();
+        let ranks: HashMap<String, f64> = res_json["graph"]["algorithms"]["pagerank"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| {
+                (
+                    v["name"].as_str().unwrap().to_string(),
+                    v["rank"].as_f64().unwrap(),
+                )
+            })
+            .collect();
+        assert!(ranks["1"] > ranks["2"]);
+        assert!(ranks["2"] > ranks["3"]);
+
+        let query = r#"
+        {
+          graph(name: "test") {
+            algorithms {
+              pagerank(iterCount: 100, seeds: ["1"], seedWeights: [1.0, 2.0]) {
+                name
+              }
+            }
+          }
+        }
+        "#;
+        let res = schema.execute(Request::new(query)).await;
+        assert_eq!(res.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn temporal_motif_query() {
+        let graph = Graph::new();
+        graph.add_edge(1, 1, 2, [("amount", 10i64)], None).unwrap();
+        graph.add_edge(2, 2, 3, [("amount", 5i64)], None).unwrap();
+        graph.add_edge(3, 3, 1, [("amount", 20i64)], None).unwrap();
+        graph.add_edge(4, 1, 2, [("amount", 1i64)], None).unwrap();
+
+        let graphs = HashMap::from([("test".to_string(), graph.into_dynamic())]);
+        let data = data::Data::from_map(graphs);
+        let schema = App::create_schema().data(data).finish().unwrap();
+
+        let query = r#"
+        {
+          graph(name: "test") {
+            algorithms {
+              temporalMotifs(sources: [0, 1, 2], destinations: [1, 2, 0], delta: 5) {
+                vertices
+                times
+              }
+            }
+          }
+        }
+        "#;
+        let res = schema.execute(Request::new(query)).await;
+        assert_eq!(res.errors, vec![]);
+        let res_json = res.data.into_json().unwrap();
+        assert_eq!(
+            res_json["graph"]["algorithms"]["temporalMotifs"],
+            json!([
+                {"vertices": ["1", "2", "3"], "times": [1, 2, 3]},
+                {"vertices": ["2", "3", "1"], "times": [2, 3, 4]},
+            ])
+        );
+
+        let query = r#"
+        {
+          graph(name: "test") {
+            algorithms {
+              temporalMotifs(
+                sources: [0, 1], destinations: [1, 2], delta: 10,
+                filterEdges: [0, 1], filterProperties: ["amount", "amount"],
+                filterComparisons: [">=", "<"], filterValues: ["10", "10"]
+              ) {
+                vertices
+              }
+            }
+          }
+        }
+        "#;
+        let res = schema.execute(Request::new(query)).await;
+        assert_eq!(res.errors, vec![]);
+        let res_json = res.data.into_json().unwrap();
+        assert_eq!(
+            res_json["graph"]["algorithms"]["temporalMotifs"],
+            json!([{"vertices": ["1", "2", "3"]}, {"vertices": ["3", "1", "2"]}])
+        );
+    }
 }
@@ -9,7 +9,13 @@ use dynamic_graphql::{
 use once_cell::sync::Lazy;
 use ordered_float::OrderedFloat;
 use raphtory::{
-    algorithms::pagerank::unweighted_page_rank,
+    algorithms::{
+        motifs::temporal_motif_matcher::{
+            temporal_motif_instances, Comparison, PropertyFilter, TemporalMotif,
+        },
+        pagerank::weighted_page_rank,
+    },
+    core::Prop,
     db::api::view::{internal::DynamicGraph, GraphViewOps},
 };
 use std::{borrow::Cow, collections::HashMap, sync::Mutex};
@@ -34,7 +40,10 @@ impl Register for Algorithms {
         let mut registry = registry;
         let mut object = Object::new("Algorithms");
 
-        let algos = HashMap::from([("pagerank", Pagerank::register_algo)]);
+        let algos = HashMap::from([
+            ("pagerank", Pagerank::register_algo as RegisterFunction),
+            ("temporalMotifs", MotifInstance::register_algo),
+        ]);
         for (name, register_algo) in algos {
             (registry, object) = register_algo(name, registry, object);
         }
@@ -115,6 +124,10 @@ impl Algorithm for Pagerank {
             ("iterCount", TypeRef::named_nn(TypeRef::INT)), // _nn stands for not null
             ("threads", TypeRef::named(TypeRef::INT)),      // this one though might be null
             ("tol", TypeRef::named(TypeRef::FLOAT)),
+            ("weight", TypeRef::named(TypeRef::STRING)),
+            ("dampingFactor", TypeRef::named(TypeRef::FLOAT)),
+            ("seeds", TypeRef::named_nn_list(TypeRef::STRING)),
+            ("seedWeights", TypeRef::named_nn_list(TypeRef::FLOAT)),
         ]
     }
     fn apply_algo<'a, G: GraphViewOps>(
@@ -125,10 +138,167 @@ impl Algorithm for Pagerank {
         let threads = ctx.args.get("threads").map(|v| v.u64()).transpose()?;
         let threads = threads.map(|v| v as usize);
         let tol = ctx.args.get("tol").map(|v| v.f64()).transpose()?;
-        let binding = unweighted_page_rank(graph, iter_count, threads, tol, true);
+        let weight = ctx
+            .args
+            .get("weight")
+            .map(|v| v.string().map(|s| s.to_string()))
+            .transpose()?;
+        let damping_factor = ctx.args.get("dampingFactor").map(|v| v.f64()).transpose()?;
+        let personalization = match ctx.args.get("seeds") {
+            None => None,
+            Some(seeds) => {
+                let seeds = seeds
+                    .list()?
+                    .iter()
+                    .map(|v| v.string().map(|s| s.to_string()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                // seeds are weighted uniformly unless weights are given
+                let seed_weights = match ctx.args.get("seedWeights") {
+                    None => vec![1f64; seeds.len()],
+                    Some(weights) => weights
+                        .list()?
+                        .iter()
+                        .map(|v| v.f64())
+                        .collect::<Result<Vec<_>, _>>()?,
+                };
+                if seed_weights.len() != seeds.len() {
+                    return Err("seeds and seedWeights need to have the same length".into());
+                }
+                Some(seeds.into_iter().zip(seed_weights).collect())
+            }
+        };
+        let binding = weighted_page_rank(
+            graph,
+            iter_count,
+            threads,
+            tol,
+            true,
+            weight,
+            damping_factor,
+            personalization,
+        );
         let result = binding
             .into_iter()
             .map(|pair| FieldValue::owned_any(Pagerank::from(pair)));
         Ok(Some(FieldValue::list(result)))
     }
 }
+
+#[derive(SimpleObject)]
+struct MotifInstance {
+    vertices: Vec<String>,
+    times: Vec<i64>,
+}
+
+/// Parses a filter value given as a string into an integer, float, boolean or string property
+fn parse_prop(value: &str) -> Prop {
+    if let Ok(v) = value.parse::<i64>() {
+        Prop::I64(v)
+    } else if let Ok(v) = value.parse::<f64>() {
+        Prop::F64(v)
+    } else if let Ok(v) = value.parse::<bool>() {
+        Prop::Bool(v)
+    } else {
+        Prop::str(value)
+    }
+}
+
+fn list_arg<T>(
+    ctx: &ResolverContext,
+    name: &str,
+    f: impl Fn(async_graphql::dynamic::ValueAccessor) -> async_graphql::Result<T>,
+) -> FieldResult<Vec<T>> {
+    match ctx.args.get(name) {
+        None => Ok(vec![]),
+        Some(list) => list.list()?.iter().map(f).collect(),
+    }
+}
+
+impl Algorithm for MotifInstance {
+    fn output_type() -> TypeRef {
+        TypeRef::named_nn_list_nn(Self::get_type_name())
+    }
+    fn args<'a>() -> Vec<(&'a str, TypeRef)> {
+        vec![
+            // the motif edges between the vertex variables given by sources[i] -> destinations[i]
+            ("sources", TypeRef::named_nn_list_nn(TypeRef::INT)),
+            ("destinations", TypeRef::named_nn_list_nn(TypeRef::INT)),
+            ("delta", TypeRef::named_nn(TypeRef::INT)),
+            // one optional layer per motif edge
+            ("layers", TypeRef::named_list(TypeRef::STRING)),
+            // property filters, the i-th filter applies to the motif edge filterEdges[i]
+            ("filterEdges", TypeRef::named_nn_list(TypeRef::INT)),
+            ("filterProperties", TypeRef::named_nn_list(TypeRef::STRING)),
+            ("filterComparisons", TypeRef::named_nn_list(TypeRef::STRING)),
+            ("filterValues", TypeRef::named_nn_list(TypeRef::STRING)),
+            ("threads", TypeRef::named(TypeRef::INT)),
+        ]
+    }
+    fn apply_algo<'a, G: GraphViewOps>(
+        graph: &G,
+        ctx: ResolverContext,
+    ) -> FieldResult<Option<FieldValue<'a>>> {
+        let sources = list_arg(&ctx, "sources", |v| Ok(v.u64()? as usize))?;
+        let destinations = list_arg(&ctx, "destinations", |v| Ok(v.u64()? as usize))?;
+        if sources.len() != destinations.len() {
+            return Err("sources and destinations need to have the same length".into());
+        }
+        let delta = ctx.args.try_get("delta")?.i64()?;
+        let layers = match ctx.args.get("layers") {
+            None => vec![None; sources.len()],
+            Some(layers) => layers
+                .list()?
+                .iter()
+                .map(|v| {
+                    if v.is_null() {
+                        Ok(None)
+                    } else {
+                        v.string().map(|s| Some(s.to_string()))
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+        if layers.len() != sources.len() {
+            return Err("layers need one entry per motif edge".into());
+        }
+        let mut motif = TemporalMotif::new(delta);
+        for ((src, dst), layer) in sources.into_iter().zip(destinations).zip(layers) {
+            motif = motif.layered_edge(src, dst, layer.as_deref());
+        }
+
+        let filter_edges = list_arg(&ctx, "filterEdges", |v| Ok(v.u64()? as usize))?;
+        let properties = list_arg(&ctx, "filterProperties", |v| Ok(v.string()?.to_string()))?;
+        let comparisons = list_arg(&ctx, "filterComparisons", |v| {
+            Ok(v.string()?.parse::<Comparison>()?)
+        })?;
+        let values = list_arg(&ctx, "filterValues", |v| Ok(parse_prop(v.string()?)))?;
+        let num_filters = filter_edges.len();
+        if [properties.len(), comparisons.len(), values.len()] != [num_filters; 3] {
+            return Err("all filter arguments need to have the same length".into());
+        }
+        for (((edge, name), comparison), value) in filter_edges
+            .into_iter()
+            .zip(properties)
+            .zip(comparisons)
+            .zip(values)
+        {
+            let edge = motif
+                .edges
+                .get_mut(edge)
+                .ok_or("filterEdges refers to a missing motif edge")?;
+            edge.filters
+                .push(PropertyFilter::new(&name, comparison, value));
+        }
+
+        let threads = ctx.args.get("threads").map(|v| v.u64()).transpose()?;
+        let result = temporal_motif_instances(graph, &motif, threads.map(|v| v as usize))
+            .into_iter()
+            .map(|instance| {
+                FieldValue::owned_any(MotifInstance {
+                    vertices: instance.vertices,
+                    times: instance.times,
+                })
+            });
+        Ok(Some(FieldValue::list(result)))
+    }
+}
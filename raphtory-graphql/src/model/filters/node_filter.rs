@@ -4,6 +4,7 @@ use crate::model::{
         property_filter::PropertyHasFilter,
     },
     graph::node::Node,
+    schema::get_vertex_type,
 };
 use dynamic_graphql::InputObject;
 use raphtory::db::api::view::VertexViewOps;
@@ -44,12 +45,7 @@ impl NodeFilter {
         }
 
         if let Some(type_filter) = &self.node_type {
-            let node_type = node
-                .vv
-                .properties()
-                .get("type")
-                .map(|v| v.to_string())
-                .unwrap_or("NONE".to_string());
+            let node_type = get_vertex_type(node.vv.clone());
             if !type_filter.matches(&node_type) {
                 return false;
             }
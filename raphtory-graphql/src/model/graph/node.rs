@@ -1,6 +1,7 @@
 use crate::model::{
     filters::edge_filter::EdgeFilter,
     graph::{edge::Edge, get_expanded_edges, property::Property, property_update::PropertyUpdate},
+    schema::get_vertex_type,
 };
 use dynamic_graphql::{ResolvedObject, ResolvedObjectFields};
 use itertools::Itertools;
@@ -42,11 +43,7 @@ impl Node {
     }
 
     pub async fn node_type(&self) -> String {
-        self.vv
-            .properties()
-            .get("type")
-            .map(|p| p.to_string())
-            .unwrap_or("NONE".to_string())
+        get_vertex_type(self.vv.clone())
     }
 
     /// Returns all the property names this node has a value for
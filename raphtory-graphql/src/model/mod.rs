@@ -120,7 +120,7 @@ impl Mut {
     ///    name of the new graph
     async fn upload_graph<'a>(ctx: &Context<'a>, name: String, graph: Upload) -> Result<String> {
         let g: MaterializedGraph =
-            bincode::deserialize_from(BufReader::new(graph.value(ctx)?.content))?;
+            MaterializedGraph::load_from_reader(BufReader::new(graph.value(ctx)?.content))?;
         let gi: IndexedGraph<DynamicGraph> = g.into_dynamic().into();
         let mut data = ctx.data_unchecked::<Data>().graphs.write();
         data.insert(name.clone(), gi.clone());
@@ -129,6 +129,10 @@ impl Mut {
 
     /// Send graph bincode as base64 encoded string
     ///
+    /// Unlike `upload_graph`, this takes the raw bincode of the graph produced by
+    /// `url_encode_graph` rather than the versioned file format, so client and server
+    /// need to run the same release.
+    ///
     /// # Returns:
     ///    name of the new graph
     async fn send_graph<'a>(ctx: &Context<'a>, name: String, graph: String) -> Result<String> {
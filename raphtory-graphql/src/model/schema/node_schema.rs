@@ -1,4 +1,6 @@
-use crate::model::schema::{merge_schemas, property_schema::PropertySchema, SchemaAggregate};
+use crate::model::schema::{
+    get_vertex_type, merge_schemas, property_schema::PropertySchema, SchemaAggregate,
+};
 use dynamic_graphql::{ResolvedObject, ResolvedObjectFields};
 use itertools::Itertools;
 use raphtory::{
@@ -30,15 +32,11 @@ impl NodeSchema {
 
     /// Returns the list of property schemas for this node
     async fn properties(&self) -> Vec<PropertySchema> {
-        let filter_type = |vertex: &VertexView<DynamicGraph>| match vertex.properties().get("type")
-        {
-            Some(node_type) => node_type.to_string() == self.type_name,
-            None => false,
-        };
-
-        let filtered_vertices = self.graph.vertices().iter().filter(filter_type);
-
-        let schema: SchemaAggregate = filtered_vertices
+        let schema: SchemaAggregate = self
+            .graph
+            .vertices()
+            .iter()
+            .filter(|vertex| get_vertex_type(vertex.clone()) == self.type_name)
             .map(collect_vertex_schema)
             .reduce(merge_schemas)
             .unwrap_or_else(|| HashMap::new());
@@ -47,7 +45,7 @@ impl NodeSchema {
     }
 }
 
-fn collect_vertex_schema(vertex: VertexView<DynamicGraph>) -> SchemaAggregate {
+fn collect_vertex_schema<G: GraphViewOps>(vertex: VertexView<G>) -> SchemaAggregate {
     let pairs = vertex
         .properties()
         .iter()
@@ -0,0 +1,566 @@
+//! # Centrality
+//!
+//! Shortest path based centrality measures: betweenness (Brandes, 2001), closeness and harmonic
+//! centrality. The single source searches run in parallel on the rayon pool of the task runner.
+//!
+//! The static measures ignore the timestamps of the edges. Parallel edges between two vertices are
+//! merged, when weighted the lightest of them is used. The weight of an edge is the latest value of
+//! the weight property in the view, edges whose weight is missing, not numeric or negative are
+//! ignored.
+//!
+//! The temporal betweenness only considers time-respecting paths, i.e. paths along edge updates with
+//! strictly increasing timestamps, and counts the paths that reach each target at the earliest
+//! possible time.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::algorithms::centrality::betweenness_centrality;
+//! use raphtory::core::Direction;
+//! use raphtory::prelude::*;
+//!
+//! let graph = Graph::new();
+//! graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+//! graph.add_edge(0, 2, 3, NO_PROPS, None).unwrap();
+//! let result = betweenness_centrality(&graph, None, None, Direction::OUT, false, None, None);
+//! assert_eq!(result.get(&"2".to_string()).unwrap().0, 1.0);
+//! ```
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        shortest_paths::weighted_neighbours,
+        utils::{seeded_rng, thread_pool, VertexIndex},
+    },
+    core::Direction,
+    prelude::*,
+};
+use ordered_float::OrderedFloat;
+use rand::prelude::*;
+use rayon::prelude::*;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+/// Merged neighbourhoods of the vertices with consecutive indices
+struct Adjacency {
+    names: Vec<String>,
+    neighbours: Vec<Vec<(usize, f64)>>,
+    weighted: bool,
+}
+
+impl Adjacency {
+    fn new<G: GraphViewOps>(g: &G, weight: Option<&str>, direction: Direction) -> Self {
+        let index = VertexIndex::new(g);
+        let neighbours = index
+            .vertices()
+            .iter()
+            .map(|v| {
+                let mut merged: HashMap<usize, f64> = HashMap::new();
+                for (n, w) in weighted_neighbours(g, *v, direction, weight) {
+                    if n != *v {
+                        let entry = merged.entry(index.index(n)).or_insert(w);
+                        *entry = entry.min(w);
+                    }
+                }
+                let mut merged: Vec<_> = merged.into_iter().collect();
+                merged.sort_by_key(|(n, _)| *n);
+                merged
+            })
+            .collect();
+        Self {
+            names: index.names(g),
+            neighbours,
+            weighted: weight.is_some(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    fn result(&self, values: Vec<f64>) -> AlgorithmResult<String, OrderedFloat<f64>> {
+        AlgorithmResult::new_with_float(self.names.iter().cloned().zip(values).collect())
+    }
+}
+
+/// Shortest paths from a single source
+struct SingleSource {
+    /// The reached vertices in order of non-decreasing distance
+    order: Vec<usize>,
+    distances: Vec<f64>,
+    /// The number of shortest paths to each vertex
+    sigma: Vec<f64>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+fn single_source(adj: &Adjacency, source: usize) -> SingleSource {
+    let n = adj.len();
+    let mut order = vec![];
+    let mut distances = vec![f64::INFINITY; n];
+    let mut sigma = vec![0f64; n];
+    let mut predecessors = vec![vec![]; n];
+    distances[source] = 0f64;
+    sigma[source] = 1f64;
+    if adj.weighted {
+        let mut done = vec![false; n];
+        let mut queue = BinaryHeap::from([Reverse((OrderedFloat(0f64), source))]);
+        while let Some(Reverse((OrderedFloat(d), v))) = queue.pop() {
+            if done[v] {
+                continue;
+            }
+            done[v] = true;
+            order.push(v);
+            for (u, w) in &adj.neighbours[v] {
+                let du = d + w;
+                if du < distances[*u] {
+                    distances[*u] = du;
+                    sigma[*u] = sigma[v];
+                    predecessors[*u] = vec![v];
+                    queue.push(Reverse((OrderedFloat(du), *u)));
+                } else if du == distances[*u] && !done[*u] {
+                    sigma[*u] += sigma[v];
+                    predecessors[*u].push(v);
+                }
+            }
+        }
+    } else {
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            let du = distances[v] + 1f64;
+            for (u, _) in &adj.neighbours[v] {
+                if distances[*u].is_infinite() {
+                    distances[*u] = du;
+                    queue.push_back(*u);
+                }
+                if distances[*u] == du {
+                    sigma[*u] += sigma[v];
+                    predecessors[*u].push(v);
+                }
+            }
+        }
+    }
+    SingleSource {
+        order,
+        distances,
+        sigma,
+        predecessors,
+    }
+}
+
+/// Sums the per source contributions on the task runner pool
+fn sum_over_sources<F>(n: usize, sources: Vec<usize>, threads: Option<usize>, f: F) -> Vec<f64>
+where
+    F: Fn(usize, &mut Vec<f64>) + Sync,
+{
+    thread_pool(threads).install(|| {
+        sources
+            .into_par_iter()
+            .fold(
+                || vec![0f64; n],
+                |mut acc, s| {
+                    f(s, &mut acc);
+                    acc
+                },
+            )
+            .reduce(
+                || vec![0f64; n],
+                |mut a, b| {
+                    a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                    a
+                },
+            )
+    })
+}
+
+/// Picks `k` random sources (all vertices if `k` is `None`) and the factor to rescale the sums by
+fn sample_sources(n: usize, k: Option<usize>, seed: Option<u64>) -> (Vec<usize>, f64) {
+    match k {
+        Some(k) if k < n => {
+            let sources = (0..n).choose_multiple(&mut seeded_rng(seed), k);
+            (sources, n as f64 / k as f64)
+        }
+        _ => ((0..n).collect(), 1f64),
+    }
+}
+
+/// Rescales raw betweenness values, which count every ordered pair of vertices
+fn rescale(values: &mut [f64], scale: f64, undirected: bool, normalized: bool) {
+    let n = values.len() as f64;
+    let factor = if normalized {
+        if n > 2f64 {
+            1f64 / ((n - 1f64) * (n - 2f64))
+        } else {
+            1f64
+        }
+    } else if undirected {
+        0.5
+    } else {
+        1f64
+    };
+    values.iter_mut().for_each(|v| *v *= factor * scale);
+}
+
+/// Betweenness centrality
+///
+/// The betweenness of a vertex is the sum over all pairs of other vertices of the fraction of
+/// shortest paths between them that pass through the vertex.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `k`: If set, the betweenness is estimated from `k` randomly sampled source vertices
+/// * `weight`: The name of the edge property holding the weights, if `None` the number of hops is used
+/// * `direction`: The direction in which edges are followed, `BOTH` treats the graph as undirected
+/// * `normalized`: If true the values are divided by `(n - 1)(n - 2)`
+/// * `seed`: The seed for sampling the sources
+/// * `threads`: Number of threads to use
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping vertex names to their betweenness
+///
+pub fn betweenness_centrality<G: GraphViewOps>(
+    g: &G,
+    k: Option<usize>,
+    weight: Option<&str>,
+    direction: Direction,
+    normalized: bool,
+    seed: Option<u64>,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, OrderedFloat<f64>> {
+    let adj = Adjacency::new(g, weight, direction);
+    let n = adj.len();
+    let (sources, scale) = sample_sources(n, k, seed);
+    let mut values = sum_over_sources(n, sources, threads, |s, acc| {
+        let paths = single_source(&adj, s);
+        let mut delta = vec![0f64; n];
+        for w in paths.order.iter().rev() {
+            for v in &paths.predecessors[*w] {
+                delta[*v] += paths.sigma[*v] / paths.sigma[*w] * (1f64 + delta[*w]);
+            }
+            if *w != s {
+                acc[*w] += delta[*w];
+            }
+        }
+    });
+    rescale(
+        &mut values,
+        scale,
+        matches!(direction, Direction::BOTH),
+        normalized,
+    );
+    adj.result(values)
+}
+
+/// Closeness centrality
+///
+/// The closeness of a vertex is the inverse of its average distance to the vertices it reaches,
+/// scaled by the fraction of the other vertices it reaches (Wasserman and Faust) so that vertices
+/// in small components do not get inflated values.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `weight`: The name of the edge property holding the weights, if `None` the number of hops is used
+/// * `direction`: The direction in which edges are followed from the vertex, use `IN` to measure
+///   the distances from the other vertices to the vertex
+/// * `threads`: Number of threads to use
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping vertex names to their closeness
+///
+pub fn closeness_centrality<G: GraphViewOps>(
+    g: &G,
+    weight: Option<&str>,
+    direction: Direction,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, OrderedFloat<f64>> {
+    let adj = Adjacency::new(g, weight, direction);
+    let n = adj.len();
+    let values = sum_over_sources(n, (0..n).collect(), threads, |s, acc| {
+        let paths = single_source(&adj, s);
+        let total: f64 = paths.order.iter().map(|v| paths.distances[*v]).sum();
+        let reached = (paths.order.len() - 1) as f64;
+        if total > 0f64 && n > 1 {
+            acc[s] = reached / total * reached / (n - 1) as f64;
+        }
+    });
+    adj.result(values)
+}
+
+/// Harmonic centrality
+///
+/// The harmonic centrality of a vertex is the sum of the inverse distances to all other vertices,
+/// unreachable vertices contribute 0.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `weight`: The name of the edge property holding the weights, if `None` the number of hops is used
+/// * `direction`: The direction in which edges are followed from the vertex
+/// * `threads`: Number of threads to use
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping vertex names to their harmonic centrality
+///
+pub fn harmonic_centrality<G: GraphViewOps>(
+    g: &G,
+    weight: Option<&str>,
+    direction: Direction,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, OrderedFloat<f64>> {
+    let adj = Adjacency::new(g, weight, direction);
+    let n = adj.len();
+    let values = sum_over_sources(n, (0..n).collect(), threads, |s, acc| {
+        let paths = single_source(&adj, s);
+        acc[s] = paths
+            .order
+            .iter()
+            .map(|v| paths.distances[*v])
+            .filter(|d| *d > 0f64)
+            .map(|d| 1f64 / d)
+            .sum();
+    });
+    adj.result(values)
+}
+
+/// Temporal betweenness centrality
+///
+/// Like the betweenness, but only counting the time-respecting paths (along edge updates with
+/// strictly increasing timestamps) that reach their target at the earliest possible time. Edges
+/// are followed in their direction.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `k`: If set, the betweenness is estimated from `k` randomly sampled source vertices
+/// * `normalized`: If true the values are divided by `(n - 1)(n - 2)`
+/// * `seed`: The seed for sampling the sources
+/// * `threads`: Number of threads to use
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping vertex names to their temporal betweenness
+///
+pub fn temporal_betweenness_centrality<G: GraphViewOps>(
+    g: &G,
+    k: Option<usize>,
+    normalized: bool,
+    seed: Option<u64>,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, OrderedFloat<f64>> {
+    let index = VertexIndex::new(g);
+    let n = index.len();
+    let events = index.updates(g);
+    // the ranges of the events with the same timestamp
+    let mut groups = vec![];
+    let mut start = 0;
+    for i in 1..=events.len() {
+        if i == events.len() || events[i].0 != events[start].0 {
+            groups.push(start..i);
+            start = i;
+        }
+    }
+
+    let (sources, scale) = sample_sources(n, k, seed);
+    let mut values = sum_over_sources(n, sources, threads, |s, acc| {
+        // number of time-respecting paths from the source ending with each event
+        let mut count = vec![0f64; events.len()];
+        let mut arrived = vec![0f64; n];
+        let mut earliest = vec![i64::MAX; n];
+        for group in &groups {
+            for i in group.clone() {
+                let (t, src, dst) = events[i];
+                if dst != s {
+                    count[i] = if src == s { 1f64 } else { arrived[src] };
+                    if count[i] > 0f64 && t < earliest[dst] {
+                        earliest[dst] = t;
+                    }
+                }
+            }
+            for i in group.clone() {
+                arrived[events[i].2] += count[i];
+            }
+        }
+        let mut total = vec![0f64; n];
+        for (i, (t, _, dst)) in events.iter().enumerate() {
+            if *t == earliest[*dst] {
+                total[*dst] += count[i];
+            }
+        }
+        // fraction of the earliest arrival paths per path from the source to each event that
+        // continue along later events
+        let mut outgoing = vec![0f64; n];
+        let mut dependency = vec![0f64; events.len()];
+        for group in groups.iter().rev() {
+            for i in group.clone() {
+                let (t, _, dst) = events[i];
+                if count[i] > 0f64 {
+                    let through = count[i] * outgoing[dst];
+                    acc[dst] += through;
+                    dependency[i] = through;
+                    if t == earliest[dst] {
+                        dependency[i] += count[i] / total[dst];
+                    }
+                }
+            }
+            for i in group.clone() {
+                if count[i] > 0f64 {
+                    outgoing[events[i].1] += dependency[i] / count[i];
+                }
+            }
+        }
+    });
+    rescale(&mut values, scale, false, normalized);
+    AlgorithmResult::new_with_float(index.names(g).into_iter().zip(values).collect())
+}
+
+#[cfg(test)]
+mod centrality_test {
+    use super::*;
+
+    fn assert_values(
+        result: &AlgorithmResult<String, OrderedFloat<f64>>,
+        expected: &[(&str, f64)],
+    ) {
+        assert_eq!(result.get_all().len(), expected.len());
+        for (name, value) in expected {
+            let actual = result.get(&name.to_string()).unwrap().0;
+            assert!(
+                (actual - value).abs() < 1e-9,
+                "{name}: expected {value}, got {actual}"
+            );
+        }
+    }
+
+    /// A square 1 - 2 - 3 - 4 - 1 with a tail 4 - 5, the values were computed with networkx
+    fn square_with_tail() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst, w) in [
+            (0, 1, 2, 1.0),
+            (1, 2, 3, 1.0),
+            (2, 3, 4, 1.0),
+            (3, 4, 1, 5.0),
+            (4, 4, 5, 1.0),
+        ] {
+            graph.add_edge(t, src, dst, [("weight", w)], None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn betweenness() {
+        let graph = square_with_tail();
+        let result = betweenness_centrality(&graph, None, None, Direction::BOTH, false, None, None);
+        assert_values(
+            &result,
+            &[("1", 1.0), ("2", 0.5), ("3", 1.0), ("4", 3.5), ("5", 0.0)],
+        );
+        let result = betweenness_centrality(
+            &graph,
+            None,
+            Some("weight"),
+            Direction::BOTH,
+            true,
+            None,
+            Some(2),
+        );
+        assert_values(
+            &result,
+            &[
+                ("1", 0.0),
+                ("2", 3.0 / 6.0),
+                ("3", 4.0 / 6.0),
+                ("4", 3.0 / 6.0),
+                ("5", 0.0),
+            ],
+        );
+        let result = betweenness_centrality(&graph, None, None, Direction::OUT, false, None, None);
+        assert_values(
+            &result,
+            &[("1", 3.0), ("2", 4.0), ("3", 5.0), ("4", 6.0), ("5", 0.0)],
+        );
+
+        // sampling all vertices is exact, sampling fewer is deterministic given a seed
+        let sampled =
+            betweenness_centrality(&graph, Some(5), None, Direction::BOTH, false, Some(1), None);
+        assert_values(
+            &sampled,
+            &[("1", 1.0), ("2", 0.5), ("3", 1.0), ("4", 3.5), ("5", 0.0)],
+        );
+        let a =
+            betweenness_centrality(&graph, Some(2), None, Direction::BOTH, false, Some(3), None);
+        let b =
+            betweenness_centrality(&graph, Some(2), None, Direction::BOTH, false, Some(3), None);
+        assert_eq!(a.get_all(), b.get_all());
+    }
+
+    #[test]
+    fn closeness_and_harmonic() {
+        let graph = square_with_tail();
+        let result = closeness_centrality(&graph, None, Direction::BOTH, None);
+        assert_values(
+            &result,
+            &[
+                ("1", 4.0 / 6.0),
+                ("2", 4.0 / 7.0),
+                ("3", 4.0 / 6.0),
+                ("4", 4.0 / 5.0),
+                ("5", 4.0 / 8.0),
+            ],
+        );
+        let result = harmonic_centrality(&graph, None, Direction::BOTH, None);
+        assert_values(
+            &result,
+            &[
+                ("1", 3.0),
+                ("2", 2.5 + 1.0 / 3.0),
+                ("3", 3.0),
+                ("4", 3.5),
+                ("5", 2.0 + 1.0 / 3.0),
+            ],
+        );
+
+        // the window only holds the path 1 -> 2 -> 3 -> 4
+        let result = closeness_centrality(&graph.window(0, 3), None, Direction::OUT, None);
+        assert_values(
+            &result,
+            &[
+                ("1", 1.0 / 2.0),
+                ("2", 2.0 / 3.0 * 2.0 / 3.0),
+                ("3", 1.0 / 3.0),
+                ("4", 0.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn temporal_betweenness() {
+        let graph = Graph::new();
+        // 1 -> 2 -> 3 respects time, 3 -> 4 happens too early to continue it
+        graph.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(2, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(3, 2, 3, NO_PROPS, None).unwrap();
+        graph.add_edge(0, 3, 4, NO_PROPS, None).unwrap();
+        // 5 reaches 3 directly at time 4, later than via 2
+        graph.add_edge(0, 5, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(4, 5, 3, NO_PROPS, None).unwrap();
+
+        let result = temporal_betweenness_centrality(&graph, None, false, None, None);
+        assert_values(
+            &result,
+            &[("1", 0.0), ("2", 2.0), ("3", 0.0), ("4", 0.0), ("5", 0.0)],
+        );
+
+        let static_result =
+            betweenness_centrality(&graph, None, None, Direction::OUT, false, None, None);
+        assert_values(
+            &static_result,
+            &[("1", 0.0), ("2", 2.0), ("3", 3.0), ("4", 0.0), ("5", 0.0)],
+        );
+    }
+}
@@ -0,0 +1,481 @@
+//! # Community Detection
+//!
+//! Modularity based community detection with the Louvain algorithm and its Leiden refinement.
+//!
+//! The graph is treated as undirected, the weight between two vertices is the sum of the weights
+//! of the edges between them in both directions. The weighted neighbourhoods of the vertices are
+//! collected in parallel on the [`TaskRunner`], the optimisation then runs on the compacted graph.
+//!
+//! Both algorithms alternate between moving single vertices to the neighbouring community with the
+//! largest modularity gain and aggregating each community into a single vertex. Leiden refines the
+//! communities before aggregating, such that every aggregated vertex is well connected within its
+//! community, which guarantees connected communities.
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::{
+        entities::{vertices::vertex_ref::VertexRef, VID},
+        state::compute_state::ComputeStateVec,
+    },
+    db::task::{
+        context::Context,
+        task::{ATask, Job, Step},
+        task_runner::TaskRunner,
+    },
+    prelude::*,
+};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default)]
+struct NeighbourState {
+    neighbours: Vec<(u64, f64)>,
+}
+
+/// Undirected weighted graph with consecutive vertex indices, `adj[i]` holds the (merged) weights
+/// `A_ij` including the diagonal
+struct WeightedGraph {
+    adj: Vec<Vec<(usize, f64)>>,
+    degrees: Vec<f64>,
+    total_weight: f64,
+}
+
+impl WeightedGraph {
+    fn new(adj: Vec<Vec<(usize, f64)>>) -> Self {
+        let degrees: Vec<f64> = adj
+            .iter()
+            .map(|ns| ns.iter().map(|(_, w)| w).sum())
+            .collect();
+        let total_weight = degrees.iter().sum();
+        Self {
+            adj,
+            degrees,
+            total_weight,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.adj.len()
+    }
+
+    /// Merge the vertices by `partition` (which uses consecutive community ids)
+    fn aggregate(&self, partition: &[usize], num_communities: usize) -> WeightedGraph {
+        let mut adj: Vec<HashMap<usize, f64>> = vec![HashMap::new(); num_communities];
+        for (i, ns) in self.adj.iter().enumerate() {
+            for (j, w) in ns {
+                *adj[partition[i]].entry(partition[*j]).or_default() += w;
+            }
+        }
+        WeightedGraph::new(
+            adj.into_iter()
+                .map(|ns| {
+                    let mut ns: Vec<_> = ns.into_iter().collect();
+                    ns.sort_by_key(|(j, _)| *j);
+                    ns
+                })
+                .collect(),
+        )
+    }
+
+    fn modularity(&self, partition: &[usize], resolution: f64) -> f64 {
+        if self.total_weight <= 0f64 {
+            return 0f64;
+        }
+        let mut internal: HashMap<usize, f64> = HashMap::new();
+        let mut totals: HashMap<usize, f64> = HashMap::new();
+        for (i, ns) in self.adj.iter().enumerate() {
+            *totals.entry(partition[i]).or_default() += self.degrees[i];
+            for (j, w) in ns {
+                if partition[i] == partition[*j] {
+                    *internal.entry(partition[i]).or_default() += w;
+                }
+            }
+        }
+        totals
+            .into_iter()
+            .map(|(c, total)| {
+                internal.get(&c).copied().unwrap_or_default() / self.total_weight
+                    - resolution * (total / self.total_weight).powi(2)
+            })
+            .sum()
+    }
+}
+
+/// Renumber the communities with consecutive ids in order of their first vertex
+fn renumber(partition: &mut [usize]) -> usize {
+    let mut ids: HashMap<usize, usize> = HashMap::new();
+    for c in partition.iter_mut() {
+        let next = ids.len();
+        *c = *ids.entry(*c).or_insert(next);
+    }
+    ids.len()
+}
+
+/// Move single vertices between communities while this increases the modularity, returns
+/// whether any vertex moved
+fn move_vertices(g: &WeightedGraph, partition: &mut [usize], resolution: f64) -> bool {
+    let m2 = g.total_weight;
+    if m2 <= 0f64 {
+        return false;
+    }
+    let mut totals: Vec<f64> = vec![0f64; g.len()];
+    for (i, c) in partition.iter().enumerate() {
+        totals[*c] += g.degrees[i];
+    }
+    let mut moved_any = false;
+    loop {
+        let mut moved = false;
+        for i in 0..g.len() {
+            let current = partition[i];
+            let k_i = g.degrees[i];
+            totals[current] -= k_i;
+            let mut links: HashMap<usize, f64> = HashMap::from([(current, 0f64)]);
+            for (j, w) in &g.adj[i] {
+                if *j != i {
+                    *links.entry(partition[*j]).or_default() += w;
+                }
+            }
+            let gain = |c: usize, k_in: f64| k_in - resolution * totals[c] * k_i / m2;
+            let mut best = current;
+            let mut best_gain = gain(current, links[&current]);
+            let mut candidates: Vec<_> = links.into_iter().collect();
+            candidates.sort_by_key(|(c, _)| *c);
+            for (c, k_in) in candidates {
+                let g_c = gain(c, k_in);
+                if g_c > best_gain + 1e-12 {
+                    best = c;
+                    best_gain = g_c;
+                }
+            }
+            totals[best] += k_i;
+            if best != current {
+                partition[i] = best;
+                moved = true;
+            }
+        }
+        if !moved {
+            break;
+        }
+        moved_any = true;
+    }
+    moved_any
+}
+
+/// Leiden refinement: split every community of `partition` into sub-communities that are built
+/// by only merging well connected vertices into well connected sub-communities
+fn refine(g: &WeightedGraph, partition: &[usize], resolution: f64) -> Vec<usize> {
+    let m2 = g.total_weight;
+    let mut refined: Vec<usize> = (0..g.len()).collect();
+    let mut community_totals: HashMap<usize, f64> = HashMap::new();
+    for (i, c) in partition.iter().enumerate() {
+        *community_totals.entry(*c).or_default() += g.degrees[i];
+    }
+    // sub-community degree totals and weights to the rest of their community
+    let mut totals: Vec<f64> = g.degrees.clone();
+    let mut external: Vec<f64> = (0..g.len())
+        .map(|i| {
+            g.adj[i]
+                .iter()
+                .filter(|(j, _)| *j != i && partition[*j] == partition[i])
+                .map(|(_, w)| w)
+                .sum()
+        })
+        .collect();
+    let mut singleton = vec![true; g.len()];
+    for i in 0..g.len() {
+        let community_total = community_totals[&partition[i]];
+        let well_connected = |weight_out: f64, total: f64| {
+            weight_out >= resolution * total * (community_total - total) / m2
+        };
+        if !singleton[i] || !well_connected(external[i], g.degrees[i]) {
+            continue;
+        }
+        let k_i = g.degrees[i];
+        let mut links: HashMap<usize, f64> = HashMap::new();
+        for (j, w) in &g.adj[i] {
+            if *j != i && partition[*j] == partition[i] {
+                *links.entry(refined[*j]).or_default() += w;
+            }
+        }
+        let mut candidates: Vec<_> = links.into_iter().collect();
+        candidates.sort_by_key(|(c, _)| *c);
+        let mut best: Option<(usize, f64, f64)> = None;
+        for (c, k_in) in candidates {
+            if c == refined[i] || !well_connected(external[c], totals[c]) {
+                continue;
+            }
+            let gain = k_in - resolution * totals[c] * k_i / m2;
+            if gain >= 0f64 && best.map_or(true, |(_, best_gain, _)| gain > best_gain) {
+                best = Some((c, gain, k_in));
+            }
+        }
+        if let Some((c, _, k_in)) = best {
+            let own = refined[i];
+            refined[i] = c;
+            totals[c] += k_i;
+            totals[own] -= k_i;
+            // the edges between i and c become internal to the merged sub-community
+            external[c] += external[i] - 2f64 * k_in;
+            external[own] = 0f64;
+            singleton[i] = false;
+            for (j, _) in &g.adj[i] {
+                if refined[*j] == c {
+                    singleton[*j] = false;
+                }
+            }
+        }
+    }
+    refined
+}
+
+/// Community assignment of the compacted graph
+fn optimise(g: WeightedGraph, resolution: f64, refinement: bool) -> Vec<usize> {
+    // the community (of the current level) of each original vertex
+    let mut membership: Vec<usize> = (0..g.len()).collect();
+    let mut graph = g;
+    let mut partition: Vec<usize> = (0..graph.len()).collect();
+    loop {
+        move_vertices(&graph, &mut partition, resolution);
+        let num_communities = renumber(&mut partition);
+        if num_communities == graph.len() {
+            break;
+        }
+        let mut aggregate_by = partition.clone();
+        if refinement {
+            let mut refined = refine(&graph, &partition, resolution);
+            let num_refined = renumber(&mut refined);
+            if num_refined < graph.len() {
+                aggregate_by = refined;
+            }
+        }
+        let num_aggregated = renumber(&mut aggregate_by);
+        // the aggregated vertices start in the community of their members
+        let mut next_partition = vec![0; num_aggregated];
+        for (i, a) in aggregate_by.iter().enumerate() {
+            next_partition[*a] = partition[i];
+        }
+        for m in membership.iter_mut() {
+            *m = aggregate_by[*m];
+        }
+        graph = graph.aggregate(&aggregate_by, num_aggregated);
+        partition = next_partition;
+    }
+    let mut result: Vec<usize> = membership.iter().map(|m| partition[*m]).collect();
+    renumber(&mut result);
+    result
+}
+
+fn detect_communities<G: GraphViewOps>(
+    g: &G,
+    weight: Option<&str>,
+    resolution: Option<f64>,
+    threads: Option<usize>,
+    refinement: bool,
+) -> (AlgorithmResult<String, usize>, f64) {
+    let resolution = resolution.unwrap_or(1f64);
+    let ctx: Context<G, ComputeStateVec> = g.into();
+    let weight = weight.map(|w| w.to_owned());
+    let step1 = ATask::new(move |vv| {
+        let id = vv.id();
+        let mut neighbours: HashMap<u64, f64> = HashMap::new();
+        for e in vv.edges() {
+            let remote = if e.src().id() == id {
+                e.dst().id()
+            } else {
+                e.src().id()
+            };
+            let w = match &weight {
+                None => 1f64,
+                Some(name) => e
+                    .properties()
+                    .temporal()
+                    .get(name)
+                    .map(|prop| {
+                        prop.values()
+                            .into_iter()
+                            .filter_map(|value| value.as_f64())
+                            .sum::<f64>()
+                    })
+                    .unwrap_or_default(),
+            };
+            *neighbours.entry(remote).or_default() += w;
+        }
+        let state: &mut NeighbourState = vv.get_mut();
+        state.neighbours = neighbours.into_iter().collect();
+        Step::Done
+    });
+
+    let mut runner: TaskRunner<G, _> = TaskRunner::new(ctx);
+    let vertices: Vec<(VID, Vec<(u64, f64)>)> = runner.run(
+        vec![],
+        vec![Job::new(step1)],
+        NeighbourState::default(),
+        |_, _, _, local| {
+            let layers = g.layer_ids();
+            let edge_filter = g.edge_filter();
+            local
+                .iter()
+                .enumerate()
+                .filter(|(v_ref, _)| {
+                    g.has_vertex_ref(VertexRef::Internal((*v_ref).into()), &layers, edge_filter)
+                })
+                .map(|(v_ref, state)| (v_ref.into(), state.neighbours.clone()))
+                .collect()
+        },
+        threads,
+        1,
+        None,
+        None,
+    );
+
+    let index: HashMap<u64, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, (v, _))| (g.vertex_id(*v), i))
+        .collect();
+    let adj = vertices
+        .iter()
+        .map(|(_, ns)| {
+            let mut ns: Vec<_> = ns
+                .iter()
+                .filter_map(|(id, w)| Some((*index.get(id)?, *w)))
+                .collect();
+            ns.sort_by_key(|(j, _)| *j);
+            ns
+        })
+        .collect();
+    let graph = WeightedGraph::new(adj);
+    let partition = optimise(
+        WeightedGraph::new(graph.adj.clone()),
+        resolution,
+        refinement,
+    );
+    let modularity = graph.modularity(&partition, resolution);
+    let result = vertices
+        .iter()
+        .zip(partition)
+        .map(|((v, _), c)| (g.vertex_name(*v), c))
+        .collect();
+    (AlgorithmResult::new(result), modularity)
+}
+
+/// Louvain community detection
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `weight`: The name of the edge property holding the weights, the values are summed over the
+///   history of each edge in the view. If `None` every edge has weight 1.
+/// * `resolution`: The resolution of the modularity (defaults to 1), larger values give smaller communities
+/// * `threads`: Number of threads to use for collecting the neighbourhoods
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping vertex names to community ids and the modularity of the communities
+///
+pub fn louvain<G: GraphViewOps>(
+    g: &G,
+    weight: Option<&str>,
+    resolution: Option<f64>,
+    threads: Option<usize>,
+) -> (AlgorithmResult<String, usize>, f64) {
+    detect_communities(g, weight, resolution, threads, false)
+}
+
+/// Leiden community detection, Louvain with a refinement pass before each aggregation
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `weight`: The name of the edge property holding the weights, the values are summed over the
+///   history of each edge in the view. If `None` every edge has weight 1.
+/// * `resolution`: The resolution of the modularity (defaults to 1), larger values give smaller communities
+/// * `threads`: Number of threads to use for collecting the neighbourhoods
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping vertex names to community ids and the modularity of the communities
+///
+pub fn leiden<G: GraphViewOps>(
+    g: &G,
+    weight: Option<&str>,
+    resolution: Option<f64>,
+    threads: Option<usize>,
+) -> (AlgorithmResult<String, usize>, f64) {
+    detect_communities(g, weight, resolution, threads, true)
+}
+
+#[cfg(test)]
+mod community_detection_test {
+    use super::*;
+
+    /// Two cliques of 4 vertices connected by a single edge
+    fn two_cliques() -> Graph {
+        let graph = Graph::new();
+        for (offset, t) in [(0, 0), (4, 1)] {
+            for i in 1..=4u64 {
+                for j in (i + 1)..=4 {
+                    graph
+                        .add_edge(t, i + offset, j + offset, [("weight", 1i64)], None)
+                        .unwrap();
+                }
+            }
+        }
+        graph.add_edge(2, 4, 5, [("weight", 1i64)], None).unwrap();
+        graph
+    }
+
+    fn groups(result: &AlgorithmResult<String, usize>) -> Vec<Vec<String>> {
+        let mut groups: Vec<Vec<String>> = result
+            .group_by()
+            .into_values()
+            .map(|mut vs| {
+                vs.sort();
+                vs
+            })
+            .collect();
+        groups.sort();
+        groups
+    }
+
+    #[test]
+    fn louvain_two_cliques() {
+        let graph = two_cliques();
+        let (result, modularity) = louvain(&graph, None, None, Some(2));
+        assert_eq!(
+            groups(&result),
+            vec![vec!["1", "2", "3", "4"], vec!["5", "6", "7", "8"]]
+        );
+        // 13 edges, each clique has 6 internal edges and a total degree of 13
+        let expected = 2.0 * (12.0 / 26.0 - (13.0f64 / 26.0).powi(2));
+        assert!((modularity - expected).abs() < 1e-9);
+
+        // the communities only depend on the edges in the view
+        let (result, _) = louvain(&graph.window(0, 1), None, None, None);
+        assert_eq!(groups(&result), vec![vec!["1", "2", "3", "4"]]);
+    }
+
+    #[test]
+    fn weighted_communities() {
+        let graph = Graph::new();
+        // a path 1 - 2 - 3 - 4 where the weights pull 2 and 3 apart
+        graph.add_edge(0, 1, 2, [("weight", 10.0)], None).unwrap();
+        graph.add_edge(1, 2, 1, [("weight", 10.0)], None).unwrap();
+        graph.add_edge(2, 2, 3, [("weight", 1.0)], None).unwrap();
+        graph.add_edge(3, 3, 4, [("weight", 20.0)], None).unwrap();
+        let (result, modularity) = leiden(&graph, Some("weight"), None, None);
+        assert_eq!(groups(&result), vec![vec!["1", "2"], vec!["3", "4"]]);
+        assert!(modularity > 0.0);
+    }
+
+    #[test]
+    fn leiden_two_cliques() {
+        let graph = two_cliques();
+        let (louvain_result, louvain_modularity) = louvain(&graph, None, None, None);
+        let (result, modularity) = leiden(&graph, Some("weight"), None, None);
+        assert_eq!(groups(&result), groups(&louvain_result));
+        assert!((modularity - louvain_modularity).abs() < 1e-9);
+
+        // a high resolution prefers single vertices
+        let (result, _) = leiden(&graph, None, Some(10.0), None);
+        assert_eq!(result.group_by().len(), 8);
+    }
+}
@@ -0,0 +1,345 @@
+//! # Community Tracking
+//!
+//! Follows communities over the windows of a [`WindowSet`]. A community detection algorithm is
+//! run on every window and the communities of consecutive windows are matched by the Jaccard
+//! overlap of their vertices. Every community that is matched to a community of the previous window
+//! keeps its id, such that the evolution of the communities can be described with events:
+//!
+//! * `Birth`: A community without a match in the previous window
+//! * `Death`: A community without a match in the next window
+//! * `Merge`: Several communities matched to the same community of the next window, the merged
+//!   community keeps the id of the largest of them
+//! * `Split`: A community matched to several communities of the next window, the largest of them
+//!   keeps the id of the community
+//! * `Growth` and `Contraction`: A community that continues with a different number of vertices
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::algorithms::{
+//!     community_tracking::{track_communities, CommunityEvent},
+//!     label_propagation::label_propagation,
+//! };
+//! use raphtory::prelude::*;
+//!
+//! let graph = Graph::new();
+//! graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+//! graph.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+//! graph.add_edge(2, 3, 4, NO_PROPS, None).unwrap();
+//!
+//! let windows = graph.expanding(1).unwrap();
+//! let timeline = track_communities(windows, |w| label_propagation(w, 100, Some(1)), 0.3);
+//! assert_eq!(timeline[0].events, vec![CommunityEvent::Birth { community: 0 }]);
+//! ```
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    db::api::view::{TimeOps, WindowSet},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// An event in the evolution of the communities between two consecutive windows
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommunityEvent {
+    Birth {
+        community: usize,
+    },
+    Death {
+        community: usize,
+    },
+    Growth {
+        community: usize,
+        previous_size: usize,
+        size: usize,
+    },
+    Contraction {
+        community: usize,
+        previous_size: usize,
+        size: usize,
+    },
+    Merge {
+        communities: Vec<usize>,
+        into: usize,
+    },
+    Split {
+        community: usize,
+        into: Vec<usize>,
+    },
+}
+
+/// The tracked communities of a single window
+#[derive(Clone, Debug)]
+pub struct CommunitySnapshot {
+    /// The start of the window (if bounded)
+    pub start: Option<i64>,
+    /// The end of the window (exclusive)
+    pub end: Option<i64>,
+    /// The vertex names of each community by its tracked id
+    pub communities: HashMap<usize, Vec<String>>,
+    /// The events relative to the previous window
+    pub events: Vec<CommunityEvent>,
+}
+
+impl CommunitySnapshot {
+    /// Returns the tracked community id of the vertex
+    pub fn community_of(&self, name: &str) -> Option<usize> {
+        self.communities
+            .iter()
+            .find(|(_, vs)| vs.iter().any(|v| v == name))
+            .map(|(c, _)| *c)
+    }
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let intersection = a.intersection(b).count();
+    let union = a.len() + b.len() - intersection;
+    if union == 0 {
+        0f64
+    } else {
+        intersection as f64 / union as f64
+    }
+}
+
+/// Runs `detect` on every window of `windows` and tracks the communities across the windows
+///
+/// # Arguments
+///
+/// * `windows` - The windows to track the communities over, e.g. from `rolling` or `expanding`
+/// * `detect` - The community detection algorithm, mapping each vertex name to a community label
+/// * `threshold` - Minimum Jaccard overlap for two communities of consecutive windows to be
+///   considered related
+///
+/// # Returns
+///
+/// A snapshot for every window holding the tracked communities and the events since the previous
+/// window
+///
+pub fn track_communities<T, F, V>(
+    windows: WindowSet<T>,
+    mut detect: F,
+    threshold: f64,
+) -> Vec<CommunitySnapshot>
+where
+    T: TimeOps + Clone + 'static,
+    F: FnMut(&T::WindowedViewType) -> AlgorithmResult<String, V>,
+    V: Clone + Ord + Hash + Eq,
+{
+    let mut next_id = 0;
+    let mut previous: Vec<(usize, HashSet<String>)> = vec![];
+    let mut snapshots = vec![];
+    for window in windows {
+        let mut current: Vec<HashSet<String>> = detect(&window)
+            .group_by()
+            .into_values()
+            .map(|vs| vs.into_iter().collect())
+            .collect();
+        // largest communities first, ties are broken by their smallest member for determinism
+        current.sort_by_cached_key(|c| {
+            (
+                std::cmp::Reverse(c.len()),
+                c.iter().min().cloned().unwrap_or_default(),
+            )
+        });
+
+        // related communities of the previous window for each current community and vice versa
+        let mut predecessors: Vec<Vec<usize>> = vec![vec![]; current.len()];
+        let mut successors: Vec<Vec<usize>> = vec![vec![]; previous.len()];
+        for (i, c) in current.iter().enumerate() {
+            for (j, (_, p)) in previous.iter().enumerate() {
+                if jaccard(c, p) >= threshold {
+                    predecessors[i].push(j);
+                    successors[j].push(i);
+                }
+            }
+        }
+
+        let mut events = vec![];
+        let mut ids: Vec<Option<usize>> = vec![None; current.len()];
+        // the previous communities are sorted by size as well, so the first related community is
+        // the largest
+        for (j, succ) in successors.iter().enumerate() {
+            let id = previous[j].0;
+            match succ.as_slice() {
+                [] => events.push(CommunityEvent::Death { community: id }),
+                [i, ..] => {
+                    if predecessors[*i].first() == Some(&j) {
+                        ids[*i] = Some(id);
+                    }
+                }
+            }
+        }
+        for id in ids.iter_mut().filter(|id| id.is_none()) {
+            *id = Some(next_id);
+            next_id += 1;
+        }
+        let ids: Vec<usize> = ids.into_iter().flatten().collect();
+
+        for (j, succ) in successors.iter().enumerate() {
+            if succ.len() > 1 {
+                events.push(CommunityEvent::Split {
+                    community: previous[j].0,
+                    into: succ.iter().map(|i| ids[*i]).collect(),
+                });
+            }
+        }
+        for (i, pred) in predecessors.iter().enumerate() {
+            match pred.as_slice() {
+                [] => events.push(CommunityEvent::Birth { community: ids[i] }),
+                [j] => {
+                    let previous_size = previous[*j].1.len();
+                    let size = current[i].len();
+                    if successors[*j].len() == 1 && size > previous_size {
+                        events.push(CommunityEvent::Growth {
+                            community: ids[i],
+                            previous_size,
+                            size,
+                        });
+                    } else if successors[*j].len() == 1 && size < previous_size {
+                        events.push(CommunityEvent::Contraction {
+                            community: ids[i],
+                            previous_size,
+                            size,
+                        });
+                    }
+                }
+                _ => events.push(CommunityEvent::Merge {
+                    communities: pred.iter().map(|j| previous[*j].0).collect(),
+                    into: ids[i],
+                }),
+            }
+        }
+
+        snapshots.push(CommunitySnapshot {
+            start: window.start(),
+            end: window.end(),
+            communities: ids
+                .iter()
+                .zip(current.iter())
+                .map(|(id, c)| {
+                    let mut vs: Vec<String> = c.iter().cloned().collect();
+                    vs.sort();
+                    (*id, vs)
+                })
+                .collect(),
+            events,
+        });
+        previous = ids.into_iter().zip(current).collect();
+    }
+    snapshots
+}
+
+#[cfg(test)]
+mod community_tracking_test {
+    use super::*;
+    use crate::{algorithms::connected_components::weakly_connected_components, prelude::*};
+
+    fn components<G: GraphViewOps>(g: &G) -> AlgorithmResult<String, u64> {
+        weakly_connected_components(g, 20, None)
+    }
+
+    #[test]
+    fn tracks_growth_merge_and_split() {
+        let graph = Graph::new();
+        // window [0, 10): {1, 2} and {3, 4}
+        graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(1, 3, 4, NO_PROPS, None).unwrap();
+        // window [10, 20): {1, 2, 5} and {3, 4, 6}
+        graph.add_edge(10, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(11, 2, 5, NO_PROPS, None).unwrap();
+        graph.add_edge(12, 3, 4, NO_PROPS, None).unwrap();
+        graph.add_edge(13, 4, 6, NO_PROPS, None).unwrap();
+        // window [20, 30): {1, 2, 3, 4, 5, 6}
+        graph.add_edge(20, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(21, 2, 5, NO_PROPS, None).unwrap();
+        graph.add_edge(22, 5, 3, NO_PROPS, None).unwrap();
+        graph.add_edge(23, 3, 4, NO_PROPS, None).unwrap();
+        graph.add_edge(24, 4, 6, NO_PROPS, None).unwrap();
+        // window [30, 40): {1, 2, 5} and {3, 4, 6}
+        graph.add_edge(30, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(31, 2, 5, NO_PROPS, None).unwrap();
+        graph.add_edge(32, 3, 4, NO_PROPS, None).unwrap();
+        graph.add_edge(33, 4, 6, NO_PROPS, None).unwrap();
+        // window [40, 50): {7, 8}
+        graph.add_edge(40, 7, 8, NO_PROPS, None).unwrap();
+
+        let timeline = track_communities(graph.rolling(10, None).unwrap(), components, 0.3);
+        assert_eq!(timeline.len(), 5);
+        assert_eq!(timeline[0].start, Some(0));
+        assert_eq!(timeline[0].end, Some(10));
+        assert_eq!(
+            timeline[0].events,
+            vec![
+                CommunityEvent::Birth { community: 0 },
+                CommunityEvent::Birth { community: 1 }
+            ]
+        );
+        assert_eq!(
+            timeline[1].events,
+            vec![
+                CommunityEvent::Growth {
+                    community: 0,
+                    previous_size: 2,
+                    size: 3
+                },
+                CommunityEvent::Growth {
+                    community: 1,
+                    previous_size: 2,
+                    size: 3
+                }
+            ]
+        );
+        assert_eq!(
+            timeline[2].events,
+            vec![CommunityEvent::Merge {
+                communities: vec![0, 1],
+                into: 0
+            }]
+        );
+        assert_eq!(
+            timeline[2].communities[&0],
+            vec!["1", "2", "3", "4", "5", "6"]
+        );
+        assert_eq!(
+            timeline[3].events,
+            vec![CommunityEvent::Split {
+                community: 0,
+                into: vec![0, 2]
+            }]
+        );
+        assert_eq!(timeline[3].community_of("1"), Some(0));
+        assert_eq!(timeline[3].community_of("6"), Some(2));
+        assert_eq!(
+            timeline[4].events,
+            vec![
+                CommunityEvent::Death { community: 0 },
+                CommunityEvent::Death { community: 2 },
+                CommunityEvent::Birth { community: 3 }
+            ]
+        );
+    }
+
+    #[test]
+    fn expanding_windows_keep_ids() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+        graph.add_edge(2, 3, 3, NO_PROPS, None).unwrap();
+        let timeline = track_communities(graph.expanding(1).unwrap(), components, 0.5);
+        assert_eq!(timeline.len(), 3);
+        for snapshot in &timeline {
+            assert_eq!(snapshot.communities.len(), 1);
+            assert_eq!(snapshot.community_of("1"), Some(0));
+        }
+        assert_eq!(
+            timeline[1].events,
+            vec![CommunityEvent::Growth {
+                community: 0,
+                previous_size: 2,
+                size: 3
+            }]
+        );
+        assert!(timeline[2].events.is_empty());
+    }
+}
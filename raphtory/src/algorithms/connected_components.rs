@@ -1,6 +1,8 @@
-use super::algorithm_result::AlgorithmResult;
+use super::{algorithm_result::AlgorithmResult, utils::VertexIndex};
 use crate::{
-    core::{entities::vertices::vertex_ref::VertexRef, state::compute_state::ComputeStateVec},
+    core::{
+        entities::vertices::vertex_ref::VertexRef, state::compute_state::ComputeStateVec, Direction,
+    },
     db::{
         api::view::{GraphViewOps, VertexViewOps},
         task::{
@@ -11,6 +13,8 @@ use crate::{
         },
     },
 };
+use rayon::prelude::*;
+use roaring::RoaringBitmap;
 use std::{cmp, collections::HashMap};
 
 #[derive(Clone, Debug)]
@@ -104,6 +108,208 @@ where
     AlgorithmResult::new(res)
 }
 
+/// Computes the strongly connected components of a directed graph using Tarjan's algorithm
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+///
+/// # Returns
+///
+/// An AlgorithmResult mapping each vertex name to the smallest vertex id in its component
+///
+pub fn strongly_connected_components<G>(graph: &G) -> AlgorithmResult<String, u64>
+where
+    G: GraphViewOps,
+{
+    let index = VertexIndex::new(graph);
+    let components = tarjan(&index.neighbours(graph, Direction::OUT));
+    let ids = index.ids(graph);
+    let mut component_ids: HashMap<usize, u64> = HashMap::new();
+    for (i, c) in components.iter().enumerate() {
+        let id = component_ids.entry(*c).or_insert(ids[i]);
+        *id = cmp::min(*id, ids[i]);
+    }
+    AlgorithmResult::new(
+        index
+            .names(graph)
+            .into_iter()
+            .zip(components)
+            .map(|(name, c)| (name, component_ids[&c]))
+            .collect(),
+    )
+}
+
+/// Computes the temporal strongly connected components of a directed graph
+///
+/// A vertex `u` temporally reaches `v` if there is a path from `u` to `v` along edge updates with
+/// strictly increasing timestamps. Unlike static reachability this relation is not transitive, two
+/// vertices are in the same component if they are linked by a chain of pairs of vertices that
+/// temporally reach each other.
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+///
+/// # Returns
+///
+/// An AlgorithmResult mapping each vertex name to the smallest vertex id in its component
+///
+pub fn temporal_strongly_connected_components<G>(graph: &G) -> AlgorithmResult<String, u64>
+where
+    G: GraphViewOps,
+{
+    let index = VertexIndex::new(graph);
+    let updates = index.updates(graph);
+
+    // a temporal path is also a static path, so all its vertices are in the same static
+    // component and temporal reachability only needs to be computed within each component
+    let mut out_neighbours: Vec<Vec<usize>> = vec![vec![]; index.len()];
+    for (_, src, dst) in &updates {
+        out_neighbours[*src].push(*dst);
+    }
+    for neighbours in out_neighbours.iter_mut() {
+        neighbours.sort_unstable();
+        neighbours.dedup();
+    }
+    let static_components = tarjan(&out_neighbours);
+    let num_static = static_components.iter().max().map_or(0, |c| c + 1);
+    let mut members: Vec<Vec<usize>> = vec![vec![]; num_static];
+    let mut position = vec![0u32; index.len()];
+    for (v, c) in static_components.iter().enumerate() {
+        position[v] = members[*c].len() as u32;
+        members[*c].push(v);
+    }
+    let mut component_updates: Vec<Vec<(i64, u32, u32)>> = vec![vec![]; num_static];
+    for (t, src, dst) in updates {
+        let c = static_components[src];
+        if c == static_components[dst] {
+            component_updates[c].push((t, position[src], position[dst]));
+        }
+    }
+
+    // the representative of the temporal component of every vertex in a non-trivial static component
+    let roots: Vec<(usize, usize)> = members
+        .par_iter()
+        .zip(component_updates.par_iter())
+        .filter(|(members, _)| members.len() > 1)
+        .flat_map_iter(|(members, updates)| {
+            temporal_components_within(members.len(), updates)
+                .into_iter()
+                .enumerate()
+                .map(|(i, root)| (members[i], members[root]))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let mut components = index.ids(graph);
+    for &(v, root) in &roots {
+        components[root] = cmp::min(components[root], components[v]);
+    }
+    for (v, root) in roots {
+        components[v] = components[root];
+    }
+    AlgorithmResult::new(index.names(graph).into_iter().zip(components).collect())
+}
+
+/// Temporal strongly connected components of the `n` vertices of a static component given its
+/// time-sorted updates, returns a representative member for every vertex
+fn temporal_components_within(n: usize, updates: &[(i64, u32, u32)]) -> Vec<usize> {
+    // the vertices reached from every vertex along time-respecting paths
+    let reachable: Vec<RoaringBitmap> = (0..n)
+        .into_par_iter()
+        .map(|source| {
+            let mut arrival = vec![i64::MAX; n];
+            arrival[source] = i64::MIN;
+            for &(t, src, dst) in updates {
+                if arrival[src as usize] < t && t < arrival[dst as usize] {
+                    arrival[dst as usize] = t;
+                }
+            }
+            arrival
+                .into_iter()
+                .enumerate()
+                .filter(|(_, t)| *t != i64::MAX)
+                .map(|(v, _)| v as u32)
+                .collect()
+        })
+        .collect();
+
+    // union the pairs of vertices that reach each other
+    let mut parent: Vec<usize> = (0..n).collect();
+    fn find(parent: &mut [usize], mut v: usize) -> usize {
+        while parent[v] != v {
+            parent[v] = parent[parent[v]];
+            v = parent[v];
+        }
+        v
+    }
+    for u in 0..n {
+        for v in reachable[u].iter().map(|v| v as usize).filter(|v| *v > u) {
+            if reachable[v].contains(u as u32) {
+                let (ru, rv) = (find(&mut parent, u), find(&mut parent, v));
+                parent[cmp::max(ru, rv)] = cmp::min(ru, rv);
+            }
+        }
+    }
+    (0..n).map(|v| find(&mut parent, v)).collect()
+}
+
+/// Iterative version of Tarjan's algorithm, returns the index of the component of each vertex
+fn tarjan(out_neighbours: &[Vec<usize>]) -> Vec<usize> {
+    let n = out_neighbours.len();
+    let mut index = vec![usize::MAX; n];
+    let mut low_link = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = vec![];
+    let mut components = vec![usize::MAX; n];
+    let mut next_index = 0;
+    let mut next_component = 0;
+    for root in 0..n {
+        if index[root] != usize::MAX {
+            continue;
+        }
+        // the call stack holds the vertex and the position of the next neighbour to visit
+        let mut call_stack = vec![(root, 0)];
+        index[root] = next_index;
+        low_link[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        while let Some((v, pos)) = call_stack.last_mut() {
+            let v = *v;
+            if let Some(w) = out_neighbours[v].get(*pos).copied() {
+                *pos += 1;
+                if index[w] == usize::MAX {
+                    index[w] = next_index;
+                    low_link[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    call_stack.push((w, 0));
+                } else if on_stack[w] {
+                    low_link[v] = cmp::min(low_link[v], index[w]);
+                }
+            } else {
+                call_stack.pop();
+                if let Some((parent, _)) = call_stack.last() {
+                    low_link[*parent] = cmp::min(low_link[*parent], low_link[v]);
+                }
+                if low_link[v] == index[v] {
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        components[w] = next_component;
+                        if w == v {
+                            break;
+                        }
+                    }
+                    next_component += 1;
+                }
+            }
+        }
+    }
+    components
+}
+
 #[cfg(test)]
 mod cc_test {
     use crate::prelude::*;
@@ -261,6 +467,79 @@ mod cc_test {
         assert_eq!(*results.get_all(), expected);
     }
 
+    #[test]
+    fn strongly_connected_components_test() {
+        let graph = Graph::new();
+        let edges = vec![
+            (1, 2, 1),
+            (2, 3, 2),
+            (3, 1, 3),
+            (3, 4, 4),
+            (4, 5, 5),
+            (5, 4, 6),
+            (5, 6, 7),
+            (6, 6, 8),
+        ];
+        for (src, dst, ts) in edges {
+            graph.add_edge(ts, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        let results = strongly_connected_components(&graph);
+        let expected = vec![("1", 1), ("2", 1), ("3", 1), ("4", 4), ("5", 4), ("6", 6)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect::<HashMap<String, u64>>();
+        assert_eq!(*results.get_all(), expected);
+
+        let results = strongly_connected_components(&graph.window(0, 5));
+        let expected = vec![("1", 1), ("2", 1), ("3", 1), ("4", 4)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect::<HashMap<String, u64>>();
+        assert_eq!(*results.get_all(), expected);
+    }
+
+    #[test]
+    fn temporal_strongly_connected_components_test() {
+        let graph = Graph::new();
+        // a static cycle 1 -> 2 -> 3 -> 1 in which only 2 and 3 reach each other in time
+        let edges = vec![(1, 2, 3), (2, 3, 2), (3, 1, 1), (4, 5, 1), (5, 4, 2)];
+        for (src, dst, ts) in edges {
+            graph.add_edge(ts, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        let results = temporal_strongly_connected_components(&graph);
+        let expected = vec![("1", 1), ("2", 2), ("3", 2), ("4", 4), ("5", 4)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect::<HashMap<String, u64>>();
+        assert_eq!(*results.get_all(), expected);
+
+        let static_results = strongly_connected_components(&graph);
+        assert_eq!(static_results.get(&"1".to_string()), Some(&1));
+        assert_eq!(static_results.get(&"3".to_string()), Some(&1));
+
+        // updates at the same time do not form a path
+        let graph = Graph::new();
+        graph.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+        graph.add_edge(1, 3, 1, NO_PROPS, None).unwrap();
+        let results = temporal_strongly_connected_components(&graph);
+        assert_eq!(results.group_by().len(), 3);
+
+        // 1 and 3 do not reach each other but are chained through 2
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, 1, 2), (2, 2, 1), (3, 2, 3), (4, 3, 2), (1, 4, 1)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        let results = temporal_strongly_connected_components(&graph);
+        let expected = vec![("1", 1), ("2", 1), ("3", 1), ("4", 4)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect::<HashMap<String, u64>>();
+        assert_eq!(*results.get_all(), expected);
+    }
+
     #[quickcheck]
     fn circle_graph_the_smallest_value_is_the_cc(vs: Vec<u64>) {
         if !vs.is_empty() {
@@ -0,0 +1,250 @@
+//! # Vertex Embeddings
+//!
+//! Learns vertex embeddings from random walks with a skip-gram model trained by negative sampling
+//! (Mikolov et al., 2013), as in DeepWalk and node2vec. Vertices that appear close to each other on
+//! the walks get similar embedding vectors.
+//!
+//! The walks are generated with [`random_walks`](crate::algorithms::random_walks::random_walks),
+//! [`vertex_embeddings`] combines both steps. The embeddings can be kept in the graph as `Prop::List`
+//! vertex properties with [`store_embeddings`].
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::algorithms::{embeddings::vertex_embeddings, random_walks::WalkStrategy};
+//! use raphtory::prelude::*;
+//!
+//! let graph = Graph::new();
+//! graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+//! graph.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+//!
+//! let embeddings = vertex_embeddings(&graph, 8, 10, 5, WalkStrategy::Uniform, 2, Some(1), None);
+//! assert_eq!(embeddings.get(&"1".to_string()).unwrap().len(), 8);
+//! ```
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        random_walks::{random_walks, WalkStrategy},
+        utils::seeded_rng,
+    },
+    core::utils::errors::GraphError,
+    prelude::*,
+};
+use rand::{distributions::WeightedIndex, prelude::*};
+use std::{collections::HashMap, sync::Arc};
+
+fn sigmoid(x: f64) -> f64 {
+    1f64 / (1f64 + (-x).exp())
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// Trains a skip-gram model with negative sampling on the walks
+///
+/// # Arguments
+///
+/// * `walks` - The walks as lists of vertex names
+/// * `dimensions` - The size of the embedding vectors
+/// * `window` - The maximum distance on a walk between a vertex and its context vertices
+/// * `negative_samples` - The number of negative samples per context vertex
+/// * `epochs` - The number of passes over the walks
+/// * `learning_rate` - The initial learning rate (defaults to 0.025), it decays linearly during training
+/// * `seed` - The seed for the initialisation and the negative samples
+///
+/// # Returns
+///
+/// An AlgorithmResult mapping the names of the vertices on the walks to their embedding
+///
+pub fn skip_gram(
+    walks: &[Vec<String>],
+    dimensions: usize,
+    window: usize,
+    negative_samples: usize,
+    epochs: usize,
+    learning_rate: Option<f64>,
+    seed: Option<u64>,
+) -> AlgorithmResult<String, Vec<f64>> {
+    let mut rng = seeded_rng(seed);
+    let learning_rate = learning_rate.unwrap_or(0.025);
+
+    let mut vocabulary: HashMap<&str, usize> = HashMap::new();
+    let mut names: Vec<String> = vec![];
+    let mut counts: Vec<f64> = vec![];
+    let walks: Vec<Vec<usize>> = walks
+        .iter()
+        .map(|walk| {
+            walk.iter()
+                .map(|name| {
+                    let i = *vocabulary.entry(name).or_insert_with(|| {
+                        names.push(name.clone());
+                        counts.push(0f64);
+                        names.len() - 1
+                    });
+                    counts[i] += 1f64;
+                    i
+                })
+                .collect()
+        })
+        .collect();
+    if names.is_empty() {
+        return AlgorithmResult::new(HashMap::new());
+    }
+    // negative samples are drawn from the unigram distribution raised to the power 3/4
+    let noise = WeightedIndex::new(counts.iter().map(|c| c.powf(0.75))).unwrap();
+
+    let mut input: Vec<Vec<f64>> = (0..names.len())
+        .map(|_| {
+            (0..dimensions)
+                .map(|_| (rng.gen::<f64>() - 0.5) / dimensions as f64)
+                .collect()
+        })
+        .collect();
+    let mut output: Vec<Vec<f64>> = vec![vec![0f64; dimensions]; names.len()];
+
+    let total = (epochs * walks.len()).max(1) as f64;
+    let mut gradient = vec![0f64; dimensions];
+    for epoch in 0..epochs {
+        for (w, walk) in walks.iter().enumerate() {
+            let progress = (epoch * walks.len() + w) as f64 / total;
+            let alpha = learning_rate * (1f64 - progress).max(1e-4);
+            for (pos, center) in walk.iter().enumerate() {
+                let start = pos.saturating_sub(window);
+                let end = (pos + window + 1).min(walk.len());
+                for (ctx_pos, context) in walk.iter().enumerate().take(end).skip(start) {
+                    if ctx_pos == pos {
+                        continue;
+                    }
+                    gradient.iter_mut().for_each(|g| *g = 0f64);
+                    let negatives = (0..negative_samples)
+                        .map(|_| noise.sample(&mut rng))
+                        .filter(|n| n != context)
+                        .map(|n| (n, 0f64));
+                    for (target, label) in std::iter::once((*context, 1f64)).chain(negatives) {
+                        let g = (label - sigmoid(dot(&input[*center], &output[target]))) * alpha;
+                        for d in 0..dimensions {
+                            gradient[d] += g * output[target][d];
+                            output[target][d] += g * input[*center][d];
+                        }
+                    }
+                    input[*center]
+                        .iter_mut()
+                        .zip(&gradient)
+                        .for_each(|(x, g)| *x += g);
+                }
+            }
+        }
+    }
+    AlgorithmResult::new(names.into_iter().zip(input).collect())
+}
+
+/// Computes vertex embeddings by training a skip-gram model on random walks
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+/// * `dimensions` - The size of the embedding vectors
+/// * `walk_length` - The maximum number of vertices in a walk
+/// * `walks_per_vertex` - The number of walks starting from each vertex
+/// * `strategy` - How the walks choose the next vertex
+/// * `window` - The maximum distance on a walk between a vertex and its context vertices
+/// * `seed` - The seed for the walks and the training
+/// * `threads` - Number of threads to use for generating the walks
+///
+/// # Returns
+///
+/// An AlgorithmResult mapping vertex names to their embedding
+///
+#[allow(clippy::too_many_arguments)]
+pub fn vertex_embeddings<G: GraphViewOps>(
+    g: &G,
+    dimensions: usize,
+    walk_length: usize,
+    walks_per_vertex: usize,
+    strategy: WalkStrategy,
+    window: usize,
+    seed: Option<u64>,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, Vec<f64>> {
+    let walks = random_walks(g, walk_length, walks_per_vertex, strategy, seed, threads);
+    skip_gram(&walks, dimensions, window, 5, 1, None, seed)
+}
+
+/// Stores the embeddings as constant `Prop::List` vertex properties called `name`
+pub fn store_embeddings<G: PropertyAdditionOps>(
+    g: &G,
+    embeddings: &AlgorithmResult<String, Vec<f64>>,
+    name: &str,
+) -> Result<(), GraphError> {
+    for (vertex, embedding) in embeddings.get_all() {
+        let value = Prop::List(Arc::new(embedding.iter().map(|x| Prop::F64(*x)).collect()));
+        g.add_vertex_properties(vertex.as_str(), [(name, value)])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod embeddings_test {
+    use super::*;
+
+    fn cosine(a: &[f64], b: &[f64]) -> f64 {
+        dot(a, b) / (dot(a, a).sqrt() * dot(b, b).sqrt())
+    }
+
+    #[test]
+    fn separates_components() {
+        let graph = Graph::new();
+        for offset in [0, 10] {
+            for i in 1..=4u64 {
+                for j in (i + 1)..=4 {
+                    graph
+                        .add_edge(0, i + offset, j + offset, NO_PROPS, None)
+                        .unwrap();
+                }
+            }
+        }
+        let walks = random_walks(
+            &graph,
+            10,
+            20,
+            WalkStrategy::Node2Vec { p: 1.0, q: 0.5 },
+            Some(1),
+            None,
+        );
+        let embeddings = skip_gram(&walks, 16, 3, 5, 5, None, Some(1));
+        assert_eq!(embeddings.get_all().len(), 8);
+        let embedding = |v: &str| embeddings.get(&v.to_string()).unwrap().clone();
+        for (a, b, c) in [("1", "2", "11"), ("3", "4", "12"), ("11", "13", "2")] {
+            assert!(cosine(&embedding(a), &embedding(b)) > cosine(&embedding(a), &embedding(c)));
+        }
+
+        // training is deterministic for a fixed seed
+        assert_eq!(
+            embeddings.get_all(),
+            skip_gram(&walks, 16, 3, 5, 5, None, Some(1)).get_all()
+        );
+    }
+
+    #[test]
+    fn stores_embeddings() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+        let embeddings =
+            vertex_embeddings(&graph, 4, 5, 2, WalkStrategy::Temporal, 2, Some(3), None);
+        store_embeddings(&graph, &embeddings, "embedding").unwrap();
+        for v in graph.vertices() {
+            let expected: Vec<Prop> = embeddings
+                .get(&v.name())
+                .unwrap()
+                .iter()
+                .map(|x| Prop::F64(*x))
+                .collect();
+            assert_eq!(
+                v.properties().get("embedding"),
+                Some(Prop::List(Arc::new(expected)))
+            );
+        }
+    }
+}
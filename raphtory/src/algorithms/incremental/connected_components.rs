@@ -0,0 +1,125 @@
+use crate::{
+    algorithms::{algorithm_result::AlgorithmResult, incremental::IncrementalAlgorithm},
+    core::entities::VID,
+    prelude::*,
+};
+
+/// Weakly connected components maintained with a union-find structure
+///
+/// As for [`weakly_connected_components`](crate::algorithms::connected_components::weakly_connected_components)
+/// the component of a vertex is labelled by the smallest vertex id in the component.
+#[derive(Clone, Debug, Default)]
+pub struct IncrementalWcc {
+    parent: Vec<usize>,
+    min_id: Vec<u64>,
+    present: Vec<bool>,
+    num_components: usize,
+}
+
+impl IncrementalWcc {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn ensure(&mut self, v: usize) {
+        if v >= self.parent.len() {
+            self.parent.extend(self.parent.len()..=v);
+            self.min_id.resize(v + 1, u64::MAX);
+            self.present.resize(v + 1, false);
+        }
+    }
+
+    fn add(&mut self, v: usize, id: u64) {
+        self.ensure(v);
+        if !self.present[v] {
+            self.present[v] = true;
+            self.num_components += 1;
+        }
+        let root = self.find(v);
+        self.min_id[root] = self.min_id[root].min(id);
+    }
+
+    fn find(&self, mut v: usize) -> usize {
+        while self.parent[v] != v {
+            v = self.parent[v];
+        }
+        v
+    }
+
+    fn find_compress(&mut self, mut v: usize) -> usize {
+        while self.parent[v] != v {
+            self.parent[v] = self.parent[self.parent[v]];
+            v = self.parent[v];
+        }
+        v
+    }
+
+    /// The number of components among the vertices added so far
+    pub fn num_components(&self) -> usize {
+        self.num_components
+    }
+
+    /// The component label of the vertex, `None` if it was not added
+    pub fn component(&self, v: VID) -> Option<u64> {
+        let v: usize = v.into();
+        self.present
+            .get(v)
+            .filter(|present| **present)
+            .map(|_| self.min_id[self.find(v)])
+    }
+
+    /// The current components of the vertices in `g`
+    pub fn result<G: GraphViewOps>(&self, g: &G) -> AlgorithmResult<String, u64> {
+        AlgorithmResult::new(
+            g.vertices()
+                .iter()
+                .filter_map(|v| Some((v.name(), self.component(v.vertex)?)))
+                .collect(),
+        )
+    }
+}
+
+impl IncrementalAlgorithm for IncrementalWcc {
+    fn vertex_added(&mut self, vertex: VID, id: u64) {
+        self.add(vertex.into(), id)
+    }
+
+    fn edge_added(&mut self, src: VID, dst: VID) {
+        let (src, dst): (usize, usize) = (src.into(), dst.into());
+        self.ensure(src.max(dst));
+        let (a, b) = (self.find_compress(src), self.find_compress(dst));
+        if a != b {
+            let (root, child) = if self.min_id[a] <= self.min_id[b] {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            self.parent[child] = root;
+            self.num_components -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod incremental_wcc_test {
+    use super::*;
+    use crate::algorithms::incremental::IncrementalGraph;
+
+    #[test]
+    fn merges_components() {
+        let graph = IncrementalGraph::new(Graph::new());
+        let wcc = graph.subscribe(IncrementalWcc::new());
+        for (t, src, dst) in [(0, 5, 6), (1, 3, 4), (2, 6, 7), (3, 1, 1)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        assert_eq!(wcc.read().num_components(), 3);
+        let component = |v: u64| wcc.read().component(graph.vertex(v).unwrap().vertex);
+        assert_eq!(component(7), Some(5));
+
+        graph.add_edge(4, 7, 4, NO_PROPS, None).unwrap();
+        graph.add_edge(5, 3, 3, NO_PROPS, None).unwrap();
+        assert_eq!(wcc.read().num_components(), 2);
+        assert_eq!(component(6), Some(3));
+        assert_eq!(component(1), Some(1));
+    }
+}
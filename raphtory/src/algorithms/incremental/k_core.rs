@@ -0,0 +1,159 @@
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        incremental::{Adjacency, IncrementalAlgorithm},
+    },
+    core::entities::VID,
+    prelude::*,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Core numbers maintained with the subcore insertion algorithm (Sariyüce et al., 2013)
+///
+/// A new edge increases the core number of some vertices by at most one. Only vertices with the
+/// same core number `r` as the lower endpoint that are connected to it through such vertices are
+/// candidates. Candidates with at most `r` neighbours in the candidate set or with a higher core
+/// number are removed repeatedly, the remaining ones move to the `r + 1` core.
+///
+/// Edge directions, repeated edges and self-loops are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct IncrementalKCore {
+    adjacency: Adjacency,
+    core: Vec<usize>,
+}
+
+impl IncrementalKCore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn ensure(&mut self, v: usize) {
+        self.adjacency.ensure(v);
+        self.core.resize(self.adjacency.len(), 0);
+    }
+
+    /// The largest `k` such that the vertex is in the k-core
+    pub fn core_number(&self, v: VID) -> usize {
+        self.core.get(usize::from(v)).copied().unwrap_or(0)
+    }
+
+    /// The vertices in the k-core, i.e. with core number at least `k`
+    pub fn k_core_set(&self, k: usize) -> HashSet<VID> {
+        self.core
+            .iter()
+            .enumerate()
+            .filter(|(_, core)| **core >= k)
+            .map(|(v, _)| v.into())
+            .collect()
+    }
+
+    /// The current core numbers of the vertices in `g`
+    pub fn result<G: GraphViewOps>(&self, g: &G) -> AlgorithmResult<String, usize> {
+        AlgorithmResult::new(
+            g.vertices()
+                .iter()
+                .map(|v| (v.name(), self.core_number(v.vertex)))
+                .collect(),
+        )
+    }
+}
+
+impl IncrementalAlgorithm for IncrementalKCore {
+    fn vertex_added(&mut self, vertex: VID, _id: u64) {
+        self.ensure(vertex.into())
+    }
+
+    fn edge_added(&mut self, src: VID, dst: VID) {
+        let (src, dst): (usize, usize) = (src.into(), dst.into());
+        self.ensure(src.max(dst));
+        if !self.adjacency.insert(src, dst) {
+            return;
+        }
+        let root = if self.core[src] <= self.core[dst] {
+            src
+        } else {
+            dst
+        };
+        let r = self.core[root];
+
+        // the candidates with the number of neighbours that can support them in the r + 1 core
+        let mut support: HashMap<usize, usize> = HashMap::from([(root, 0)]);
+        let mut queue = VecDeque::from([root]);
+        while let Some(v) = queue.pop_front() {
+            let mut count = 0;
+            for w in self.adjacency.neighbours(v) {
+                if self.core[*w] >= r {
+                    count += 1;
+                }
+                if self.core[*w] == r && !support.contains_key(w) {
+                    support.insert(*w, 0);
+                    queue.push_back(*w);
+                }
+            }
+            support.insert(v, count);
+        }
+
+        let mut evicted: HashSet<usize> = HashSet::new();
+        let mut queue: VecDeque<usize> = support
+            .iter()
+            .filter(|(_, count)| **count <= r)
+            .map(|(v, _)| *v)
+            .collect();
+        while let Some(v) = queue.pop_front() {
+            if !evicted.insert(v) {
+                continue;
+            }
+            for w in self.adjacency.neighbours(v) {
+                if evicted.contains(w) {
+                    continue;
+                }
+                if let Some(count) = support.get_mut(w) {
+                    *count -= 1;
+                    if *count == r {
+                        queue.push_back(*w);
+                    }
+                }
+            }
+        }
+        for v in support.keys() {
+            if !evicted.contains(v) {
+                self.core[*v] += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod incremental_k_core_test {
+    use super::*;
+    use crate::algorithms::incremental::IncrementalGraph;
+
+    #[test]
+    fn core_numbers() {
+        let graph = IncrementalGraph::new(Graph::new());
+        let cores = graph.subscribe(IncrementalKCore::new());
+        // a 4-clique on 1..=4 with a tail 4 - 5 - 6 and a triangle 5, 6, 7
+        let edges = [
+            (1, 2),
+            (5, 6),
+            (2, 3),
+            (3, 1),
+            (4, 5),
+            (1, 4),
+            (6, 7),
+            (2, 4),
+            (7, 5),
+            (3, 4),
+        ];
+        for (t, (src, dst)) in edges.into_iter().enumerate() {
+            graph.add_edge(t as i64, src, dst, NO_PROPS, None).unwrap();
+        }
+        let result = cores.read().result(&graph);
+        let expected: Vec<(u64, usize)> =
+            vec![(1, 3), (2, 3), (3, 3), (4, 3), (5, 2), (6, 2), (7, 2)];
+        for (v, core) in expected {
+            assert_eq!(result.get(&v.to_string()), Some(&core));
+        }
+        assert_eq!(cores.read().k_core_set(3).len(), 4);
+    }
+}
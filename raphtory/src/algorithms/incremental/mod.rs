@@ -0,0 +1,265 @@
+//! # Incremental Algorithms
+//!
+//! Algorithms that maintain their result while the graph grows instead of recomputing it over the
+//! whole view. An [`IncrementalGraph`] wraps a graph and forwards every vertex and edge addition
+//! made through [`InternalAdditionOps`] to the subscribed algorithms. The [`Subscription`] returned
+//! when subscribing gives access to the current state of the algorithm at any time.
+//!
+//! Subscribing to a graph that already has vertices and edges replays them first, so the result is
+//! always up to date with the graph. Deletions are not tracked.
+//!
+//! The available algorithms are
+//!
+//! * [`IncrementalWcc`](connected_components::IncrementalWcc) - weakly connected components
+//! * [`IncrementalTriangleCount`](triangle_count::IncrementalTriangleCount) - global and local triangle counts
+//! * [`IncrementalKCore`](k_core::IncrementalKCore) - core numbers and k-core sets
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::algorithms::incremental::{
+//!     connected_components::IncrementalWcc, triangle_count::IncrementalTriangleCount,
+//!     IncrementalGraph,
+//! };
+//! use raphtory::prelude::*;
+//!
+//! let graph = IncrementalGraph::new(Graph::new());
+//! let components = graph.subscribe(IncrementalWcc::new());
+//! let triangles = graph.subscribe(IncrementalTriangleCount::new());
+//!
+//! graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+//! graph.add_edge(1, 3, 4, NO_PROPS, None).unwrap();
+//! assert_eq!(components.read().num_components(), 2);
+//!
+//! graph.add_edge(2, 2, 3, NO_PROPS, None).unwrap();
+//! graph.add_edge(3, 3, 1, NO_PROPS, None).unwrap();
+//! assert_eq!(components.read().num_components(), 1);
+//! assert_eq!(triangles.read().count(), 1);
+//! ```
+pub mod connected_components;
+pub mod k_core;
+pub mod triangle_count;
+
+use crate::{
+    core::{
+        entities::{EID, VID},
+        storage::timeindex::TimeIndexEntry,
+        utils::errors::GraphError,
+    },
+    db::api::{
+        mutation::internal::InternalAdditionOps,
+        view::internal::{DynamicGraph, InheritViewOps, IntoDynamic},
+    },
+    prelude::*,
+};
+use parking_lot::{RwLock, RwLockReadGuard};
+use std::{collections::HashSet, ops::Deref, sync::Arc};
+
+/// An algorithm that is updated with every addition to the graph
+pub trait IncrementalAlgorithm: Send + Sync + 'static {
+    /// Called for every vertex update, including the ones made when adding edges
+    fn vertex_added(&mut self, vertex: VID, id: u64);
+
+    /// Called for every edge update, after the vertex updates of its endpoints
+    fn edge_added(&mut self, src: VID, dst: VID);
+}
+
+type Subscribers = Arc<RwLock<Vec<Arc<RwLock<dyn IncrementalAlgorithm>>>>>;
+
+/// A graph that forwards its additions to the subscribed incremental algorithms
+#[derive(Clone)]
+pub struct IncrementalGraph<G> {
+    graph: G,
+    subscribers: Subscribers,
+}
+
+/// A handle to an algorithm subscribed to an [`IncrementalGraph`]
+pub struct Subscription<A> {
+    algorithm: Arc<RwLock<A>>,
+}
+
+impl<A> Clone for Subscription<A> {
+    fn clone(&self) -> Self {
+        Self {
+            algorithm: self.algorithm.clone(),
+        }
+    }
+}
+
+impl<A> Subscription<A> {
+    /// Locks the algorithm to read its current state, additions wait until the guard is dropped
+    pub fn read(&self) -> RwLockReadGuard<'_, A> {
+        self.algorithm.read()
+    }
+}
+
+impl<G> Deref for IncrementalGraph<G> {
+    type Target = G;
+
+    fn deref(&self) -> &Self::Target {
+        &self.graph
+    }
+}
+
+impl<G: GraphViewOps> IntoDynamic for IncrementalGraph<G> {
+    fn into_dynamic(self) -> DynamicGraph {
+        DynamicGraph::new(self)
+    }
+}
+
+impl<G: GraphViewOps> InheritViewOps for IncrementalGraph<G> {}
+
+impl<G: GraphViewOps + InternalAdditionOps> IncrementalGraph<G> {
+    pub fn new(graph: G) -> Self {
+        Self {
+            graph,
+            subscribers: Default::default(),
+        }
+    }
+
+    /// Subscribes the algorithm to the additions, the existing vertices and edges are replayed first
+    pub fn subscribe<A: IncrementalAlgorithm>(&self, algorithm: A) -> Subscription<A> {
+        let algorithm = Arc::new(RwLock::new(algorithm));
+        // holding the lock while replaying means an addition racing with the subscription is
+        // seen at least once, which the algorithms tolerate as they ignore repeated updates
+        let mut subscribers = self.subscribers.write();
+        {
+            let mut state = algorithm.write();
+            for v in self.graph.vertices() {
+                state.vertex_added(v.vertex, v.id());
+            }
+            for e in self.graph.edges() {
+                state.edge_added(e.edge.src(), e.edge.dst());
+            }
+        }
+        subscribers.push(algorithm.clone());
+        Subscription { algorithm }
+    }
+
+    fn notify(&self, update: impl Fn(&mut dyn IncrementalAlgorithm)) {
+        for algorithm in self.subscribers.read().iter() {
+            update(&mut *algorithm.write());
+        }
+    }
+}
+
+impl<G: GraphViewOps + InternalAdditionOps> InternalAdditionOps for IncrementalGraph<G> {
+    fn next_event_id(&self) -> usize {
+        self.graph.next_event_id()
+    }
+
+    fn internal_add_vertex(
+        &self,
+        t: TimeIndexEntry,
+        v: u64,
+        name: Option<&str>,
+        vertex_type: Option<&str>,
+        props: Vec<(String, Prop)>,
+        layer: Option<&str>,
+    ) -> Result<VID, GraphError> {
+        let vid = self
+            .graph
+            .internal_add_vertex(t, v, name, vertex_type, props, layer)?;
+        self.notify(|algorithm| algorithm.vertex_added(vid, v));
+        Ok(vid)
+    }
+
+    fn internal_add_edge(
+        &self,
+        t: TimeIndexEntry,
+        src: u64,
+        dst: u64,
+        props: Vec<(String, Prop)>,
+        layer: Option<&str>,
+    ) -> Result<EID, GraphError> {
+        let eid = self.graph.internal_add_edge(t, src, dst, props, layer)?;
+        if let (Some(src), Some(dst)) = (self.graph.vertex(src), self.graph.vertex(dst)) {
+            self.notify(|algorithm| algorithm.edge_added(src.vertex, dst.vertex));
+        }
+        Ok(eid)
+    }
+}
+
+/// Undirected adjacency sets without self-loops, indexed by `VID`
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Adjacency {
+    neighbours: Vec<HashSet<usize>>,
+}
+
+impl Adjacency {
+    pub(crate) fn len(&self) -> usize {
+        self.neighbours.len()
+    }
+
+    pub(crate) fn ensure(&mut self, v: usize) {
+        if v >= self.neighbours.len() {
+            self.neighbours.resize_with(v + 1, Default::default);
+        }
+    }
+
+    /// Inserts the edge and returns `false` if it is a self-loop or already present
+    pub(crate) fn insert(&mut self, u: usize, v: usize) -> bool {
+        self.ensure(u.max(v));
+        u != v && self.neighbours[u].insert(v) && self.neighbours[v].insert(u)
+    }
+
+    pub(crate) fn neighbours(&self, v: usize) -> &HashSet<usize> {
+        &self.neighbours[v]
+    }
+}
+
+#[cfg(test)]
+mod incremental_test {
+    use super::{
+        connected_components::IncrementalWcc, k_core::IncrementalKCore,
+        triangle_count::IncrementalTriangleCount, *,
+    };
+    use crate::algorithms::{
+        connected_components::weakly_connected_components, k_core::k_core_set,
+        triangle_count::triangle_count,
+    };
+
+    #[test]
+    fn matches_batch_algorithms() {
+        let edges = [
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (4, 2),
+            (6, 7),
+            (2, 1),
+            (8, 6),
+            (1, 4),
+        ];
+        let graph = IncrementalGraph::new(Graph::new());
+        graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_vertex(0, 9, NO_PROPS, None).unwrap();
+
+        // subscribing replays the existing graph
+        let components = graph.subscribe(IncrementalWcc::new());
+        let triangles = graph.subscribe(IncrementalTriangleCount::new());
+        let cores = graph.subscribe(IncrementalKCore::new());
+
+        for (t, (src, dst)) in edges.iter().enumerate() {
+            graph
+                .add_edge(t as i64, *src, *dst, NO_PROPS, Some("layer"))
+                .unwrap();
+
+            assert_eq!(
+                components.read().result(&graph).get_all(),
+                weakly_connected_components(&graph.graph, usize::MAX, None).get_all()
+            );
+            assert_eq!(triangles.read().count(), triangle_count(&graph.graph, None));
+            for k in 1..4 {
+                assert_eq!(
+                    cores.read().k_core_set(k),
+                    k_core_set(&graph.graph, k, usize::MAX, None)
+                );
+            }
+        }
+        assert_eq!(components.read().num_components(), 3);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        incremental::{Adjacency, IncrementalAlgorithm},
+    },
+    core::entities::VID,
+    prelude::*,
+};
+
+/// Global and local triangle counts, ignoring edge directions, repeated edges and self-loops
+///
+/// Every new undirected edge closes one triangle for each common neighbour of its endpoints.
+#[derive(Clone, Debug, Default)]
+pub struct IncrementalTriangleCount {
+    adjacency: Adjacency,
+    local: Vec<usize>,
+    count: usize,
+}
+
+impl IncrementalTriangleCount {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn ensure(&mut self, v: usize) {
+        self.adjacency.ensure(v);
+        self.local.resize(self.adjacency.len(), 0);
+    }
+
+    /// The number of triangles in the graph
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The number of triangles the vertex is part of
+    pub fn local_count(&self, v: VID) -> usize {
+        self.local.get(usize::from(v)).copied().unwrap_or(0)
+    }
+
+    /// The current local triangle counts of the vertices in `g`
+    pub fn result<G: GraphViewOps>(&self, g: &G) -> AlgorithmResult<String, usize> {
+        AlgorithmResult::new(
+            g.vertices()
+                .iter()
+                .map(|v| (v.name(), self.local_count(v.vertex)))
+                .collect(),
+        )
+    }
+}
+
+impl IncrementalAlgorithm for IncrementalTriangleCount {
+    fn vertex_added(&mut self, vertex: VID, _id: u64) {
+        self.ensure(vertex.into())
+    }
+
+    fn edge_added(&mut self, src: VID, dst: VID) {
+        let (src, dst): (usize, usize) = (src.into(), dst.into());
+        self.ensure(src.max(dst));
+        if !self.adjacency.insert(src, dst) {
+            return;
+        }
+        let (small, large) =
+            match self.adjacency.neighbours(src).len() <= self.adjacency.neighbours(dst).len() {
+                true => (src, dst),
+                false => (dst, src),
+            };
+        let common: Vec<usize> = self
+            .adjacency
+            .neighbours(small)
+            .iter()
+            .filter(|w| self.adjacency.neighbours(large).contains(w))
+            .copied()
+            .collect();
+        self.count += common.len();
+        self.local[src] += common.len();
+        self.local[dst] += common.len();
+        for w in common {
+            self.local[w] += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod incremental_triangle_count_test {
+    use super::*;
+    use crate::algorithms::{
+        incremental::IncrementalGraph, local_triangle_count::local_triangle_count,
+    };
+
+    #[test]
+    fn local_counts() {
+        let graph = IncrementalGraph::new(Graph::new());
+        let triangles = graph.subscribe(IncrementalTriangleCount::new());
+        let edges = [(1, 2), (2, 3), (3, 1), (2, 1), (3, 4), (4, 2), (5, 1)];
+        for (t, (src, dst)) in edges.into_iter().enumerate() {
+            graph.add_edge(t as i64, src, dst, NO_PROPS, None).unwrap();
+        }
+        assert_eq!(triangles.read().count(), 2);
+        let result = triangles.read().result(&graph);
+        for v in 1..=5u64 {
+            assert_eq!(
+                result.get(&v.to_string()).copied(),
+                local_triangle_count(&*graph, v)
+            );
+        }
+    }
+}
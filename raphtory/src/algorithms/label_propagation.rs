@@ -0,0 +1,162 @@
+//! # Label Propagation
+//!
+//! Asynchronous label propagation community detection (Raghavan, Albert and Kumara, 2007).
+//!
+//! Every vertex starts with its own label. The vertices are then visited in a random order and each
+//! vertex adopts the label carried by most of its neighbours, using the labels its neighbours have
+//! at the moment of the visit. This is repeated until every vertex carries a label that is most
+//! frequent in its neighbourhood or the maximum number of iterations is reached. The edges are
+//! treated as undirected.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::algorithms::label_propagation::label_propagation;
+//! use raphtory::prelude::*;
+//!
+//! let graph = Graph::new();
+//! for (src, dst) in [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4)] {
+//!     graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+//! }
+//! let result = label_propagation(&graph, 100, Some(42));
+//! assert_eq!(result.get(&"1".to_string()), result.get(&"2".to_string()));
+//! assert_ne!(result.get(&"1".to_string()), result.get(&"4".to_string()));
+//! ```
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        utils::{seeded_rng, VertexIndex},
+    },
+    core::Direction,
+    db::api::view::GraphViewOps,
+};
+use rand::prelude::*;
+use std::collections::HashMap;
+
+/// Asynchronous label propagation
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+/// * `iter_count` - The maximum number of times every vertex is visited, the labels may not have
+///   converged if the limit is reached
+/// * `seed` - Seed for the order in which the vertices are visited and for breaking ties, if `None`
+///   a random seed is used
+///
+/// # Returns
+///
+/// An AlgorithmResult mapping each vertex name to its label, the labels are the ids of one of the
+/// vertices in the community
+///
+pub fn label_propagation<G: GraphViewOps>(
+    g: &G,
+    iter_count: usize,
+    seed: Option<u64>,
+) -> AlgorithmResult<String, u64> {
+    let mut rng = seeded_rng(seed);
+    let index = VertexIndex::new(g);
+    let neighbours = index.neighbours(g, Direction::BOTH);
+    let mut labels = index.ids(g);
+    let mut order: Vec<usize> = (0..index.len()).collect();
+
+    let most_frequent = |labels: &[u64], i: usize| -> Vec<u64> {
+        let mut counts: HashMap<u64, usize> = HashMap::new();
+        for j in &neighbours[i] {
+            *counts.entry(labels[*j]).or_default() += 1;
+        }
+        let max = counts.values().copied().max().unwrap_or_default();
+        let mut best: Vec<u64> = counts
+            .into_iter()
+            .filter(|(_, count)| *count == max)
+            .map(|(label, _)| label)
+            .collect();
+        best.sort();
+        best
+    };
+
+    for _ in 0..iter_count {
+        order.shuffle(&mut rng);
+        for i in order.iter().copied() {
+            let best = most_frequent(&labels, i);
+            if !best.is_empty() && !best.contains(&labels[i]) {
+                labels[i] = *best.choose(&mut rng).unwrap();
+            }
+        }
+        let converged = (0..index.len()).all(|i| {
+            let best = most_frequent(&labels, i);
+            best.is_empty() || best.contains(&labels[i])
+        });
+        if converged {
+            break;
+        }
+    }
+
+    AlgorithmResult::new(index.names(g).into_iter().zip(labels).collect())
+}
+
+#[cfg(test)]
+mod label_propagation_test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn two_triangles() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph.add_vertex(0, 7, NO_PROPS, None).unwrap();
+
+        for seed in 0..10 {
+            let result = label_propagation(&graph, 100, Some(seed));
+            let label = |v: &str| *result.get(&v.to_string()).unwrap();
+            assert_eq!(label("1"), label("2"));
+            assert_eq!(label("1"), label("3"));
+            assert_eq!(label("4"), label("5"));
+            assert_eq!(label("4"), label("6"));
+            assert_ne!(label("1"), label("4"));
+            // isolated vertices keep their own label
+            assert_eq!(label("7"), 7);
+        }
+
+        // without iterations every vertex keeps its own label
+        let result = label_propagation(&graph, 0, Some(0));
+        for v in graph.vertices() {
+            assert_eq!(result.get(&v.name()), Some(&v.id()));
+        }
+    }
+
+    #[test]
+    fn labels_are_stable() {
+        let graph = Graph::new();
+        for (src, dst) in [
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+            (6, 7),
+        ] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        for seed in 0..10 {
+            let result = label_propagation(&graph, 100, Some(seed));
+            for v in graph.vertices() {
+                let mut counts: HashMap<u64, usize> = HashMap::new();
+                for n in v.neighbours() {
+                    *counts
+                        .entry(result.get(&n.name()).copied().unwrap())
+                        .or_default() += 1;
+                }
+                let max = counts.values().copied().max().unwrap();
+                assert_eq!(counts[result.get(&v.name()).unwrap()], max);
+            }
+            assert_eq!(
+                result.get_all(),
+                label_propagation(&graph, 100, Some(seed)).get_all()
+            );
+        }
+    }
+}
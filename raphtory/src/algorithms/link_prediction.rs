@@ -0,0 +1,328 @@
+//! # Link Prediction
+//!
+//! Neighbourhood based scores for how likely two vertices are to become connected (Liben-Nowell
+//! and Kleinberg, 2007). The edges are treated as undirected, `N(v)` denotes the neighbours of `v`:
+//!
+//! * `CommonNeighbours`: `|N(a) ∩ N(b)|`
+//! * `Jaccard`: `|N(a) ∩ N(b)| / |N(a) ∪ N(b)|`
+//! * `AdamicAdar`: the sum of `1 / ln |N(z)|` over the common neighbours `z`
+//! * `PreferentialAttachment`: `|N(a)| * |N(b)|`
+//! * `ResourceAllocation`: the sum of `1 / |N(z)|` over the common neighbours `z`
+//!
+//! To evaluate the predictions, compute them on one window and compare with the edges of the next
+//! window using [`prediction_precision`].
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::algorithms::link_prediction::{
+//!     prediction_precision, top_k_predictions, LinkPredictionScore,
+//! };
+//! use raphtory::prelude::*;
+//!
+//! let graph = Graph::new();
+//! graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+//! graph.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+//! graph.add_edge(10, 1, 3, NO_PROPS, None).unwrap();
+//!
+//! let predictions = top_k_predictions(
+//!     &graph.window(0, 10),
+//!     1,
+//!     LinkPredictionScore::CommonNeighbours,
+//!     None,
+//! );
+//! let predicted = predictions.get(&"1".to_string()).unwrap();
+//! assert_eq!(predicted[0].0, "3");
+//!
+//! let pairs = vec![("1".to_string(), "3".to_string())];
+//! assert_eq!(prediction_precision(&pairs, &graph.window(10, 20)), 1.0);
+//! ```
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        utils::{thread_pool, VertexIndex},
+    },
+    core::{entities::vertices::input_vertex::InputVertex, Direction},
+    prelude::*,
+};
+use ordered_float::OrderedFloat;
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// The link prediction score to compute
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinkPredictionScore {
+    CommonNeighbours,
+    Jaccard,
+    AdamicAdar,
+    PreferentialAttachment,
+    ResourceAllocation,
+}
+
+/// Sorted undirected neighbourhoods of the vertices with consecutive indices
+struct Neighbourhoods {
+    names: Vec<String>,
+    index: VertexIndex,
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl Neighbourhoods {
+    fn new<G: GraphViewOps>(g: &G) -> Self {
+        let index = VertexIndex::new(g);
+        Self {
+            names: index.names(g),
+            neighbours: index.neighbours(g, Direction::BOTH),
+            index,
+        }
+    }
+
+    fn degree(&self, v: usize) -> f64 {
+        self.neighbours[v].len() as f64
+    }
+
+    fn common_neighbours(&self, a: usize, b: usize) -> impl Iterator<Item = usize> + '_ {
+        let (a, b) = (&self.neighbours[a], &self.neighbours[b]);
+        a.iter()
+            .copied()
+            .filter(move |z| b.binary_search(z).is_ok())
+    }
+
+    fn score(&self, a: usize, b: usize, score: LinkPredictionScore) -> f64 {
+        match score {
+            LinkPredictionScore::CommonNeighbours => self.common_neighbours(a, b).count() as f64,
+            LinkPredictionScore::Jaccard => {
+                let common = self.common_neighbours(a, b).count() as f64;
+                let union = self.degree(a) + self.degree(b) - common;
+                if union > 0f64 {
+                    common / union
+                } else {
+                    0f64
+                }
+            }
+            LinkPredictionScore::AdamicAdar => self
+                .common_neighbours(a, b)
+                .map(|z| 1f64 / self.degree(z).ln())
+                .sum(),
+            LinkPredictionScore::PreferentialAttachment => self.degree(a) * self.degree(b),
+            LinkPredictionScore::ResourceAllocation => self
+                .common_neighbours(a, b)
+                .map(|z| 1f64 / self.degree(z))
+                .sum(),
+        }
+    }
+}
+
+/// Computes a link prediction score for the candidate pairs
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+/// * `pairs` - The candidate pairs of vertices
+/// * `score` - The score to compute
+///
+/// # Returns
+///
+/// An AlgorithmResult mapping the pairs of vertex names to their score, pairs with a vertex that
+/// is not in the graph are left out
+///
+pub fn link_prediction_scores<G: GraphViewOps, T: InputVertex>(
+    g: &G,
+    pairs: Vec<(T, T)>,
+    score: LinkPredictionScore,
+) -> AlgorithmResult<(String, String), OrderedFloat<f64>> {
+    let neighbourhoods = Neighbourhoods::new(g);
+    let index = |v: &T| neighbourhoods.index.get(g.vertex(v.id())?.vertex);
+    AlgorithmResult::new_with_float(
+        pairs
+            .iter()
+            .filter_map(|(a, b)| {
+                let (a, b) = (index(a)?, index(b)?);
+                Some((
+                    (
+                        neighbourhoods.names[a].clone(),
+                        neighbourhoods.names[b].clone(),
+                    ),
+                    neighbourhoods.score(a, b, score),
+                ))
+            })
+            .collect(),
+    )
+}
+
+/// Computes the `k` highest scoring non-adjacent vertices for every vertex
+///
+/// For the scores based on common neighbours the candidates are the vertices at distance 2, for
+/// the preferential attachment all non-adjacent vertices are candidates.
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+/// * `k` - The number of predictions per vertex
+/// * `score` - The score to rank the candidates by
+/// * `threads` - Number of threads to use
+///
+/// # Returns
+///
+/// An AlgorithmResult mapping each vertex name to its predictions, given by the names of the
+/// predicted neighbours with their scores in descending order of the score
+///
+pub fn top_k_predictions<G: GraphViewOps>(
+    g: &G,
+    k: usize,
+    score: LinkPredictionScore,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, Vec<(String, f64)>> {
+    let neighbourhoods = Neighbourhoods::new(g);
+    let n = neighbourhoods.names.len();
+    let predictions: Vec<Vec<(String, f64)>> = thread_pool(threads).install(|| {
+        (0..n)
+            .into_par_iter()
+            .map(|a| {
+                let neighbours = &neighbourhoods.neighbours[a];
+                let candidates: HashSet<usize> = match score {
+                    LinkPredictionScore::PreferentialAttachment => (0..n).collect(),
+                    _ => neighbours
+                        .iter()
+                        .flat_map(|z| neighbourhoods.neighbours[*z].iter().copied())
+                        .collect(),
+                };
+                let mut scored: Vec<(usize, f64)> = candidates
+                    .into_iter()
+                    .filter(|b| *b != a && neighbours.binary_search(b).is_err())
+                    .map(|b| (b, neighbourhoods.score(a, b, score)))
+                    .collect();
+                scored.sort_by(|(a, sa), (b, sb)| {
+                    sb.total_cmp(sa)
+                        .then_with(|| neighbourhoods.names[*a].cmp(&neighbourhoods.names[*b]))
+                });
+                scored
+                    .into_iter()
+                    .take(k)
+                    .map(|(b, s)| (neighbourhoods.names[b].clone(), s))
+                    .collect()
+            })
+            .collect()
+    });
+    AlgorithmResult::new(
+        neighbourhoods
+            .names
+            .iter()
+            .cloned()
+            .zip(predictions)
+            .collect(),
+    )
+}
+
+/// The fraction of the predicted pairs that are connected (in either direction) in `future`
+///
+/// # Arguments
+///
+/// * `predicted` - The predicted pairs of vertex names
+/// * `future` - The view to check the predictions against, e.g. the next window
+///
+pub fn prediction_precision<G: GraphViewOps>(predicted: &[(String, String)], future: &G) -> f64 {
+    if predicted.is_empty() {
+        return 0f64;
+    }
+    let hits = predicted
+        .iter()
+        .filter(|(a, b)| {
+            future.has_edge(a.as_str(), b.as_str(), Layer::All)
+                || future.has_edge(b.as_str(), a.as_str(), Layer::All)
+        })
+        .count();
+    hits as f64 / predicted.len() as f64
+}
+
+#[cfg(test)]
+mod link_prediction_test {
+    use super::*;
+
+    /// 1 and 2 share the neighbours 3 and 4, 4 is also connected to 5
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [(0, 1, 3), (1, 3, 2), (2, 1, 4), (3, 2, 4), (4, 4, 5)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    fn pair_score(graph: &Graph, score: LinkPredictionScore) -> f64 {
+        link_prediction_scores(graph, vec![(1, 2)], score)
+            .get(&("1".to_string(), "2".to_string()))
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn scores() {
+        let graph = graph();
+        assert_eq!(
+            pair_score(&graph, LinkPredictionScore::CommonNeighbours),
+            2.0
+        );
+        assert_eq!(pair_score(&graph, LinkPredictionScore::Jaccard), 1.0);
+        let expected = 1.0 / 2f64.ln() + 1.0 / 3f64.ln();
+        assert!((pair_score(&graph, LinkPredictionScore::AdamicAdar) - expected).abs() < 1e-12);
+        assert_eq!(
+            pair_score(&graph, LinkPredictionScore::PreferentialAttachment),
+            4.0
+        );
+        let expected = 1.0 / 2.0 + 1.0 / 3.0;
+        assert!(
+            (pair_score(&graph, LinkPredictionScore::ResourceAllocation) - expected).abs() < 1e-12
+        );
+
+        // pairs with missing vertices are left out, the scores follow the view
+        let result = link_prediction_scores(
+            &graph.window(0, 2),
+            vec![(1, 2), (1, 5)],
+            LinkPredictionScore::CommonNeighbours,
+        );
+        assert_eq!(result.get_all().len(), 1);
+        assert_eq!(
+            result.get(&("1".to_string(), "2".to_string())).unwrap().0,
+            1.0
+        );
+    }
+
+    #[test]
+    fn top_k() {
+        let graph = graph();
+        let result = top_k_predictions(&graph, 2, LinkPredictionScore::CommonNeighbours, None);
+        assert_eq!(
+            result.get(&"1".to_string()).unwrap(),
+            &vec![("2".to_string(), 2.0), ("5".to_string(), 1.0)]
+        );
+        assert_eq!(
+            result.get(&"3".to_string()).unwrap(),
+            &vec![("4".to_string(), 2.0)]
+        );
+
+        let result =
+            top_k_predictions(&graph, 1, LinkPredictionScore::PreferentialAttachment, None);
+        assert_eq!(
+            result.get(&"4".to_string()).unwrap(),
+            &vec![("3".to_string(), 6.0)]
+        );
+
+        // the predictions on the first window are checked against the edges of the next one
+        let predictions = top_k_predictions(
+            &graph.window(0, 2),
+            1,
+            LinkPredictionScore::Jaccard,
+            Some(2),
+        );
+        let predicted: Vec<(String, String)> = predictions
+            .get_all()
+            .iter()
+            .flat_map(|(a, ps)| ps.iter().map(|(b, _)| (a.clone(), b.clone())))
+            .collect();
+        assert_eq!(predicted.len(), 2);
+        assert_eq!(prediction_precision(&predicted, &graph.window(2, 5)), 0.0);
+        assert_eq!(
+            prediction_precision(&[("1".to_string(), "4".to_string())], &graph.window(2, 5)),
+            1.0
+        );
+    }
+}
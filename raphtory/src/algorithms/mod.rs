@@ -28,17 +28,29 @@
 
 pub mod algorithm_result;
 pub mod balance;
+pub mod centrality;
 pub mod clustering_coefficient;
+pub mod community_detection;
+pub mod community_tracking;
 pub mod connected_components;
 pub mod degree;
 pub mod directed_graph_density;
+pub mod embeddings;
 pub mod hits;
+pub mod incremental;
 pub mod k_core;
+pub mod label_propagation;
+pub mod link_prediction;
 pub mod local_clustering_coefficient;
 pub mod local_triangle_count;
 pub mod motifs;
 pub mod pagerank;
+pub mod random_walks;
 pub mod reciprocity;
+pub mod shortest_paths;
+pub mod temporal_paths;
 pub mod temporal_reachability;
+pub mod time_series;
 pub mod triangle_count;
 pub mod triplet_count;
+pub(crate) mod utils;
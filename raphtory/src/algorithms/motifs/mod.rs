@@ -1,3 +1,4 @@
 pub mod three_node_local_single_thread;
 pub mod three_node_motifs;
 pub mod three_node_temporal_motifs;
+pub mod temporal_motif_matcher;
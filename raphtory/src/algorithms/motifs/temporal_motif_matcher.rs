@@ -0,0 +1,497 @@
+//! # Temporal Motif Matcher
+//!
+//! Counts and enumerates the instances of an arbitrary delta-temporal motif (Paranjape et al.,
+//! 2017). A motif is an ordered list of edges over `k` vertex variables. An instance maps the
+//! variables to distinct vertices and the motif edges to edge updates between the mapped vertices,
+//! such that the timestamps of the updates are strictly increasing in the order of the motif edges
+//! and the last update happens at most `delta` after the first.
+//!
+//! Each motif edge can be restricted to a layer and to updates whose properties satisfy a list of
+//! filters. The properties are evaluated at the time of the update.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::algorithms::motifs::temporal_motif_matcher::{count_temporal_motif, TemporalMotif};
+//! use raphtory::prelude::*;
+//!
+//! let graph = Graph::new();
+//! graph.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+//! graph.add_edge(2, 2, 3, NO_PROPS, None).unwrap();
+//! graph.add_edge(3, 3, 1, NO_PROPS, None).unwrap();
+//!
+//! // a cyclic triangle completed within 5 time units
+//! let triangle = TemporalMotif::new(5).edge(0, 1).edge(1, 2).edge(2, 0);
+//! assert_eq!(count_temporal_motif(&graph, &triangle, None), 1);
+//! ```
+use crate::{
+    algorithms::utils::thread_pool,
+    core::{entities::VID, Prop},
+    prelude::*,
+};
+use rayon::prelude::*;
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+/// Comparison between a property value and the value of a [`PropertyFilter`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl FromStr for Comparison {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "==" => Ok(Comparison::Eq),
+            "!=" => Ok(Comparison::Ne),
+            "<" => Ok(Comparison::Lt),
+            "<=" => Ok(Comparison::Le),
+            ">" => Ok(Comparison::Gt),
+            ">=" => Ok(Comparison::Ge),
+            _ => Err(format!("unknown comparison '{s}'")),
+        }
+    }
+}
+
+/// A condition on a property of the edge updates matched by a motif edge
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyFilter {
+    pub name: String,
+    pub comparison: Comparison,
+    pub value: Prop,
+}
+
+impl PropertyFilter {
+    pub fn new(name: &str, comparison: Comparison, value: Prop) -> Self {
+        Self {
+            name: name.to_owned(),
+            comparison,
+            value,
+        }
+    }
+
+    /// Whether `value` satisfies the filter, numbers of different types are compared as `f64`
+    pub fn matches(&self, value: &Prop) -> bool {
+        let ordering = value
+            .partial_cmp(&self.value)
+            .or_else(|| value.as_f64()?.partial_cmp(&self.value.as_f64()?));
+        match self.comparison {
+            Comparison::Eq => ordering == Some(Ordering::Equal),
+            Comparison::Ne => ordering != Some(Ordering::Equal),
+            Comparison::Lt => ordering == Some(Ordering::Less),
+            Comparison::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+            Comparison::Gt => ordering == Some(Ordering::Greater),
+            Comparison::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+        }
+    }
+}
+
+/// An edge of a motif between the vertex variables `src` and `dst`
+#[derive(Clone, Debug, PartialEq)]
+pub struct MotifEdge {
+    pub src: usize,
+    pub dst: usize,
+    pub layer: Option<String>,
+    pub filters: Vec<PropertyFilter>,
+}
+
+/// A temporal motif given by an ordered list of edges that have to happen within `delta`
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemporalMotif {
+    pub edges: Vec<MotifEdge>,
+    pub delta: i64,
+}
+
+impl TemporalMotif {
+    pub fn new(delta: i64) -> Self {
+        Self {
+            edges: vec![],
+            delta,
+        }
+    }
+
+    /// Append an edge between the variables `src` and `dst`
+    pub fn edge(self, src: usize, dst: usize) -> Self {
+        self.layered_edge(src, dst, None)
+    }
+
+    /// Append an edge between the variables `src` and `dst` that only matches updates in `layer`
+    pub fn layered_edge(mut self, src: usize, dst: usize, layer: Option<&str>) -> Self {
+        self.edges.push(MotifEdge {
+            src,
+            dst,
+            layer: layer.map(|l| l.to_owned()),
+            filters: vec![],
+        });
+        self
+    }
+
+    /// Restrict the last edge to updates whose property `name` compares to `value`
+    pub fn filter(mut self, name: &str, comparison: Comparison, value: Prop) -> Self {
+        if let Some(edge) = self.edges.last_mut() {
+            edge.filters
+                .push(PropertyFilter::new(name, comparison, value));
+        }
+        self
+    }
+
+    /// The number of vertex variables
+    pub fn num_variables(&self) -> usize {
+        self.edges
+            .iter()
+            .map(|e| e.src.max(e.dst) + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// An instance of a motif
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MotifInstance {
+    /// The names of the vertices mapped to the variables
+    pub vertices: Vec<String>,
+    /// The timestamps of the updates matched by the motif edges
+    pub times: Vec<i64>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Event {
+    t: i64,
+    src: VID,
+    dst: VID,
+}
+
+/// The updates matching a motif edge, sorted by time and indexed by their endpoints
+#[derive(Default)]
+struct EdgeEvents {
+    events: Vec<Event>,
+    by_src: HashMap<VID, Vec<Event>>,
+    by_dst: HashMap<VID, Vec<Event>>,
+}
+
+impl EdgeEvents {
+    fn new<G: GraphViewOps>(g: &G, filters: &[PropertyFilter]) -> Self {
+        let mut events: Vec<Event> = g
+            .edges()
+            .flat_map(|e| e.explode())
+            .filter_map(|e| {
+                let t = e.time()?;
+                let properties = e.properties();
+                filters
+                    .iter()
+                    .all(|filter| {
+                        properties
+                            .temporal()
+                            .get(&filter.name)
+                            .and_then(|prop| prop.at(t))
+                            .or_else(|| properties.constant().get(&filter.name))
+                            .map_or(false, |value| filter.matches(&value))
+                    })
+                    .then_some(Event {
+                        t,
+                        src: e.edge.src(),
+                        dst: e.edge.dst(),
+                    })
+            })
+            .collect();
+        events.sort_by_key(|e| e.t);
+        let mut by_src: HashMap<VID, Vec<Event>> = HashMap::new();
+        let mut by_dst: HashMap<VID, Vec<Event>> = HashMap::new();
+        for e in &events {
+            by_src.entry(e.src).or_default().push(*e);
+            by_dst.entry(e.dst).or_default().push(*e);
+        }
+        Self {
+            events,
+            by_src,
+            by_dst,
+        }
+    }
+
+    /// The candidate updates given the bound endpoints within the time range (`after`, `until`]
+    fn candidates(&self, src: Option<VID>, dst: Option<VID>, after: i64, until: i64) -> &[Event] {
+        let events = match (src, dst) {
+            (Some(src), _) => self.by_src.get(&src).map(|e| e.as_slice()),
+            (None, Some(dst)) => self.by_dst.get(&dst).map(|e| e.as_slice()),
+            (None, None) => Some(self.events.as_slice()),
+        }
+        .unwrap_or_default();
+        let start = events.partition_point(|e| e.t <= after);
+        let end = events.partition_point(|e| e.t <= until);
+        &events[start..end.max(start)]
+    }
+}
+
+struct Matcher<'a> {
+    motif: &'a TemporalMotif,
+    events: Vec<EdgeEvents>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new<G: GraphViewOps>(g: &G, motif: &'a TemporalMotif) -> Self {
+        let events = motif
+            .edges
+            .iter()
+            .map(|edge| match &edge.layer {
+                None => EdgeEvents::new(g, &edge.filters),
+                Some(layer) => g
+                    .layer(layer.as_str())
+                    .map(|g| EdgeEvents::new(&g, &edge.filters))
+                    .unwrap_or_default(),
+            })
+            .collect();
+        Self { motif, events }
+    }
+
+    /// Extends the partial instance given by `bindings` and `times` with the motif edge `i`,
+    /// calling `found` for every complete instance
+    fn extend<F: FnMut(&[Option<VID>], &[i64])>(
+        &self,
+        i: usize,
+        bindings: &mut Vec<Option<VID>>,
+        times: &mut Vec<i64>,
+        found: &mut F,
+    ) {
+        if i == self.motif.edges.len() {
+            found(bindings, times);
+            return;
+        }
+        let edge = &self.motif.edges[i];
+        let after = times.last().copied().unwrap_or(i64::MIN);
+        let until = times
+            .first()
+            .map_or(i64::MAX, |t| t.saturating_add(self.motif.delta));
+        let (src, dst) = (bindings[edge.src], bindings[edge.dst]);
+        for event in self.events[i].candidates(src, dst, after, until) {
+            if (edge.src == edge.dst) != (event.src == event.dst) {
+                continue;
+            }
+            // `bind` only sets unbound variables, remember which ones to reset
+            let new_src = bindings[edge.src].is_none();
+            if !self.bind(bindings, edge.src, event.src) {
+                continue;
+            }
+            let new_dst = bindings[edge.dst].is_none();
+            if self.bind(bindings, edge.dst, event.dst) {
+                times.push(event.t);
+                self.extend(i + 1, bindings, times, found);
+                times.pop();
+                if new_dst {
+                    bindings[edge.dst] = None;
+                }
+            }
+            if new_src {
+                bindings[edge.src] = None;
+            }
+        }
+    }
+
+    /// Binds the variable to the vertex if it is unbound and no other variable is bound to it,
+    /// returns whether the variable is bound to the vertex afterwards
+    fn bind(&self, bindings: &mut [Option<VID>], variable: usize, v: VID) -> bool {
+        match bindings[variable] {
+            Some(bound) => bound == v,
+            None => {
+                if bindings.contains(&Some(v)) {
+                    false
+                } else {
+                    bindings[variable] = Some(v);
+                    true
+                }
+            }
+        }
+    }
+
+    /// Runs `f` in parallel for every update matching the first motif edge and combines the results
+    fn run<T, F, R>(&self, threads: Option<usize>, f: F, reduce: R) -> T
+    where
+        T: Send + Default,
+        F: Fn(&mut Vec<Option<VID>>, &mut Vec<i64>) -> T + Sync,
+        R: Fn(T, T) -> T + Sync + Send,
+    {
+        if self.motif.edges.is_empty() {
+            return T::default();
+        }
+        let first = &self.motif.edges[0];
+        thread_pool(threads).install(|| {
+            self.events[0]
+                .events
+                .par_iter()
+                .filter(|e| (first.src == first.dst) == (e.src == e.dst))
+                .map(|e| {
+                    let mut bindings = vec![None; self.motif.num_variables()];
+                    bindings[first.src] = Some(e.src);
+                    bindings[first.dst] = Some(e.dst);
+                    let mut times = vec![e.t];
+                    f(&mut bindings, &mut times)
+                })
+                .reduce(T::default, &reduce)
+        })
+    }
+}
+
+/// Counts the instances of a temporal motif
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+/// * `motif` - The motif to count
+/// * `threads` - Number of threads to use
+///
+/// # Returns
+///
+/// The number of instances of the motif
+///
+pub fn count_temporal_motif<G: GraphViewOps>(
+    g: &G,
+    motif: &TemporalMotif,
+    threads: Option<usize>,
+) -> usize {
+    let matcher = Matcher::new(g, motif);
+    matcher.run(
+        threads,
+        |bindings, times| {
+            let mut count = 0;
+            matcher.extend(1, bindings, times, &mut |_, _| count += 1);
+            count
+        },
+        |a, b| a + b,
+    )
+}
+
+/// Enumerates the instances of a temporal motif
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+/// * `motif` - The motif to match
+/// * `threads` - Number of threads to use
+///
+/// # Returns
+///
+/// The instances of the motif sorted by their timestamps
+///
+pub fn temporal_motif_instances<G: GraphViewOps>(
+    g: &G,
+    motif: &TemporalMotif,
+    threads: Option<usize>,
+) -> Vec<MotifInstance> {
+    let matcher = Matcher::new(g, motif);
+    let mut instances = matcher.run(
+        threads,
+        |bindings, times| {
+            let mut instances = vec![];
+            matcher.extend(1, bindings, times, &mut |bindings, times| {
+                instances.push(MotifInstance {
+                    vertices: bindings
+                        .iter()
+                        .map(|v| v.map(|v| g.vertex_name(v)).unwrap_or_default())
+                        .collect(),
+                    times: times.to_vec(),
+                })
+            });
+            instances
+        },
+        |mut a, b| {
+            a.extend(b);
+            a
+        },
+    );
+    instances.sort_by(|a, b| a.times.cmp(&b.times).then_with(|| a.cmp(b)));
+    instances
+}
+
+#[cfg(test)]
+mod temporal_motif_matcher_test {
+    use super::*;
+
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst, amount) in [
+            (1, 1, 2, 10i64),
+            (2, 2, 3, 5),
+            (3, 3, 1, 20),
+            (4, 1, 2, 1),
+            (5, 2, 4, 10),
+            (12, 4, 1, 10),
+        ] {
+            graph
+                .add_edge(t, src, dst, [("amount", amount)], None)
+                .unwrap();
+        }
+        graph.add_edge(6, 2, 3, NO_PROPS, Some("other")).unwrap();
+        graph
+    }
+
+    #[test]
+    fn count_triangles_and_paths() {
+        let graph = graph();
+        let triangle = TemporalMotif::new(5).edge(0, 1).edge(1, 2).edge(2, 0);
+        assert_eq!(
+            temporal_motif_instances(&graph, &triangle, None),
+            vec![
+                MotifInstance {
+                    vertices: vec!["1".into(), "2".into(), "3".into()],
+                    times: vec![1, 2, 3]
+                },
+                MotifInstance {
+                    vertices: vec!["2".into(), "3".into(), "1".into()],
+                    times: vec![2, 3, 4]
+                },
+                MotifInstance {
+                    vertices: vec!["3".into(), "1".into(), "2".into()],
+                    times: vec![3, 4, 6]
+                }
+            ]
+        );
+        // 1 -> 2 -> 4 -> 1 only fits within 10 when starting from the update at 4
+        let long_triangle = TemporalMotif::new(10).edge(0, 1).edge(1, 2).edge(2, 0);
+        assert_eq!(count_temporal_motif(&graph, &long_triangle, Some(2)), 4);
+
+        // two hop paths between distinct vertices
+        let path = TemporalMotif::new(1).edge(0, 1).edge(1, 2);
+        assert_eq!(count_temporal_motif(&graph, &path, None), 4);
+
+        // repeated edge
+        let repeated = TemporalMotif::new(3).edge(0, 1).edge(0, 1);
+        assert_eq!(count_temporal_motif(&graph, &repeated, None), 1);
+    }
+
+    #[test]
+    fn layers_and_filters() {
+        let graph = graph();
+        let path = TemporalMotif::new(10)
+            .edge(0, 1)
+            .layered_edge(1, 2, Some("other"));
+        assert_eq!(
+            temporal_motif_instances(&graph, &path, None),
+            vec![
+                MotifInstance {
+                    vertices: vec!["1".into(), "2".into(), "3".into()],
+                    times: vec![1, 6]
+                },
+                MotifInstance {
+                    vertices: vec!["1".into(), "2".into(), "3".into()],
+                    times: vec![4, 6]
+                }
+            ]
+        );
+        let missing = TemporalMotif::new(10).layered_edge(0, 1, Some("missing"));
+        assert_eq!(count_temporal_motif(&graph, &missing, None), 0);
+
+        // money flowing on with a smaller amount
+        let flow = TemporalMotif::new(10)
+            .edge(0, 1)
+            .filter("amount", Comparison::Ge, Prop::I64(10))
+            .edge(1, 2)
+            .filter("amount", Comparison::Lt, Prop::F64(10.0));
+        assert_eq!(count_temporal_motif(&graph, &flow, None), 2);
+        assert_eq!(count_temporal_motif(&graph.window(2, 10), &flow, None), 1);
+    }
+}
@@ -1,11 +1,14 @@
 use crate::{
     algorithms::algorithm_result::AlgorithmResult,
     core::{
-        entities::{vertices::vertex_ref::VertexRef, VID},
+        entities::{
+            vertices::{input_vertex::InputVertex, vertex_ref::VertexRef},
+            VID,
+        },
         state::{accumulator_id::accumulators, compute_state::ComputeStateVec},
     },
     db::{
-        api::view::{GraphViewOps, VertexViewOps},
+        api::view::{EdgeViewOps, GraphViewOps, VertexViewOps},
         task::{
             context::Context,
             task::{ATask, Job, Step},
@@ -20,14 +23,14 @@ use std::collections::HashMap;
 #[derive(Clone, Debug)]
 struct PageRankState {
     score: f64,
-    out_degree: usize,
+    out_weight: f64,
 }
 
 impl PageRankState {
     fn new(num_vertices: usize) -> Self {
         Self {
             score: 1f64 / num_vertices as f64,
-            out_degree: 0,
+            out_weight: 0f64,
         }
     }
 
@@ -36,6 +39,29 @@ impl PageRankState {
     }
 }
 
+/// Sum of the values of the `weight` property over the history of the edge in the current view,
+/// falling back to the constant property of the edge, every edge has a weight of 1 if no property
+/// is given
+fn edge_weight<E: EdgeViewOps>(e: E, weight: Option<&str>) -> f64 {
+    match weight {
+        None => 1f64,
+        Some(name) => {
+            let props = e.properties();
+            props
+                .temporal()
+                .get(name)
+                .map(|prop| {
+                    prop.values()
+                        .into_iter()
+                        .map(|value| value.as_f64().unwrap_or(0f64))
+                        .sum::<f64>()
+                })
+                .or_else(|| props.constant().get(name).and_then(|prop| prop.as_f64()))
+                .unwrap_or(0f64)
+        }
+    }
+}
+
 /// PageRank Algorithm:
 /// PageRank shows how important a vertex is in a graph.
 ///
@@ -51,23 +77,86 @@ impl PageRankState {
 ///
 /// * An AlgorithmResult object containing the mapping from vertex ID to the PageRank score of the vertex
 ///
-#[allow(unused_variables)]
 pub fn unweighted_page_rank<G: GraphViewOps>(
     g: &G,
     iter_count: usize,
     threads: Option<usize>,
     tol: Option<f64>,
     use_l2_norm: bool,
+) -> AlgorithmResult<String, OrderedFloat<f64>> {
+    weighted_page_rank::<G, u64>(g, iter_count, threads, tol, use_l2_norm, None, None, None)
+}
+
+/// Weighted and personalized PageRank Algorithm:
+/// Scores flow along each out-edge of a vertex in proportion to the edge weight. With a
+/// personalization vector the random surfer only teleports (and leaves vertices without
+/// out-edges) to the seed vertices, with probability proportional to their value.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `iter_count`: Number of iterations to run the algorithm for
+/// * `threads`: Number of threads to use for parallel execution
+/// * `tol`: The tolerance value for convergence
+/// * `use_l2_norm`: Whether to use L2 norm for convergence
+/// * `weight`: The name of the edge property holding the weight, the values of the property are
+///   summed over the history of the edge in the view, edges without a temporal value use the
+///   constant property instead. If `None` every edge has a weight of 1.
+/// * `damping_factor`: The probability of following an edge instead of teleporting (defaults to 0.85)
+/// * `personalization`: The seed vertices with their (non-negative) teleport values, seeds that are
+///   not in the view are ignored. If `None` or all values are 0, teleports are uniform.
+///
+/// Result:
+///
+/// * An AlgorithmResult object containing the mapping from vertex ID to the PageRank score of the vertex
+///
+#[allow(clippy::too_many_arguments)]
+pub fn weighted_page_rank<G: GraphViewOps, T: InputVertex>(
+    g: &G,
+    iter_count: usize,
+    threads: Option<usize>,
+    tol: Option<f64>,
+    use_l2_norm: bool,
+    weight: Option<String>,
+    damping_factor: Option<f64>,
+    personalization: Option<Vec<(T, f64)>>,
 ) -> AlgorithmResult<String, OrderedFloat<f64>> {
     let n = g.num_vertices();
-    let total_edges = g.num_edges();
 
     let mut ctx: Context<G, ComputeStateVec> = g.into();
 
-    let tol: f64 = tol.unwrap_or_else(|| 0.000001f64);
-    let damp = 0.85;
-    let teleport_prob = (1f64 - damp) / n as f64;
-    let factor = damp / n as f64;
+    let tol: f64 = tol.unwrap_or(0.000001f64);
+    let damp = damping_factor.unwrap_or(0.85);
+
+    // teleport probabilities of the seed vertices, `None` means uniform
+    let mut seeds: HashMap<u64, f64> = HashMap::new();
+    for (v, value) in personalization.into_iter().flatten() {
+        if g.has_vertex(v.id()) {
+            *seeds.entry(v.id()).or_default() += value;
+        }
+    }
+    let total: f64 = seeds.values().sum();
+    let seeds = (total > 0f64).then(|| {
+        seeds
+            .into_iter()
+            .map(|(v, value)| (v, value / total))
+            .collect::<HashMap<_, _>>()
+    });
+    // the sinks share their score like teleports, the uniform case keeps the arithmetic of the
+    // unweighted algorithm
+    let factor = match &seeds {
+        None => damp / n as f64,
+        Some(_) => damp,
+    };
+    let sink_seeds = seeds.clone();
+    let teleport_prob = move |v: u64| match &seeds {
+        None => (1f64 - damp) / n as f64,
+        Some(seeds) => (1f64 - damp) * seeds.get(&v).copied().unwrap_or_default(),
+    };
+    let sink_share = move |v: u64| match &sink_seeds {
+        None => 1f64,
+        Some(seeds) => seeds.get(&v).copied().unwrap_or_default(),
+    };
 
     let max_diff = accumulators::sum::<f64>(2);
 
@@ -77,10 +166,14 @@ pub fn unweighted_page_rank<G: GraphViewOps>(
 
     ctx.global_agg_reset(total_sink_contribution);
 
+    let weight_step2 = weight.clone();
     let step1 = ATask::new(move |s| {
-        let out_degree = s.out_degree();
+        let out_weight = s
+            .out_edges()
+            .map(|e| edge_weight(e, weight.as_deref()))
+            .sum();
         let state: &mut PageRankState = s.get_mut();
-        state.out_degree = out_degree;
+        state.out_weight = out_weight;
         Step::Continue
     });
 
@@ -91,22 +184,22 @@ pub fn unweighted_page_rank<G: GraphViewOps>(
             state.reset();
         }
 
-        for t in s.in_neighbours() {
-            let prev = t.prev();
-
-            s.get_mut().score += prev.score / prev.out_degree as f64;
+        let mut score = 0f64;
+        for e in s.in_edges() {
+            let prev = e.src().prev().clone();
+            if prev.out_weight > 0f64 {
+                score += prev.score * edge_weight(e, weight_step2.as_deref()) / prev.out_weight;
+            }
         }
 
-        s.get_mut().score *= damp;
-
-        s.get_mut().score += teleport_prob;
+        s.get_mut().score = damp * score + teleport_prob(s.id());
         Step::Continue
     });
 
     let step3 = ATask::new(move |s| {
         let state: &mut PageRankState = s.get_mut();
 
-        if state.out_degree == 0 {
+        if state.out_weight <= 0f64 {
             let curr = s.prev().score;
 
             let ts_contrib = factor * curr;
@@ -120,9 +213,10 @@ pub fn unweighted_page_rank<G: GraphViewOps>(
         let total_sink_contribution = s
             .read_global_state(&total_sink_contribution)
             .unwrap_or_default();
-        // update local score with total sink contribution
+        // update local score with the share of the total sink contribution
+        let sink_contribution = total_sink_contribution * sink_share(s.id());
         let state: &mut PageRankState = s.get_mut();
-        state.score += total_sink_contribution;
+        state.score += sink_contribution;
 
         // update global max diff
 
@@ -130,7 +224,7 @@ pub fn unweighted_page_rank<G: GraphViewOps>(
         let prev = s.prev().score;
 
         let md = if use_l2_norm {
-            f64::powi(abs(prev - curr) as f64, 2)
+            f64::powi(abs(prev - curr), 2)
         } else {
             abs(prev - curr)
         };
@@ -142,10 +236,10 @@ pub fn unweighted_page_rank<G: GraphViewOps>(
     let step5 = Job::Check(Box::new(move |state| {
         let max_diff_val = state.read(&max_diff);
         let cont = if use_l2_norm {
-            let sum_d = f64::sqrt(max_diff_val as f64);
-            (sum_d as f64) > tol * n as f64
+            let sum_d = f64::sqrt(max_diff_val);
+            sum_d > tol * n as f64
         } else {
-            (max_diff_val as f64) > tol * n as f64
+            max_diff_val > tol * n as f64
         };
         if cont {
             Step::Continue
@@ -196,8 +290,11 @@ mod page_rank_tests {
     use pretty_assertions::assert_eq;
 
     use crate::{
-        db::{api::mutation::AdditionOps, graph::graph::Graph},
-        prelude::NO_PROPS,
+        db::{
+            api::mutation::{AdditionOps, PropertyAdditionOps},
+            graph::graph::Graph,
+        },
+        prelude::{TimeOps, NO_PROPS},
     };
 
     use super::*;
@@ -480,6 +577,210 @@ mod page_rank_tests {
         );
     }
 
+    fn load_weighted_graph() -> Graph {
+        let graph = Graph::new();
+
+        let edges = vec![
+            (1, 1, 2, 3.0),
+            (2, 1, 2, 2.0),
+            (3, 1, 3, 1.0),
+            (4, 2, 3, 2.0),
+            (5, 3, 1, 4.0),
+            (6, 3, 4, 1.0),
+        ];
+
+        for (t, src, dst, weight) in edges {
+            graph
+                .add_edge(t, src, dst, [("weight", weight)], None)
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn weighted_page_rank_test() {
+        let graph = load_weighted_graph();
+
+        let results = weighted_page_rank::<_, u64>(
+            &graph,
+            1000,
+            Some(2),
+            Some(1e-10),
+            false,
+            Some("weight".to_string()),
+            None,
+            None,
+        );
+
+        let expected = [
+            ("1", 0.28653),
+            ("2", 0.26567),
+            ("3", 0.32913),
+            ("4", 0.11867),
+        ];
+        for (name, rank) in expected {
+            assert_eq_f64(
+                Some(&results.get(&name.to_string()).unwrap().0),
+                Some(&rank),
+                5,
+            );
+        }
+
+        // the weights are aggregated over the history in the window only
+        let windowed = weighted_page_rank::<_, u64>(
+            &graph.window(2, 7),
+            1000,
+            Some(2),
+            Some(1e-10),
+            false,
+            Some("weight".to_string()),
+            None,
+            None,
+        );
+        assert!(windowed.get(&"2".to_string()).unwrap().0 < 0.26567);
+    }
+
+    #[test]
+    fn weighted_page_rank_integer_weights_test() {
+        let graph = Graph::new();
+        let edges = vec![
+            (1, 1, 2, 3i64),
+            (2, 1, 2, 2i64),
+            (3, 1, 3, 1i64),
+            (4, 2, 3, 2i64),
+            (5, 3, 1, 4i64),
+            (6, 3, 4, 1i64),
+        ];
+        for (t, src, dst, weight) in edges {
+            graph
+                .add_edge(t, src, dst, [("weight", weight)], None)
+                .unwrap();
+        }
+
+        let results = weighted_page_rank::<_, u64>(
+            &graph,
+            1000,
+            Some(2),
+            Some(1e-10),
+            false,
+            Some("weight".to_string()),
+            None,
+            None,
+        );
+
+        let expected = [
+            ("1", 0.28653),
+            ("2", 0.26567),
+            ("3", 0.32913),
+            ("4", 0.11867),
+        ];
+        for (name, rank) in expected {
+            assert_eq_f64(
+                Some(&results.get(&name.to_string()).unwrap().0),
+                Some(&rank),
+                5,
+            );
+        }
+    }
+
+    #[test]
+    fn weighted_page_rank_constant_weights_test() {
+        let graph = Graph::new();
+        let edges = vec![
+            (1, 2, 5.0),
+            (1, 3, 1.0),
+            (2, 3, 2.0),
+            (3, 1, 4.0),
+            (3, 4, 1.0),
+        ];
+        for (src, dst, weight) in edges {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+            graph
+                .add_edge_properties(src, dst, [("weight", weight)], None)
+                .unwrap();
+        }
+
+        let results = weighted_page_rank::<_, u64>(
+            &graph,
+            1000,
+            Some(2),
+            Some(1e-10),
+            false,
+            Some("weight".to_string()),
+            None,
+            None,
+        );
+
+        let expected = [
+            ("1", 0.28653),
+            ("2", 0.26567),
+            ("3", 0.32913),
+            ("4", 0.11867),
+        ];
+        for (name, rank) in expected {
+            assert_eq_f64(
+                Some(&results.get(&name.to_string()).unwrap().0),
+                Some(&rank),
+                5,
+            );
+        }
+    }
+
+    #[test]
+    fn personalized_page_rank_test() {
+        let graph = load_weighted_graph();
+
+        let results = weighted_page_rank(
+            &graph,
+            1000,
+            Some(2),
+            Some(1e-10),
+            false,
+            None,
+            Some(0.5),
+            Some(vec![(1, 1.0), (42, 1.0)]),
+        );
+
+        let expected = [
+            ("1", 0.58182),
+            ("2", 0.14545),
+            ("3", 0.21818),
+            ("4", 0.05455),
+        ];
+        for (name, rank) in expected {
+            assert_eq_f64(
+                Some(&results.get(&name.to_string()).unwrap().0),
+                Some(&rank),
+                5,
+            );
+        }
+
+        let results = weighted_page_rank(
+            &graph,
+            1000,
+            Some(2),
+            Some(1e-10),
+            false,
+            Some("weight".to_string()),
+            None,
+            Some(vec![("1", 3.0), ("2", 1.0)]),
+        );
+
+        let expected = [
+            ("1", 0.35038),
+            ("2", 0.29659),
+            ("3", 0.30174),
+            ("4", 0.05130),
+        ];
+        for (name, rank) in expected {
+            assert_eq_f64(
+                Some(&results.get(&name.to_string()).unwrap().0),
+                Some(&rank),
+                5,
+            );
+        }
+    }
+
     fn assert_eq_f64<T: Borrow<f64> + PartialEq + std::fmt::Debug>(
         a: Option<T>,
         b: Option<T>,
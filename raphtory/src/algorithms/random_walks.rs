@@ -0,0 +1,238 @@
+//! # Random Walks
+//!
+//! Generates random walks over a graph view, e.g. as input for the skip-gram trainer in
+//! [`embeddings`](crate::algorithms::embeddings).
+//!
+//! * `Uniform` walks move to a uniformly chosen neighbour, ignoring the direction of the edges.
+//! * `Node2Vec` walks are biased by the return parameter `p` and the in-out parameter `q`
+//!   (Grover and Leskovec, 2016): from the previous vertex `t` and the current vertex `v`, the walk
+//!   moves back to `t` with weight `1/p`, to a common neighbour of `t` and `v` with weight 1 and to
+//!   any other neighbour of `v` with weight `1/q`.
+//! * `Temporal` walks follow edge updates in their direction, always moving along an update that
+//!   happens strictly after the previous one. The first step is chosen uniformly among all updates
+//!   of the start vertex, a walk ends early if there is no later update.
+//!
+//! Self-loops are ignored by all strategies.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::algorithms::random_walks::{random_walks, WalkStrategy};
+//! use raphtory::prelude::*;
+//!
+//! let graph = Graph::new();
+//! graph.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+//! graph.add_edge(2, 2, 3, NO_PROPS, None).unwrap();
+//!
+//! let walks = random_walks(&graph, 5, 1, WalkStrategy::Temporal, Some(42), None);
+//! assert!(walks.contains(&vec!["1".to_string(), "2".to_string(), "3".to_string()]));
+//! ```
+use crate::{
+    algorithms::utils::{thread_pool, VertexIndex},
+    core::Direction,
+    prelude::*,
+};
+use rand::{prelude::*, rngs::StdRng};
+use rayon::prelude::*;
+
+/// How the next vertex of a walk is chosen
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WalkStrategy {
+    Uniform,
+    Node2Vec { p: f64, q: f64 },
+    Temporal,
+}
+
+struct Walker {
+    names: Vec<String>,
+    /// sorted undirected neighbours for the static walks
+    neighbours: Vec<Vec<usize>>,
+    /// outgoing updates sorted by time for the temporal walks
+    updates: Vec<Vec<(i64, usize)>>,
+}
+
+impl Walker {
+    fn new<G: GraphViewOps>(g: &G, strategy: WalkStrategy) -> Self {
+        let index = VertexIndex::new(g);
+        let (neighbours, updates) = match strategy {
+            WalkStrategy::Temporal => {
+                let mut updates = vec![vec![]; index.len()];
+                for (t, src, dst) in index.updates(g) {
+                    updates[src].push((t, dst));
+                }
+                (vec![], updates)
+            }
+            _ => (index.neighbours(g, Direction::BOTH), vec![]),
+        };
+        Self {
+            names: index.names(g),
+            neighbours,
+            updates,
+        }
+    }
+
+    fn walk(
+        &self,
+        start: usize,
+        walk_length: usize,
+        strategy: WalkStrategy,
+        rng: &mut StdRng,
+    ) -> Vec<usize> {
+        let mut walk = vec![start];
+        let mut time = i64::MIN;
+        while walk.len() < walk_length {
+            let current = walk[walk.len() - 1];
+            let next = match strategy {
+                WalkStrategy::Uniform => self.neighbours[current].choose(rng).copied(),
+                WalkStrategy::Node2Vec { p, q } => match walk.len() {
+                    1 => self.neighbours[current].choose(rng).copied(),
+                    n => {
+                        let previous = walk[n - 2];
+                        self.neighbours[current]
+                            .choose_weighted(rng, |x| {
+                                if *x == previous {
+                                    1f64 / p
+                                } else if self.neighbours[previous].binary_search(x).is_ok() {
+                                    1f64
+                                } else {
+                                    1f64 / q
+                                }
+                            })
+                            .ok()
+                            .copied()
+                    }
+                },
+                WalkStrategy::Temporal => {
+                    let updates = &self.updates[current];
+                    let later = updates.partition_point(|(t, _)| *t <= time);
+                    updates[later..].choose(rng).map(|(t, v)| {
+                        time = *t;
+                        *v
+                    })
+                }
+            };
+            match next {
+                Some(next) => walk.push(next),
+                None => break,
+            }
+        }
+        walk
+    }
+}
+
+/// Generates random walks starting from every vertex
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+/// * `walk_length` - The maximum number of vertices in a walk (including the start)
+/// * `walks_per_vertex` - The number of walks starting from each vertex
+/// * `strategy` - How the next vertex of a walk is chosen
+/// * `seed` - The seed for the random choices, if `None` a random seed is used
+/// * `threads` - Number of threads to use
+///
+/// # Returns
+///
+/// The walks as lists of vertex names, `walks_per_vertex` rounds with one walk per vertex each
+///
+pub fn random_walks<G: GraphViewOps>(
+    g: &G,
+    walk_length: usize,
+    walks_per_vertex: usize,
+    strategy: WalkStrategy,
+    seed: Option<u64>,
+    threads: Option<usize>,
+) -> Vec<Vec<String>> {
+    let walker = Walker::new(g, strategy);
+    let n = walker.names.len();
+    let seed = seed.unwrap_or_else(|| thread_rng().gen());
+    thread_pool(threads).install(|| {
+        (0..n * walks_per_vertex)
+            .into_par_iter()
+            .map(|i| {
+                // every walk has its own generator so the walks do not depend on the scheduling
+                let mut rng = StdRng::seed_from_u64(seed.wrapping_add(i as u64));
+                walker
+                    .walk(i % n, walk_length, strategy, &mut rng)
+                    .into_iter()
+                    .map(|v| walker.names[v].clone())
+                    .collect()
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod random_walks_test {
+    use super::*;
+
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, 1, 2), (2, 2, 3), (3, 3, 4), (0, 4, 1), (5, 2, 5)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    fn is_edge(graph: &Graph, a: &str, b: &str) -> bool {
+        graph.has_edge(a, b, Layer::All) || graph.has_edge(b, a, Layer::All)
+    }
+
+    #[test]
+    fn static_walks() {
+        let graph = graph();
+        for strategy in [
+            WalkStrategy::Uniform,
+            WalkStrategy::Node2Vec { p: 0.5, q: 2.0 },
+        ] {
+            let walks = random_walks(&graph, 6, 3, strategy, Some(7), Some(2));
+            assert_eq!(walks.len(), 15);
+            for (i, walk) in walks.iter().enumerate() {
+                assert_eq!(walk.len(), 6);
+                assert_eq!(walk[0], graph.vertices().iter().nth(i % 5).unwrap().name());
+                for step in walk.windows(2) {
+                    assert!(is_edge(&graph, &step[0], &step[1]));
+                }
+            }
+            assert_eq!(
+                walks,
+                random_walks(&graph, 6, 3, strategy, Some(7), Some(1))
+            );
+        }
+
+        // a very small return parameter always walks back
+        let walks = random_walks(
+            &graph,
+            5,
+            1,
+            WalkStrategy::Node2Vec { p: 1e-9, q: 1.0 },
+            Some(1),
+            None,
+        );
+        for walk in walks {
+            assert_eq!(walk[0], walk[2]);
+            assert_eq!(walk[1], walk[3]);
+        }
+    }
+
+    #[test]
+    fn temporal_walks() {
+        let graph = graph();
+        // 4 -> 1 at 0 can continue with 1 -> 2 at 1 and then either 2 -> 3 at 2 or 2 -> 5 at 5
+        let possible = [
+            vec!["4", "1", "2", "3", "4"],
+            vec!["4", "1", "2", "5"],
+            vec!["1", "2", "3", "4"],
+            vec!["1", "2", "5"],
+            vec!["2", "3", "4"],
+            vec!["2", "5"],
+            vec!["3", "4"],
+            vec!["5"],
+        ];
+        let walks = random_walks(&graph, 10, 10, WalkStrategy::Temporal, Some(3), None);
+        assert_eq!(walks.len(), 50);
+        for walk in walks {
+            assert!(possible.iter().any(|p| *p == walk), "{walk:?}");
+        }
+    }
+}
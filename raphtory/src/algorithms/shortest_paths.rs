@@ -0,0 +1,438 @@
+//! # Shortest Paths
+//!
+//! Hop distances (breadth-first search) and weighted distances (Dijkstra) on graph views,
+//! ignoring the timestamps of the edges. The distances are returned together with one shortest
+//! path per vertex, given by the vertex names from the source to the vertex.
+//!
+//! The weight of an edge is the latest value of the weight property in the view. Edges whose
+//! weight is missing, not numeric or negative are ignored by the weighted algorithms.
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::{
+        entities::{vertices::input_vertex::InputVertex, VID},
+        Direction,
+    },
+    db::graph::vertex::VertexView,
+    prelude::*,
+};
+use ordered_float::OrderedFloat;
+use rayon::prelude::*;
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+};
+
+fn reverse(direction: Direction) -> Direction {
+    match direction {
+        Direction::OUT => Direction::IN,
+        Direction::IN => Direction::OUT,
+        Direction::BOTH => Direction::BOTH,
+    }
+}
+
+/// The neighbours of `v` in `direction` with the weights of the connecting edges
+pub(crate) fn weighted_neighbours<G: GraphViewOps>(
+    g: &G,
+    v: VID,
+    direction: Direction,
+    weight: Option<&str>,
+) -> Vec<(VID, f64)> {
+    let v = VertexView::new_internal(g.clone(), v);
+    let edges = match direction {
+        Direction::OUT => v.out_edges(),
+        Direction::IN => v.in_edges(),
+        Direction::BOTH => v.edges(),
+    };
+    edges
+        .filter_map(|e| {
+            let w = match weight {
+                None => 1f64,
+                Some(name) => e
+                    .properties()
+                    .get(name)
+                    .and_then(|prop| prop.as_f64())
+                    .filter(|w| *w >= 0f64)?,
+            };
+            Some((e.edge.remote(), w))
+        })
+        .collect()
+}
+
+/// The path from the root of the search to `v` following the predecessors
+fn path_to(predecessors: &HashMap<VID, Option<VID>>, v: VID) -> Vec<VID> {
+    let mut path = vec![v];
+    let mut current = v;
+    while let Some(Some(pred)) = predecessors.get(&current) {
+        path.push(*pred);
+        current = *pred;
+    }
+    path.reverse();
+    path
+}
+
+/// Weighted distances and predecessors of all vertices reachable from `source`
+fn dijkstra<G: GraphViewOps>(
+    g: &G,
+    source: VID,
+    weight: Option<&str>,
+    direction: Direction,
+) -> (HashMap<VID, f64>, HashMap<VID, Option<VID>>) {
+    let mut distances: HashMap<VID, f64> = HashMap::new();
+    let mut predecessors: HashMap<VID, Option<VID>> = HashMap::from([(source, None)]);
+    let mut tentative: HashMap<VID, f64> = HashMap::from([(source, 0f64)]);
+    let mut queue = BinaryHeap::from([Reverse((OrderedFloat(0f64), source))]);
+    while let Some(Reverse((OrderedFloat(d), v))) = queue.pop() {
+        if distances.contains_key(&v) {
+            continue;
+        }
+        distances.insert(v, d);
+        for (n, w) in weighted_neighbours(g, v, direction, weight) {
+            let nd = d + w;
+            if !distances.contains_key(&n) && tentative.get(&n).map_or(true, |old| nd < *old) {
+                tentative.insert(n, nd);
+                predecessors.insert(n, Some(v));
+                queue.push(Reverse((OrderedFloat(nd), n)));
+            }
+        }
+    }
+    (distances, predecessors)
+}
+
+/// Hop distances from `source` with a breadth-first search
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `source`: The vertex to start from
+/// * `direction`: The direction in which edges are followed
+/// * `cutoff`: The maximum number of hops to search
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping the names of the reachable vertices (including the source) to
+///   their number of hops from the source and one shortest path
+///
+pub fn bfs_shortest_paths<G: GraphViewOps, T: InputVertex>(
+    g: &G,
+    source: T,
+    direction: Direction,
+    cutoff: Option<usize>,
+) -> AlgorithmResult<String, (usize, Vec<String>)> {
+    let source = match g.vertex(source.id()) {
+        Some(v) => v.vertex,
+        None => return AlgorithmResult::new(HashMap::new()),
+    };
+    let cutoff = cutoff.unwrap_or(usize::MAX);
+    let mut distances: HashMap<VID, usize> = HashMap::from([(source, 0)]);
+    let mut predecessors: HashMap<VID, Option<VID>> = HashMap::from([(source, None)]);
+    let mut queue = VecDeque::from([source]);
+    while let Some(v) = queue.pop_front() {
+        let d = distances[&v];
+        if d >= cutoff {
+            continue;
+        }
+        for (n, _) in weighted_neighbours(g, v, direction, None) {
+            if let Entry::Vacant(entry) = distances.entry(n) {
+                entry.insert(d + 1);
+                predecessors.insert(n, Some(v));
+                queue.push_back(n);
+            }
+        }
+    }
+    let result = distances
+        .into_iter()
+        .map(|(v, d)| {
+            let path = path_to(&predecessors, v);
+            (
+                g.vertex_name(v),
+                (d, path.into_iter().map(|v| g.vertex_name(v)).collect()),
+            )
+        })
+        .collect();
+    AlgorithmResult::new(result)
+}
+
+/// Weighted distances from `source` with Dijkstra's algorithm
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `source`: The vertex to start from
+/// * `weight`: The name of the edge property holding the weights, if `None` every edge has weight 1
+/// * `direction`: The direction in which edges are followed
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping the names of the reachable vertices (including the source) to
+///   their distance from the source and one shortest path
+///
+pub fn dijkstra_shortest_paths<G: GraphViewOps, T: InputVertex>(
+    g: &G,
+    source: T,
+    weight: Option<&str>,
+    direction: Direction,
+) -> AlgorithmResult<String, (OrderedFloat<f64>, Vec<String>)> {
+    let source = match g.vertex(source.id()) {
+        Some(v) => v.vertex,
+        None => return AlgorithmResult::new(HashMap::new()),
+    };
+    let (distances, predecessors) = dijkstra(g, source, weight, direction);
+    let result = distances
+        .into_iter()
+        .map(|(v, d)| {
+            let path = path_to(&predecessors, v);
+            (
+                g.vertex_name(v),
+                (
+                    OrderedFloat(d),
+                    path.into_iter().map(|v| g.vertex_name(v)).collect(),
+                ),
+            )
+        })
+        .collect();
+    AlgorithmResult::new(result)
+}
+
+/// Shortest path between `source` and `target` with a bidirectional Dijkstra search
+///
+/// The search alternates between expanding from the source and (against `direction`) from the
+/// target and stops once the two searches meet on a shortest path, which usually visits far fewer
+/// vertices than a search from the source only.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `source`: The vertex the path starts at
+/// * `target`: The vertex the path ends at
+/// * `weight`: The name of the edge property holding the weights, if `None` every edge has weight 1
+/// * `direction`: The direction in which edges are followed
+///
+/// Result:
+///
+/// * The distance and the vertex names along a shortest path, `None` if `target` is not reachable
+///
+pub fn bidirectional_shortest_path<G: GraphViewOps, T: InputVertex>(
+    g: &G,
+    source: T,
+    target: T,
+    weight: Option<&str>,
+    direction: Direction,
+) -> Option<(f64, Vec<String>)> {
+    let source = g.vertex(source.id())?.vertex;
+    let target = g.vertex(target.id())?.vertex;
+    let directions = [direction, reverse(direction)];
+    let mut distances: [HashMap<VID, f64>; 2] = [HashMap::new(), HashMap::new()];
+    let mut tentative = [
+        HashMap::from([(source, 0f64)]),
+        HashMap::from([(target, 0f64)]),
+    ];
+    let mut predecessors: [HashMap<VID, Option<VID>>; 2] = [
+        HashMap::from([(source, None)]),
+        HashMap::from([(target, None)]),
+    ];
+    let mut queues = [
+        BinaryHeap::from([Reverse((OrderedFloat(0f64), source))]),
+        BinaryHeap::from([Reverse((OrderedFloat(0f64), target))]),
+    ];
+    // the length of the shortest path found so far and the vertex where the searches meet
+    let mut best: Option<(f64, VID)> = None;
+    let mut side = 0;
+    loop {
+        let tops = [0, 1].map(|i| queues[i].peek().map(|Reverse((d, _))| d.0));
+        match (tops, best) {
+            ([Some(f), Some(b)], Some((mu, _))) if f + b >= mu => break,
+            ([None, _], _) | ([_, None], _) => break,
+            _ => {}
+        }
+        let Reverse((OrderedFloat(d), v)) = queues[side].pop().expect("queue is not empty");
+        if let Entry::Vacant(entry) = distances[side].entry(v) {
+            entry.insert(d);
+            for (n, w) in weighted_neighbours(g, v, directions[side], weight) {
+                let nd = d + w;
+                if distances[side].contains_key(&n) {
+                    continue;
+                }
+                if tentative[side].get(&n).map_or(true, |old| nd < *old) {
+                    tentative[side].insert(n, nd);
+                    predecessors[side].insert(n, Some(v));
+                    queues[side].push(Reverse((OrderedFloat(nd), n)));
+                }
+                if let Some(other) = tentative[1 - side].get(&n) {
+                    let total = tentative[side][&n] + other;
+                    if best.map_or(true, |(mu, _)| total < mu) {
+                        best = Some((total, n));
+                    }
+                }
+            }
+            if let Some(other) = tentative[1 - side].get(&v) {
+                if best.map_or(true, |(mu, _)| d + other < mu) {
+                    best = Some((d + other, v));
+                }
+            }
+        }
+        side = 1 - side;
+    }
+    let (distance, meet) = best?;
+    let mut path = path_to(&predecessors[0], meet);
+    let mut back = path_to(&predecessors[1], meet);
+    back.reverse();
+    path.extend(back.into_iter().skip(1));
+    Some((
+        distance,
+        path.into_iter().map(|v| g.vertex_name(v)).collect(),
+    ))
+}
+
+/// Weighted distances between all pairs of vertices, use for small views only
+///
+/// Runs [`dijkstra_shortest_paths`] from every vertex in parallel.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `weight`: The name of the edge property holding the weights, if `None` every edge has weight 1
+/// * `direction`: The direction in which edges are followed
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping pairs of vertex names `(source, target)` to the distance from
+///   source to target and one shortest path, for all pairs where target is reachable from source
+///
+pub fn all_pairs_shortest_paths<G: GraphViewOps>(
+    g: &G,
+    weight: Option<&str>,
+    direction: Direction,
+) -> AlgorithmResult<(String, String), (OrderedFloat<f64>, Vec<String>)> {
+    let vertices: Vec<VID> = g.vertices().iter().map(|v| v.vertex).collect();
+    let result = vertices
+        .into_par_iter()
+        .flat_map_iter(|source| {
+            let (distances, predecessors) = dijkstra(g, source, weight, direction);
+            let source_name = g.vertex_name(source);
+            distances
+                .into_iter()
+                .map(|(v, d)| {
+                    let path = path_to(&predecessors, v);
+                    (
+                        (source_name.clone(), g.vertex_name(v)),
+                        (
+                            OrderedFloat(d),
+                            path.into_iter().map(|v| g.vertex_name(v)).collect(),
+                        ),
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    AlgorithmResult::new(result)
+}
+
+#[cfg(test)]
+mod shortest_paths_test {
+    use super::*;
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        let edges = vec![
+            (0, 1, 2, 4.0),
+            (1, 1, 3, 1.0),
+            (2, 3, 2, 2.0),
+            (3, 2, 4, 5.0),
+            (4, 3, 4, 8.0),
+            (5, 4, 5, 3.0),
+            (6, 6, 1, 1.0),
+        ];
+        for (t, src, dst, w) in edges {
+            graph
+                .add_edge(t, src, dst, [("weight", Prop::F64(w))], None)
+                .unwrap();
+        }
+        graph
+    }
+
+    fn names(path: &[&str]) -> Vec<String> {
+        path.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn bfs() {
+        let graph = load_graph();
+        let result = bfs_shortest_paths(&graph, 1, Direction::OUT, None);
+        assert_eq!(result.get_all().len(), 5);
+        assert_eq!(result.get(&"1".to_string()), Some(&(0, names(&["1"]))));
+        assert_eq!(result.get(&"4".to_string()).unwrap().0, 2);
+        assert_eq!(
+            result.get(&"5".to_string()).unwrap().0,
+            3,
+            "hops ignore the weights"
+        );
+        assert_eq!(result.get(&"6".to_string()), None);
+
+        let result = bfs_shortest_paths(&graph, 1, Direction::BOTH, Some(1));
+        let mut reached: Vec<_> = result.get_all().keys().cloned().collect();
+        reached.sort();
+        assert_eq!(reached, names(&["1", "2", "3", "6"]));
+
+        // the edge 1 -> 3 is not in the window
+        let result = bfs_shortest_paths(&graph.window(2, 10), 3, Direction::IN, None);
+        assert_eq!(result.get(&"1".to_string()), None);
+        assert_eq!(
+            bfs_shortest_paths(&graph.window(2, 10), 5, Direction::IN, None).get(&"3".to_string()),
+            Some(&(2, names(&["5", "4", "3"])))
+        );
+    }
+
+    #[test]
+    fn dijkstra() {
+        let graph = load_graph();
+        let result = dijkstra_shortest_paths(&graph, 1, Some("weight"), Direction::OUT);
+        assert_eq!(
+            result.get(&"2".to_string()),
+            Some(&(OrderedFloat(3.0), names(&["1", "3", "2"])))
+        );
+        assert_eq!(
+            result.get(&"5".to_string()),
+            Some(&(OrderedFloat(11.0), names(&["1", "3", "2", "4", "5"])))
+        );
+
+        let result = dijkstra_shortest_paths(&graph, 1, None, Direction::OUT);
+        assert_eq!(result.get(&"5".to_string()).unwrap().0, OrderedFloat(3.0));
+    }
+
+    #[test]
+    fn bidirectional() {
+        let graph = load_graph();
+        assert_eq!(
+            bidirectional_shortest_path(&graph, 1, 5, Some("weight"), Direction::OUT),
+            Some((11.0, names(&["1", "3", "2", "4", "5"])))
+        );
+        assert_eq!(
+            bidirectional_shortest_path(&graph, 6, 4, None, Direction::OUT).map(|(d, _)| d),
+            Some(3.0)
+        );
+        assert_eq!(
+            bidirectional_shortest_path(&graph, 5, 1, None, Direction::OUT),
+            None
+        );
+        assert_eq!(
+            bidirectional_shortest_path(&graph, 5, 1, Some("weight"), Direction::BOTH),
+            Some((11.0, names(&["5", "4", "2", "3", "1"])))
+        );
+        assert_eq!(
+            bidirectional_shortest_path(&graph, 2, 2, None, Direction::OUT),
+            Some((0.0, names(&["2"])))
+        );
+    }
+
+    #[test]
+    fn all_pairs() {
+        let graph = load_graph();
+        let result = all_pairs_shortest_paths(&graph, Some("weight"), Direction::OUT);
+        let single = dijkstra_shortest_paths(&graph, 6, Some("weight"), Direction::OUT);
+        for (target, value) in single.get_all() {
+            assert_eq!(result.get(&("6".to_string(), target.clone())), Some(value));
+        }
+        assert_eq!(result.get(&("5".to_string(), "1".to_string())), None);
+        assert_eq!(result.get_all().len(), 21);
+    }
+}
@@ -0,0 +1,444 @@
+//! # Temporal Paths
+//!
+//! Time-respecting paths from a source vertex over the exploded edges of a graph view. A
+//! time-respecting path is a sequence of exploded edges `(v_1, v_2, t_1), (v_2, v_3, t_2), ...`
+//! with strictly increasing timestamps, where the first edge starts at the source no earlier than
+//! the start time. Edges are traversed instantaneously, i.e., a path departs the source at `t_1`
+//! and arrives at its last vertex at `t_k`.
+//!
+//! The waits `t_{i+1} - t_i` at intermediate vertices can be constrained with
+//! [`WaitConstraints`]. As the best paths under such constraints are not composed of best
+//! sub-paths, all objectives are computed exactly with a single pass over the exploded edges in
+//! time order, which keeps the best labels for every edge instead of every vertex. The best
+//! arrival at a vertex within the wait window of an edge is maintained incrementally as the window
+//! slides forward in time.
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::{
+        entities::{edges::edge_ref::EdgeRef, vertices::input_vertex::InputVertex, VID},
+        storage::timeindex::{AsTime, TimeIndexEntry},
+    },
+    db::graph::edge::EdgeView,
+    prelude::*,
+};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, VecDeque},
+};
+
+/// The cost minimised (or maximised) by [`temporal_shortest_paths`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TemporalPathObjective {
+    /// Arrive at the vertex as early as possible
+    EarliestArrival,
+    /// Leave the source as late as possible and still reach the vertex
+    LatestDeparture,
+    /// Minimise the time between leaving the source and arriving at the vertex
+    Fastest,
+    /// Minimise the number of hops
+    Shortest,
+}
+
+/// Bounds on the time spent at an intermediate vertex between arriving and leaving again
+///
+/// Consecutive edges of a path always have strictly increasing timestamps, `min_wait` can only
+/// increase the required gap.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WaitConstraints {
+    pub min_wait: Option<i64>,
+    pub max_wait: Option<i64>,
+}
+
+impl WaitConstraints {
+    pub fn new(min_wait: Option<i64>, max_wait: Option<i64>) -> Self {
+        Self { min_wait, max_wait }
+    }
+}
+
+/// A time-respecting path given by its exploded edges
+#[derive(Clone, Debug)]
+pub struct TemporalPath<G: GraphViewOps> {
+    pub edges: Vec<EdgeView<G>>,
+}
+
+impl<G: GraphViewOps> TemporalPath<G> {
+    /// The time the path leaves the source
+    pub fn departure(&self) -> Option<i64> {
+        self.edges.first().and_then(|e| e.time())
+    }
+
+    /// The time the path arrives at its destination
+    pub fn arrival(&self) -> Option<i64> {
+        self.edges.last().and_then(|e| e.time())
+    }
+
+    /// The time between leaving the source and arriving at the destination
+    pub fn duration(&self) -> Option<i64> {
+        Some(self.arrival()? - self.departure()?)
+    }
+
+    pub fn hops(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+/// The best ways of reaching an exploded edge
+struct EdgeLabel {
+    hops: usize,
+    hops_pred: Option<usize>,
+    departure: i64,
+    departure_pred: Option<usize>,
+}
+
+/// The reachable arrivals at a vertex and the best of them within the current wait window
+#[derive(Default)]
+struct Arrivals {
+    /// `(arrival time, update)` in time order
+    reached: Vec<(i64, usize)>,
+    /// The number of arrivals that entered the window so far
+    admitted: usize,
+    /// `(arrival time, hops, update)` with strictly increasing hops
+    hops: VecDeque<(i64, usize, usize)>,
+    /// `(arrival time, departure, update)` with strictly decreasing departures
+    departure: VecDeque<(i64, i64, usize)>,
+}
+
+impl Arrivals {
+    /// Move the window to the arrivals in `[lower, upper]` and extend the best of them by one edge
+    ///
+    /// The bounds never decrease as the updates are visited in time order, so every arrival enters
+    /// and leaves the window once. Ties are broken by the earlier arrival.
+    fn extend(
+        &mut self,
+        labels: &[Option<EdgeLabel>],
+        lower: i64,
+        upper: i64,
+    ) -> Option<EdgeLabel> {
+        while let Some(&(t, j)) = self.reached.get(self.admitted) {
+            if t > upper {
+                break;
+            }
+            let label = labels[j].as_ref().expect("arrivals are reachable");
+            while matches!(self.hops.back(), Some((_, hops, _)) if *hops > label.hops) {
+                self.hops.pop_back();
+            }
+            self.hops.push_back((t, label.hops, j));
+            while matches!(self.departure.back(), Some((_, dep, _)) if *dep < label.departure) {
+                self.departure.pop_back();
+            }
+            self.departure.push_back((t, label.departure, j));
+            self.admitted += 1;
+        }
+        while matches!(self.hops.front(), Some((t, _, _)) if *t < lower) {
+            self.hops.pop_front();
+        }
+        while matches!(self.departure.front(), Some((t, _, _)) if *t < lower) {
+            self.departure.pop_front();
+        }
+        let &(_, hops, hops_pred) = self.hops.front()?;
+        let &(_, departure, departure_pred) = self.departure.front()?;
+        Some(EdgeLabel {
+            hops: hops + 1,
+            hops_pred: Some(hops_pred),
+            departure,
+            departure_pred: Some(departure_pred),
+        })
+    }
+}
+
+/// Temporal shortest paths from `source` to every vertex reachable by a time-respecting path
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object, only the exploded edges in the view are used
+/// * `source`: The vertex the paths start from
+/// * `objective`: The cost of a path, see [`TemporalPathObjective`]
+/// * `start_time`: The earliest time a path can leave the source (defaults to the start of the view)
+/// * `constraints`: The minimum and maximum wait at intermediate vertices
+///
+/// Result:
+///
+/// * An AlgorithmResult mapping the names of the reachable vertices (other than the source) to
+///   one of their best paths. Ties are broken by earlier arrival and fewer hops.
+///
+pub fn temporal_shortest_paths<G: GraphViewOps, T: InputVertex>(
+    g: &G,
+    source: T,
+    objective: TemporalPathObjective,
+    start_time: Option<i64>,
+    constraints: WaitConstraints,
+) -> AlgorithmResult<String, TemporalPath<G>> {
+    let source = match g.vertex(source.id()) {
+        Some(v) => v.vertex,
+        None => return AlgorithmResult::new(HashMap::new()),
+    };
+    let start_time = start_time.or_else(|| g.start()).unwrap_or(i64::MIN);
+    let min_wait = constraints.min_wait.unwrap_or(1).max(1);
+    let max_wait = constraints.max_wait.unwrap_or(i64::MAX);
+
+    // the exploded edges as `(t, src, dst, idx)` in time order, `idx` points into `refs`
+    let refs: Vec<EdgeRef> = g
+        .edges()
+        .flat_map(|e| e.explode())
+        .map(|e| e.edge)
+        .collect();
+    let mut updates: Vec<(TimeIndexEntry, VID, VID, usize)> = refs
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, e)| Some((e.time()?, e.src(), e.dst(), idx)))
+        .filter(|(t, _, _, _)| *t.t() >= start_time)
+        .collect();
+    updates.sort_unstable();
+
+    let mut labels: Vec<Option<EdgeLabel>> = Vec::with_capacity(updates.len());
+    let mut arrivals: HashMap<VID, Arrivals> = HashMap::new();
+    for (i, (t, src, dst, _)) in updates.iter().enumerate() {
+        let t = *t.t();
+        let label = if *src == source {
+            // leaving the source directly is always best for hops and departure
+            Some(EdgeLabel {
+                hops: 1,
+                hops_pred: None,
+                departure: t,
+                departure_pred: None,
+            })
+        } else {
+            arrivals.get_mut(src).and_then(|preds| {
+                preds.extend(
+                    &labels,
+                    t.saturating_sub(max_wait),
+                    t.saturating_sub(min_wait),
+                )
+            })
+        };
+        if label.is_some() {
+            arrivals.entry(*dst).or_default().reached.push((t, i));
+        }
+        labels.push(label);
+    }
+    let edge = |i: usize| EdgeView::new(g.clone(), refs[updates[i].3]);
+
+    let result = arrivals
+        .into_iter()
+        .filter(|(v, _)| *v != source)
+        .filter_map(|(v, arrivals)| {
+            // `reached` is in order of arrival, so `min_by_key` prefers earlier arrivals
+            let best = arrivals.reached.iter().map(|(t, i)| {
+                let label = labels[*i].as_ref().expect("arrivals are reachable");
+                (*t, *i, label)
+            });
+            let (i, follow_hops) = match objective {
+                TemporalPathObjective::EarliestArrival => best
+                    .min_by_key(|(t, _, l)| (*t, l.hops))
+                    .map(|(_, i, _)| (i, true))?,
+                TemporalPathObjective::LatestDeparture => best
+                    .min_by_key(|(_, _, l)| Reverse(l.departure))
+                    .map(|(_, i, _)| (i, false))?,
+                TemporalPathObjective::Fastest => best
+                    .min_by_key(|(t, _, l)| t - l.departure)
+                    .map(|(_, i, _)| (i, false))?,
+                TemporalPathObjective::Shortest => best
+                    .min_by_key(|(_, _, l)| l.hops)
+                    .map(|(_, i, _)| (i, true))?,
+            };
+            let mut path = vec![edge(i)];
+            let mut current = i;
+            while let Some(pred) = labels[current].as_ref().and_then(|l| match follow_hops {
+                true => l.hops_pred,
+                false => l.departure_pred,
+            }) {
+                path.push(edge(pred));
+                current = pred;
+            }
+            path.reverse();
+            Some((g.vertex_name(v), TemporalPath { edges: path }))
+        })
+        .collect();
+
+    AlgorithmResult::new(result)
+}
+
+#[cfg(test)]
+mod temporal_paths_test {
+    use super::*;
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        let edges = vec![
+            (1, 1, 2),
+            (2, 2, 3),
+            (3, 1, 3),
+            (4, 1, 2),
+            (6, 2, 3),
+            (7, 3, 4),
+            (10, 1, 4),
+            (12, 4, 5),
+        ];
+        for (t, src, dst) in edges {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    type PathSummary = Vec<(String, Vec<(u64, u64, i64)>)>;
+
+    fn summary<G: GraphViewOps>(result: &AlgorithmResult<String, TemporalPath<G>>) -> PathSummary {
+        let mut paths: Vec<_> = result
+            .get_all()
+            .iter()
+            .map(|(name, path)| {
+                let edges = path
+                    .edges
+                    .iter()
+                    .map(|e| (e.src().id(), e.dst().id(), e.time().unwrap()))
+                    .collect();
+                (name.clone(), edges)
+            })
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn earliest_arrival() {
+        let graph = load_graph();
+        let result = temporal_shortest_paths(
+            &graph,
+            1,
+            TemporalPathObjective::EarliestArrival,
+            None,
+            WaitConstraints::default(),
+        );
+        assert_eq!(
+            summary(&result),
+            vec![
+                ("2".to_string(), vec![(1, 2, 1)]),
+                ("3".to_string(), vec![(1, 2, 1), (2, 3, 2)]),
+                ("4".to_string(), vec![(1, 3, 3), (3, 4, 7)]),
+                ("5".to_string(), vec![(1, 4, 10), (4, 5, 12)]),
+            ]
+        );
+        assert_eq!(result.get(&"5".to_string()).unwrap().arrival(), Some(12));
+
+        // starting later or in a window removes the early edges
+        let result = temporal_shortest_paths(
+            &graph.window(2, 20),
+            1,
+            TemporalPathObjective::EarliestArrival,
+            None,
+            WaitConstraints::default(),
+        );
+        assert_eq!(result.get(&"3".to_string()).unwrap().arrival(), Some(3));
+        let result = temporal_shortest_paths(
+            &graph,
+            1,
+            TemporalPathObjective::EarliestArrival,
+            Some(8),
+            WaitConstraints::default(),
+        );
+        assert_eq!(
+            summary(&result),
+            vec![
+                ("4".to_string(), vec![(1, 4, 10)]),
+                ("5".to_string(), vec![(1, 4, 10), (4, 5, 12)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn latest_departure_and_fastest() {
+        let graph = load_graph();
+        let result = temporal_shortest_paths(
+            &graph,
+            1,
+            TemporalPathObjective::LatestDeparture,
+            None,
+            WaitConstraints::default(),
+        );
+        assert_eq!(result.get(&"3".to_string()).unwrap().departure(), Some(4));
+        assert_eq!(result.get(&"5".to_string()).unwrap().departure(), Some(10));
+
+        let result = temporal_shortest_paths(
+            &graph,
+            1,
+            TemporalPathObjective::Fastest,
+            None,
+            WaitConstraints::default(),
+        );
+        assert_eq!(
+            summary(&result),
+            vec![
+                ("2".to_string(), vec![(1, 2, 1)]),
+                ("3".to_string(), vec![(1, 3, 3)]),
+                ("4".to_string(), vec![(1, 4, 10)]),
+                ("5".to_string(), vec![(1, 4, 10), (4, 5, 12)]),
+            ]
+        );
+        assert_eq!(result.get(&"5".to_string()).unwrap().duration(), Some(2));
+    }
+
+    #[test]
+    fn shortest() {
+        let graph = load_graph();
+        let result = temporal_shortest_paths(
+            &graph,
+            1,
+            TemporalPathObjective::Shortest,
+            None,
+            WaitConstraints::default(),
+        );
+        assert_eq!(
+            summary(&result),
+            vec![
+                ("2".to_string(), vec![(1, 2, 1)]),
+                ("3".to_string(), vec![(1, 3, 3)]),
+                ("4".to_string(), vec![(1, 4, 10)]),
+                ("5".to_string(), vec![(1, 4, 10), (4, 5, 12)]),
+            ]
+        );
+        assert!(temporal_shortest_paths(
+            &graph,
+            42,
+            TemporalPathObjective::Shortest,
+            None,
+            WaitConstraints::default(),
+        )
+        .get_all()
+        .is_empty());
+    }
+
+    #[test]
+    fn wait_constraints() {
+        let graph = load_graph();
+        // waiting at most 2 at a vertex rules out (1, 3, 3) -> (3, 4, 7)
+        let result = temporal_shortest_paths(
+            &graph,
+            1,
+            TemporalPathObjective::EarliestArrival,
+            None,
+            WaitConstraints::new(None, Some(2)),
+        );
+        assert_eq!(
+            summary(&result),
+            vec![
+                ("2".to_string(), vec![(1, 2, 1)]),
+                ("3".to_string(), vec![(1, 2, 1), (2, 3, 2)]),
+                ("4".to_string(), vec![(1, 2, 4), (2, 3, 6), (3, 4, 7)]),
+                ("5".to_string(), vec![(1, 4, 10), (4, 5, 12)]),
+            ]
+        );
+
+        // waiting at least 3 rules out (1, 2, 1) -> (2, 3, 2)
+        let result = temporal_shortest_paths(
+            &graph,
+            1,
+            TemporalPathObjective::EarliestArrival,
+            None,
+            WaitConstraints::new(Some(3), None),
+        );
+        assert_eq!(
+            result.get(&"3".to_string()).unwrap().edges[0].time(),
+            Some(3)
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! # Algorithm Results over Windows
+//!
+//! Runs an algorithm on every window of a rolling or expanding [`WindowSet`] and collects the
+//! per-vertex results into a [`TimeSeriesResult`]. The windows are processed in parallel, the
+//! timestamps of the series are given by the [`time_index`](WindowSet::time_index) of the window
+//! set.
+//!
+//! The series can be exported in long (tidy) format with one row per vertex and window, either as
+//! plain columns or, with the `arrow` feature, as a [`DFView`](crate::arrow::dataframe::DFView).
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::algorithms::{pagerank::unweighted_page_rank, time_series::run_over_windows};
+//! use raphtory::prelude::*;
+//!
+//! let graph = Graph::new();
+//! graph.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+//! graph.add_edge(2, 2, 3, NO_PROPS, None).unwrap();
+//! graph.add_edge(3, 3, 1, NO_PROPS, None).unwrap();
+//!
+//! let series = run_over_windows(
+//!     graph.expanding(1).unwrap(),
+//!     false,
+//!     |w| unweighted_page_rank(w, 20, None, None, true),
+//!     None,
+//! );
+//! assert_eq!(series.timestamps(), &[1, 2, 3]);
+//! assert_eq!(series.get("3").unwrap()[0], None);
+//! assert!(series.get("3").unwrap()[1].is_some());
+//! ```
+use crate::{
+    algorithms::{algorithm_result::AlgorithmResult, utils::thread_pool},
+    db::api::view::{TimeOps, WindowSet},
+};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Per-vertex values of an algorithm over a sequence of windows
+///
+/// The value of a vertex is `None` for the windows where the algorithm did not return a result for
+/// it, e.g. because the vertex is not part of the window.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeSeriesResult<V> {
+    timestamps: Vec<i64>,
+    series: HashMap<String, Vec<Option<V>>>,
+}
+
+impl<V: Clone> TimeSeriesResult<V> {
+    /// Creates a series from the timestamps and the result for each of them
+    pub fn new(timestamps: Vec<i64>, results: Vec<AlgorithmResult<String, V>>) -> Self {
+        let mut series: HashMap<String, Vec<Option<V>>> = HashMap::new();
+        for (i, result) in results.into_iter().enumerate() {
+            for (vertex, value) in result.result {
+                series
+                    .entry(vertex)
+                    .or_insert_with(|| vec![None; timestamps.len()])[i] = Some(value);
+            }
+        }
+        Self { timestamps, series }
+    }
+
+    /// The timestamp of each window
+    pub fn timestamps(&self) -> &[i64] {
+        &self.timestamps
+    }
+
+    /// The value of the vertex for each window
+    pub fn get(&self, vertex: &str) -> Option<&[Option<V>]> {
+        self.series.get(vertex).map(|s| s.as_slice())
+    }
+
+    /// The names of the vertices in the series, sorted
+    pub fn vertices(&self) -> Vec<&String> {
+        let mut vertices: Vec<_> = self.series.keys().collect();
+        vertices.sort();
+        vertices
+    }
+
+    /// The result of the window with index `i`
+    pub fn window(&self, i: usize) -> AlgorithmResult<String, V> {
+        AlgorithmResult::new(
+            self.series
+                .iter()
+                .filter_map(|(vertex, s)| Some((vertex.clone(), s.get(i)?.clone()?)))
+                .collect(),
+        )
+    }
+
+    /// Applies `f` to every value
+    pub fn map<W, F: Fn(V) -> W>(self, f: F) -> TimeSeriesResult<W> {
+        TimeSeriesResult {
+            timestamps: self.timestamps,
+            series: self
+                .series
+                .into_iter()
+                .map(|(vertex, s)| (vertex, s.into_iter().map(|v| v.map(&f)).collect()))
+                .collect(),
+        }
+    }
+
+    /// The series in long format as `(vertex, time, value)` columns, with a row for every vertex
+    /// and window that has a value, sorted by vertex and time
+    pub fn to_columns(&self) -> (Vec<String>, Vec<i64>, Vec<V>) {
+        let mut vertices = vec![];
+        let mut times = vec![];
+        let mut values = vec![];
+        for vertex in self.vertices() {
+            for (t, value) in self.timestamps.iter().zip(&self.series[vertex]) {
+                if let Some(value) = value {
+                    vertices.push(vertex.clone());
+                    times.push(*t);
+                    values.push(value.clone());
+                }
+            }
+        }
+        (vertices, times, values)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl<V: Clone + arrow2::types::NativeType> TimeSeriesResult<V> {
+    /// The series in long format as a dataframe with the columns `vertex`, `time` and `value`
+    pub fn to_df(&self) -> crate::arrow::dataframe::DFView {
+        use arrow2::{
+            array::{PrimitiveArray, Utf8Array},
+            chunk::Chunk,
+        };
+        let (vertices, times, values) = self.to_columns();
+        let chunk = Chunk::new(vec![
+            Utf8Array::<i64>::from_slice(vertices).boxed(),
+            PrimitiveArray::from_vec(times).boxed(),
+            PrimitiveArray::from_vec(values).boxed(),
+        ]);
+        crate::arrow::dataframe::DFView::new(
+            vec!["vertex".into(), "time".into(), "value".into()],
+            vec![chunk],
+        )
+        .expect("the chunk has one array per column")
+    }
+}
+
+/// Runs the algorithm on every window of the window set in parallel
+///
+/// # Arguments
+///
+/// * `windows` - The rolling or expanding windows
+/// * `center` - Whether the timestamp of a window is its center instead of its last time point
+/// * `algorithm` - The algorithm to run on each window
+/// * `threads` - Number of threads to use for running the windows
+///
+/// # Returns
+///
+/// A TimeSeriesResult with a value per vertex and window
+///
+pub fn run_over_windows<T, F, V>(
+    windows: WindowSet<T>,
+    center: bool,
+    algorithm: F,
+    threads: Option<usize>,
+) -> TimeSeriesResult<V>
+where
+    T: TimeOps + Clone + 'static,
+    T::WindowedViewType: Send + Sync,
+    F: Fn(&T::WindowedViewType) -> AlgorithmResult<String, V> + Sync,
+    V: Clone + Send,
+{
+    let timestamps: Vec<i64> = windows.time_index(center).collect();
+    let windows: Vec<T::WindowedViewType> = windows.collect();
+    let results = thread_pool(threads).install(|| windows.par_iter().map(&algorithm).collect());
+    TimeSeriesResult::new(timestamps, results)
+}
+
+#[cfg(test)]
+mod time_series_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn degrees<G: GraphViewOps>(g: &G) -> AlgorithmResult<String, usize> {
+        AlgorithmResult::new(
+            g.vertices()
+                .iter()
+                .map(|v| (v.name(), v.degree()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn rolling_degrees() {
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, 1, 2), (2, 1, 3), (4, 2, 3), (6, 1, 4), (7, 4, 2)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        let series = run_over_windows(graph.rolling(2, None).unwrap(), false, degrees, Some(2));
+        assert_eq!(series.timestamps(), &[2, 4, 6, 8]);
+        assert_eq!(series.vertices(), vec!["1", "2", "3", "4"]);
+        assert_eq!(series.get("1").unwrap(), &[Some(2), None, Some(1), None]);
+        assert_eq!(series.get("4").unwrap(), &[None, None, Some(1), Some(1)]);
+
+        // every window of the series matches running the algorithm on the window itself
+        for (i, window) in graph.rolling(2, None).unwrap().enumerate() {
+            assert_eq!(series.window(i).get_all(), degrees(&window).get_all());
+        }
+
+        let centered = run_over_windows(graph.rolling(2, None).unwrap(), true, degrees, None);
+        assert_eq!(centered.timestamps(), &[2, 4, 6, 8]);
+        let (vertices, times, values) = series.map(|d| d as u64).to_columns();
+        assert_eq!(vertices, vec!["1", "1", "2", "2", "2", "3", "3", "4", "4"]);
+        assert_eq!(times, vec![2, 6, 2, 4, 8, 2, 4, 6, 8]);
+        assert_eq!(values, vec![2, 1, 1, 1, 1, 1, 1, 1, 1]);
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn to_df() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+        let df = run_over_windows(graph.expanding(1).unwrap(), false, degrees, None)
+            .map(|d| d as u64)
+            .to_df();
+        assert_eq!(df.names(), &["vertex", "time", "value"]);
+        assert_eq!(df.num_rows(), 5);
+    }
+}
@@ -0,0 +1,111 @@
+//! Helpers shared by the algorithms that work on a snapshot of a graph view with the vertices
+//! numbered consecutively.
+use crate::{
+    core::{entities::VID, Direction},
+    db::{
+        graph::vertex::VertexView,
+        task::{custom_pool, POOL},
+    },
+    prelude::*,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use rayon::ThreadPool;
+use std::{collections::HashMap, sync::Arc};
+
+/// The vertices of a view with consecutive indices in the order of `g.vertices()`
+pub(crate) struct VertexIndex {
+    vertices: Vec<VID>,
+    index: HashMap<VID, usize>,
+}
+
+impl VertexIndex {
+    pub(crate) fn new<G: GraphViewOps>(g: &G) -> Self {
+        let vertices: Vec<VID> = g.vertices().iter().map(|v| v.vertex).collect();
+        let index = vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        Self { vertices, index }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub(crate) fn vertices(&self) -> &[VID] {
+        &self.vertices
+    }
+
+    /// The index of a vertex in the view
+    pub(crate) fn index(&self, v: VID) -> usize {
+        self.index[&v]
+    }
+
+    /// The index of a vertex, `None` if it is not in the view
+    pub(crate) fn get(&self, v: VID) -> Option<usize> {
+        self.index.get(&v).copied()
+    }
+
+    pub(crate) fn names<G: GraphViewOps>(&self, g: &G) -> Vec<String> {
+        self.vertices.iter().map(|v| g.vertex_name(*v)).collect()
+    }
+
+    pub(crate) fn ids<G: GraphViewOps>(&self, g: &G) -> Vec<u64> {
+        self.vertices.iter().map(|v| g.vertex_id(*v)).collect()
+    }
+
+    /// The sorted indices of the neighbours of every vertex in `direction`, ignoring self-loops
+    pub(crate) fn neighbours<G: GraphViewOps>(
+        &self,
+        g: &G,
+        direction: Direction,
+    ) -> Vec<Vec<usize>> {
+        self.vertices
+            .iter()
+            .map(|v| {
+                let vertex = VertexView::new_internal(g.clone(), *v);
+                let neighbours = match direction {
+                    Direction::OUT => vertex.out_neighbours(),
+                    Direction::IN => vertex.in_neighbours(),
+                    Direction::BOTH => vertex.neighbours(),
+                };
+                let mut neighbours: Vec<usize> = neighbours
+                    .iter()
+                    .filter(|n| n.vertex != *v)
+                    .map(|n| self.index(n.vertex))
+                    .collect();
+                neighbours.sort_unstable();
+                neighbours.dedup();
+                neighbours
+            })
+            .collect()
+    }
+
+    /// The exploded edges of the view as `(t, src, dst)` sorted by time, ignoring self-loops
+    pub(crate) fn updates<G: GraphViewOps>(&self, g: &G) -> Vec<(i64, usize, usize)> {
+        let mut updates: Vec<_> = g
+            .edges()
+            .flat_map(|e| e.explode())
+            .filter_map(|e| {
+                Some((
+                    e.time()?,
+                    self.index(e.edge.src()),
+                    self.index(e.edge.dst()),
+                ))
+            })
+            .filter(|(_, src, dst)| src != dst)
+            .collect();
+        updates.sort_unstable();
+        updates
+    }
+}
+
+/// A pool with `threads` threads, or the shared task runner pool if `None`
+pub(crate) fn thread_pool(threads: Option<usize>) -> Arc<ThreadPool> {
+    threads.map(custom_pool).unwrap_or_else(|| POOL.clone())
+}
+
+/// A generator seeded with `seed`, or from entropy if `None`
+pub(crate) fn seeded_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
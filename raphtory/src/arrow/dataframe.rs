@@ -0,0 +1,277 @@
+use crate::core::{entities::vertices::input_vertex::InputVertex, utils::errors::GraphError, Prop};
+use arrow2::{
+    array::{Array, BooleanArray, PrimitiveArray, Utf8Array},
+    chunk::Chunk,
+    datatypes::{DataType, Schema, TimeUnit},
+    types::NativeType,
+};
+use chrono::NaiveDateTime;
+
+/// A table of named columns split into arrow chunks (record batches)
+///
+/// All chunks have one array per column name, the arrays of a chunk have the same length.
+#[derive(Debug, Clone)]
+pub struct DFView {
+    names: Vec<String>,
+    chunks: Vec<Chunk<Box<dyn Array>>>,
+    progress: bool,
+}
+
+impl DFView {
+    /// Create a view of `chunks` with the column `names`
+    ///
+    /// Fails if a chunk does not have exactly one array per name.
+    pub fn new(names: Vec<String>, chunks: Vec<Chunk<Box<dyn Array>>>) -> Result<Self, GraphError> {
+        if let Some(chunk) = chunks.iter().find(|c| c.arrays().len() != names.len()) {
+            return Err(GraphError::LoadFailure(format!(
+                "expected {} columns but found a chunk with {} columns",
+                names.len(),
+                chunk.arrays().len()
+            )));
+        }
+        Ok(Self {
+            names,
+            chunks,
+            progress: false,
+        })
+    }
+
+    /// Create a view of `chunks` with the column names of `schema`
+    pub fn from_schema(
+        schema: &Schema,
+        chunks: Vec<Chunk<Box<dyn Array>>>,
+    ) -> Result<Self, GraphError> {
+        Self::new(
+            schema.fields.iter().map(|f| f.name.clone()).collect(),
+            chunks,
+        )
+    }
+
+    /// Show a progress bar on stderr when the view is loaded into a graph
+    pub fn with_progress(mut self) -> Self {
+        self.progress = true;
+        self
+    }
+
+    pub fn progress(&self) -> bool {
+        self.progress
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn chunks(&self) -> &[Chunk<Box<dyn Array>>] {
+        &self.chunks
+    }
+
+    /// The total number of rows over all chunks
+    pub fn num_rows(&self) -> usize {
+        self.chunks.iter().map(|c| c.len()).sum()
+    }
+
+    pub(crate) fn column<'a>(
+        &self,
+        chunk: &'a Chunk<Box<dyn Array>>,
+        name: &str,
+    ) -> Result<&'a dyn Array, GraphError> {
+        self.names
+            .iter()
+            .position(|n| n == name)
+            .map(|i| chunk.arrays()[i].as_ref())
+            .ok_or_else(|| GraphError::LoadFailure(format!("missing column '{}'", name)))
+    }
+}
+
+fn downcast<T: 'static>(array: &dyn Array) -> &T {
+    array.as_any().downcast_ref::<T>().unwrap()
+}
+
+fn primitive<T: NativeType>(array: &dyn Array, row: usize) -> Option<T> {
+    let array = downcast::<PrimitiveArray<T>>(array);
+    array.is_valid(row).then(|| array.value(row))
+}
+
+/// Vertex ids are either numbers or names
+#[derive(Clone, Copy)]
+pub(crate) enum RowId<'a> {
+    Num(u64),
+    Name(&'a str),
+}
+
+impl<'a> InputVertex for RowId<'a> {
+    fn id(&self) -> u64 {
+        match self {
+            RowId::Num(id) => *id,
+            RowId::Name(name) => name.id(),
+        }
+    }
+
+    fn id_str(&self) -> Option<&str> {
+        match self {
+            RowId::Num(_) => None,
+            RowId::Name(name) => Some(name),
+        }
+    }
+}
+
+pub(crate) struct IdColumn<'a>(&'a dyn Array);
+
+impl<'a> IdColumn<'a> {
+    pub(crate) fn new(array: &'a dyn Array, name: &str) -> Result<Self, GraphError> {
+        match array.data_type() {
+            DataType::UInt64
+            | DataType::UInt32
+            | DataType::Int64
+            | DataType::Int32
+            | DataType::Utf8
+            | DataType::LargeUtf8 => Ok(Self(array)),
+            dtype => Err(GraphError::LoadFailure(format!(
+                "vertex id column '{}' must be an integer or string column, found {:?}",
+                name, dtype
+            ))),
+        }
+    }
+
+    pub(crate) fn get(&self, row: usize) -> Option<RowId<'a>> {
+        match self.0.data_type() {
+            DataType::UInt64 => primitive::<u64>(self.0, row).map(RowId::Num),
+            DataType::UInt32 => primitive::<u32>(self.0, row).map(|v| RowId::Num(v as u64)),
+            DataType::Int64 => primitive::<i64>(self.0, row).map(|v| RowId::Num(v as u64)),
+            DataType::Int32 => primitive::<i32>(self.0, row).map(|v| RowId::Num(v as u64)),
+            _ => StrColumn(self.0).get(row).map(RowId::Name),
+        }
+    }
+}
+
+pub(crate) struct StrColumn<'a>(&'a dyn Array);
+
+impl<'a> StrColumn<'a> {
+    pub(crate) fn new(array: &'a dyn Array, name: &str) -> Result<Self, GraphError> {
+        match array.data_type() {
+            DataType::Utf8 | DataType::LargeUtf8 => Ok(Self(array)),
+            dtype => Err(GraphError::LoadFailure(format!(
+                "column '{}' must be a string column, found {:?}",
+                name, dtype
+            ))),
+        }
+    }
+
+    pub(crate) fn get(&self, row: usize) -> Option<&'a str> {
+        match self.0.data_type() {
+            DataType::Utf8 => {
+                let array = downcast::<Utf8Array<i32>>(self.0);
+                array.is_valid(row).then(|| array.value(row))
+            }
+            _ => {
+                let array = downcast::<Utf8Array<i64>>(self.0);
+                array.is_valid(row).then(|| array.value(row))
+            }
+        }
+    }
+}
+
+/// Times are integers or timestamps (converted to milliseconds since the epoch)
+pub(crate) struct TimeColumn<'a>(&'a dyn Array);
+
+impl<'a> TimeColumn<'a> {
+    pub(crate) fn new(array: &'a dyn Array, name: &str) -> Result<Self, GraphError> {
+        match array.data_type() {
+            DataType::Int64 | DataType::Int32 | DataType::Timestamp(_, _) | DataType::Date64 => {
+                Ok(Self(array))
+            }
+            dtype => Err(GraphError::LoadFailure(format!(
+                "time column '{}' must be an integer or timestamp column, found {:?}",
+                name, dtype
+            ))),
+        }
+    }
+
+    pub(crate) fn get(&self, row: usize) -> Option<i64> {
+        match self.0.data_type() {
+            DataType::Int32 => primitive::<i32>(self.0, row).map(|t| t as i64),
+            DataType::Timestamp(unit, _) => {
+                primitive::<i64>(self.0, row).map(|t| timestamp_millis(t, unit))
+            }
+            _ => primitive::<i64>(self.0, row),
+        }
+    }
+}
+
+fn timestamp_millis(t: i64, unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => t * 1000,
+        TimeUnit::Millisecond => t,
+        TimeUnit::Microsecond => t / 1000,
+        TimeUnit::Nanosecond => t / 1_000_000,
+    }
+}
+
+/// Property columns, arrow types are mapped to the matching [`Prop`] variants
+pub(crate) struct PropColumns<'a>(Vec<(&'a str, &'a dyn Array)>);
+
+impl<'a> PropColumns<'a> {
+    pub(crate) fn new<S: AsRef<str>>(
+        df: &DFView,
+        chunk: &'a Chunk<Box<dyn Array>>,
+        names: &'a [S],
+    ) -> Result<Self, GraphError> {
+        let mut columns = vec![];
+        for name in names {
+            let name = name.as_ref();
+            let array = df.column(chunk, name)?;
+            match array.data_type() {
+                DataType::Utf8
+                | DataType::LargeUtf8
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::Float32
+                | DataType::Float64
+                | DataType::Boolean
+                | DataType::Timestamp(_, _)
+                | DataType::Date64 => columns.push((name, array)),
+                dtype => {
+                    return Err(GraphError::LoadFailure(format!(
+                        "property column '{}' has unsupported type {:?}",
+                        name, dtype
+                    )))
+                }
+            }
+        }
+        Ok(Self(columns))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The non-null properties of `row`
+    pub(crate) fn get(&self, row: usize) -> Vec<(&'a str, Prop)> {
+        self.0
+            .iter()
+            .filter_map(|(name, array)| prop_at(*array, row).map(|prop| (*name, prop)))
+            .collect()
+    }
+}
+
+fn prop_at(array: &dyn Array, row: usize) -> Option<Prop> {
+    match array.data_type() {
+        DataType::Utf8 | DataType::LargeUtf8 => StrColumn(array).get(row).map(Prop::str),
+        DataType::Int32 => primitive(array, row).map(Prop::I32),
+        DataType::Int64 => primitive(array, row).map(Prop::I64),
+        DataType::UInt32 => primitive(array, row).map(Prop::U32),
+        DataType::UInt64 => primitive(array, row).map(Prop::U64),
+        DataType::Float32 => primitive(array, row).map(Prop::F32),
+        DataType::Float64 => primitive(array, row).map(Prop::F64),
+        DataType::Boolean => {
+            let array = downcast::<BooleanArray>(array);
+            array.is_valid(row).then(|| Prop::Bool(array.value(row)))
+        }
+        _ => TimeColumn(array)
+            .get(row)
+            .and_then(NaiveDateTime::from_timestamp_millis)
+            .map(Prop::DTime),
+    }
+}
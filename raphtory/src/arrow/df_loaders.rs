@@ -0,0 +1,602 @@
+//! Load the rows of a [`DFView`] into a graph.
+//!
+//! The chunks of the view are ingested in parallel, the rows of a chunk are added in order. Rows
+//! with a missing vertex id or time are skipped, as are null property values. A progress bar is
+//! shown on stderr for views created with [`DFView::with_progress`].
+use crate::{
+    arrow::dataframe::{DFView, IdColumn, PropColumns, RowId, StrColumn, TimeColumn},
+    core::{utils::errors::GraphError, Prop},
+    db::api::mutation::{internal::InternalDeletionOps, AdditionOps, PropertyAdditionOps},
+};
+use arrow2::{array::Array, chunk::Chunk};
+use kdam::{tqdm, BarExt};
+use parking_lot::Mutex;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Run `f` on all chunks of `df` in parallel, showing a progress bar if the view asks for one
+fn par_load<F>(df: &DFView, desc: &str, f: F) -> Result<(), GraphError>
+where
+    F: Fn(&Chunk<Box<dyn Array>>) -> Result<(), GraphError> + Sync,
+{
+    let bar = df.progress().then(|| {
+        Mutex::new(tqdm!(
+            total = df.num_rows(),
+            desc = desc,
+            animation = kdam::Animation::FillUp,
+            unit_scale = true
+        ))
+    });
+    df.chunks().par_iter().try_for_each(|chunk| {
+        f(chunk)?;
+        if let Some(bar) = &bar {
+            let _ = bar.lock().update(chunk.len());
+        }
+        Ok(())
+    })
+}
+
+/// Drop an empty set of shared properties so that no call is made for them
+fn shared_props(props: Option<HashMap<String, Prop>>) -> Option<HashMap<String, Prop>> {
+    props.filter(|props| !props.is_empty())
+}
+
+fn prop_names<'a>(props: &'a Option<Vec<&'a str>>) -> &'a [&'a str] {
+    props.as_deref().unwrap_or_default()
+}
+
+/// The layer of each row, an explicit layer takes precedence over a layer column
+enum Layers<'a> {
+    Fixed(Option<&'a str>),
+    Column(StrColumn<'a>),
+}
+
+impl<'a> Layers<'a> {
+    fn new(
+        df: &DFView,
+        chunk: &'a Chunk<Box<dyn Array>>,
+        layer: Option<&'a str>,
+        layer_in_df: Option<&str>,
+    ) -> Result<Self, GraphError> {
+        match (layer, layer_in_df) {
+            (None, Some(name)) => Ok(Layers::Column(StrColumn::new(
+                df.column(chunk, name)?,
+                name,
+            )?)),
+            _ => Ok(Layers::Fixed(layer)),
+        }
+    }
+
+    fn get(&self, row: usize) -> Option<&'a str> {
+        match self {
+            Layers::Fixed(layer) => *layer,
+            Layers::Column(column) => column.get(row),
+        }
+    }
+}
+
+/// Load one vertex update per row of `df`
+///
+/// # Arguments
+///
+/// * `vertex_id` - the column of vertex ids (integers or strings)
+/// * `time` - the column of update times (integers or timestamps)
+/// * `props` - the columns to add as temporal properties
+/// * `const_props` - the columns to add as constant properties
+/// * `shared_const_props` - constant properties to add to every vertex
+pub fn load_vertices_from_df<G: AdditionOps + PropertyAdditionOps + Sync>(
+    df: &DFView,
+    vertex_id: &str,
+    time: &str,
+    props: Option<Vec<&str>>,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    graph: &G,
+) -> Result<(), GraphError> {
+    let shared_const_props = shared_props(shared_const_props);
+    par_load(df, "Loading vertices", |chunk| {
+        let ids = IdColumn::new(df.column(chunk, vertex_id)?, vertex_id)?;
+        let times = TimeColumn::new(df.column(chunk, time)?, time)?;
+        let props = PropColumns::new(df, chunk, prop_names(&props))?;
+        let const_props = PropColumns::new(df, chunk, prop_names(&const_props))?;
+        for row in 0..chunk.len() {
+            if let (Some(v), Some(t)) = (ids.get(row), times.get(row)) {
+                graph.add_vertex(t, v, props.get(row), None)?;
+                if !const_props.is_empty() {
+                    graph.add_vertex_properties(v, const_props.get(row))?;
+                }
+                if let Some(shared_const_props) = &shared_const_props {
+                    graph.add_vertex_properties(v, shared_const_props.iter())?;
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Load one edge update per row of `df`
+///
+/// # Arguments
+///
+/// * `src`, `dst` - the columns of source and destination vertex ids (integers or strings)
+/// * `time` - the column of update times (integers or timestamps)
+/// * `props` - the columns to add as temporal properties
+/// * `const_props` - the columns to add as constant properties
+/// * `shared_const_props` - constant properties to add to every edge
+/// * `layer` - the layer of all edges
+/// * `layer_in_df` - the column with the layer of each edge, ignored if `layer` is set
+#[allow(clippy::too_many_arguments)]
+pub fn load_edges_from_df<S: AsRef<str>, G: AdditionOps + PropertyAdditionOps + Sync>(
+    df: &DFView,
+    src: &str,
+    dst: &str,
+    time: &str,
+    props: Option<Vec<&str>>,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<S>,
+    layer_in_df: Option<S>,
+    graph: &G,
+) -> Result<(), GraphError> {
+    load_edge_rows(
+        df,
+        src,
+        dst,
+        time,
+        None,
+        props,
+        const_props,
+        shared_const_props,
+        layer.as_ref().map(|l| l.as_ref()),
+        layer_in_df.as_ref().map(|l| l.as_ref()),
+        graph,
+        |t, _, src, dst, props, layer| {
+            graph.add_edge(t, src, dst, props, layer)?;
+            Ok(())
+        },
+    )
+}
+
+/// Load one edge per row of `df` which exists from the time in column `start` until it is deleted
+/// at the time in column `end`
+///
+/// Rows with a missing end time are never deleted. The other arguments are the same as for
+/// [`load_edges_from_df`].
+#[allow(clippy::too_many_arguments)]
+pub fn load_edge_intervals_from_df<
+    S: AsRef<str>,
+    G: AdditionOps + PropertyAdditionOps + InternalDeletionOps + Sync,
+>(
+    df: &DFView,
+    src: &str,
+    dst: &str,
+    start: &str,
+    end: &str,
+    props: Option<Vec<&str>>,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<S>,
+    layer_in_df: Option<S>,
+    graph: &G,
+) -> Result<(), GraphError> {
+    load_edge_rows(
+        df,
+        src,
+        dst,
+        start,
+        Some(end),
+        props,
+        const_props,
+        shared_const_props,
+        layer.as_ref().map(|l| l.as_ref()),
+        layer_in_df.as_ref().map(|l| l.as_ref()),
+        graph,
+        |start, end, src, dst, props, layer| {
+            match end {
+                Some(end) => graph.add_edge_interval(start, end, src, dst, props, layer)?,
+                None => graph.add_edge(start, src, dst, props, layer)?,
+            };
+            Ok(())
+        },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn load_edge_rows<G, F>(
+    df: &DFView,
+    src: &str,
+    dst: &str,
+    time: &str,
+    end: Option<&str>,
+    props: Option<Vec<&str>>,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<&str>,
+    layer_in_df: Option<&str>,
+    graph: &G,
+    add_edge: F,
+) -> Result<(), GraphError>
+where
+    G: PropertyAdditionOps + Sync,
+    F: Fn(
+            i64,
+            Option<i64>,
+            RowId,
+            RowId,
+            Vec<(&str, Prop)>,
+            Option<&str>,
+        ) -> Result<(), GraphError>
+        + Sync,
+{
+    let shared_const_props = shared_props(shared_const_props);
+    par_load(df, "Loading edges", |chunk| {
+        let srcs = IdColumn::new(df.column(chunk, src)?, src)?;
+        let dsts = IdColumn::new(df.column(chunk, dst)?, dst)?;
+        let times = TimeColumn::new(df.column(chunk, time)?, time)?;
+        let ends = match end {
+            Some(end) => Some(TimeColumn::new(df.column(chunk, end)?, end)?),
+            None => None,
+        };
+        let props = PropColumns::new(df, chunk, prop_names(&props))?;
+        let const_props = PropColumns::new(df, chunk, prop_names(&const_props))?;
+        let layers = Layers::new(df, chunk, layer, layer_in_df)?;
+        for row in 0..chunk.len() {
+            if let (Some(src), Some(dst), Some(t)) = (srcs.get(row), dsts.get(row), times.get(row))
+            {
+                let layer = layers.get(row);
+                let end = ends.as_ref().and_then(|ends| ends.get(row));
+                add_edge(t, end, src, dst, props.get(row), layer)?;
+                if !const_props.is_empty() {
+                    graph.add_edge_properties(src, dst, const_props.get(row), layer)?;
+                }
+                if let Some(shared_const_props) = &shared_const_props {
+                    graph.add_edge_properties(src, dst, shared_const_props.iter(), layer)?;
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Add constant properties to the vertices in column `vertex_id` of `df`
+pub fn load_vertex_props_from_df<G: PropertyAdditionOps + Sync>(
+    df: &DFView,
+    vertex_id: &str,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    graph: &G,
+) -> Result<(), GraphError> {
+    let shared_const_props = shared_props(shared_const_props);
+    par_load(df, "Loading vertex properties", |chunk| {
+        let ids = IdColumn::new(df.column(chunk, vertex_id)?, vertex_id)?;
+        let const_props = PropColumns::new(df, chunk, prop_names(&const_props))?;
+        for row in 0..chunk.len() {
+            if let Some(v) = ids.get(row) {
+                if !const_props.is_empty() {
+                    graph.add_vertex_properties(v, const_props.get(row))?;
+                }
+                if let Some(shared_const_props) = &shared_const_props {
+                    graph.add_vertex_properties(v, shared_const_props.iter())?;
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Add constant properties to the edges in columns `src` and `dst` of `df`
+#[allow(clippy::too_many_arguments)]
+pub fn load_edges_props_from_df<S: AsRef<str>, G: PropertyAdditionOps + Sync>(
+    df: &DFView,
+    src: &str,
+    dst: &str,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<S>,
+    layer_in_df: Option<S>,
+    graph: &G,
+) -> Result<(), GraphError> {
+    let layer = layer.as_ref().map(|l| l.as_ref());
+    let layer_in_df = layer_in_df.as_ref().map(|l| l.as_ref());
+    let shared_const_props = shared_props(shared_const_props);
+    par_load(df, "Loading edge properties", |chunk| {
+        let srcs = IdColumn::new(df.column(chunk, src)?, src)?;
+        let dsts = IdColumn::new(df.column(chunk, dst)?, dst)?;
+        let const_props = PropColumns::new(df, chunk, prop_names(&const_props))?;
+        let layers = Layers::new(df, chunk, layer, layer_in_df)?;
+        for row in 0..chunk.len() {
+            if let (Some(src), Some(dst)) = (srcs.get(row), dsts.get(row)) {
+                let layer = layers.get(row);
+                if !const_props.is_empty() {
+                    graph.add_edge_properties(src, dst, const_props.get(row), layer)?;
+                }
+                if let Some(shared_const_props) = &shared_const_props {
+                    graph.add_edge_properties(src, dst, shared_const_props.iter(), layer)?;
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{db::graph::views::deletion_graph::GraphWithDeletions, prelude::*};
+    use arrow2::array::{PrimitiveArray, Utf8Array};
+
+    fn df(names: &[&str], chunks: Vec<Vec<Box<dyn Array>>>) -> DFView {
+        DFView::new(
+            names.iter().map(|s| s.to_string()).collect(),
+            chunks.into_iter().map(Chunk::new).collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn load_edges() {
+        let df = df(
+            &["src", "dst", "time", "prop1", "prop2"],
+            vec![
+                vec![
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(1)])),
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(2)])),
+                    Box::new(PrimitiveArray::<i64>::from(vec![Some(1)])),
+                    Box::new(PrimitiveArray::<f64>::from(vec![Some(1.0)])),
+                    Box::new(Utf8Array::<i32>::from(vec![Some("a")])),
+                ],
+                vec![
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(2), Some(3)])),
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(3), Some(4)])),
+                    Box::new(PrimitiveArray::<i64>::from(vec![Some(2), Some(3)])),
+                    Box::new(PrimitiveArray::<f64>::from(vec![Some(2.0), Some(3.0)])),
+                    Box::new(Utf8Array::<i32>::from(vec![Some("b"), Some("c")])),
+                ],
+            ],
+        );
+        let graph = Graph::new();
+        load_edges_from_df(
+            &df,
+            "src",
+            "dst",
+            "time",
+            Some(vec!["prop1", "prop2"]),
+            None,
+            None,
+            None::<&str>,
+            None,
+            &graph,
+        )
+        .expect("failed to load edges");
+
+        let mut actual = graph
+            .edges()
+            .map(|e| {
+                (
+                    e.src().id(),
+                    e.dst().id(),
+                    e.latest_time(),
+                    e.properties()
+                        .temporal()
+                        .get("prop1")
+                        .and_then(|v| v.latest()),
+                    e.properties()
+                        .temporal()
+                        .get("prop2")
+                        .and_then(|v| v.latest()),
+                )
+            })
+            .collect::<Vec<_>>();
+        actual.sort_by_key(|(src, dst, ..)| (*src, *dst));
+
+        assert_eq!(
+            actual,
+            vec![
+                (1, 2, Some(1), Some(Prop::F64(1.0)), Some(Prop::str("a"))),
+                (2, 3, Some(2), Some(Prop::F64(2.0)), Some(Prop::str("b"))),
+                (3, 4, Some(3), Some(Prop::F64(3.0)), Some(Prop::str("c"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn load_edge_layers() {
+        let df = df(
+            &["src", "dst", "time", "layer"],
+            vec![vec![
+                Box::new(Utf8Array::<i32>::from(vec![Some("a"), Some("b")])),
+                Box::new(Utf8Array::<i32>::from(vec![Some("b"), Some("c")])),
+                Box::new(PrimitiveArray::<i32>::from(vec![Some(1), Some(2)])),
+                Box::new(Utf8Array::<i32>::from(vec![Some("x"), Some("y")])),
+            ]],
+        );
+        let graph = Graph::new();
+        load_edges_from_df(
+            &df,
+            "src",
+            "dst",
+            "time",
+            None,
+            None,
+            Some(HashMap::from([("tag".to_string(), Prop::str("t"))])),
+            None,
+            Some("layer"),
+            &graph,
+        )
+        .unwrap();
+        assert!(graph.has_edge("a", "b", "x"));
+        assert!(graph.has_edge("b", "c", "y"));
+        assert_eq!(
+            graph
+                .layer("x")
+                .and_then(|g| g.edge("a", "b"))
+                .and_then(|e| e.properties().constant().get("tag")),
+            Some(Prop::str("t"))
+        );
+
+        // an explicit layer takes precedence over the layer column
+        let graph = Graph::new();
+        load_edges_from_df(
+            &df,
+            "src",
+            "dst",
+            "time",
+            None,
+            None,
+            None,
+            Some("z"),
+            Some("layer"),
+            &graph,
+        )
+        .unwrap();
+        assert!(graph.has_edge("a", "b", "z"));
+        assert!(!graph.has_edge("a", "b", "x"));
+    }
+
+    #[test]
+    fn load_edge_intervals() {
+        let df = df(
+            &["src", "dst", "start", "end"],
+            vec![vec![
+                Box::new(Utf8Array::<i32>::from(vec![Some("a"), Some("b")])),
+                Box::new(Utf8Array::<i32>::from(vec![Some("b"), Some("c")])),
+                Box::new(PrimitiveArray::<i64>::from(vec![Some(1), Some(2)])),
+                Box::new(PrimitiveArray::<i64>::from(vec![Some(5), None])),
+            ]],
+        );
+        let graph = GraphWithDeletions::new();
+        load_edge_intervals_from_df(
+            &df,
+            "src",
+            "dst",
+            "start",
+            "end",
+            None,
+            None,
+            None,
+            None::<&str>,
+            None,
+            &graph,
+        )
+        .expect("failed to load edge intervals");
+
+        assert!(graph.window(3, 4).has_edge("a", "b", Layer::All));
+        assert!(!graph.window(6, 7).has_edge("a", "b", Layer::All));
+        assert!(graph.window(6, 7).has_edge("b", "c", Layer::All));
+    }
+
+    #[test]
+    fn load_vertices() {
+        let df = df(
+            &["id", "name", "time"],
+            vec![
+                vec![
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(1)])),
+                    Box::new(Utf8Array::<i32>::from(vec![Some("a")])),
+                    Box::new(PrimitiveArray::<i64>::from(vec![Some(1)])),
+                ],
+                vec![
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(2)])),
+                    Box::new(Utf8Array::<i32>::from(vec![Some("b")])),
+                    Box::new(PrimitiveArray::<i64>::from(vec![Some(2)])),
+                ],
+            ],
+        );
+        let graph = Graph::new();
+        load_vertices_from_df(&df, "id", "time", Some(vec!["name"]), None, None, &graph)
+            .expect("failed to load vertices");
+
+        let mut actual = graph
+            .vertices()
+            .iter()
+            .map(|v| {
+                (
+                    v.id(),
+                    v.latest_time(),
+                    v.properties()
+                        .temporal()
+                        .get("name")
+                        .and_then(|v| v.latest()),
+                )
+            })
+            .collect::<Vec<_>>();
+        actual.sort_by_key(|(id, ..)| *id);
+
+        assert_eq!(
+            actual,
+            vec![
+                (1, Some(1), Some(Prop::str("a"))),
+                (2, Some(2), Some(Prop::str("b"))),
+            ]
+        );
+
+        load_vertex_props_from_df(&df, "id", Some(vec!["name"]), None, &graph).unwrap();
+        assert_eq!(
+            graph
+                .vertex(1)
+                .and_then(|v| v.properties().constant().get("name")),
+            Some(Prop::str("a"))
+        );
+    }
+
+    #[test]
+    fn no_const_props_are_not_added() {
+        let df = df(
+            &["src", "dst"],
+            vec![vec![
+                Box::new(PrimitiveArray::<u64>::from(vec![Some(1)])),
+                Box::new(PrimitiveArray::<u64>::from(vec![Some(2)])),
+            ]],
+        );
+        // the edge does not exist, so adding properties to it would fail
+        let graph = Graph::new();
+        load_edges_props_from_df(
+            &df,
+            "src",
+            "dst",
+            None,
+            Some(HashMap::new()),
+            None::<&str>,
+            None,
+            &graph,
+        )
+        .unwrap();
+        assert_eq!(graph.num_edges(), 0);
+    }
+
+    #[test]
+    fn schema_errors() {
+        let df = df(
+            &["src", "dst", "time"],
+            vec![vec![
+                Box::new(PrimitiveArray::<f64>::from(vec![Some(1.0)])),
+                Box::new(PrimitiveArray::<u64>::from(vec![Some(2)])),
+                Box::new(PrimitiveArray::<i64>::from(vec![Some(1)])),
+            ]],
+        );
+        let graph = Graph::new();
+        let load = |src: &str, time: &str| {
+            load_edges_from_df(
+                &df,
+                src,
+                "dst",
+                time,
+                None,
+                None,
+                None,
+                None::<&str>,
+                None,
+                &graph,
+            )
+        };
+        assert!(matches!(
+            load("src", "time"),
+            Err(GraphError::LoadFailure(_))
+        ));
+        assert!(matches!(
+            load("dst", "missing"),
+            Err(GraphError::LoadFailure(_))
+        ));
+        assert!(DFView::new(vec!["a".to_string()], df.chunks().to_vec()).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+//! Bulk loading of arrow data into graphs.
+//!
+//! A [`DFView`](dataframe::DFView) wraps arrow2 chunks (record batches) with their column names.
+//! The functions in [`df_loaders`] add the rows of a view to any graph supporting mutation,
+//! processing the chunks in parallel.
+//!
+//! # Example
+//!
+//! ```rust
+//! use arrow2::{
+//!     array::{Array, PrimitiveArray, Utf8Array},
+//!     chunk::Chunk,
+//! };
+//! use raphtory::arrow::{dataframe::DFView, df_loaders::load_edges_from_df};
+//! use raphtory::prelude::*;
+//!
+//! let chunk = Chunk::new(vec![
+//!     PrimitiveArray::from_vec(vec![1u64, 2]).boxed(),
+//!     PrimitiveArray::from_vec(vec![2u64, 3]).boxed(),
+//!     PrimitiveArray::from_vec(vec![10i64, 20]).boxed(),
+//!     Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+//! ]);
+//! let df = DFView::new(
+//!     vec!["src".into(), "dst".into(), "time".into(), "name".into()],
+//!     vec![chunk],
+//! )
+//! .unwrap();
+//!
+//! let g = Graph::new();
+//! load_edges_from_df(&df, "src", "dst", "time", Some(vec!["name"]), None, None, None::<&str>, None, &g)
+//!     .unwrap();
+//! assert_eq!(g.num_edges(), 2);
+//! ```
+pub mod dataframe;
+pub mod df_loaders;
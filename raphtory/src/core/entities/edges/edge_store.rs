@@ -1,7 +1,10 @@
 use crate::core::{
     entities::{
         edges::edge_ref::EdgeRef,
-        properties::{props::Props, tprop::TProp},
+        properties::{
+            props::{Meta, Props},
+            tprop::TProp,
+        },
         LayerIds, EID, VID,
     },
     storage::{
@@ -14,7 +17,10 @@ use crate::core::{
 };
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use std::ops::{Deref, DerefMut, Range};
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut, Range},
+};
 use tantivy::HasLen;
 
 #[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
@@ -47,6 +53,11 @@ impl EdgeLayer {
         props.add_prop(t, prop_id, prop)
     }
 
+    /// Check that the temporal properties can be added without changing their types
+    pub fn check_props(&self, props: &[(usize, Prop)]) -> Result<(), GraphError> {
+        Props::check_props(self.props.as_ref(), props)
+    }
+
     pub fn add_static_prop(
         &mut self,
         prop_id: usize,
@@ -214,6 +225,24 @@ impl EdgeStore {
         }
     }
 
+    pub(crate) fn from_parts(
+        eid: EID,
+        src: VID,
+        dst: VID,
+        layers: Vec<EdgeLayer>,
+        additions: Vec<TimeIndex<TimeIndexEntry>>,
+        deletions: Vec<TimeIndex<TimeIndexEntry>>,
+    ) -> Self {
+        Self {
+            eid,
+            src,
+            dst,
+            layers,
+            additions,
+            deletions,
+        }
+    }
+
     pub fn layer(&self, layer_id: usize) -> Option<&EdgeLayer> {
         self.layers.get(layer_id)
     }
@@ -314,6 +343,101 @@ impl EdgeStore {
         }
     }
 
+    /// Get a static property for the given layers, properties from multiple layers are
+    /// combined into a map keyed by layer name
+    pub(crate) fn static_property_layers(
+        &self,
+        layer_ids: LayerIds,
+        prop_id: usize,
+        meta: &Meta,
+    ) -> Option<Prop> {
+        let layer_name = |id: usize| meta.get_layer_name_by_id(id).unwrap_or_default();
+        match layer_ids {
+            LayerIds::None => None,
+            LayerIds::All => {
+                if meta.layer_meta().len() == 1 {
+                    // iterator has at most 1 element
+                    self.layer_iter()
+                        .next()
+                        .and_then(|layer| layer.static_property(prop_id).cloned())
+                } else {
+                    let prop_map: HashMap<_, _> = self
+                        .layer_iter()
+                        .enumerate()
+                        .flat_map(|(id, layer)| {
+                            layer
+                                .static_property(prop_id)
+                                .map(|p| (layer_name(id), p.clone()))
+                        })
+                        .collect();
+                    if prop_map.is_empty() {
+                        None
+                    } else {
+                        Some(prop_map.into())
+                    }
+                }
+            }
+            LayerIds::One(id) => self
+                .layer(id)
+                .and_then(|l| l.static_property(prop_id).cloned()),
+            LayerIds::Multiple(ids) => {
+                let prop_map: HashMap<_, _> = ids
+                    .iter()
+                    .flat_map(|&id| {
+                        self.layer(id).and_then(|layer| {
+                            layer
+                                .static_property(prop_id)
+                                .map(|p| (layer_name(id), p.clone()))
+                        })
+                    })
+                    .collect();
+                if prop_map.is_empty() {
+                    None
+                } else {
+                    Some(prop_map.into())
+                }
+            }
+        }
+    }
+
+    /// Sorted ids of the static properties present in any of the layers
+    pub(crate) fn static_prop_ids_layers(&self, layer_ids: LayerIds) -> Vec<usize> {
+        match layer_ids {
+            LayerIds::None => vec![],
+            LayerIds::All => self
+                .layer_iter()
+                .map(|l| l.static_prop_ids())
+                .kmerge()
+                .dedup()
+                .collect(),
+            LayerIds::One(id) => self
+                .layer(id)
+                .map(|l| l.static_prop_ids())
+                .unwrap_or_default(),
+            LayerIds::Multiple(ids) => ids
+                .iter()
+                .flat_map(|id| self.layer(*id).map(|l| l.static_prop_ids()))
+                .kmerge()
+                .dedup()
+                .collect(),
+        }
+    }
+
+    /// Sorted ids of the temporal properties present in any of the layers
+    pub(crate) fn temp_prop_ids_layers(&self, layer_ids: LayerIds) -> Vec<usize> {
+        match layer_ids {
+            LayerIds::None => vec![],
+            LayerIds::All => self.temp_prop_ids(None),
+            LayerIds::One(id) => self.temp_prop_ids(Some(id)),
+            LayerIds::Multiple(ids) => ids
+                .iter()
+                .map(|id| self.temp_prop_ids(Some(*id)))
+                .kmerge()
+                .dedup()
+                .collect(),
+        }
+    }
+
     pub(crate) fn temp_prop_ids(&self, layer_id: Option<usize>) -> Vec<usize> {
         if let Some(layer_id) = layer_id {
             self.layers
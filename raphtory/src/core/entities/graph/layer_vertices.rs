@@ -0,0 +1,81 @@
+use crate::core::entities::{LayerIds, VID};
+use parking_lot::RwLock;
+use roaring::RoaringTreemap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The vertices that were added to or have edges in each layer
+///
+/// The sets are not serialised, a deserialised graph starts out stale and the sets are rebuilt from
+/// the vertices the first time they are needed. Vertices are always recorded as they join a layer,
+/// so a rebuild racing with mutations only ever adds vertices that are already members.
+#[derive(Debug)]
+pub(crate) struct LayerVertices {
+    layers: RwLock<Vec<RoaringTreemap>>,
+    stale: AtomicBool,
+}
+
+impl LayerVertices {
+    pub(crate) fn new() -> Self {
+        Self {
+            layers: RwLock::new(vec![]),
+            stale: AtomicBool::new(false),
+        }
+    }
+
+    pub(crate) fn stale() -> Self {
+        Self {
+            layers: RwLock::new(vec![]),
+            stale: AtomicBool::new(true),
+        }
+    }
+
+    pub(crate) fn is_stale(&self) -> bool {
+        self.stale.load(Ordering::Acquire)
+    }
+
+    /// Record that `v` joined `layer`
+    pub(crate) fn insert(&self, layer: usize, v: VID) {
+        let mut layers = self.layers.write();
+        if layer >= layers.len() {
+            layers.resize_with(layer + 1, RoaringTreemap::new);
+        }
+        layers[layer].insert(usize::from(v) as u64);
+    }
+
+    /// Merge the memberships found by scanning the vertices and mark the sets as up to date
+    pub(crate) fn rebuild(&self, scanned: Vec<RoaringTreemap>) {
+        let mut layers = self.layers.write();
+        if layers.len() < scanned.len() {
+            layers.resize_with(scanned.len(), RoaringTreemap::new);
+        }
+        for (layer, vertices) in layers.iter_mut().zip(scanned) {
+            *layer |= vertices;
+        }
+        self.stale.store(false, Ordering::Release);
+    }
+
+    /// The vertices in any of the `layers`, `LayerIds::All` is handled by the caller
+    pub(crate) fn vertices(&self, layers: &LayerIds) -> RoaringTreemap {
+        let sets = self.layers.read();
+        match layers {
+            LayerIds::One(layer) => sets.get(*layer).cloned().unwrap_or_default(),
+            LayerIds::Multiple(layer_ids) => layer_ids
+                .iter()
+                .filter_map(|layer| sets.get(*layer))
+                .fold(RoaringTreemap::new(), |acc, vertices| acc | vertices),
+            LayerIds::All | LayerIds::None => RoaringTreemap::new(),
+        }
+    }
+
+    /// The number of vertices in any of the `layers`, `LayerIds::All` is handled by the caller
+    pub(crate) fn len(&self, layers: &LayerIds) -> usize {
+        match layers {
+            LayerIds::One(layer) => self
+                .layers
+                .read()
+                .get(*layer)
+                .map_or(0, |vertices| vertices.len() as usize),
+            _ => self.vertices(layers).len() as usize,
+        }
+    }
+}
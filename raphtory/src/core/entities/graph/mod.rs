@@ -1,6 +1,8 @@
+pub(crate) mod layer_vertices;
 pub mod tgraph;
 pub mod tgraph_storage;
 pub(crate) mod timer;
+pub mod wal;
 
 #[cfg(test)]
 mod test {
@@ -7,10 +7,16 @@ use crate::{
                 edge_store::{EdgeLayer, EdgeStore},
             },
             graph::{
+                layer_vertices::LayerVertices,
                 tgraph_storage::{GraphStorage, LockedIter},
                 timer::{MaxCounter, MinCounter, TimeCounterTrait},
+                wal::WriteAheadLog,
+            },
+            properties::{
+                graph_props::GraphProps,
+                props::{Meta, Props},
+                tprop::TProp,
             },
-            properties::{graph_props::GraphProps, props::Meta, tprop::TProp},
             vertices::{
                 input_vertex::InputVertex,
                 vertex::{ArcEdge, ArcVertex, Vertex},
@@ -20,6 +26,7 @@ use crate::{
             LayerIds, EID, VID,
         },
         storage::{
+            lazy_vec::IllegalSet,
             locked_view::LockedView,
             timeindex::{AsTime, LayeredIndex, TimeIndexEntry, TimeIndexOps},
             ArcEntry, Entry,
@@ -34,8 +41,9 @@ use crate::{
 };
 use dashmap::DashMap;
 use itertools::Itertools;
-use parking_lot::RwLockReadGuard;
+use parking_lot::{RwLock, RwLockReadGuard};
 use rayon::prelude::*;
+use roaring::RoaringTreemap;
 use rustc_hash::FxHasher;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -76,13 +84,21 @@ pub struct TemporalGraph<const N: usize> {
     pub(in crate::core) latest_time: MaxCounter,
 
     // props meta data for vertices (mapping between strings and ids)
-    pub(in crate::core) vertex_meta: Arc<Meta>,
+    pub(crate) vertex_meta: Arc<Meta>,
 
     // props meta data for edges (mapping between strings and ids)
-    pub(in crate::core) edge_meta: Arc<Meta>,
+    pub(crate) edge_meta: Arc<Meta>,
 
     // graph properties
     pub(crate) graph_props: GraphProps,
+
+    // the vertices in each layer, rebuilt from the vertices after deserialisation
+    #[serde(skip, default = "LayerVertices::stale")]
+    layer_vertices: LayerVertices,
+
+    // write-ahead log recording mutations, not part of the serialised graph
+    #[serde(skip)]
+    pub(crate) wal: RwLock<Option<WriteAheadLog>>,
 }
 
 impl<const N: usize> std::fmt::Display for InnerTemporalGraph<N> {
@@ -107,6 +123,8 @@ impl<const N: usize> Default for InnerTemporalGraph<N> {
             vertex_meta: Arc::new(Meta::new()),
             edge_meta: Arc::new(Meta::new()),
             graph_props: GraphProps::new(),
+            layer_vertices: LayerVertices::new(),
+            wal: RwLock::new(None),
         };
 
         Self(Arc::new(tg))
@@ -119,30 +137,7 @@ impl<const N: usize> TemporalGraph<N> {
     }
 
     pub(crate) fn layer_names(&self, layer_ids: LayerIds) -> Vec<String> {
-        match layer_ids {
-            LayerIds::None => {
-                vec![]
-            }
-            LayerIds::All => self.edge_meta.layer_meta().get_keys().clone(),
-            LayerIds::One(id) => {
-                vec![self
-                    .edge_meta
-                    .layer_meta()
-                    .reverse_lookup(id)
-                    .unwrap()
-                    .clone()]
-            }
-            LayerIds::Multiple(ids) => ids
-                .iter()
-                .map(|id| {
-                    self.edge_meta
-                        .layer_meta()
-                        .reverse_lookup(*id)
-                        .unwrap()
-                        .clone()
-                })
-                .collect(),
-        }
+        self.edge_meta.layer_names(layer_ids)
     }
 
     pub(crate) fn get_all_vertex_property_names(&self, is_static: bool) -> Vec<String> {
@@ -158,33 +153,7 @@ impl<const N: usize> TemporalGraph<N> {
     }
 
     pub(crate) fn layer_id(&self, key: Layer) -> LayerIds {
-        match key {
-            Layer::All => LayerIds::All,
-            Layer::Default => LayerIds::One(0),
-            Layer::One(id) => match self.edge_meta.get_layer_id(&id) {
-                Some(id) => LayerIds::One(id),
-                None => LayerIds::None,
-            },
-            Layer::Multiple(ids) => {
-                let mut new_layers = ids
-                    .iter()
-                    .filter_map(|id| self.edge_meta.get_layer_id(id))
-                    .collect::<Vec<_>>();
-                let num_layers = self.num_layers();
-                let num_new_layers = new_layers.len();
-                if num_new_layers == 0 {
-                    LayerIds::None
-                } else if num_new_layers == 1 {
-                    LayerIds::One(new_layers[0])
-                } else if num_new_layers == num_layers {
-                    LayerIds::All
-                } else {
-                    new_layers.sort_unstable();
-                    new_layers.dedup();
-                    LayerIds::Multiple(new_layers.into())
-                }
-            }
-        }
+        self.edge_meta.layer_ids(key)
     }
 
     pub(crate) fn get_layer_name(&self, layer: usize) -> String {
@@ -343,24 +312,55 @@ impl<const N: usize> TemporalGraph<N> {
         time: TimeIndexEntry,
         v: u64,
         name: Option<&str>,
+        vertex_type: Option<&str>,
         props: Vec<(String, Prop)>,
+        layer: Option<&str>,
     ) -> Result<VID, GraphError> {
-        self.update_time(time);
+        self.add_vertex_until(time, None, v, name, vertex_type, props, layer)
+    }
 
-        // resolve the props without holding any locks
+    /// Add a vertex at `start` that is deleted at `end`, either both are recorded or neither is
+    pub(crate) fn add_vertex_interval_internal(
+        &self,
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        v: u64,
+        name: Option<&str>,
+        props: Vec<(String, Prop)>,
+    ) -> Result<VID, GraphError> {
+        self.add_vertex_until(start, Some(end), v, name, None, props, None)
+    }
+
+    /// Add a vertex and delete it again at `end` if set, all checks happen before the first write
+    #[allow(clippy::too_many_arguments)]
+    fn add_vertex_until(
+        &self,
+        time: TimeIndexEntry,
+        end: Option<TimeIndexEntry>,
+        v: u64,
+        name: Option<&str>,
+        vertex_type: Option<&str>,
+        props: Vec<(String, Prop)>,
+        layer: Option<&str>,
+    ) -> Result<VID, GraphError> {
+        // resolve the props and layer without holding any locks
+        let layer = layer.map(|layer| self.edge_meta.get_or_create_layer_id(layer.to_owned()));
         let props = self
             .vertex_meta
             .resolve_prop_ids(props, false)
             .collect::<Vec<_>>();
+        Props::check_props(None, &props)?;
 
-        let name_prop = name
+        let static_props = name
             .or_else(|| v.id_str())
-            .map(|n| {
-                self.vertex_meta
-                    .resolve_prop_ids(vec![("_id".to_string(), Prop::Str(n.to_owned()))], true)
-                    .collect::<Vec<_>>()
+            .map(|n| ("_id", n))
+            .into_iter()
+            .chain(vertex_type.map(|t| ("_type", t)))
+            .map(|(key, value)| {
+                let prop_id = self.vertex_meta.resolve_prop_id(key, true);
+                (key, prop_id, Prop::Str(value.to_owned()))
             })
-            .unwrap_or_default();
+            .collect::<Vec<_>>();
 
         // update the logical to physical mapping if needed
         let v_id = *(self.logical_to_physical.entry(v.id()).or_insert_with(|| {
@@ -368,28 +368,90 @@ impl<const N: usize> TemporalGraph<N> {
             self.storage.push_node(node_store)
         }));
 
-        // get the node and update the time index
         let mut node = self.storage.get_node_mut(v_id);
+
+        // reject a conflicting name or type before anything is written
+        for (key, prop_id, prop) in static_props.iter() {
+            if let Some(previous) = node.static_property(*prop_id) {
+                if previous != prop {
+                    return Err(MutateGraphError::IllegalVertexPropertyChange {
+                        vertex_id: v,
+                        source: IllegalMutate::from_source(
+                            IllegalSet {
+                                index: *prop_id,
+                                previous_value: Some(previous.clone()),
+                                new_value: Some(prop.clone()),
+                            },
+                            key,
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+        node.check_props(layer, &props)?;
+
+        // update the time index
+        self.update_time(time);
         node.update_time(time);
+        if let Some(layer) = layer {
+            if !node.has_layer(&LayerIds::One(layer)) {
+                self.layer_vertices.insert(layer, v_id);
+            }
+            node.add_to_layer(time, layer);
+        }
 
         // update the properties;
         for (prop_id, prop) in props {
-            node.add_prop(time, prop_id, prop)?;
+            node.add_prop(time, layer, prop_id, prop)?;
         }
 
-        // update node prop
-        for (prop_id, prop) in name_prop {
+        // update the name and type, these cannot fail after the check above
+        for (key, prop_id, prop) in static_props {
             node.add_static_prop(prop_id, prop).map_err(|err| {
                 MutateGraphError::IllegalVertexPropertyChange {
                     vertex_id: v,
-                    source: IllegalMutate::from_source(err, "_id"),
+                    source: IllegalMutate::from_source(err, key),
                 }
             })?;
         }
 
+        if let Some(end) = end {
+            self.update_time(end);
+            node.delete(end);
+        }
+
         Ok(v_id)
     }
 
+    /// Check that `name` does not conflict with the name of an existing vertex `v`
+    fn check_vertex_name(&self, v: u64, name: Option<&str>) -> Result<(), GraphError> {
+        let name = match name.or_else(|| v.id_str()) {
+            Some(name) => Prop::Str(name.to_owned()),
+            None => return Ok(()),
+        };
+        if let Some(v_id) = self.logical_to_physical.get(&v).map(|entry| *entry) {
+            let prop_id = self.vertex_meta.resolve_prop_id("_id", true);
+            if let Some(previous) = self.node_entry(v_id).static_property(prop_id) {
+                if previous != &name {
+                    return Err(MutateGraphError::IllegalVertexPropertyChange {
+                        vertex_id: v,
+                        source: IllegalMutate::from_source(
+                            IllegalSet {
+                                index: prop_id,
+                                previous_value: Some(previous.clone()),
+                                new_value: Some(name),
+                            },
+                            "_id",
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn add_vertex_no_props(&self, t: TimeIndexEntry, v: u64) -> Result<VID, GraphError> {
         self.update_time(t);
 
@@ -565,6 +627,19 @@ impl<const N: usize> TemporalGraph<N> {
         Ok(())
     }
 
+    /// Record a deletion of the vertex, a vertex that does not exist yet is created without
+    /// any additions
+    pub(crate) fn delete_vertex(&self, t: TimeIndexEntry, v: u64) -> Result<(), GraphError> {
+        self.update_time(t);
+        let v_id = *(self
+            .logical_to_physical
+            .entry(v)
+            .or_insert_with(|| self.storage.push_node(VertexStore::empty(v))));
+        let mut node = self.storage.get_node_mut(v_id);
+        node.delete(t);
+        Ok(())
+    }
+
     fn get_or_allocate_layer(&self, layer: Option<&str>) -> usize {
         layer
             .map(|layer| self.edge_meta.get_or_create_layer_id(layer.to_owned()))
@@ -595,8 +670,14 @@ impl<const N: usize> TemporalGraph<N> {
             }
         };
 
+        if !src.has_layer(&LayerIds::One(layer)) {
+            self.layer_vertices.insert(layer, src_id);
+        }
         src.add_edge(dst_id, Direction::OUT, layer, edge_id);
         let dst = node_pair.get_mut_j();
+        if !dst.has_layer(&LayerIds::One(layer)) {
+            self.layer_vertices.insert(layer, dst_id);
+        }
         dst.add_edge(src_id, Direction::IN, layer, edge_id);
         Ok(edge_id)
     }
@@ -628,11 +709,95 @@ impl<const N: usize> TemporalGraph<N> {
         })
     }
 
+    /// Add an edge at `start` that is deleted at `end`, either both are recorded or neither is
+    ///
+    /// The names of the endpoints and the types of the properties are checked before the first
+    /// write.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn add_edge_interval_internal(
+        &self,
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        src: u64,
+        src_name: Option<&str>,
+        dst: u64,
+        dst_name: Option<&str>,
+        props: Vec<(String, Prop)>,
+        layer: Option<&str>,
+    ) -> Result<EID, GraphError> {
+        let props: Vec<_> = self.edge_meta.resolve_prop_ids(props, false).collect();
+        Props::check_props(None, &props)?;
+        self.check_vertex_name(src, src_name)?;
+        self.check_vertex_name(dst, dst_name)?;
+        let layer_id = match layer {
+            Some(layer) => self.edge_meta.get_layer_id(layer),
+            None => Some(0),
+        };
+        let src_id = self.logical_to_physical.get(&src).map(|entry| *entry);
+        let dst_id = self.logical_to_physical.get(&dst).map(|entry| *entry);
+        if let (Some(src_id), Some(dst_id), Some(layer_id)) = (src_id, dst_id, layer_id) {
+            if let Some(e_id) = self.find_edge(src_id, dst_id, &LayerIds::All) {
+                if let Some(edge_layer) = self.storage.get_edge(e_id).layer(layer_id) {
+                    edge_layer.check_props(&props)?;
+                }
+            }
+        }
+
+        // the checks above cover everything that can fail below
+        let src_id = self.add_vertex_internal(start, src, src_name, None, vec![], None)?;
+        let dst_id = self.add_vertex_internal(start, dst, dst_name, None, vec![], None)?;
+        self.update_time(end);
+        let layer = self.get_or_allocate_layer(layer);
+        self.link_nodes(src_id, dst_id, start, layer, move |edge| {
+            edge.additions_mut(layer).insert(start);
+            edge.deletions_mut(layer).insert(end);
+            let mut edge_layer = edge.layer_mut(layer);
+            for (prop_id, prop_value) in props {
+                edge_layer.add_prop(start, prop_id, prop_value)?;
+            }
+            Ok(())
+        })
+    }
+
     #[inline]
     pub(crate) fn vertex_ids(&self) -> impl Iterator<Item = VID> {
         (0..self.storage.nodes.len()).map(|i| i.into())
     }
 
+    fn fresh_layer_vertices(&self) -> &LayerVertices {
+        if self.layer_vertices.is_stale() {
+            let mut scanned: Vec<RoaringTreemap> = vec![];
+            for v in self.vertex_ids() {
+                for layer in self.node_entry(v).layer_ids_iter() {
+                    if layer >= scanned.len() {
+                        scanned.resize_with(layer + 1, RoaringTreemap::new);
+                    }
+                    scanned[layer].insert(usize::from(v) as u64);
+                }
+            }
+            self.layer_vertices.rebuild(scanned);
+        }
+        &self.layer_vertices
+    }
+
+    /// The vertices that were added to or have edges in any of the `layers`
+    pub(crate) fn layer_vertex_ids(&self, layers: &LayerIds) -> impl Iterator<Item = VID> {
+        self.fresh_layer_vertices()
+            .vertices(layers)
+            .into_iter()
+            .map(|v| VID::from(v as usize))
+    }
+
+    /// The number of vertices that were added to or have edges in any of the `layers`
+    pub(crate) fn num_layer_vertices(&self, layers: &LayerIds) -> usize {
+        self.fresh_layer_vertices().len(layers)
+    }
+
+    #[inline]
+    pub(crate) fn vertex_has_layer(&self, v: VID, layers: &LayerIds) -> bool {
+        matches!(layers, LayerIds::All) || self.node_entry(v).has_layer(layers)
+    }
+
     pub(crate) fn locked_edges(&self) -> impl Iterator<Item = ArcEntry<EdgeStore>> {
         self.storage.locked_edges()
     }
@@ -0,0 +1,633 @@
+//! Append-only write-ahead log of graph mutations.
+//!
+//! When a log is attached to a graph every mutation going through the internal mutation traits
+//! (`InternalAdditionOps`, `InternalDeletionOps` and `InternalPropertyAdditionOps`) is appended to
+//! the log file before it is applied to the graph. A mutation that is rejected by the graph is
+//! removed from the log again, so the log only ever contains mutations that succeeded. Combined with periodic snapshots this
+//! allows a long running ingestion process to be restarted without losing data: load the last
+//! snapshot and replay the log on top of it.
+//!
+//! Each record is framed as `[payload length: u64][xxhash64 of payload: u64][bincode payload]`
+//! so that a record torn by a crash is detected and discarded on recovery.
+
+use crate::{
+    core::{
+        entities::graph::tgraph::InnerTemporalGraph, storage::timeindex::TimeIndexEntry,
+        utils::errors::GraphError, Prop,
+    },
+    db::api::mutation::internal::{
+        InternalAdditionOps, InternalDeletionOps, InternalPropertyAdditionOps,
+    },
+};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    hash::Hasher,
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use twox_hash::XxHash64;
+
+const HEADER_LEN: usize = 16;
+
+/// A single logged mutation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WalEntry {
+    AddVertex {
+        t: TimeIndexEntry,
+        v: u64,
+        name: Option<String>,
+        props: Vec<(String, Prop)>,
+    },
+    AddEdge {
+        t: TimeIndexEntry,
+        src: u64,
+        dst: u64,
+        props: Vec<(String, Prop)>,
+        layer: Option<String>,
+    },
+    DeleteEdge {
+        t: TimeIndexEntry,
+        src: u64,
+        dst: u64,
+        layer: Option<String>,
+    },
+    AddVertexProperties {
+        v: u64,
+        props: Vec<(String, Prop)>,
+    },
+    AddEdgeProperties {
+        src: u64,
+        dst: u64,
+        props: Vec<(String, Prop)>,
+        layer: Option<String>,
+    },
+    AddProperties {
+        t: TimeIndexEntry,
+        props: Vec<(String, Prop)>,
+    },
+    AddStaticProperties {
+        props: Vec<(String, Prop)>,
+    },
+    // appended last to keep the encoding of existing entries stable
+    DeleteVertex {
+        t: TimeIndexEntry,
+        v: u64,
+    },
+    AddLayerVertex {
+        t: TimeIndexEntry,
+        v: u64,
+        name: Option<String>,
+        props: Vec<(String, Prop)>,
+        layer: String,
+    },
+    AddVertexInterval {
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        v: u64,
+        name: Option<String>,
+        props: Vec<(String, Prop)>,
+    },
+    AddEdgeInterval {
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        src: u64,
+        src_name: Option<String>,
+        dst: u64,
+        dst_name: Option<String>,
+        props: Vec<(String, Prop)>,
+        layer: Option<String>,
+    },
+}
+
+impl WalEntry {
+    /// The time index of the mutation if it is a temporal one
+    pub fn time(&self) -> Option<TimeIndexEntry> {
+        match self {
+            WalEntry::AddVertex { t, .. }
+            | WalEntry::AddEdge { t, .. }
+            | WalEntry::DeleteEdge { t, .. }
+            | WalEntry::DeleteVertex { t, .. }
+            | WalEntry::AddLayerVertex { t, .. }
+            | WalEntry::AddProperties { t, .. } => Some(*t),
+            // the deletion at `end` is the later of the two events
+            WalEntry::AddVertexInterval { end, .. } | WalEntry::AddEdgeInterval { end, .. } => {
+                Some(*end)
+            }
+            _ => None,
+        }
+    }
+
+    /// Re-apply the mutation to `graph`
+    pub fn replay<G>(self, graph: &G) -> Result<(), GraphError>
+    where
+        G: InternalAdditionOps + InternalDeletionOps + InternalPropertyAdditionOps,
+    {
+        match self {
+            WalEntry::AddVertex { t, v, name, props } => {
+                graph.internal_add_vertex(t, v, name.as_deref(), None, props, None)?;
+            }
+            WalEntry::AddLayerVertex {
+                t,
+                v,
+                name,
+                props,
+                layer,
+            } => {
+                graph.internal_add_vertex(t, v, name.as_deref(), None, props, Some(&layer))?;
+            }
+            WalEntry::AddEdge {
+                t,
+                src,
+                dst,
+                props,
+                layer,
+            } => {
+                graph.internal_add_edge(t, src, dst, props, layer.as_deref())?;
+            }
+            WalEntry::DeleteEdge { t, src, dst, layer } => {
+                graph.internal_delete_edge(t, src, dst, layer.as_deref())?;
+            }
+            WalEntry::DeleteVertex { t, v } => {
+                graph.internal_delete_vertex(t, v)?;
+            }
+            WalEntry::AddVertexInterval {
+                start,
+                end,
+                v,
+                name,
+                props,
+            } => {
+                graph.internal_add_vertex_interval(start, end, v, name.as_deref(), props)?;
+            }
+            WalEntry::AddEdgeInterval {
+                start,
+                end,
+                src,
+                src_name,
+                dst,
+                dst_name,
+                props,
+                layer,
+            } => {
+                graph.internal_add_edge_interval(
+                    start,
+                    end,
+                    src,
+                    src_name.as_deref(),
+                    dst,
+                    dst_name.as_deref(),
+                    props,
+                    layer.as_deref(),
+                )?;
+            }
+            WalEntry::AddVertexProperties { v, props } => {
+                graph.internal_add_vertex_properties(v, props)?;
+            }
+            WalEntry::AddEdgeProperties {
+                src,
+                dst,
+                props,
+                layer,
+            } => {
+                graph.internal_add_edge_properties(src, dst, props, layer.as_deref())?;
+            }
+            WalEntry::AddProperties { t, props } => {
+                graph.internal_add_properties(t, props)?;
+            }
+            WalEntry::AddStaticProperties { props } => {
+                graph.internal_add_static_properties(props)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Append-only log file of [`WalEntry`] records
+#[derive(Debug)]
+pub struct WriteAheadLog {
+    path: PathBuf,
+    file: Mutex<File>,
+    len: AtomicUsize,
+}
+
+fn checksum(payload: &[u8]) -> u64 {
+    let mut hasher = XxHash64::default();
+    hasher.write(payload);
+    hasher.finish()
+}
+
+/// Decode all complete records in `bytes`, returning them together with the length of the valid prefix
+fn decode_entries(bytes: &[u8]) -> (Vec<WalEntry>, usize) {
+    let mut entries = vec![];
+    let mut offset = 0;
+    while bytes.len() - offset >= HEADER_LEN {
+        let len = u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap()) as usize;
+        let hash = u64::from_le_bytes(bytes[offset + 8..offset + HEADER_LEN].try_into().unwrap());
+        let start = offset + HEADER_LEN;
+        if bytes.len() - start < len {
+            break;
+        }
+        let payload = &bytes[start..start + len];
+        if checksum(payload) != hash {
+            break;
+        }
+        match bincode::deserialize(payload) {
+            Ok(entry) => entries.push(entry),
+            Err(_) => break,
+        }
+        offset = start + len;
+    }
+    (entries, offset)
+}
+
+/// Append the framed record of `entry` to `record`
+fn encode_record(entry: &WalEntry, record: &mut Vec<u8>) -> Result<(), GraphError> {
+    let payload = bincode::serialize(entry)?;
+    record.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    record.extend_from_slice(&checksum(&payload).to_le_bytes());
+    record.extend_from_slice(&payload);
+    Ok(())
+}
+
+impl WriteAheadLog {
+    /// Open the log at `path` for appending, creating it if it does not exist.
+    ///
+    /// A partially written record at the end of an existing log is truncated away.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, GraphError> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)?;
+        let mut bytes = vec![];
+        file.read_to_end(&mut bytes)?;
+        let (entries, valid_len) = decode_entries(&bytes);
+        if valid_len < bytes.len() {
+            file.set_len(valid_len as u64)?;
+        }
+        file.seek(SeekFrom::Start(valid_len as u64))?;
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+            len: AtomicUsize::new(entries.len()),
+        })
+    }
+
+    /// Read all complete entries from the log at `path`, an empty list is returned if the file does not exist
+    pub fn read_entries<P: AsRef<Path>>(path: P) -> Result<Vec<WalEntry>, GraphError> {
+        match std::fs::read(path) {
+            Ok(bytes) => Ok(decode_entries(&bytes).0),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Append a single entry to the log
+    pub fn append(&self, entry: &WalEntry) -> Result<(), GraphError> {
+        let mut record = vec![];
+        encode_record(entry, &mut record)?;
+        self.file.lock().write_all(&record)?;
+        self.len.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Append `entries` to the log and then run `apply`, which applies them to the graph
+    ///
+    /// The log stays locked until `apply` returns so the mutations are applied in the order they
+    /// are logged. If `apply` fails the entries are removed from the log again.
+    pub fn log_then_apply<T, F: FnOnce() -> Result<T, GraphError>>(
+        &self,
+        entries: &[WalEntry],
+        apply: F,
+    ) -> Result<T, GraphError> {
+        let mut record = vec![];
+        for entry in entries {
+            encode_record(entry, &mut record)?;
+        }
+        let mut file = self.file.lock();
+        let offset = file.stream_position()?;
+        let result = file
+            .write_all(&record)
+            .map_err(GraphError::from)
+            .and_then(|_| apply());
+        match result {
+            Ok(value) => {
+                self.len.fetch_add(entries.len(), Ordering::Relaxed);
+                Ok(value)
+            }
+            Err(err) => {
+                file.set_len(offset)?;
+                file.seek(SeekFrom::Start(offset))?;
+                Err(err)
+            }
+        }
+    }
+
+    /// Make sure all appended entries have reached the disk
+    pub fn sync(&self) -> Result<(), GraphError> {
+        Ok(self.file.lock().sync_data()?)
+    }
+
+    /// Drop all entries from the log (used after a snapshot has been written)
+    pub fn truncate(&self) -> Result<(), GraphError> {
+        let mut file = self.file.lock();
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.sync_all()?;
+        self.len.store(0, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Number of entries currently in the log
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl<const N: usize> InnerTemporalGraph<N> {
+    /// Start recording all mutations in the log at `path`, any previously attached log is synced and detached
+    pub(crate) fn enable_wal<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        let wal = WriteAheadLog::open(path)?;
+        if let Some(old) = self.inner().wal.write().replace(wal) {
+            old.sync()?;
+        }
+        Ok(())
+    }
+
+    /// Stop recording mutations
+    pub(crate) fn disable_wal(&self) -> Result<(), GraphError> {
+        match self.inner().wal.write().take() {
+            Some(wal) => wal.sync(),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn sync_wal(&self) -> Result<(), GraphError> {
+        match self.inner().wal.read().as_ref() {
+            Some(wal) => wal.sync(),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn wal_len(&self) -> usize {
+        self.inner().wal.read().as_ref().map_or(0, |wal| wal.len())
+    }
+
+    /// Replay the entries of the log at `path` into this graph, returning the number of replayed entries
+    pub(crate) fn replay_wal<P: AsRef<Path>>(&self, path: P) -> Result<usize, GraphError> {
+        let entries = WriteAheadLog::read_entries(path)?;
+        let num_entries = entries.len();
+        for entry in entries {
+            if let Some(t) = entry.time() {
+                self.inner()
+                    .event_counter
+                    .fetch_max(t.i() + 1, Ordering::Relaxed);
+            }
+            entry.replay(self)?;
+        }
+        Ok(num_entries)
+    }
+
+    /// Write a snapshot using `snapshot` and truncate the attached log.
+    ///
+    /// Mutations are blocked while the snapshot is written so the snapshot and the log never overlap.
+    pub(crate) fn checkpoint<F: FnOnce() -> Result<(), GraphError>>(
+        &self,
+        snapshot: F,
+    ) -> Result<(), GraphError> {
+        let wal = self.inner().wal.write();
+        snapshot()?;
+        match wal.as_ref() {
+            Some(wal) => wal.truncate(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod wal_test {
+    use super::*;
+    use crate::{
+        db::{
+            api::mutation::internal::InternalAdditionOps,
+            graph::views::deletion_graph::GraphWithDeletions,
+        },
+        prelude::*,
+    };
+    use tempdir::TempDir;
+
+    #[test]
+    fn recover_from_log_only() {
+        let dir = TempDir::new("raphtory_wal").unwrap();
+        let wal = dir.path().join("graph.wal");
+        let snapshot = dir.path().join("graph.bincode");
+
+        let g = Graph::new();
+        g.enable_wal(&wal).unwrap();
+        g.add_vertex(1, "Alice", [("age", 30)], None).unwrap();
+        g.add_edge(2, "Alice", "Bob", [("weight", 1.5)], Some("friends"))
+            .unwrap();
+        g.add_vertex_properties("Alice", [("company", "Pometry")])
+            .unwrap();
+        g.add_edge_properties("Alice", "Bob", [("since", 2020)], Some("friends"))
+            .unwrap();
+        g.add_properties(3, [("version", 1)]).unwrap();
+        g.add_static_properties([("name", "test")]).unwrap();
+        drop(g);
+
+        let g2 = Graph::recover(&snapshot, &wal).unwrap();
+        assert_eq!(g2.num_vertices(), 2);
+        assert_eq!(g2.num_edges(), 1);
+        assert_eq!(
+            g2.vertex("Alice").unwrap().properties().get("age"),
+            Some(Prop::I32(30))
+        );
+        assert_eq!(
+            g2.vertex("Alice").unwrap().properties().get("company"),
+            Some(Prop::Str("Pometry".to_string()))
+        );
+        let e = g2.edge("Alice", "Bob").unwrap();
+        assert_eq!(e.properties().get("weight"), Some(Prop::F64(1.5)));
+        assert_eq!(
+            e.layer("friends").unwrap().properties().get("since"),
+            Some(Prop::I32(2020))
+        );
+        assert_eq!(g2.properties().get("version"), Some(Prop::I32(1)));
+        assert_eq!(
+            g2.properties().get("name"),
+            Some(Prop::Str("test".to_string()))
+        );
+        assert!(g2.next_event_id() >= 3);
+    }
+
+    #[test]
+    fn rejected_type_change_is_not_logged() {
+        let dir = TempDir::new("raphtory_wal").unwrap();
+        let wal = dir.path().join("graph.wal");
+        let snapshot = dir.path().join("graph.bincode");
+
+        let g = Graph::new();
+        g.enable_wal(&wal).unwrap();
+        g.add_vertex(1, "Alice", NO_PROPS, Some("person")).unwrap();
+        let logged = g.wal_len();
+        assert!(g
+            .add_vertex(2, "Alice", [("age", 30)], Some("company"))
+            .is_err());
+        assert_eq!(g.wal_len(), logged);
+        drop(g);
+
+        let g2 = Graph::recover(&snapshot, &wal).unwrap();
+        let alice = g2.vertex("Alice").unwrap();
+        assert_eq!(alice.history(), vec![1]);
+        assert_eq!(alice.vertex_type(), Some("person".to_string()));
+        assert!(alice.properties().get("age").is_none());
+    }
+
+    #[test]
+    fn checkpoint_truncates_log() {
+        let dir = TempDir::new("raphtory_wal").unwrap();
+        let wal = dir.path().join("graph.wal");
+        let snapshot = dir.path().join("graph.bincode");
+
+        let g = Graph::new();
+        g.enable_wal(&wal).unwrap();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        assert_eq!(g.wal_len(), 3);
+        g.checkpoint(&snapshot).unwrap();
+        assert_eq!(g.wal_len(), 0);
+        assert!(WriteAheadLog::read_entries(&wal).unwrap().is_empty());
+
+        g.add_edge(2, 2, 3, NO_PROPS, None).unwrap();
+        drop(g);
+
+        let g2 = Graph::recover(&snapshot, &wal).unwrap();
+        assert_eq!(g2.num_vertices(), 3);
+        assert_eq!(g2.num_edges(), 2);
+
+        // the recovered graph keeps logging to the same file
+        g2.add_edge(3, 3, 4, NO_PROPS, None).unwrap();
+        drop(g2);
+        let g3 = Graph::recover(&snapshot, &wal).unwrap();
+        assert_eq!(g3.num_edges(), 3);
+    }
+
+    #[test]
+    fn torn_record_is_ignored() {
+        let dir = TempDir::new("raphtory_wal").unwrap();
+        let wal = dir.path().join("graph.wal");
+        let log = WriteAheadLog::open(&wal).unwrap();
+        let entry = WalEntry::AddVertex {
+            t: TimeIndexEntry::new(1, 0),
+            v: 1,
+            name: None,
+            props: vec![],
+        };
+        log.append(&entry).unwrap();
+        log.append(&entry).unwrap();
+        drop(log);
+
+        let bytes = std::fs::read(&wal).unwrap();
+        std::fs::write(&wal, &bytes[..bytes.len() - 3]).unwrap();
+        assert_eq!(WriteAheadLog::read_entries(&wal).unwrap(), vec![entry]);
+
+        let log = WriteAheadLog::open(&wal).unwrap();
+        assert_eq!(log.len(), 1);
+        assert_eq!(
+            std::fs::metadata(&wal).unwrap().len() as usize,
+            bytes.len() / 2
+        );
+    }
+
+    #[test]
+    fn entries_are_logged_before_they_are_applied() {
+        let dir = TempDir::new("raphtory_wal").unwrap();
+        let wal = dir.path().join("graph.wal");
+        let log = WriteAheadLog::open(&wal).unwrap();
+        let entry = WalEntry::DeleteVertex {
+            t: TimeIndexEntry::new(1, 0),
+            v: 1,
+        };
+
+        let logged = log
+            .log_then_apply(std::slice::from_ref(&entry), || {
+                Ok(WriteAheadLog::read_entries(&wal).unwrap())
+            })
+            .unwrap();
+        assert_eq!(logged, vec![entry.clone()]);
+        assert_eq!(log.len(), 1);
+
+        // a failed mutation is removed from the log again
+        let result: Result<(), GraphError> =
+            log.log_then_apply(std::slice::from_ref(&entry), || {
+                assert_eq!(WriteAheadLog::read_entries(&wal).unwrap().len(), 2);
+                Err(GraphError::IncorrectPropertyType)
+            });
+        assert!(result.is_err());
+        assert_eq!(log.len(), 1);
+        log.append(&entry).unwrap();
+        assert_eq!(
+            WriteAheadLog::read_entries(&wal).unwrap(),
+            vec![entry.clone(), entry]
+        );
+    }
+
+    #[test]
+    fn recover_deletions() {
+        let dir = TempDir::new("raphtory_wal").unwrap();
+        let wal = dir.path().join("graph.wal");
+        let snapshot = dir.path().join("graph.bincode");
+
+        let g = GraphWithDeletions::new();
+        g.enable_wal(&wal).unwrap();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        g.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+        g.checkpoint(&snapshot).unwrap();
+        g.delete_edge(5, 1, 2, None).unwrap();
+        g.delete_vertex(6, 3).unwrap();
+        drop(g);
+
+        let g2 = GraphWithDeletions::recover(&snapshot, &wal).unwrap();
+        assert!(g2.window(0, 5).has_edge(1, 2, Layer::All));
+        assert!(!g2.window(6, 10).has_edge(1, 2, Layer::All));
+        assert!(g2.window(0, 6).has_edge(2, 3, Layer::All));
+        assert!(!g2.window(7, 10).has_vertex(3));
+        assert!(Graph::recover(&snapshot, &wal).is_err());
+    }
+
+    #[test]
+    fn interval_is_logged_once() {
+        let dir = TempDir::new("raphtory_wal").unwrap();
+        let wal = dir.path().join("graph.wal");
+        let snapshot = dir.path().join("graph.bincode");
+
+        let g = GraphWithDeletions::new();
+        g.enable_wal(&wal).unwrap();
+        g.add_edge_interval(1, 5, "Alice", "Bob", [("weight", 1.5)], Some("friends"))
+            .unwrap();
+        assert_eq!(g.wal_len(), 1);
+        g.add_vertex_interval(2, 8, "Carol", [("age", 30)]).unwrap();
+        assert_eq!(g.wal_len(), 2);
+        drop(g);
+
+        let g2 = GraphWithDeletions::recover(&snapshot, &wal).unwrap();
+        assert!(g2.window(2, 3).has_edge("Alice", "Bob", Layer::All));
+        assert!(!g2.window(6, 7).has_edge("Alice", "Bob", Layer::All));
+        assert_eq!(g2.vertex("Alice").unwrap().name(), "Alice");
+        assert!(g2.window(6, 7).has_vertex("Carol"));
+        assert!(!g2.window(9, 10).has_vertex("Carol"));
+        assert_eq!(g2.latest_time(), Some(8));
+    }
+}
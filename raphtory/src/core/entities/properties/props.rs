@@ -1,18 +1,22 @@
-use crate::core::{
-    entities::{graph::tgraph::FxDashMap, properties::tprop::TProp},
-    storage::{
-        lazy_vec::{IllegalSet, LazyVec},
-        locked_view::LockedView,
-        timeindex::TimeIndexEntry,
+use crate::{
+    core::{
+        entities::{graph::tgraph::FxDashMap, properties::tprop::TProp, LayerIds},
+        storage::{
+            lazy_vec::{IllegalSet, LazyVec},
+            locked_view::LockedView,
+            timeindex::TimeIndexEntry,
+        },
+        utils::errors::{GraphError, IllegalMutate, MutateGraphError},
+        Prop,
     },
-    utils::errors::{GraphError, IllegalMutate, MutateGraphError},
-    Prop,
+    db::api::view::Layer,
 };
 use parking_lot::{RwLock, RwLockReadGuard};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::Debug,
     hash::Hash,
+    mem,
     ops::Deref,
     sync::atomic::{AtomicUsize, Ordering},
 };
@@ -47,6 +51,26 @@ impl Props {
         self.temporal_props.update(prop_id, |p| p.set(t, prop))
     }
 
+    /// Check that the temporal properties can all be added without changing the type of a property
+    pub fn check_props(
+        props: Option<&Self>,
+        new_props: &[(usize, Prop)],
+    ) -> Result<(), GraphError> {
+        for (i, (prop_id, prop)) in new_props.iter().enumerate() {
+            let matches_existing = props
+                .and_then(|props| props.temporal_props.get(*prop_id))
+                .map_or(true, |tprop| tprop.accepts(prop));
+            let matches_batch = new_props[..i]
+                .iter()
+                .filter(|(id, _)| id == prop_id)
+                .all(|(_, other)| mem::discriminant(other) == mem::discriminant(prop));
+            if !(matches_existing && matches_batch) {
+                return Err(GraphError::IncorrectPropertyType);
+            }
+        }
+        Ok(())
+    }
+
     pub fn add_static_prop(
         &mut self,
         prop_id: usize,
@@ -172,6 +196,52 @@ impl Meta {
         self.meta_layer.reverse_lookup(id).map(|v| v.to_string())
     }
 
+    pub(crate) fn layer_names(&self, layer_ids: LayerIds) -> Vec<String> {
+        match layer_ids {
+            LayerIds::None => {
+                vec![]
+            }
+            LayerIds::All => self.meta_layer.get_keys().clone(),
+            LayerIds::One(id) => {
+                vec![self.meta_layer.reverse_lookup(id).unwrap().clone()]
+            }
+            LayerIds::Multiple(ids) => ids
+                .iter()
+                .map(|id| self.meta_layer.reverse_lookup(*id).unwrap().clone())
+                .collect(),
+        }
+    }
+
+    pub(crate) fn layer_ids(&self, key: Layer) -> LayerIds {
+        match key {
+            Layer::All => LayerIds::All,
+            Layer::Default => LayerIds::One(0),
+            Layer::One(id) => match self.get_layer_id(&id) {
+                Some(id) => LayerIds::One(id),
+                None => LayerIds::None,
+            },
+            Layer::Multiple(ids) => {
+                let mut new_layers = ids
+                    .iter()
+                    .filter_map(|id| self.get_layer_id(id))
+                    .collect::<Vec<_>>();
+                let num_layers = self.meta_layer.len();
+                let num_new_layers = new_layers.len();
+                if num_new_layers == 0 {
+                    LayerIds::None
+                } else if num_new_layers == 1 {
+                    LayerIds::One(new_layers[0])
+                } else if num_new_layers == num_layers {
+                    LayerIds::All
+                } else {
+                    new_layers.sort_unstable();
+                    new_layers.dedup();
+                    LayerIds::Multiple(new_layers.into())
+                }
+            }
+        }
+    }
+
     pub fn get_all_layers(&self) -> Vec<usize> {
         self.meta_layer
             .map
@@ -85,6 +85,16 @@ impl<A: Clone + Default + Debug + PartialEq> TCell<A> {
         }
     }
 
+    /// Iterate over the values together with their full time index entries
+    pub fn iter_entries(&self) -> Box<dyn Iterator<Item = (&TimeIndexEntry, &A)> + '_> {
+        match self {
+            TCell::Empty => Box::new(std::iter::empty()),
+            TCell::TCell1(t, value) => Box::new(std::iter::once((t, value))),
+            TCell::TCellCap(svm) => Box::new(svm.iter()),
+            TCell::TCellN(btm) => Box::new(btm.iter()),
+        }
+    }
+
     #[allow(dead_code)]
     pub fn iter_window(&self, r: Range<i64>) -> Box<dyn Iterator<Item = &A> + '_> {
         match self {
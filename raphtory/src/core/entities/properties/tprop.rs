@@ -53,6 +53,26 @@ impl TProp {
         }
     }
 
+    /// Check if `prop` has the type of the values already set
+    pub(crate) fn accepts(&self, prop: &Prop) -> bool {
+        matches!(
+            (self, prop),
+            (TProp::Empty, _)
+                | (TProp::Str(_), Prop::Str(_))
+                | (TProp::I32(_), Prop::I32(_))
+                | (TProp::I64(_), Prop::I64(_))
+                | (TProp::U32(_), Prop::U32(_))
+                | (TProp::U64(_), Prop::U64(_))
+                | (TProp::F32(_), Prop::F32(_))
+                | (TProp::F64(_), Prop::F64(_))
+                | (TProp::Bool(_), Prop::Bool(_))
+                | (TProp::DTime(_), Prop::DTime(_))
+                | (TProp::Graph(_), Prop::Graph(_))
+                | (TProp::List(_), Prop::List(_))
+                | (TProp::Map(_), Prop::Map(_))
+        )
+    }
+
     pub(crate) fn set(&mut self, t: TimeIndexEntry, prop: Prop) -> Result<(), GraphError> {
         if matches!(self, TProp::Empty) {
             *self = TProp::from(t, prop);
@@ -174,6 +194,61 @@ impl TProp {
         }
     }
 
+    /// Iterate over the values together with their full time index entries
+    pub(crate) fn iter_entries(&self) -> Box<dyn Iterator<Item = (TimeIndexEntry, Prop)> + '_> {
+        match self {
+            TProp::Empty => Box::new(iter::empty()),
+            TProp::Str(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::Str(value.clone()))),
+            ),
+            TProp::I32(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::I32(*value))),
+            ),
+            TProp::I64(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::I64(*value))),
+            ),
+            TProp::U32(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::U32(*value))),
+            ),
+            TProp::U64(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::U64(*value))),
+            ),
+            TProp::F32(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::F32(*value))),
+            ),
+            TProp::F64(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::F64(*value))),
+            ),
+            TProp::Bool(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::Bool(*value))),
+            ),
+            TProp::DTime(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::DTime(*value))),
+            ),
+            TProp::Graph(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::Graph(value.clone()))),
+            ),
+            TProp::List(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::List(value.clone()))),
+            ),
+            TProp::Map(cell) => Box::new(
+                cell.iter_entries()
+                    .map(|(t, value)| (*t, Prop::Map(value.clone()))),
+            ),
+        }
+    }
+
     pub(crate) fn iter_window(&self, r: Range<i64>) -> Box<dyn Iterator<Item = (i64, Prop)> + '_> {
         match self {
             TProp::Empty => Box::new(std::iter::empty()),
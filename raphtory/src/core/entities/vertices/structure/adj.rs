@@ -22,6 +22,10 @@ pub enum Adj {
 }
 
 impl Adj {
+    pub(crate) fn is_empty(&self) -> bool {
+        matches!(self, Adj::Solo)
+    }
+
     pub(crate) fn get_edge(&self, v: VID, dir: Direction) -> Option<EID> {
         match self {
             Adj::Solo => None,
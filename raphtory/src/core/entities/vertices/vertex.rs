@@ -65,9 +65,10 @@ impl<'a, const N: usize> Vertex<'a, N> {
         &'a self,
         name: &str,
         window: Option<Range<i64>>,
+        layers: &LayerIds,
     ) -> impl Iterator<Item = (i64, Prop)> + 'a {
         let prop_id = self.graph.vertex_meta.resolve_prop_id(name, false);
-        self.node.temporal_properties(prop_id, window)
+        self.node.temporal_properties(prop_id, window, layers)
     }
 
     pub fn neighbours<'b>(
@@ -95,6 +96,16 @@ impl<'a, const N: usize> Vertex<'a, N> {
         }
     }
 
+    pub(crate) fn deletions(self) -> Option<LockedView<'a, TimeIndex<i64>>> {
+        match self.node {
+            VRef::Entry(entry) => {
+                let t_index = entry.map(|entry| entry.deletions());
+                Some(t_index)
+            }
+            _ => None,
+        }
+    }
+
     pub(crate) fn temporal_property(self, prop_id: usize) -> Option<LockedView<'a, TProp>> {
         match self.node {
             VRef::Entry(entry) => {
@@ -106,6 +117,23 @@ impl<'a, const N: usize> Vertex<'a, N> {
             _ => None,
         }
     }
+
+    pub(crate) fn layer_temporal_property(
+        self,
+        layer: usize,
+        prop_id: usize,
+    ) -> Option<LockedView<'a, TProp>> {
+        match self.node {
+            VRef::Entry(entry) => {
+                entry.layer_temporal_property(layer, prop_id)?;
+
+                let t_index =
+                    entry.map(|entry| entry.layer_temporal_property(layer, prop_id).unwrap());
+                Some(t_index)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl<'a, const N: usize> IntoIterator for Vertex<'a, N> {
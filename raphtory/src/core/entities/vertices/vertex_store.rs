@@ -15,23 +15,84 @@ use crate::core::{
     Direction, Prop,
 };
 use itertools::Itertools;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
+    cell::Cell,
     iter,
     ops::{Deref, Range},
     sync::Arc,
 };
 
 #[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+#[serde(remote = "Self")]
 pub struct VertexStore {
     global_id: u64,
     pub(crate) vid: VID,
     // all the timestamps that have been seen by this vertex
     timestamps: TimeIndex<i64>,
+    // all the timestamps at which this vertex was deleted
+    deletions: TimeIndex<i64>,
     // each layer represents a separate view of the graph
     pub(crate) layers: Vec<Adj>,
-    // props for vertex
+    // props for vertex, the temporal props are the ones set without a layer
     pub(crate) props: Option<Props>,
+    // the timestamps at which the vertex was added to each layer
+    layer_timestamps: Vec<TimeIndex<i64>>,
+    // temporal props set in each layer
+    layer_props: Vec<Option<Props>>,
+}
+
+thread_local! {
+    static LEGACY_LAYOUT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Run `f` with [`VertexStore`] deserialising the layout used before vertex deletions were tracked
+///
+/// Used to migrate graph files written by earlier releases.
+pub(crate) fn with_legacy_layout<T>(f: impl FnOnce() -> T) -> T {
+    struct Reset(bool);
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            LEGACY_LAYOUT.with(|legacy| legacy.set(self.0));
+        }
+    }
+    let _reset = Reset(LEGACY_LAYOUT.with(|legacy| legacy.replace(true)));
+    f()
+}
+
+#[derive(Deserialize)]
+struct LegacyVertexStore {
+    global_id: u64,
+    vid: VID,
+    timestamps: TimeIndex<i64>,
+    layers: Vec<Adj>,
+    props: Option<Props>,
+}
+
+impl Serialize for VertexStore {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VertexStore::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VertexStore {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if LEGACY_LAYOUT.with(|legacy| legacy.get()) {
+            let legacy = LegacyVertexStore::deserialize(deserializer)?;
+            Ok(Self {
+                global_id: legacy.global_id,
+                vid: legacy.vid,
+                timestamps: legacy.timestamps,
+                deletions: Default::default(),
+                layers: legacy.layers,
+                props: legacy.props,
+                layer_timestamps: Default::default(),
+                layer_props: Default::default(),
+            })
+        } else {
+            VertexStore::deserialize(deserializer)
+        }
+    }
 }
 
 impl VertexStore {
@@ -42,8 +103,46 @@ impl VertexStore {
             global_id,
             vid: 0.into(),
             timestamps: TimeIndex::one(*t.t()),
+            deletions: TimeIndex::Empty,
             layers,
             props: None,
+            layer_timestamps: Vec::new(),
+            layer_props: Vec::new(),
+        }
+    }
+
+    /// A vertex without any additions, used when a vertex is deleted before it is added
+    pub fn empty(global_id: u64) -> Self {
+        Self {
+            global_id,
+            vid: 0.into(),
+            timestamps: TimeIndex::Empty,
+            deletions: TimeIndex::Empty,
+            layers: vec![Adj::Solo],
+            props: None,
+            layer_timestamps: Vec::new(),
+            layer_props: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_parts(
+        global_id: u64,
+        vid: VID,
+        timestamps: TimeIndex<i64>,
+        deletions: TimeIndex<i64>,
+        props: Option<Props>,
+        layer_timestamps: Vec<TimeIndex<i64>>,
+        layer_props: Vec<Option<Props>>,
+    ) -> Self {
+        Self {
+            global_id,
+            vid,
+            timestamps,
+            deletions,
+            layers: vec![Adj::Solo],
+            props,
+            layer_timestamps,
+            layer_props,
         }
     }
 
@@ -59,16 +158,99 @@ impl VertexStore {
         self.timestamps.insert(*t.t());
     }
 
+    pub fn deletions(&self) -> &TimeIndex<i64> {
+        &self.deletions
+    }
+
+    pub fn delete(&mut self, t: TimeIndexEntry) {
+        self.deletions.insert(*t.t());
+    }
+
+    /// Record that the vertex was added to `layer` at time `t`
+    pub fn add_to_layer(&mut self, t: TimeIndexEntry, layer: usize) {
+        if layer >= self.layer_timestamps.len() {
+            self.layer_timestamps
+                .resize_with(layer + 1, || TimeIndex::Empty);
+        }
+        self.layer_timestamps[layer].insert(*t.t());
+    }
+
+    pub fn layer_timestamps(&self, layer: usize) -> Option<&TimeIndex<i64>> {
+        self.layer_timestamps.get(layer)
+    }
+
+    pub fn layer_props(&self, layer: usize) -> Option<&Props> {
+        self.layer_props.get(layer)?.as_ref()
+    }
+
+    /// Check that the temporal properties can be added in `layer` without changing their types
+    pub fn check_props(
+        &self,
+        layer: Option<usize>,
+        props: &[(usize, Prop)],
+    ) -> Result<(), GraphError> {
+        let existing = match layer {
+            None => self.props.as_ref(),
+            Some(layer) => self.layer_props(layer),
+        };
+        Props::check_props(existing, props)
+    }
+
+    /// Add a temporal property, properties set without a layer are visible in all layers
     pub fn add_prop(
         &mut self,
         t: TimeIndexEntry,
+        layer: Option<usize>,
         prop_id: usize,
         prop: Prop,
     ) -> Result<(), GraphError> {
-        let props = self.props.get_or_insert_with(Props::new);
+        let props = match layer {
+            None => self.props.get_or_insert_with(Props::new),
+            Some(layer) => {
+                if layer >= self.layer_props.len() {
+                    self.layer_props.resize_with(layer + 1, || None);
+                }
+                self.layer_props[layer].get_or_insert_with(Props::new)
+            }
+        };
         props.add_prop(t, prop_id, prop)
     }
 
+    fn in_layer(&self, layer: usize) -> bool {
+        self.layer_timestamps
+            .get(layer)
+            .filter(|t_index| !t_index.is_empty())
+            .is_some()
+            || self
+                .layers
+                .get(layer)
+                .filter(|adj| !adj.is_empty())
+                .is_some()
+    }
+
+    /// Check if the vertex was added to or has edges in any of the `layers`
+    pub fn has_layer(&self, layers: &LayerIds) -> bool {
+        match layers {
+            LayerIds::All => true,
+            LayerIds::One(layer_id) => self.in_layer(*layer_id),
+            LayerIds::Multiple(layer_ids) => layer_ids.iter().any(|id| self.in_layer(*id)),
+            LayerIds::None => false,
+        }
+    }
+
+    pub fn layer_ids_iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.layers.len().max(self.layer_timestamps.len())).filter(|id| self.in_layer(*id))
+    }
+
+    pub fn layer_ids(&self) -> LayerIds {
+        let layer_ids = self.layer_ids_iter().collect::<Vec<_>>();
+        match layer_ids.len() {
+            0 => LayerIds::None,
+            1 => LayerIds::One(layer_ids[0]),
+            _ => LayerIds::Multiple(layer_ids.into()),
+        }
+    }
+
     pub fn add_static_prop(
         &mut self,
         prop_id: usize,
@@ -118,18 +300,25 @@ impl VertexStore {
         &self,
         prop_id: usize,
         window: Option<Range<i64>>,
+        layers: &LayerIds,
     ) -> impl Iterator<Item = (i64, Prop)> + '_ {
-        if let Some(window) = window {
-            self.props
-                .as_ref()
-                .map(|ps| ps.temporal_props_window(prop_id, window.start, window.end))
-                .unwrap_or_else(|| Box::new(iter::empty()))
-        } else {
-            self.props
-                .as_ref()
-                .map(|ps| ps.temporal_props(prop_id))
-                .unwrap_or_else(|| Box::new(iter::empty()))
-        }
+        let layer_ids: Vec<usize> = match layers {
+            LayerIds::None => vec![],
+            LayerIds::All => (0..self.layer_props.len()).collect(),
+            LayerIds::One(id) => vec![*id],
+            LayerIds::Multiple(ids) => ids.to_vec(),
+        };
+        let layer_props = layer_ids
+            .into_iter()
+            .filter_map(|layer| self.layer_props.get(layer)?.as_ref());
+        self.props
+            .iter()
+            .chain(layer_props)
+            .map(move |ps| match &window {
+                Some(window) => ps.temporal_props_window(prop_id, window.start, window.end),
+                None => ps.temporal_props(prop_id),
+            })
+            .kmerge_by(|(t1, _), (t2, _)| t1 < t2)
     }
 
     pub(crate) fn static_property(&self, prop_id: usize) -> Option<&Prop> {
@@ -316,11 +505,18 @@ impl VertexStore {
         self.props.as_ref().and_then(|ps| ps.temporal_prop(prop_id))
     }
 
+    pub(crate) fn layer_temporal_property(&self, layer: usize, prop_id: usize) -> Option<&TProp> {
+        self.layer_props(layer)?.temporal_prop(prop_id)
+    }
+
     pub(crate) fn temp_prop_ids(&self) -> Vec<usize> {
         self.props
-            .as_ref()
-            .map(|ps| ps.temporal_prop_ids())
-            .unwrap_or_default()
+            .iter()
+            .chain(self.layer_props.iter().flatten())
+            .flat_map(|ps| ps.temporal_prop_ids())
+            .sorted()
+            .dedup()
+            .collect()
     }
 
     pub(crate) fn active(&self, w: Range<i64>) -> bool {
@@ -97,6 +97,19 @@ impl Prop {
         }
     }
 
+    /// The value of a numeric property as `f64`, `None` for all other properties
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Prop::I32(v) => Some(*v as f64),
+            Prop::I64(v) => Some(*v as f64),
+            Prop::U32(v) => Some(*v as f64),
+            Prop::U64(v) => Some(*v as f64),
+            Prop::F32(v) => Some(*v as f64),
+            Prop::F64(v) => Some(*v),
+            _ => None,
+        }
+    }
+
     pub fn divide(self, other: Prop) -> Option<Prop> {
         match (self, other) {
             (Prop::I32(a), Prop::I32(b)) if b != 0 => Some(Prop::I32(a / b)),
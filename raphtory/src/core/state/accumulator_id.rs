@@ -14,6 +14,14 @@ impl<A, IN, OUT, ACC: Accumulator<A, IN, OUT>> AccId<A, IN, OUT, ACC> {
     pub fn id(&self) -> u32 {
         self.id
     }
+
+    /// The same accumulator with a different id
+    pub(crate) fn with_id(self, id: u32) -> Self {
+        Self {
+            id,
+            _a: std::marker::PhantomData,
+        }
+    }
 }
 impl<A: 'static, IN: 'static, OUT: 'static, ACC: Accumulator<A, IN, OUT>> AccId<A, IN, OUT, ACC> {
     pub fn init<I: Init<A> + 'static>(self) -> AccId<A, IN, OUT, InitAcc<A, IN, OUT, ACC, I>> {
@@ -2,7 +2,11 @@ use super::{
     container::{merge_2_vecs, DynArray, VecArray},
     StateType,
 };
-use crate::{core::state::agg::Accumulator, db::api::view::GraphViewOps};
+use crate::{
+    core::{state::agg::Accumulator, utils::errors::GraphError},
+    db::api::view::GraphViewOps,
+};
+use serde::{de::DeserializeOwned, Serialize};
 use std::collections::HashMap;
 
 pub trait ComputeState: std::fmt::Debug + Clone + Send + Sync {
@@ -12,6 +16,12 @@ pub trait ComputeState: std::fmt::Debug + Clone + Send + Sync {
 
     fn new_mutable_primitive<T: StateType>(zero: T) -> Self;
 
+    /// Serializes the state holding values of type `T`
+    fn encode<T: StateType + Serialize>(&self) -> Result<Vec<u8>, GraphError>;
+
+    /// Deserializes a state holding values of type `T` that was serialized with `encode`
+    fn decode<T: StateType + DeserializeOwned>(bytes: &[u8]) -> Result<Self, GraphError>;
+
     fn read<A: StateType, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
         &self,
         ss: usize,
@@ -89,6 +99,20 @@ impl ComputeState for ComputeStateVec {
         ComputeStateVec(Box::new(VecArray::new(zero)))
     }
 
+    fn encode<T: StateType + Serialize>(&self) -> Result<Vec<u8>, GraphError> {
+        let vec = self
+            .current()
+            .as_any()
+            .downcast_ref::<VecArray<T>>()
+            .ok_or_else(|| GraphError::CheckpointError("state type mismatch".to_string()))?;
+        Ok(bincode::serialize(vec)?)
+    }
+
+    fn decode<T: StateType + DeserializeOwned>(bytes: &[u8]) -> Result<Self, GraphError> {
+        let vec: VecArray<T> = bincode::deserialize(bytes)?;
+        Ok(ComputeStateVec(Box::new(vec)))
+    }
+
     fn read<A: StateType, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
         &self,
         ss: usize,
@@ -1,4 +1,5 @@
 use super::StateType;
+use serde::{Deserialize, Serialize};
 use std::any::Any;
 
 pub trait DynArray: std::fmt::Debug + Send + Sync {
@@ -11,7 +12,7 @@ pub trait DynArray: std::fmt::Debug + Send + Sync {
     fn reset(&mut self, ss: usize);
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct VecArray<T> {
     odd: Vec<T>,
     even: Vec<T>,
@@ -7,7 +7,7 @@ pub const GLOBAL_STATE_KEY: usize = 0;
 
 #[derive(Debug, Clone)]
 pub struct MorcelComputeState<CS: ComputeState + Send> {
-    morcel_size: usize,
+    pub(crate) morcel_size: usize,
     pub(crate) states: FxHashMap<u32, CS>,
 }
 
@@ -15,9 +15,12 @@ use std::{borrow::Borrow, collections::HashMap, sync::Arc};
 
 #[derive(Debug, Clone)]
 pub struct ShuffleComputeState<CS: ComputeState + Send> {
-    morcel_size: usize,
+    pub(crate) morcel_size: usize,
     pub global: MorcelComputeState<CS>,
     pub parts: Vec<MorcelComputeState<CS>>,
+    // the edge states are indexed by the edge id, they are empty unless created with `with_edges`
+    pub(crate) edge_morcel_size: usize,
+    pub edges: Vec<MorcelComputeState<CS>>,
 }
 
 // every partition has a struct as such
@@ -28,6 +31,30 @@ impl<CS: ComputeState + Send + Sync> ShuffleComputeState<CS> {
         (morcel_id, offset)
     }
 
+    fn resolve_eid(&self, e_id: usize) -> (usize, usize) {
+        let morcel_id = e_id / self.edge_morcel_size;
+        let offset = e_id % self.edge_morcel_size;
+        (morcel_id, offset)
+    }
+
+    fn morcels(
+        total_len: usize,
+        n_parts: usize,
+        morcel_size: usize,
+    ) -> Vec<MorcelComputeState<CS>> {
+        let last_one_size = total_len % morcel_size;
+        let mut parts: Vec<MorcelComputeState<CS>> = (0..n_parts - 1)
+            .map(|_| MorcelComputeState::new(morcel_size))
+            .collect();
+
+        if last_one_size != 0 {
+            parts.push(MorcelComputeState::new(last_one_size));
+        } else {
+            parts.push(MorcelComputeState::new(morcel_size));
+        }
+        parts
+    }
+
     pub fn merge_mut<A, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
         &mut self,
         other: &Self,
@@ -43,6 +70,11 @@ impl<CS: ComputeState + Send + Sync> ShuffleComputeState<CS> {
             .iter_mut()
             .zip(other.parts.iter())
             .for_each(|(s, o)| s.merge(o, &agg_ref, ss));
+        assert_eq!(self.edges.len(), other.edges.len());
+        self.edges
+            .iter_mut()
+            .zip(other.edges.iter())
+            .for_each(|(s, o)| s.merge(o, &agg_ref, ss));
     }
 
     pub fn set_from_other<A, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
@@ -60,6 +92,11 @@ impl<CS: ComputeState + Send + Sync> ShuffleComputeState<CS> {
             .iter_mut()
             .zip(other.parts.iter())
             .for_each(|(s, o)| s.set_from_other(o, agg_ref, ss));
+        assert_eq!(self.edges.len(), other.edges.len());
+        self.edges
+            .iter_mut()
+            .zip(other.edges.iter())
+            .for_each(|(s, o)| s.set_from_other(o, agg_ref, ss));
     }
 
     pub fn merge_mut_global<
@@ -81,6 +118,7 @@ impl<CS: ComputeState + Send + Sync> ShuffleComputeState<CS> {
 
     pub fn copy_over_next_ss(&mut self, ss: usize) {
         self.parts.iter_mut().for_each(|p| p.copy_over_next_ss(ss));
+        self.edges.iter_mut().for_each(|p| p.copy_over_next_ss(ss));
     }
 
     pub fn reset_states(&mut self, ss: usize, states: &[u32]) {
@@ -88,6 +126,9 @@ impl<CS: ComputeState + Send + Sync> ShuffleComputeState<CS> {
         self.parts
             .iter_mut()
             .for_each(|p| p.reset_states(ss, states));
+        self.edges
+            .iter_mut()
+            .for_each(|p| p.reset_states(ss, states));
     }
 
     pub fn reset_global_states(&mut self, ss: usize, states: &Vec<u32>) {
@@ -95,30 +136,31 @@ impl<CS: ComputeState + Send + Sync> ShuffleComputeState<CS> {
     }
 
     pub fn new(total_len: usize, n_parts: usize, morcel_size: usize) -> Self {
-        let last_one_size = total_len % morcel_size;
-        let mut parts: Vec<MorcelComputeState<CS>> = (0..n_parts - 1)
-            .into_iter()
-            .map(|_| MorcelComputeState::new(morcel_size))
-            .collect();
-
-        if last_one_size != 0 {
-            parts.push(MorcelComputeState::new(last_one_size));
-        } else {
-            parts.push(MorcelComputeState::new(morcel_size));
-        }
-
         Self {
             morcel_size,
-            parts,
+            parts: Self::morcels(total_len, n_parts, morcel_size),
             global: MorcelComputeState::new(1),
+            edge_morcel_size: 1,
+            edges: vec![],
         }
     }
 
+    /// Adds the states for `num_edges` edges split into `n_parts` morcels
+    pub fn with_edges(mut self, num_edges: usize, n_parts: usize, morcel_size: usize) -> Self {
+        if num_edges > 0 {
+            self.edge_morcel_size = morcel_size;
+            self.edges = Self::morcels(num_edges, n_parts, morcel_size);
+        }
+        self
+    }
+
     pub fn global() -> Self {
         Self {
             morcel_size: 1,
             parts: vec![],
             global: MorcelComputeState::new(1),
+            edge_morcel_size: 1,
+            edges: vec![],
         }
     }
 
@@ -149,6 +191,33 @@ impl<CS: ComputeState + Send + Sync> ShuffleComputeState<CS> {
         self.parts[morcel_id].read::<A, IN, OUT, ACC>(offset, agg_ref.id(), ss)
     }
 
+    pub fn accumulate_into_edge<A, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
+        &mut self,
+        ss: usize,
+        e_id: usize,
+        a: IN,
+        agg_ref: &AccId<A, IN, OUT, ACC>,
+    ) where
+        A: StateType,
+    {
+        let (morcel_id, offset) = self.resolve_eid(e_id);
+        self.edges[morcel_id].accumulate_into(ss, offset, a, agg_ref)
+    }
+
+    pub fn read_edge<A, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
+        &self,
+        ss: usize,
+        e_id: usize,
+        agg_ref: &AccId<A, IN, OUT, ACC>,
+    ) -> Option<OUT>
+    where
+        A: StateType,
+        OUT: std::fmt::Debug,
+    {
+        let (morcel_id, offset) = self.resolve_eid(e_id);
+        self.edges[morcel_id].read::<A, IN, OUT, ACC>(offset, agg_ref.id(), ss)
+    }
+
     pub fn accumulate_global<A, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
         &mut self,
         ss: usize,
@@ -223,6 +292,37 @@ impl<CS: ComputeState + Send + Sync> ShuffleComputeState<CS> {
             .collect()
     }
 
+    /// Like `finalize` but for the edge states, only the edges of `g` are included
+    pub fn finalize_edges<A, B, F, IN, OUT, ACC: Accumulator<A, IN, OUT>, G: GraphViewOps>(
+        &self,
+        agg_def: &AccId<A, IN, OUT, ACC>,
+        ss: usize,
+        g: &G,
+        f: F,
+    ) -> HashMap<(String, String), B>
+    where
+        OUT: StateType,
+        A: StateType,
+        F: Fn(OUT) -> B + Copy,
+    {
+        let layer_ids = g.layer_ids();
+        let edge_filter = g.edge_filter();
+        self.edges
+            .iter()
+            .flat_map(|part| part.iter(ss, agg_def))
+            .enumerate()
+            .filter_map(|(e_id, a)| {
+                let e = g.find_edge_id(e_id.into(), &layer_ids, edge_filter)?;
+                let out = a
+                    .map(|a| ACC::finish(a))
+                    .unwrap_or_else(|| ACC::finish(&ACC::zero()));
+                let src = g.vertex_name(e.src()).to_string();
+                let dst = g.vertex_name(e.dst()).to_string();
+                Some(((src, dst), f(out)))
+            })
+            .collect()
+    }
+
     pub fn iter<'a, A: StateType, IN: 'a, OUT: 'a, ACC: Accumulator<A, IN, OUT>>(
         &'a self,
         ss: usize,
@@ -309,6 +409,23 @@ impl<G: GraphViewOps, CS: ComputeState + Send> EvalShardState<G, CS> {
         }
     }
 
+    /// The values of the accumulator for the edges, keyed by the names of their source and
+    /// destination vertices
+    pub fn finalize_edges<A, B, F, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
+        &self,
+        agg_def: &AccId<A, IN, OUT, ACC>,
+        f: F,
+    ) -> HashMap<(String, String), B>
+    where
+        OUT: StateType,
+        A: StateType,
+        F: Fn(OUT) -> B + Copy,
+    {
+        self.shard_states
+            .inner()
+            .finalize_edges(agg_def, self.ss, &self.g, f)
+    }
+
     pub fn values(&self) -> &Shard<CS> {
         &self.shard_states
     }
@@ -0,0 +1,293 @@
+//! Read-only columns backed by memory-mapped files.
+//!
+//! A column file is a headerless array of fixed-width values in native byte order.
+//! Variable-length data is stored as a pair of files, `<name>.off` holding `len + 1`
+//! offsets and `<name>.col` holding the concatenated values, so that the values of
+//! row `i` are `values[off[i]..off[i + 1]]`.
+//!
+//! The files are mapped read-only and are assumed not to change while mapped.
+
+use memmap2::Mmap;
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    marker::PhantomData,
+    mem,
+    ops::Range,
+    path::{Path, PathBuf},
+    slice,
+    sync::Arc,
+};
+
+/// Plain-old-data types that can be stored in a column
+pub trait FixedWidth: Copy + Send + Sync + 'static {}
+
+impl FixedWidth for u8 {}
+impl FixedWidth for i32 {}
+impl FixedWidth for u32 {}
+impl FixedWidth for u64 {}
+impl FixedWidth for f32 {}
+impl FixedWidth for f64 {}
+impl FixedWidth for i64 {}
+impl FixedWidth for [u64; 2] {}
+impl FixedWidth for [i64; 2] {}
+
+pub struct Column<T: FixedWidth> {
+    map: Option<Arc<Mmap>>,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: FixedWidth> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: FixedWidth> Column<T> {
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path.as_ref())?;
+        let bytes = file.metadata()?.len() as usize;
+        if bytes % mem::size_of::<T>() != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "column {} has a truncated trailing value",
+                    path.as_ref().display()
+                ),
+            ));
+        }
+        // mapping an empty file fails on some platforms
+        let map = if bytes == 0 {
+            None
+        } else {
+            // Safety: the file is opened read-only and columns are never modified once written
+            Some(Arc::new(unsafe { Mmap::map(&file)? }))
+        };
+        Ok(Self {
+            map,
+            len: bytes / mem::size_of::<T>(),
+            marker: PhantomData,
+        })
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        match &self.map {
+            // Safety: mappings are page aligned, the length was checked on open and all
+            // `FixedWidth` types are valid for any bit pattern
+            Some(map) => unsafe { slice::from_raw_parts(map.as_ptr() as *const T, self.len) },
+            None => &[],
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, i: usize) -> T {
+        self.as_slice()[i]
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// A column with a variable number of values per row
+pub struct OffsetColumn<T: FixedWidth> {
+    offsets: Column<u64>,
+    values: Column<T>,
+}
+
+impl<T: FixedWidth> Clone for OffsetColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            offsets: self.offsets.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<T: FixedWidth> OffsetColumn<T> {
+    pub fn open<P: AsRef<Path>>(dir: P, name: &str) -> io::Result<Self> {
+        let (off_path, col_path) = offset_column_paths(dir.as_ref(), name);
+        let offsets = Column::open(off_path)?;
+        let values = Column::open(col_path)?;
+        let valid = offsets
+            .as_slice()
+            .first()
+            .map_or(false, |first| *first == 0)
+            && offsets.as_slice().windows(2).all(|w| w[0] <= w[1])
+            && offsets.as_slice().last().copied() == Some(values.len() as u64);
+        if !valid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("offsets of column {name} are inconsistent with its values"),
+            ));
+        }
+        Ok(Self { offsets, values })
+    }
+
+    /// Number of rows in the column
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn range(&self, i: usize) -> Range<usize> {
+        self.offsets.get(i) as usize..self.offsets.get(i + 1) as usize
+    }
+
+    #[inline]
+    pub fn get(&self, i: usize) -> &[T] {
+        &self.values.as_slice()[self.range(i)]
+    }
+
+    /// Get the value at position `j` within the flattened values
+    #[inline]
+    pub fn value(&self, j: usize) -> T {
+        self.values.get(j)
+    }
+}
+
+fn offset_column_paths(dir: &Path, name: &str) -> (PathBuf, PathBuf) {
+    (
+        dir.join(format!("{name}.off")),
+        dir.join(format!("{name}.col")),
+    )
+}
+
+pub struct ColumnWriter<T: FixedWidth> {
+    out: BufWriter<File>,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: FixedWidth> ColumnWriter<T> {
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self {
+            out: BufWriter::new(File::create(path)?),
+            len: 0,
+            marker: PhantomData,
+        })
+    }
+
+    pub fn push(&mut self, value: T) -> io::Result<()> {
+        self.push_slice(slice::from_ref(&value))
+    }
+
+    pub fn push_slice(&mut self, values: &[T]) -> io::Result<()> {
+        // Safety: `FixedWidth` types have no padding
+        let bytes = unsafe {
+            slice::from_raw_parts(values.as_ptr() as *const u8, mem::size_of_val(values))
+        };
+        self.out.write_all(bytes)?;
+        self.len += values.len();
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn finish(self) -> io::Result<()> {
+        let file = self.out.into_inner().map_err(|e| e.into_error())?;
+        file.sync_all()
+    }
+}
+
+pub struct OffsetColumnWriter<T: FixedWidth> {
+    offsets: ColumnWriter<u64>,
+    values: ColumnWriter<T>,
+}
+
+impl<T: FixedWidth> OffsetColumnWriter<T> {
+    pub fn create<P: AsRef<Path>>(dir: P, name: &str) -> io::Result<Self> {
+        let (off_path, col_path) = offset_column_paths(dir.as_ref(), name);
+        let mut offsets = ColumnWriter::create(off_path)?;
+        offsets.push(0)?;
+        Ok(Self {
+            offsets,
+            values: ColumnWriter::create(col_path)?,
+        })
+    }
+
+    /// Append a row
+    pub fn push_row(&mut self, values: &[T]) -> io::Result<()> {
+        self.values.push_slice(values)?;
+        self.offsets.push(self.values.len() as u64)
+    }
+
+    pub fn push_iter<I: IntoIterator<Item = T>>(&mut self, values: I) -> io::Result<()> {
+        for v in values {
+            self.values.push(v)?;
+        }
+        self.offsets.push(self.values.len() as u64)
+    }
+
+    pub fn finish(self) -> io::Result<()> {
+        self.offsets.finish()?;
+        self.values.finish()
+    }
+}
+
+#[cfg(test)]
+mod mmap_test {
+    use super::*;
+    use tempdir::TempDir;
+
+    #[test]
+    fn round_trip_columns() {
+        let dir = TempDir::new("mmap_columns").unwrap();
+        let mut writer = ColumnWriter::create(dir.path().join("ids.col")).unwrap();
+        writer.push_slice(&[3u64, 1, 4]).unwrap();
+        writer.finish().unwrap();
+
+        let mut writer = OffsetColumnWriter::create(dir.path(), "times").unwrap();
+        writer.push_row(&[1i64, 2]).unwrap();
+        writer.push_row(&[]).unwrap();
+        writer.push_iter([5i64]).unwrap();
+        writer.finish().unwrap();
+
+        let ids: Column<u64> = Column::open(dir.path().join("ids.col")).unwrap();
+        assert_eq!(ids.as_slice(), &[3, 1, 4]);
+
+        let times: OffsetColumn<i64> = OffsetColumn::open(dir.path(), "times").unwrap();
+        assert_eq!(times.len(), 3);
+        assert_eq!(times.get(0), &[1, 2]);
+        assert!(times.get(1).is_empty());
+        assert_eq!(times.get(2), &[5]);
+    }
+
+    #[test]
+    fn reject_inconsistent_offsets() {
+        let dir = TempDir::new("mmap_columns").unwrap();
+        let mut writer = OffsetColumnWriter::create(dir.path(), "times").unwrap();
+        writer.push_row(&[1i64, 2]).unwrap();
+        writer.finish().unwrap();
+
+        let mut values = ColumnWriter::create(dir.path().join("times.col")).unwrap();
+        values.push(1i64).unwrap();
+        values.finish().unwrap();
+
+        assert!(OffsetColumn::<i64>::open(dir.path(), "times").is_err());
+    }
+}
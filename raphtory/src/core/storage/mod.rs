@@ -3,6 +3,7 @@
 pub(crate) mod iter;
 pub mod lazy_vec;
 pub mod locked_view;
+pub mod mmap;
 pub mod sorted_vec_map;
 pub mod timeindex;
 
@@ -279,6 +280,17 @@ impl<T> Clone for ArcEntry<T> {
     }
 }
 
+impl<T> ArcEntry<T> {
+    /// Wrap a value that is not backed by `RawStorage` (e.g., decoded from disk)
+    pub(crate) fn owned(value: T) -> Self {
+        let data = Arc::new(RwLock::new(vec![value]));
+        Self {
+            guard: Arc::new(RwLock::read_arc(&data)),
+            i: 0,
+        }
+    }
+}
+
 impl<T> Deref for ArcEntry<T> {
     type Target = T;
 
@@ -47,6 +47,10 @@ impl TimeIndexEntry {
         })
     }
 
+    pub fn i(&self) -> usize {
+        self.1
+    }
+
     pub fn start(t: i64) -> Self {
         Self(t, 0)
     }
@@ -83,6 +87,16 @@ pub enum TimeIndex<T: Ord + Eq + Copy + Debug> {
     Set(BTreeSet<T>),
 }
 
+impl<T: AsTime> FromIterator<T> for TimeIndex<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut index = TimeIndex::Empty;
+        for t in iter {
+            index.insert(t);
+        }
+        index
+    }
+}
+
 impl<T: AsTime> TimeIndex<T> {
     pub fn is_empty(&self) -> bool {
         matches!(self, TimeIndex::Empty)
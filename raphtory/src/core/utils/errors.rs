@@ -25,16 +25,27 @@ pub enum GraphError {
     VertexIdNotStringOrNumber,
     #[error("Invalid layer.")]
     InvalidLayer,
+    #[error("Invalid interval, end {end} is before start {start}")]
+    InvalidInterval { start: i64, end: i64 },
     #[error("Bincode operation failed")]
     BinCodeError { source: Box<bincode::ErrorKind> },
 
     #[error("The loaded graph is of the wrong kind")]
     GraphLoadError,
 
+    #[error("Unsupported graph file format version {version}, this release supports versions up to {supported}")]
+    UnsupportedFormatVersion { version: u32, supported: u32 },
+
+    #[error("Corrupt graph file: {0}")]
+    CorruptGraphFile(String),
+
+    #[error("Checkpoint failed: {0}")]
+    CheckpointError(String),
+
     #[error("IO operation failed")]
     IOError { source: std::io::Error },
 
-    #[cfg(feature = "python")]
+    #[cfg(feature = "arrow")]
     #[error("Failed to load graph: {0}")]
     LoadFailure(String),
 
@@ -1,11 +1,14 @@
 use crate::{
     core::{
         entities::{edges::edge_ref::EdgeRef, vertices::input_vertex::InputVertex},
-        storage::timeindex::TimeIndexEntry,
+        storage::timeindex::{AsTime, TimeIndexEntry},
         utils::{errors::GraphError, time::IntoTimeWithFormat},
     },
     db::{
-        api::mutation::{internal::InternalAdditionOps, TryIntoInputTime},
+        api::mutation::{
+            internal::{InternalAdditionOps, InternalDeletionOps},
+            TryIntoInputTime,
+        },
         graph::{edge::EdgeView, vertex::VertexView},
     },
     prelude::GraphViewOps,
@@ -22,6 +25,7 @@ pub trait AdditionOps: GraphViewOps {
     /// * `t` - The time
     /// * `v` - The vertex (can be a string or integer)
     /// * `props` - The properties of the vertex
+    /// * `vertex_type` - The type of the vertex, the type of a vertex cannot change once it is set
     ///
     /// # Returns
     ///
@@ -32,14 +36,50 @@ pub trait AdditionOps: GraphViewOps {
     /// ```
     /// use raphtory::prelude::*;
     /// let g = Graph::new();
-    /// let v = g.add_vertex(0, "Alice", NO_PROPS);
-    /// let v = g.add_vertex(0, 5, NO_PROPS);
+    /// let v = g.add_vertex(0, "Alice", NO_PROPS, None);
+    /// let v = g.add_vertex(0, 5, NO_PROPS, Some("person")).unwrap();
+    /// assert_eq!(v.vertex_type(), Some("person".to_string()));
     /// ```
     fn add_vertex<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
         &self,
         t: T,
         v: V,
         props: PI,
+        vertex_type: Option<&str>,
+    ) -> Result<VertexView<Self>, GraphError>;
+
+    /// Add a vertex to a layer of the graph
+    ///
+    /// The temporal properties are only visible in views including the layer. Vertices and
+    /// properties added with [`add_vertex`](AdditionOps::add_vertex) are visible in all layers, but
+    /// the vertex is hidden from layered views unless it was added to or has edges in one of their
+    /// layers.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The time
+    /// * `v` - The vertex (can be a string or integer)
+    /// * `props` - The properties of the vertex
+    /// * `vertex_type` - The type of the vertex, the type of a vertex cannot change once it is set
+    /// * `layer` - The layer the vertex is added to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// let g = Graph::new();
+    /// g.add_vertex(0, 5, NO_PROPS, None).unwrap();
+    /// g.add_vertex_to_layer(1, "Bob", [("status", "online")], None, "chat").unwrap();
+    /// assert!(g.layer("chat").unwrap().has_vertex("Bob"));
+    /// assert!(!g.layer("chat").unwrap().has_vertex(5));
+    /// ```
+    fn add_vertex_to_layer<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
+        &self,
+        t: T,
+        v: V,
+        props: PI,
+        vertex_type: Option<&str>,
+        layer: &str,
     ) -> Result<VertexView<Self>, GraphError>;
 
     fn add_vertex_with_custom_time_format<V: InputVertex, PI: CollectProperties>(
@@ -48,11 +88,46 @@ pub trait AdditionOps: GraphViewOps {
         fmt: &str,
         v: V,
         props: PI,
+        vertex_type: Option<&str>,
     ) -> Result<VertexView<Self>, GraphError> {
         let time: i64 = t.parse_time(fmt)?;
-        self.add_vertex(time, v, props)
+        self.add_vertex(time, v, props, vertex_type)
     }
 
+    /// Add a vertex that exists during the interval `[start, end)`
+    ///
+    /// The vertex is added at `start` and deleted at `end` as a single mutation. The times, names
+    /// and property types are validated before the graph is modified, so either both are recorded
+    /// or neither is.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The time the vertex is added
+    /// * `end` - The time the vertex is deleted
+    /// * `v` - The vertex (can be a string or integer)
+    /// * `props` - The properties of the vertex
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// use raphtory::db::graph::views::deletion_graph::GraphWithDeletions;
+    /// let g = GraphWithDeletions::new();
+    /// g.add_vertex_interval(1, 5, "Alice", NO_PROPS).unwrap();
+    /// assert!(g.window(2, 3).has_vertex("Alice"));
+    /// assert!(!g.window(6, 7).has_vertex("Alice"));
+    /// assert!(g.add_vertex_interval(5, 1, "Bob", NO_PROPS).is_err());
+    /// ```
+    fn add_vertex_interval<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
+        &self,
+        start: T,
+        end: T,
+        v: V,
+        props: PI,
+    ) -> Result<VertexView<Self>, GraphError>
+    where
+        Self: InternalDeletionOps;
+
     // TODO: Vertex.name which gets ._id property else numba as string
     /// Adds an edge between the source and destination vertices with the given timestamp and properties.
     ///
@@ -69,8 +144,8 @@ pub trait AdditionOps: GraphViewOps {
     /// use raphtory::prelude::*;
     ///
     /// let graph = Graph::new();
-    /// graph.add_vertex(1, "Alice", NO_PROPS).unwrap();
-    /// graph.add_vertex(2, "Bob", NO_PROPS).unwrap();
+    /// graph.add_vertex(1, "Alice", NO_PROPS, None).unwrap();
+    /// graph.add_vertex(2, "Bob", NO_PROPS, None).unwrap();
     /// graph.add_edge(3, "Alice", "Bob", NO_PROPS, None).unwrap();
     /// ```    
     fn add_edge<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
@@ -94,6 +169,44 @@ pub trait AdditionOps: GraphViewOps {
         let time: i64 = t.parse_time(fmt)?;
         self.add_edge(time, src, dst, props, layer)
     }
+
+    /// Adds an edge that exists during the interval `[start, end)`
+    ///
+    /// The edge is added at `start` and deleted at `end` as a single mutation. The times, names
+    /// and property types are validated before the graph is modified, so either both are recorded
+    /// or neither is.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The time the edge is added.
+    /// * `end` - The time the edge is deleted.
+    /// * `src` - The source vertex.
+    /// * `dst` - The destination vertex.
+    /// * `props` - The properties of the edge, set at `start`.
+    /// * `layer` - The layer of the edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// use raphtory::db::graph::views::deletion_graph::GraphWithDeletions;
+    ///
+    /// let graph = GraphWithDeletions::new();
+    /// graph.add_edge_interval(1, 5, "Alice", "Bob", NO_PROPS, None).unwrap();
+    /// assert!(graph.window(2, 3).has_edge("Alice", "Bob", Layer::All));
+    /// assert!(!graph.window(6, 7).has_edge("Alice", "Bob", Layer::All));
+    /// ```
+    fn add_edge_interval<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
+        &self,
+        start: T,
+        end: T,
+        src: V,
+        dst: V,
+        props: PI,
+        layer: Option<&str>,
+    ) -> Result<EdgeView<Self>, GraphError>
+    where
+        Self: InternalDeletionOps;
 }
 
 impl<G: InternalAdditionOps + GraphViewOps> AdditionOps for G {
@@ -102,10 +215,43 @@ impl<G: InternalAdditionOps + GraphViewOps> AdditionOps for G {
         t: T,
         v: V,
         props: PI,
+        vertex_type: Option<&str>,
     ) -> Result<VertexView<G>, GraphError> {
         let properties = props.collect_properties();
         let ti = TimeIndexEntry::from_input(self, t)?;
-        let vref = self.internal_add_vertex(ti, v.id(), v.id_str(), properties)?;
+        let vref =
+            self.internal_add_vertex(ti, v.id(), v.id_str(), vertex_type, properties, None)?;
+        Ok(VertexView::new_internal(self.clone(), vref))
+    }
+
+    fn add_vertex_to_layer<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
+        &self,
+        t: T,
+        v: V,
+        props: PI,
+        vertex_type: Option<&str>,
+        layer: &str,
+    ) -> Result<VertexView<G>, GraphError> {
+        let properties = props.collect_properties();
+        let ti = TimeIndexEntry::from_input(self, t)?;
+        let vref =
+            self.internal_add_vertex(ti, v.id(), v.id_str(), vertex_type, properties, Some(layer))?;
+        Ok(VertexView::new_internal(self.clone(), vref))
+    }
+
+    fn add_vertex_interval<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
+        &self,
+        start: T,
+        end: T,
+        v: V,
+        props: PI,
+    ) -> Result<VertexView<G>, GraphError>
+    where
+        Self: InternalDeletionOps,
+    {
+        let (start, end) = interval_from_input(self, start, end)?;
+        let properties = props.collect_properties();
+        let vref = self.internal_add_vertex_interval(start, end, v.id(), v.id_str(), properties)?;
         Ok(VertexView::new_internal(self.clone(), vref))
     }
 
@@ -120,8 +266,8 @@ impl<G: InternalAdditionOps + GraphViewOps> AdditionOps for G {
         let ti = TimeIndexEntry::from_input(self, t)?;
         let src_id = src.id();
         let dst_id = dst.id();
-        let src_vid = self.internal_add_vertex(ti, src_id, src.id_str(), vec![])?;
-        let dst_vid = self.internal_add_vertex(ti, dst_id, dst.id_str(), vec![])?;
+        let src_vid = self.internal_add_vertex(ti, src_id, src.id_str(), None, vec![], None)?;
+        let dst_vid = self.internal_add_vertex(ti, dst_id, dst.id_str(), None, vec![], None)?;
 
         let properties = props.collect_properties();
         let eid = self.internal_add_edge(ti, src_id, dst_id, properties, layer)?;
@@ -130,4 +276,52 @@ impl<G: InternalAdditionOps + GraphViewOps> AdditionOps for G {
             EdgeRef::new_outgoing(eid, src_vid, dst_vid),
         ))
     }
+
+    fn add_edge_interval<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
+        &self,
+        start: T,
+        end: T,
+        src: V,
+        dst: V,
+        props: PI,
+        layer: Option<&str>,
+    ) -> Result<EdgeView<G>, GraphError>
+    where
+        Self: InternalDeletionOps,
+    {
+        let (start, end) = interval_from_input(self, start, end)?;
+        let properties = props.collect_properties();
+        let eid = self.internal_add_edge_interval(
+            start,
+            end,
+            src.id(),
+            src.id_str(),
+            dst.id(),
+            dst.id_str(),
+            properties,
+            layer,
+        )?;
+        let src_vid = self.internalise_vertex_unchecked(src.into());
+        let dst_vid = self.internalise_vertex_unchecked(dst.into());
+        Ok(EdgeView::new(
+            self.clone(),
+            EdgeRef::new_outgoing(eid, src_vid, dst_vid),
+        ))
+    }
+}
+
+fn interval_from_input<G: InternalAdditionOps, T: TryIntoInputTime>(
+    g: &G,
+    start: T,
+    end: T,
+) -> Result<(TimeIndexEntry, TimeIndexEntry), GraphError> {
+    let start = TimeIndexEntry::from_input(g, start)?;
+    let end = TimeIndexEntry::from_input(g, end)?;
+    if end.t() < start.t() {
+        return Err(GraphError::InvalidInterval {
+            start: *start.t(),
+            end: *end.t(),
+        });
+    }
+    Ok((start, end))
 }
@@ -30,6 +30,23 @@ pub trait DeletionOps {
         let time: i64 = t.parse_time(fmt)?;
         self.delete_edge(time, src, dst, layer)
     }
+
+    /// Delete vertex `v` at time `t`, implicitly closing all its edges that are alive at `t`
+    fn delete_vertex<V: InputVertex, T: TryIntoInputTime>(
+        &self,
+        t: T,
+        v: V,
+    ) -> Result<(), GraphError>;
+
+    fn delete_vertex_with_custom_time_format<V: InputVertex>(
+        &self,
+        t: &str,
+        fmt: &str,
+        v: V,
+    ) -> Result<(), GraphError> {
+        let time: i64 = t.parse_time(fmt)?;
+        self.delete_vertex(time, v)
+    }
 }
 
 impl<G: InternalDeletionOps + InternalAdditionOps> DeletionOps for G {
@@ -43,4 +60,13 @@ impl<G: InternalDeletionOps + InternalAdditionOps> DeletionOps for G {
         let ti = TimeIndexEntry::from_input(self, t)?;
         self.internal_delete_edge(ti, src.id(), dst.id(), layer)
     }
+
+    fn delete_vertex<V: InputVertex, T: TryIntoInputTime>(
+        &self,
+        t: T,
+        v: V,
+    ) -> Result<(), GraphError> {
+        let ti = TimeIndexEntry::from_input(self, t)?;
+        self.internal_delete_vertex(ti, v.id())
+    }
 }
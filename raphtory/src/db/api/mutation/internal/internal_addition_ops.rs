@@ -16,7 +16,9 @@ pub trait InternalAdditionOps {
         t: TimeIndexEntry,
         v: u64,
         name: Option<&str>,
+        vertex_type: Option<&str>,
         props: Vec<(String, Prop)>,
+        layer: Option<&str>,
     ) -> Result<VID, GraphError>;
 
     fn internal_add_edge(
@@ -59,9 +61,12 @@ impl<G: DelegateAdditionOps> InternalAdditionOps for G {
         t: TimeIndexEntry,
         v: u64,
         name: Option<&str>,
+        vertex_type: Option<&str>,
         props: Vec<(String, Prop)>,
+        layer: Option<&str>,
     ) -> Result<VID, GraphError> {
-        self.graph().internal_add_vertex(t, v, name, props)
+        self.graph()
+            .internal_add_vertex(t, v, name, vertex_type, props, layer)
     }
 
     #[inline(always)]
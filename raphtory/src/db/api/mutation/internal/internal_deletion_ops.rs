@@ -1,5 +1,10 @@
 use crate::{
-    core::{storage::timeindex::TimeIndexEntry, utils::errors::GraphError},
+    core::{
+        entities::{EID, VID},
+        storage::timeindex::TimeIndexEntry,
+        utils::errors::GraphError,
+        Prop,
+    },
     db::api::view::internal::Base,
 };
 
@@ -11,6 +16,32 @@ pub trait InternalDeletionOps {
         dst: u64,
         layer: Option<&str>,
     ) -> Result<(), GraphError>;
+
+    fn internal_delete_vertex(&self, t: TimeIndexEntry, v: u64) -> Result<(), GraphError>;
+
+    /// Add a vertex at `start` and delete it at `end` as a single mutation
+    fn internal_add_vertex_interval(
+        &self,
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        v: u64,
+        name: Option<&str>,
+        props: Vec<(String, Prop)>,
+    ) -> Result<VID, GraphError>;
+
+    /// Add an edge at `start` and delete it at `end` as a single mutation
+    #[allow(clippy::too_many_arguments)]
+    fn internal_add_edge_interval(
+        &self,
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        src: u64,
+        src_name: Option<&str>,
+        dst: u64,
+        dst_name: Option<&str>,
+        props: Vec<(String, Prop)>,
+        layer: Option<&str>,
+    ) -> Result<EID, GraphError>;
 }
 
 pub trait InheritDeletionOps: Base {}
@@ -43,4 +74,38 @@ impl<G: DelegateDeletionOps> InternalDeletionOps for G {
     ) -> Result<(), GraphError> {
         self.graph().internal_delete_edge(t, src, dst, layer)
     }
+
+    #[inline(always)]
+    fn internal_delete_vertex(&self, t: TimeIndexEntry, v: u64) -> Result<(), GraphError> {
+        self.graph().internal_delete_vertex(t, v)
+    }
+
+    #[inline(always)]
+    fn internal_add_vertex_interval(
+        &self,
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        v: u64,
+        name: Option<&str>,
+        props: Vec<(String, Prop)>,
+    ) -> Result<VID, GraphError> {
+        self.graph()
+            .internal_add_vertex_interval(start, end, v, name, props)
+    }
+
+    #[inline(always)]
+    fn internal_add_edge_interval(
+        &self,
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        src: u64,
+        src_name: Option<&str>,
+        dst: u64,
+        dst_name: Option<&str>,
+        props: Vec<(String, Prop)>,
+        layer: Option<&str>,
+    ) -> Result<EID, GraphError> {
+        self.graph()
+            .internal_add_edge_interval(start, end, src, src_name, dst, dst_name, props, layer)
+    }
 }
@@ -25,7 +25,7 @@ pub trait PropertyAdditionOps {
     /// ```
     /// use raphtory::prelude::*;
     /// let graph = Graph::new();
-    /// graph.add_vertex(0, "Alice", NO_PROPS);
+    /// graph.add_vertex(0, "Alice", NO_PROPS, None);
     /// let properties = vec![("color".to_owned(), Prop::Str("blue".to_owned())), ("weight".to_owned(), Prop::I64(11))];
     /// let result = graph.add_vertex_properties("Alice", properties);
     /// ```
@@ -56,8 +56,8 @@ pub trait PropertyAdditionOps {
     /// ```
     /// use raphtory::prelude::*;
     /// let graph = Graph::new();
-    /// graph.add_vertex(1, "Alice", NO_PROPS);
-    /// graph.add_vertex(2, "Bob", NO_PROPS);
+    /// graph.add_vertex(1, "Alice", NO_PROPS, None);
+    /// graph.add_vertex(2, "Bob", NO_PROPS, None);
     /// graph.add_edge(3, "Alice", "Bob", NO_PROPS, None);
     /// let properties = vec![("price", 100)];
     /// let result = graph.add_edge_properties("Alice", "Bob", properties, None);
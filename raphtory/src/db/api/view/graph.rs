@@ -4,6 +4,7 @@ use crate::{
             graph::tgraph::InnerTemporalGraph, vertices::vertex_ref::VertexRef, LayerIds, VID,
         },
         utils::{errors::GraphError, time::IntoTime},
+        Prop,
     },
     db::{
         api::{
@@ -34,6 +35,12 @@ pub trait GraphViewOps: BoxableGraphView + Clone + Sized {
         &self,
         vertices: I,
     ) -> VertexSubgraph<Self>;
+    /// Return a view of the graph only containing the vertices with one of the given `types`,
+    /// vertices without a type are excluded
+    fn type_filter<I: IntoIterator<Item = T>, T: AsRef<str>>(
+        &self,
+        types: I,
+    ) -> VertexSubgraph<Self>;
     /// Return all the layer ids in the graph
     fn get_unique_layers(&self) -> Vec<String>;
     /// Timestamp of earliest activity in the graph
@@ -99,6 +106,15 @@ impl<G: BoxableGraphView + Sized + Clone> GraphViewOps for G {
         VertexSubgraph::new(self.clone(), vertices)
     }
 
+    fn type_filter<I: IntoIterator<Item = T>, T: AsRef<str>>(&self, types: I) -> VertexSubgraph<G> {
+        let types: FxHashSet<String> = types.into_iter().map(|t| t.as_ref().to_owned()).collect();
+        let vertices: FxHashSet<VID> = self
+            .vertex_refs(self.layer_ids(), self.edge_filter())
+            .filter(|v| self.vertex_type(*v).filter(|t| types.contains(t)).is_some())
+            .collect();
+        VertexSubgraph::new(self.clone(), vertices)
+    }
+
     /// Return all the layer ids in the graph
     fn get_unique_layers(&self) -> Vec<String> {
         self.get_layer_names_from_ids(self.layer_ids())
@@ -209,18 +225,67 @@ impl<G: BoxableGraphView + Sized + Clone> GraphViewOps for G {
             }
         }
 
+        let w = self.view_start().unwrap_or(i64::MIN)..self.view_end().unwrap_or(i64::MAX);
+        let layer_ids = self.layer_ids();
         for v in self.vertices().iter() {
             for h in v.history() {
-                g.add_vertex(h, v.id(), NO_PROPS)?;
+                g.add_vertex(h, v.id(), NO_PROPS, None)?;
+            }
+            let prop_names: Vec<_> = v
+                .properties()
+                .temporal()
+                .keys()
+                .map(|k| k.to_string())
+                .collect();
+            for name in &prop_names {
+                for (t, prop) in self.temporal_vertex_prop_vec(v.vertex, name, LayerIds::None) {
+                    g.add_vertex(t, v.id(), [(name.clone(), prop)], None)?;
+                }
             }
-            for (name, prop_view) in v.properties().temporal().iter() {
-                for (t, prop) in prop_view.iter() {
-                    g.add_vertex(t, v.id(), [(name.clone(), prop)])?;
+            let vertex = self.core_vertex(v.vertex);
+            for layer in vertex.layer_ids_iter().filter(|l| layer_ids.contains(l)) {
+                let layer_names = self.get_layer_names_from_ids(LayerIds::One(layer));
+                let layer_name = layer_names[0].as_str();
+                for t in vertex
+                    .layer_timestamps(layer)
+                    .into_iter()
+                    .flat_map(|ts| ts.range_iter(w.clone()))
+                {
+                    g.add_vertex_to_layer(*t, v.id(), NO_PROPS, None, layer_name)?;
+                }
+                for name in &prop_names {
+                    if let Some(prop) = self.temporal_vertex_layer_prop(v.vertex, name, layer) {
+                        for (t, prop) in prop.iter_window(w.clone()) {
+                            g.add_vertex_to_layer(
+                                t,
+                                v.id(),
+                                [(name.clone(), prop)],
+                                None,
+                                layer_name,
+                            )?;
+                        }
+                    }
+                }
+            }
+            g.add_vertex_properties(
+                v.id(),
+                v.properties()
+                    .constant()
+                    .into_iter()
+                    .chain(v.vertex_type().map(|t| ("_type".to_string(), Prop::Str(t)))),
+            )?;
+            if self.include_deletions() {
+                for t in self.vertex_deletion_history(v.vertex) {
+                    g.delete_vertex(t, v.id())?;
                 }
             }
-            g.add_vertex_properties(v.id(), v.properties().constant())?;
         }
 
+        for (name, prop_view) in self.properties().temporal().iter() {
+            for (t, prop) in prop_view.iter() {
+                g.add_properties(t, [(name.clone(), prop)])?;
+            }
+        }
         g.add_static_properties(self.properties().constant())?;
 
         Ok(self.new_base_graph(g))
@@ -299,10 +364,65 @@ mod test_materialize {
         g.add_properties(0, props_0.clone()).unwrap();
         assert!(g.add_properties(1, props_1.clone()).is_err());
 
-        g.add_vertex(0, 1, props_0.clone()).unwrap();
-        assert!(g.add_vertex(1, 1, props_1.clone()).is_err());
+        g.add_vertex(0, 1, props_0.clone(), None).unwrap();
+        assert!(g.add_vertex(1, 1, props_1.clone(), None).is_err());
 
         g.add_edge(0, 1, 2, props_0.clone(), None).unwrap();
         assert!(g.add_edge(1, 1, 2, props_1.clone(), None).is_err());
     }
+
+    #[test]
+    fn vertex_types() {
+        let g = Graph::new();
+        g.add_vertex(0, "Alice", NO_PROPS, Some("person")).unwrap();
+        g.add_vertex(0, "Bob", NO_PROPS, Some("person")).unwrap();
+        g.add_vertex(0, "Acme", NO_PROPS, Some("company")).unwrap();
+        g.add_edge(1, "Alice", "Bob", NO_PROPS, None).unwrap();
+        g.add_edge(1, "Alice", "Acme", NO_PROPS, None).unwrap();
+        g.add_edge(1, "Alice", "Carol", NO_PROPS, None).unwrap();
+
+        // the type is kept when it is not given again
+        g.add_vertex(2, "Alice", NO_PROPS, None).unwrap();
+        assert_eq!(
+            g.vertex("Alice").unwrap().vertex_type(),
+            Some("person".into())
+        );
+        assert_eq!(g.vertex("Carol").unwrap().vertex_type(), None);
+        assert!(g
+            .add_vertex(3, "Alice", [("age", 30)], Some("company"))
+            .is_err());
+        // a rejected type change leaves the vertex untouched
+        let alice = g.vertex("Alice").unwrap();
+        assert_eq!(alice.history(), vec![0, 1, 2]);
+        assert!(alice.properties().get("age").is_none());
+        // the type is not exposed as a property
+        assert!(!alice.properties().constant().contains("_type"));
+        assert_eq!(alice.properties().constant().keys(), vec!["_id"]);
+
+        let people = g.type_filter(["person"]);
+        assert_eq!(people.num_vertices(), 2);
+        assert_eq!(people.num_edges(), 1);
+        let typed = g.type_filter(["person", "company"]);
+        assert_eq!(typed.num_vertices(), 3);
+        assert_eq!(typed.num_edges(), 2);
+
+        let gm = g.materialize().unwrap();
+        assert_eq!(
+            gm.into_events()
+                .unwrap()
+                .vertex("Acme")
+                .unwrap()
+                .vertex_type(),
+            Some("company".into())
+        );
+
+        let dir = tempdir::TempDir::new("vertex_types").unwrap();
+        let path = dir.path().join("graph.bin");
+        g.save_to_file(&path).unwrap();
+        let loaded = Graph::load_from_file(&path).unwrap();
+        assert_eq!(
+            loaded.vertex("Alice").unwrap().vertex_type(),
+            Some("person".into())
+        );
+    }
 }
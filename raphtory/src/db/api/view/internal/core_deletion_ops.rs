@@ -1,7 +1,10 @@
 use crate::{
     core::{
-        entities::{edges::edge_ref::EdgeRef, LayerIds},
-        storage::timeindex::{LockedLayeredIndex, TimeIndexEntry},
+        entities::{edges::edge_ref::EdgeRef, LayerIds, VID},
+        storage::{
+            locked_view::LockedView,
+            timeindex::{LockedLayeredIndex, TimeIndex, TimeIndexEntry},
+        },
     },
     db::api::view::internal::Base,
 };
@@ -14,6 +17,10 @@ pub trait CoreDeletionOps {
         eref: EdgeRef,
         layer_ids: LayerIds,
     ) -> LockedLayeredIndex<'_, TimeIndexEntry>;
+
+    /// Get all the deletion timestamps for a vertex
+    /// (this should always be global and not affected by windowing, see `edge_deletions`)
+    fn vertex_deletions(&self, v: VID) -> LockedView<'_, TimeIndex<i64>>;
 }
 
 pub trait InheritCoreDeletionOps: Base {}
@@ -43,4 +50,8 @@ impl<G: DelegateCoreDeletionOps> CoreDeletionOps for G {
     ) -> LockedLayeredIndex<'_, TimeIndexEntry> {
         self.graph().edge_deletions(eref, layer_ids)
     }
+
+    fn vertex_deletions(&self, v: VID) -> LockedView<'_, TimeIndex<i64>> {
+        self.graph().vertex_deletions(v)
+    }
 }
@@ -11,7 +11,7 @@ use crate::{
             timeindex::{LockedLayeredIndex, TimeIndex, TimeIndexEntry},
             ArcEntry,
         },
-        Prop,
+        Prop, PropUnwrap,
     },
     db::api::view::internal::Base,
 };
@@ -21,6 +21,9 @@ pub trait CoreGraphOps {
     /// get the number of vertices in the main graph
     fn unfiltered_num_vertices(&self) -> usize;
 
+    /// get the number of edges in the main graph
+    fn unfiltered_num_edges(&self) -> usize;
+
     /// Get the layer name for a given id
     fn get_layer_names_from_ids(&self, layer_ids: LayerIds) -> Vec<String>;
 
@@ -96,6 +99,12 @@ pub trait CoreGraphOps {
     /// Option<Prop> - The property value if it exists.
     fn static_vertex_prop(&self, v: VID, name: &str) -> Option<Prop>;
 
+    /// Returns the type of a vertex if it has one
+    fn vertex_type(&self, v: VID) -> Option<String> {
+        self.static_vertex_prop(v, "_type")
+            .and_then(|t| t.into_str())
+    }
+
     /// Gets the keys of static properties of a given vertex
     ///
     /// # Arguments
@@ -122,6 +131,24 @@ pub trait CoreGraphOps {
     /// Option<LockedView<TProp>> - The history of property values if it exists.
     fn temporal_vertex_prop(&self, v: VID, name: &str) -> Option<LockedView<TProp>>;
 
+    /// Gets the values of a temporal property of a given vertex that were set in `layer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A reference to the vertex for which the property is being queried.
+    /// * `name` - The name of the property.
+    /// * `layer` - The id of the layer.
+    ///
+    /// # Returns
+    ///
+    /// Option<LockedView<TProp>> - The history of property values in the layer if it exists.
+    fn temporal_vertex_layer_prop(
+        &self,
+        v: VID,
+        name: &str,
+        layer: usize,
+    ) -> Option<LockedView<'_, TProp>>;
+
     /// Returns a vector of all names of temporal properties within the given vertex
     ///
     /// # Arguments
@@ -242,6 +269,10 @@ impl<G: DelegateCoreOps + ?Sized> CoreGraphOps for G {
         self.graph().unfiltered_num_vertices()
     }
 
+    fn unfiltered_num_edges(&self) -> usize {
+        self.graph().unfiltered_num_edges()
+    }
+
     fn get_layer_names_from_ids(&self, layer_ids: LayerIds) -> Vec<String> {
         self.graph().get_layer_names_from_ids(layer_ids)
     }
@@ -305,6 +336,15 @@ impl<G: DelegateCoreOps + ?Sized> CoreGraphOps for G {
         self.graph().temporal_vertex_prop(v, name)
     }
 
+    fn temporal_vertex_layer_prop(
+        &self,
+        v: VID,
+        name: &str,
+        layer: usize,
+    ) -> Option<LockedView<'_, TProp>> {
+        self.graph().temporal_vertex_layer_prop(v, name, layer)
+    }
+
     fn temporal_vertex_prop_names<'a>(
         &'a self,
         v: VID,
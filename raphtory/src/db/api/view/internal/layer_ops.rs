@@ -1,5 +1,5 @@
 use crate::{
-    core::entities::{edges::edge_store::EdgeStore, LayerIds},
+    core::entities::{edges::edge_store::EdgeStore, LayerIds, VID},
     db::api::view::internal::Base,
     prelude::Layer,
 };
@@ -13,6 +13,9 @@ pub trait LayerOps {
 
     /// get the layer ids for the given edge id
     fn edge_layer_ids(&self, e: &EdgeStore) -> LayerIds;
+
+    /// get the layer ids the given vertex was added to or has edges in
+    fn vertex_layer_ids(&self, v: VID) -> LayerIds;
 }
 
 pub trait InheritLayerOps: Base {}
@@ -50,4 +53,9 @@ impl<G: DelegateLayerOps> LayerOps for G {
     fn edge_layer_ids(&self, e: &EdgeStore) -> LayerIds {
         self.graph().edge_layer_ids(e)
     }
+
+    #[inline]
+    fn vertex_layer_ids(&self, v: VID) -> LayerIds {
+        self.graph().vertex_layer_ids(v)
+    }
 }
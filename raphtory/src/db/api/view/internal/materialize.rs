@@ -3,6 +3,7 @@ use crate::{
     db::{
         api::view::internal::{Base, DynamicGraph, IntoDynamic},
         graph::{
+            format,
             graph::{Graph, InternalGraph},
             views::deletion_graph::GraphWithDeletions,
         },
@@ -52,16 +53,45 @@ impl MaterializedGraph {
         }
     }
 
+    /// Load a graph from a file written by [`save_to_file`](Self::save_to_file)
+    ///
+    /// Files written by earlier releases are migrated on load, see [`format`] for details.
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, GraphError> {
         let f = std::fs::File::open(path)?;
-        let mut reader = std::io::BufReader::new(f);
-        Ok(bincode::deserialize_from(&mut reader)?)
+        Self::load_from_reader(std::io::BufReader::new(f))
     }
 
+    /// Load a graph from the contents of a file written by [`save_to_file`](Self::save_to_file)
+    pub fn load_from_reader<R: std::io::Read + std::io::Seek>(
+        reader: R,
+    ) -> Result<Self, GraphError> {
+        format::read_graph(reader)
+    }
+
+    /// Save the graph to a file using the current version of the [`format`]
     pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
         let f = std::fs::File::create(path)?;
+        format::write_graph(std::io::BufWriter::new(f), self)
+    }
+
+    /// Atomically replace the file at `path` with a snapshot of the graph
+    ///
+    /// The snapshot is written to a temporary file next to `path`, synced to disk and then renamed,
+    /// such that a crash never leaves a partially written snapshot behind.
+    pub(crate) fn save_snapshot<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        let path = path.as_ref();
+        let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+        tmp_name.push(".tmp");
+        let tmp_path = path.with_file_name(tmp_name);
+        let f = std::fs::File::create(&tmp_path)?;
         let mut writer = std::io::BufWriter::new(f);
-        Ok(bincode::serialize_into(&mut writer, self)?)
+        format::write_graph(&mut writer, self)?;
+        writer
+            .into_inner()
+            .map_err(|err| err.into_error())?
+            .sync_all()?;
+        std::fs::rename(tmp_path, path)?;
+        Ok(())
     }
 }
 
@@ -143,7 +143,7 @@ mod test {
     fn test_boxing() {
         // this tests that a boxed graph actually compiles
         let g = Graph::new();
-        g.add_vertex(0, 1, NO_PROPS).unwrap();
+        g.add_vertex(0, 1, NO_PROPS, None).unwrap();
         let boxed: Arc<dyn BoxableGraphView> = Arc::new(g);
         assert_eq!(boxed.vertices().id().collect_vec(), vec![1])
     }
@@ -138,6 +138,12 @@ pub trait TimeSemantics: GraphOps + CoreGraphOps {
         layer_ids: LayerIds,
     ) -> Vec<i64>;
 
+    /// Get the vertex deletions for use with materialize
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64>;
+
+    /// Get the vertex deletions for use with materialize restricted to window `w`
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64>;
+
     /// Returns a vector of all temporal values of the graph property with the given name
     ///
     /// # Arguments
@@ -174,13 +180,15 @@ pub trait TimeSemantics: GraphOps + CoreGraphOps {
     ///
     /// * `v` - A reference to the vertex for which to retrieve the temporal property vector.
     /// * `name` - The name of the property to retrieve.
+    /// * `layer_ids` - The layers to include values from, values set without a layer are always included.
     ///
     /// # Returns
     ///
     /// A vector of tuples representing the temporal values of the property for the given vertex
     /// that fall within the specified time window, where the first element of each tuple is the timestamp
     /// and the second element is the property value.
-    fn temporal_vertex_prop_vec(&self, v: VID, name: &str) -> Vec<(i64, Prop)>;
+    fn temporal_vertex_prop_vec(&self, v: VID, name: &str, layer_ids: LayerIds)
+        -> Vec<(i64, Prop)>;
 
     /// Returns a vector of all temporal values of the vertex property with the given name for the given vertex
     /// that fall within the specified time window.
@@ -191,6 +199,7 @@ pub trait TimeSemantics: GraphOps + CoreGraphOps {
     /// * `name` - The name of the property to retrieve.
     /// * `t_start` - The start time of the window to consider.
     /// * `t_end` - The end time of the window to consider.
+    /// * `layer_ids` - The layers to include values from, values set without a layer are always included.
     ///
     /// # Returns
     ///
@@ -203,6 +212,7 @@ pub trait TimeSemantics: GraphOps + CoreGraphOps {
         name: &str,
         t_start: i64,
         t_end: i64,
+        layer_ids: LayerIds,
     ) -> Vec<(i64, Prop)>;
 
     /// Returns a vector of tuples containing the values of the temporal property with the given name
@@ -412,6 +422,16 @@ impl<G: DelegateTimeSemantics + ?Sized> TimeSemantics for G {
         self.graph().edge_deletion_history_window(e, w, layer_ids)
     }
 
+    #[inline]
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.graph().vertex_deletion_history(v)
+    }
+
+    #[inline]
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.graph().vertex_deletion_history_window(v, w)
+    }
+
     #[inline]
     fn temporal_prop_vec(&self, name: &str) -> Vec<(i64, Prop)> {
         self.graph().temporal_prop_vec(name)
@@ -423,8 +443,13 @@ impl<G: DelegateTimeSemantics + ?Sized> TimeSemantics for G {
     }
 
     #[inline]
-    fn temporal_vertex_prop_vec(&self, v: VID, name: &str) -> Vec<(i64, Prop)> {
-        self.graph().temporal_vertex_prop_vec(v, name)
+    fn temporal_vertex_prop_vec(
+        &self,
+        v: VID,
+        name: &str,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
+        self.graph().temporal_vertex_prop_vec(v, name, layer_ids)
     }
 
     #[inline]
@@ -434,9 +459,10 @@ impl<G: DelegateTimeSemantics + ?Sized> TimeSemantics for G {
         name: &str,
         t_start: i64,
         t_end: i64,
+        layer_ids: LayerIds,
     ) -> Vec<(i64, Prop)> {
         self.graph()
-            .temporal_vertex_prop_vec_window(v, name, t_start, t_end)
+            .temporal_vertex_prop_vec_window(v, name, t_start, t_end, layer_ids)
     }
 
     #[inline]
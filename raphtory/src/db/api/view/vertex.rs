@@ -25,6 +25,12 @@ pub trait VertexViewOps: TimeOps {
     /// The name of the vertex if one exists, otherwise the ID as a string.
     fn name(&self) -> Self::ValueType<String>;
 
+    /// Get the type of this vertex if one was set when it was added.
+    fn vertex_type(&self) -> Self::ValueType<Option<String>>;
+
+    /// Get the names of the layers this vertex was added to or has edges in.
+    fn layer_names(&self) -> Self::ValueType<Vec<String>>;
+
     /// Get the timestamp for the earliest activity of the vertex
     fn earliest_time(&self) -> Self::ValueType<Option<i64>>;
 
@@ -142,6 +148,12 @@ pub trait VertexListOps:
     fn id(self) -> Self::IterType<u64>;
     fn name(self) -> Self::IterType<String>;
 
+    /// Returns the types of vertices in the list.
+    fn vertex_type(self) -> Self::IterType<Option<String>>;
+
+    /// Returns the layer names of vertices in the list.
+    fn layer_names(self) -> Self::IterType<Vec<String>>;
+
     /// Returns an iterator over properties of the vertices
     fn properties(self) -> Self::IterType<Properties<VertexView<Self::Graph>>>;
 
@@ -0,0 +1,390 @@
+//! The versioned on-disk format used by `save_to_file` and `load_from_file`.
+//!
+//! A graph file consists of
+//!
+//! * the magic bytes `RAPHTORY`,
+//! * the format version as a little-endian `u32`,
+//! * a version-specific body encoded with bincode.
+//!
+//! The body of version 1 starts with a [`FileHeader`] (graph kind and entity counts) followed by a
+//! description of the graph that only uses vertex ids, layer names, timestamps and property values,
+//! such that it does not depend on the in-memory layout of the graph. The counts in the header are
+//! checked when the file is read. Version 2 appends the additions and temporal properties of
+//! vertices in layers to the body of version 1.
+//!
+//! Files without the magic bytes were written by earlier releases which stored the raw bincode
+//! encoding of the in-memory graph. They are treated as version 0 and migrated on load.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::db::graph::format::{read_header, GraphKind, FORMAT_VERSION};
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, "Alice", "Bob", NO_PROPS, None).unwrap();
+//!
+//! let dir = tempdir::TempDir::new("format").unwrap();
+//! let path = dir.path().join("graph");
+//! g.save_to_file(&path).unwrap();
+//!
+//! let header = read_header(&path).unwrap().unwrap();
+//! assert_eq!(header.version, FORMAT_VERSION);
+//! assert_eq!(header.kind, GraphKind::Event);
+//! assert_eq!(header.num_vertices, 2);
+//! assert_eq!(header.num_edges, 1);
+//! ```
+
+mod v1;
+mod v2;
+
+use crate::{
+    core::{entities::vertices::vertex_store::with_legacy_layout, utils::errors::GraphError},
+    db::api::view::internal::{Base, MaterializedGraph},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::{BufReader, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+/// Magic bytes at the start of every versioned graph file
+pub const MAGIC: &[u8; 8] = b"RAPHTORY";
+
+/// The format version written by this release
+pub const FORMAT_VERSION: u32 = 2;
+
+/// The kind of graph stored in a file
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphKind {
+    /// A [`Graph`](crate::db::graph::graph::Graph) with event semantics
+    Event,
+    /// A [`GraphWithDeletions`](crate::db::graph::views::deletion_graph::GraphWithDeletions) with persistent semantics
+    Persistent,
+}
+
+/// The header of a versioned graph file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileHeader {
+    pub version: u32,
+    pub kind: GraphKind,
+    pub num_vertices: u64,
+    pub num_edges: u64,
+    pub num_layers: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HeaderV1 {
+    kind: GraphKind,
+    num_vertices: u64,
+    num_edges: u64,
+    num_layers: u64,
+}
+
+/// Read the header of the graph file at `path`
+///
+/// Returns `None` for headerless files written by earlier releases.
+pub fn read_header<P: AsRef<Path>>(path: P) -> Result<Option<FileHeader>, GraphError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    match read_version(&mut reader)? {
+        None => Ok(None),
+        Some(version) => {
+            check_version(version)?;
+            let header: HeaderV1 = bincode::deserialize_from(&mut reader)?;
+            Ok(Some(FileHeader {
+                version,
+                kind: header.kind,
+                num_vertices: header.num_vertices,
+                num_edges: header.num_edges,
+                num_layers: header.num_layers,
+            }))
+        }
+    }
+}
+
+pub(crate) fn write_graph<W: Write>(
+    mut writer: W,
+    graph: &MaterializedGraph,
+) -> Result<(), GraphError> {
+    let (kind, graph) = match graph {
+        MaterializedGraph::EventGraph(g) => (GraphKind::Event, &*g.0),
+        MaterializedGraph::PersistentGraph(g) => (GraphKind::Persistent, g.base()),
+    };
+    let body = v1::GraphV1::encode(graph);
+    let vertex_layers = v2::VertexLayersV2::encode(graph);
+    let header = HeaderV1 {
+        kind,
+        num_vertices: body.vertices.len() as u64,
+        num_edges: body.edges.len() as u64,
+        num_layers: body.layers.len() as u64,
+    };
+    writer.write_all(MAGIC)?;
+    writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+    bincode::serialize_into(&mut writer, &header)?;
+    bincode::serialize_into(&mut writer, &body)?;
+    bincode::serialize_into(&mut writer, &vertex_layers)?;
+    writer.flush()?;
+    Ok(())
+}
+
+pub(crate) fn read_graph<R: Read + Seek>(mut reader: R) -> Result<MaterializedGraph, GraphError> {
+    match read_version(&mut reader)? {
+        None => {
+            reader.seek(SeekFrom::Start(0))?;
+            with_legacy_layout(|| bincode::deserialize_from(&mut reader)).map_err(|err| {
+                GraphError::CorruptGraphFile(format!(
+                    "not a versioned graph file and not a legacy graph file ({err})"
+                ))
+            })
+        }
+        Some(version) => {
+            check_version(version)?;
+            let header: HeaderV1 = bincode::deserialize_from(&mut reader)
+                .map_err(|err| GraphError::CorruptGraphFile(format!("invalid header ({err})")))?;
+            let body: v1::GraphV1 = bincode::deserialize_from(&mut reader)
+                .map_err(|err| GraphError::CorruptGraphFile(format!("invalid body ({err})")))?;
+            check_count("vertices", header.num_vertices, body.vertices.len())?;
+            check_count("edges", header.num_edges, body.edges.len())?;
+            check_count("layers", header.num_layers, body.layers.len())?;
+            let vertex_layers: Option<v2::VertexLayersV2> = match version {
+                1 => None,
+                _ => Some(bincode::deserialize_from(&mut reader).map_err(|err| {
+                    GraphError::CorruptGraphFile(format!("invalid vertex layers ({err})"))
+                })?),
+            };
+            let layers = body.layers.clone();
+            let graph = body.decode()?;
+            if let Some(vertex_layers) = vertex_layers {
+                vertex_layers.decode_into(&graph, &layers)?;
+            }
+            Ok(match header.kind {
+                GraphKind::Event => MaterializedGraph::EventGraph(graph.into()),
+                GraphKind::Persistent => MaterializedGraph::PersistentGraph(graph.into()),
+            })
+        }
+    }
+}
+
+/// Read the magic bytes and version, returns `None` if the file does not start with the magic bytes
+fn read_version<R: Read>(reader: &mut R) -> Result<Option<u32>, GraphError> {
+    let mut magic = [0u8; 8];
+    if read_full(reader, &mut magic)? < magic.len() || &magic != MAGIC {
+        return Ok(None);
+    }
+    let mut version = [0u8; 4];
+    if read_full(reader, &mut version)? < version.len() {
+        return Err(GraphError::CorruptGraphFile(
+            "file ends before the format version".to_string(),
+        ));
+    }
+    Ok(Some(u32::from_le_bytes(version)))
+}
+
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<usize, GraphError> {
+    let mut read = 0;
+    while read < buf.len() {
+        match reader.read(&mut buf[read..])? {
+            0 => break,
+            n => read += n,
+        }
+    }
+    Ok(read)
+}
+
+fn check_version(version: u32) -> Result<(), GraphError> {
+    if version == 0 || version > FORMAT_VERSION {
+        Err(GraphError::UnsupportedFormatVersion {
+            version,
+            supported: FORMAT_VERSION,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn check_count(what: &'static str, expected: u64, actual: usize) -> Result<(), GraphError> {
+    if expected != actual as u64 {
+        Err(GraphError::CorruptGraphFile(format!(
+            "header declares {expected} {what} but the file contains {actual}"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{read_header, GraphKind, FORMAT_VERSION, MAGIC};
+    use crate::{
+        core::utils::errors::GraphError, db::graph::views::deletion_graph::GraphWithDeletions,
+        prelude::*,
+    };
+    use std::{collections::HashMap, path::PathBuf, sync::Arc};
+    use tempdir::TempDir;
+
+    fn resource(name: &str) -> PathBuf {
+        [env!("CARGO_MANIFEST_DIR"), "resources", "test", name]
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        let nested = Graph::new();
+        nested.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+
+        let g = Graph::new();
+        g.add_vertex(0, "Alice", [("age", Prop::I64(30))], None)
+            .unwrap();
+        g.add_vertex(
+            2,
+            7,
+            [("list", Prop::List(Arc::new(vec![Prop::U32(1)])))],
+            None,
+        )
+        .unwrap();
+        g.add_edge(1, "Alice", "Bob", [("weight", Prop::F64(1.5))], None)
+            .unwrap();
+        g.add_edge(1, "Alice", "Bob", [("weight", Prop::F64(2.5))], None)
+            .unwrap();
+        g.add_edge(3, "Bob", "7", [("g", Prop::Graph(nested))], Some("layer"))
+            .unwrap();
+        g.add_edge_properties("Bob", "7", [("const", Prop::Bool(true))], Some("layer"))
+            .unwrap();
+        g.add_vertex_properties(
+            "Bob",
+            [(
+                "map",
+                Prop::Map(Arc::new(HashMap::from([("a".to_string(), Prop::I32(1))]))),
+            )],
+        )
+        .unwrap();
+        g.add_properties(4, [("version", Prop::str("one"))])
+            .unwrap();
+        g.add_static_properties([("name", Prop::str("test"))])
+            .unwrap();
+
+        let dir = TempDir::new("format").unwrap();
+        let path = dir.path().join("graph");
+        g.save_to_file(&path).unwrap();
+        let g2 = Graph::load_from_file(&path).unwrap();
+        assert_eq!(g, g2);
+        assert_eq!(g2.vertex("Alice").unwrap().name(), "Alice");
+        assert_eq!(g2.vertex(7).unwrap().name(), "7");
+        assert_eq!(g2.edge("Alice", "Bob").unwrap().explode().count(), 2);
+
+        let header = read_header(&path).unwrap().unwrap();
+        assert_eq!(header.version, FORMAT_VERSION);
+        assert_eq!(header.kind, GraphKind::Event);
+        assert_eq!(header.num_vertices, 3);
+        assert_eq!(header.num_edges, 2);
+        assert_eq!(header.num_layers, 2);
+    }
+
+    #[test]
+    fn round_trip_persistent() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        g.delete_edge(5, 1, 2, None).unwrap();
+        g.delete_edge(3, 2, 3, Some("layer")).unwrap();
+        g.add_vertex(0, 4, NO_PROPS, None).unwrap();
+        g.delete_vertex(6, 4).unwrap();
+
+        let dir = TempDir::new("format").unwrap();
+        let path = dir.path().join("graph");
+        g.save_to_file(&path).unwrap();
+        let g2 = GraphWithDeletions::load_from_file(&path).unwrap();
+        assert_eq!(g, g2);
+        assert!(g2.window(6, 7).edge(1, 2).is_none());
+        assert!(g2.window(5, 6).vertex(4).is_some());
+        assert!(g2.window(7, 10).vertex(4).is_none());
+        assert_eq!(
+            read_header(&path).unwrap().unwrap().kind,
+            GraphKind::Persistent
+        );
+
+        assert!(matches!(
+            Graph::load_from_file(&path),
+            Err(GraphError::GraphLoadError)
+        ));
+    }
+
+    #[test]
+    fn load_legacy_files() {
+        let path = resource("legacy_event_graph.bin");
+        assert_eq!(read_header(&path).unwrap(), None);
+        let g = Graph::load_from_file(&path).unwrap();
+        assert_eq!(g.num_vertices(), 3);
+        assert_eq!(g.num_edges(), 2);
+        assert_eq!(g.vertex("Alice").unwrap().name(), "Alice");
+        assert_eq!(
+            g.vertex("Alice").unwrap().properties().get("age"),
+            Some(Prop::I64(30))
+        );
+        assert_eq!(
+            g.vertex("Alice").unwrap().properties().get("team"),
+            Some(Prop::str("blue"))
+        );
+        assert_eq!(g.layer("friends").unwrap().num_edges(), 1);
+        assert_eq!(g.edge("Bob", "Carol").unwrap().explode().count(), 2);
+        assert_eq!(g.properties().get("version"), Some(Prop::U32(0)));
+
+        // migrated files are written in the current format
+        let dir = TempDir::new("format").unwrap();
+        let new_path = dir.path().join("graph");
+        g.save_to_file(&new_path).unwrap();
+        assert_eq!(
+            read_header(&new_path).unwrap().unwrap().version,
+            FORMAT_VERSION
+        );
+        assert_eq!(Graph::load_from_file(&new_path).unwrap(), g);
+
+        let g =
+            GraphWithDeletions::load_from_file(resource("legacy_persistent_graph.bin")).unwrap();
+        assert!(g.window(4, 5).edge(1, 2).is_some());
+        assert!(g.window(6, 10).edge(1, 2).is_none());
+        assert_eq!(
+            g.edge(2, 3).unwrap().properties().get("weight"),
+            Some(Prop::I64(7))
+        );
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let dir = TempDir::new("format").unwrap();
+        let path = dir.path().join("graph");
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend((FORMAT_VERSION + 1).to_le_bytes());
+        std::fs::write(&path, bytes).unwrap();
+        match Graph::load_from_file(&path) {
+            Err(GraphError::UnsupportedFormatVersion { version, supported }) => {
+                assert_eq!(version, FORMAT_VERSION + 1);
+                assert_eq!(supported, FORMAT_VERSION);
+            }
+            _ => panic!("expected unsupported version error"),
+        }
+    }
+
+    #[test]
+    fn corrupt_files() {
+        let dir = TempDir::new("format").unwrap();
+        let path = dir.path().join("graph");
+
+        std::fs::write(&path, b"not a graph").unwrap();
+        assert!(matches!(
+            Graph::load_from_file(&path),
+            Err(GraphError::CorruptGraphFile(_))
+        ));
+
+        let g = Graph::new();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        g.save_to_file(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() - 4]).unwrap();
+        assert!(matches!(
+            Graph::load_from_file(&path),
+            Err(GraphError::CorruptGraphFile(_))
+        ));
+    }
+}
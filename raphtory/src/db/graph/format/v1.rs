@@ -0,0 +1,300 @@
+//! Version 1 of the graph file format.
+//!
+//! The graph is described in terms of vertex ids, layer names, timestamps and property values only.
+//! Writing goes through the view APIs and reading rebuilds the graph through the mutation APIs, so
+//! changes to the in-memory layout do not affect the format.
+
+use crate::{
+    core::{
+        entities::{vertices::input_vertex::InputVertex, LayerIds},
+        utils::errors::GraphError,
+        Prop,
+    },
+    db::{
+        api::{
+            mutation::DeletionOps,
+            view::internal::{CoreGraphOps, TimeSemantics},
+        },
+        graph::graph::{Graph, InternalGraph},
+    },
+    prelude::*,
+};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct GraphV1 {
+    /// Layer names indexed by layer id, the first layer is the default layer
+    pub(super) layers: Vec<String>,
+    pub(super) vertices: Vec<VertexV1>,
+    pub(super) edges: Vec<EdgeV1>,
+    const_props: Vec<(String, PropV1)>,
+    temporal_props: Vec<(String, Vec<(i64, PropV1)>)>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct VertexV1 {
+    id: u64,
+    name: String,
+    additions: Vec<i64>,
+    deletions: Vec<i64>,
+    const_props: Vec<(String, PropV1)>,
+    temporal_props: Vec<(String, Vec<(i64, PropV1)>)>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct EdgeV1 {
+    src: u64,
+    dst: u64,
+    layers: Vec<EdgeLayerV1>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EdgeLayerV1 {
+    layer: usize,
+    /// One entry per update together with the temporal properties set by the update
+    additions: Vec<(i64, Vec<(String, PropV1)>)>,
+    deletions: Vec<i64>,
+    const_props: Vec<(String, PropV1)>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) enum PropV1 {
+    Str(String),
+    I32(i32),
+    I64(i64),
+    U32(u32),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    Bool(bool),
+    List(Vec<PropV1>),
+    Map(Vec<(String, PropV1)>),
+    DTime(NaiveDateTime),
+    Graph(Box<GraphV1>),
+}
+
+impl From<Prop> for PropV1 {
+    fn from(value: Prop) -> Self {
+        match value {
+            Prop::Str(v) => PropV1::Str(v),
+            Prop::I32(v) => PropV1::I32(v),
+            Prop::I64(v) => PropV1::I64(v),
+            Prop::U32(v) => PropV1::U32(v),
+            Prop::U64(v) => PropV1::U64(v),
+            Prop::F32(v) => PropV1::F32(v),
+            Prop::F64(v) => PropV1::F64(v),
+            Prop::Bool(v) => PropV1::Bool(v),
+            Prop::List(v) => PropV1::List(v.iter().cloned().map(|p| p.into()).collect()),
+            Prop::Map(v) => PropV1::Map(
+                v.iter()
+                    .map(|(k, p)| (k.clone(), p.clone().into()))
+                    .collect(),
+            ),
+            Prop::DTime(v) => PropV1::DTime(v),
+            Prop::Graph(g) => PropV1::Graph(Box::new(GraphV1::encode(&g.0))),
+        }
+    }
+}
+
+impl PropV1 {
+    pub(super) fn decode(self) -> Result<Prop, GraphError> {
+        Ok(match self {
+            PropV1::Str(v) => Prop::Str(v),
+            PropV1::I32(v) => Prop::I32(v),
+            PropV1::I64(v) => Prop::I64(v),
+            PropV1::U32(v) => Prop::U32(v),
+            PropV1::U64(v) => Prop::U64(v),
+            PropV1::F32(v) => Prop::F32(v),
+            PropV1::F64(v) => Prop::F64(v),
+            PropV1::Bool(v) => Prop::Bool(v),
+            PropV1::List(v) => Prop::List(Arc::new(
+                v.into_iter()
+                    .map(|p| p.decode())
+                    .collect::<Result<_, _>>()?,
+            )),
+            PropV1::Map(v) => Prop::Map(Arc::new(
+                v.into_iter()
+                    .map(|(k, p)| Ok((k, p.decode()?)))
+                    .collect::<Result<HashMap<_, _>, GraphError>>()?,
+            )),
+            PropV1::DTime(v) => Prop::DTime(v),
+            PropV1::Graph(g) => Prop::Graph(Graph(Arc::new(g.decode()?))),
+        })
+    }
+}
+
+/// A vertex with its original id and name, which may not be derivable from each other
+#[derive(Clone, Copy)]
+struct StoredVertex<'a> {
+    id: u64,
+    name: &'a str,
+}
+
+impl<'a> InputVertex for StoredVertex<'a> {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn id_str(&self) -> Option<&str> {
+        Some(self.name)
+    }
+}
+
+fn encode_props<I: IntoIterator<Item = (String, Prop)>>(props: I) -> Vec<(String, PropV1)> {
+    props.into_iter().map(|(k, v)| (k, v.into())).collect()
+}
+
+pub(super) fn encode_props_history<I: Iterator<Item = (i64, Prop)>>(
+    history: I,
+) -> Vec<(i64, PropV1)> {
+    history.map(|(t, v)| (t, v.into())).collect()
+}
+
+fn decode_props(props: Vec<(String, PropV1)>) -> Result<Vec<(String, Prop)>, GraphError> {
+    props
+        .into_iter()
+        .map(|(k, v)| Ok((k, v.decode()?)))
+        .collect()
+}
+
+impl GraphV1 {
+    pub(super) fn encode(g: &InternalGraph) -> Self {
+        let layers = g.get_layer_names_from_ids(LayerIds::All);
+        let edges = g
+            .edges()
+            .map(|e| EdgeV1 {
+                src: e.src().id(),
+                dst: e.dst().id(),
+                layers: e
+                    .explode_layers()
+                    .map(|ee| {
+                        let layer = *ee.edge.layer().unwrap();
+                        EdgeLayerV1 {
+                            layer,
+                            additions: ee
+                                .explode()
+                                .map(|ex| {
+                                    (
+                                        ex.time().unwrap(),
+                                        encode_props(
+                                            ex.properties().temporal().collect_properties(),
+                                        ),
+                                    )
+                                })
+                                .collect(),
+                            deletions: g.edge_deletion_history(e.edge, LayerIds::One(layer)),
+                            const_props: encode_props(ee.properties().constant()),
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+        let vertices = g
+            .vertices()
+            .iter()
+            .map(|v| VertexV1 {
+                id: v.id(),
+                name: v.name(),
+                additions: v.history(),
+                deletions: g.vertex_deletion_history(v.vertex),
+                // the name is stored separately, the type is stored as a constant property
+                const_props: encode_props(
+                    v.properties()
+                        .constant()
+                        .into_iter()
+                        .filter(|(k, _)| k != "_id")
+                        .chain(v.vertex_type().map(|t| ("_type".to_string(), Prop::Str(t)))),
+                ),
+                // properties set in a layer are stored in the vertex layers of version 2
+                temporal_props: v
+                    .properties()
+                    .temporal()
+                    .keys()
+                    .map(|k| {
+                        let history = g.temporal_vertex_prop_vec(v.vertex, &k, LayerIds::None);
+                        (k.to_string(), encode_props_history(history.into_iter()))
+                    })
+                    .filter(|(_, history)| !history.is_empty())
+                    .collect(),
+            })
+            .collect();
+        Self {
+            layers,
+            vertices,
+            edges,
+            const_props: encode_props(g.properties().constant()),
+            temporal_props: g
+                .properties()
+                .temporal()
+                .iter()
+                .map(|(k, prop)| (k.clone(), encode_props_history(prop.iter())))
+                .collect(),
+        }
+    }
+
+    pub(super) fn decode(self) -> Result<InternalGraph, GraphError> {
+        let GraphV1 {
+            layers,
+            vertices,
+            edges,
+            const_props,
+            temporal_props,
+        } = self;
+        let g = InternalGraph::default();
+        // create the layers up front such that they keep their ids
+        for layer in layers.iter().skip(1) {
+            g.inner().edge_meta.get_or_create_layer_id(layer.clone());
+        }
+        // Add edges first so we definitely have all associated vertices (important in case of persistent edges)
+        for edge in edges {
+            for edge_layer in edge.layers {
+                let layer = match edge_layer.layer {
+                    0 => None,
+                    id => Some(layers.get(id).ok_or_else(|| {
+                        GraphError::CorruptGraphFile(format!("edge refers to unknown layer {id}"))
+                    })?),
+                }
+                .map(|name| name.as_str());
+                for (t, props) in edge_layer.additions {
+                    g.add_edge(t, edge.src, edge.dst, decode_props(props)?, layer)?;
+                }
+                for t in edge_layer.deletions {
+                    g.delete_edge(t, edge.src, edge.dst, layer)?;
+                }
+                g.add_edge_properties(
+                    edge.src,
+                    edge.dst,
+                    decode_props(edge_layer.const_props)?,
+                    layer,
+                )?;
+            }
+        }
+        for vertex in vertices {
+            let v = StoredVertex {
+                id: vertex.id,
+                name: &vertex.name,
+            };
+            for t in vertex.additions {
+                g.add_vertex(t, v, NO_PROPS, None)?;
+            }
+            for (name, history) in vertex.temporal_props {
+                for (t, prop) in history {
+                    g.add_vertex(t, v, [(name.clone(), prop.decode()?)], None)?;
+                }
+            }
+            g.add_vertex_properties(v, decode_props(vertex.const_props)?)?;
+            for t in vertex.deletions {
+                g.delete_vertex(t, v)?;
+            }
+        }
+        for (name, history) in temporal_props {
+            for (t, prop) in history {
+                g.add_properties(t, [(name.clone(), prop.decode()?)])?;
+            }
+        }
+        g.add_static_properties(decode_props(const_props)?)?;
+        Ok(g)
+    }
+}
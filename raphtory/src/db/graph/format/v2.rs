@@ -0,0 +1,103 @@
+//! Version 2 of the graph file format.
+//!
+//! Version 2 appends the vertex layers to the body of version 1, i.e., the additions and temporal
+//! properties of vertices that were set in a layer. The version 1 part of the body only holds the
+//! vertex properties set without a layer. Graphs stored as property values are encoded as
+//! version 1 bodies and do not keep their vertex layers.
+
+use super::v1::{encode_props_history, PropV1};
+use crate::{
+    core::utils::errors::GraphError,
+    db::{api::view::internal::CoreGraphOps, graph::graph::InternalGraph},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct VertexLayersV2 {
+    /// Vertex ids with the layers the vertex was added to
+    vertices: Vec<(u64, Vec<VertexLayerV2>)>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct VertexLayerV2 {
+    layer: usize,
+    additions: Vec<i64>,
+    temporal_props: Vec<(String, Vec<(i64, PropV1)>)>,
+}
+
+impl VertexLayersV2 {
+    pub(super) fn encode(g: &InternalGraph) -> Self {
+        let vertices = g
+            .vertices()
+            .iter()
+            .filter_map(|v| {
+                let vertex = g.core_vertex(v.vertex);
+                let prop_names: Vec<_> = v
+                    .properties()
+                    .temporal()
+                    .keys()
+                    .map(|k| k.to_string())
+                    .collect();
+                let layers: Vec<_> = vertex
+                    .layer_ids_iter()
+                    .filter_map(|layer| {
+                        let additions: Vec<i64> = vertex
+                            .layer_timestamps(layer)
+                            .map(|ts| ts.iter().copied().collect())
+                            .unwrap_or_default();
+                        let temporal_props: Vec<_> = prop_names
+                            .iter()
+                            .filter_map(|name| {
+                                let prop = g.temporal_vertex_layer_prop(v.vertex, name, layer)?;
+                                Some((name.clone(), encode_props_history(prop.iter())))
+                            })
+                            .collect();
+                        (!additions.is_empty() || !temporal_props.is_empty()).then_some(
+                            VertexLayerV2 {
+                                layer,
+                                additions,
+                                temporal_props,
+                            },
+                        )
+                    })
+                    .collect();
+                (!layers.is_empty()).then_some((v.id(), layers))
+            })
+            .collect();
+        Self { vertices }
+    }
+
+    /// Add the vertex layers to `g`, `layers` are the layer names of the version 1 body
+    pub(super) fn decode_into(
+        self,
+        g: &InternalGraph,
+        layers: &[String],
+    ) -> Result<(), GraphError> {
+        for (id, vertex_layers) in self.vertices {
+            for vertex_layer in vertex_layers {
+                let layer = layers.get(vertex_layer.layer).ok_or_else(|| {
+                    GraphError::CorruptGraphFile(format!(
+                        "vertex refers to unknown layer {}",
+                        vertex_layer.layer
+                    ))
+                })?;
+                for t in vertex_layer.additions {
+                    g.add_vertex_to_layer(t, id, NO_PROPS, None, layer)?;
+                }
+                for (name, history) in vertex_layer.temporal_props {
+                    for (t, prop) in history {
+                        g.add_vertex_to_layer(
+                            t,
+                            id,
+                            [(name.clone(), prop.decode()?)],
+                            None,
+                            layer,
+                        )?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -9,8 +9,8 @@
 //! ```rust
 //! use raphtory::prelude::*;
 //! let graph = Graph::new();
-//! graph.add_vertex(0, "Alice", NO_PROPS).unwrap();
-//! graph.add_vertex(1, "Bob", NO_PROPS).unwrap();
+//! graph.add_vertex(0, "Alice", NO_PROPS, None).unwrap();
+//! graph.add_vertex(1, "Bob", NO_PROPS, None).unwrap();
 //! graph.add_edge(2, "Alice", "Bob", NO_PROPS, None).unwrap();
 //! graph.num_edges();
 //! ```
@@ -131,6 +131,63 @@ impl Graph {
         MaterializedGraph::from(self.clone()).save_to_file(path)
     }
 
+    /// Record all subsequent mutations in the write-ahead log at `path`
+    ///
+    /// Entries are appended if the log already exists.
+    pub fn enable_wal<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        self.0.enable_wal(path)
+    }
+
+    /// Stop recording mutations in the write-ahead log
+    pub fn disable_wal(&self) -> Result<(), GraphError> {
+        self.0.disable_wal()
+    }
+
+    /// Flush the write-ahead log to disk
+    pub fn sync_wal(&self) -> Result<(), GraphError> {
+        self.0.sync_wal()
+    }
+
+    /// Number of mutations recorded in the write-ahead log since the last checkpoint
+    pub fn wal_len(&self) -> usize {
+        self.0.wal_len()
+    }
+
+    /// Write a snapshot of the graph to `path` and truncate the write-ahead log
+    ///
+    /// Call this periodically while ingesting to bound the size of the log and the recovery time.
+    pub fn checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        self.0
+            .checkpoint(|| MaterializedGraph::from(self.clone()).save_snapshot(path))
+    }
+
+    /// Recover a graph from the snapshot at `snapshot` and the write-ahead log at `wal`
+    ///
+    /// The snapshot is loaded if it exists, all mutations in the log are replayed on top of it
+    /// and the log is attached to the recovered graph such that it keeps recording mutations.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use raphtory::prelude::*;
+    /// let g = Graph::recover("path/to/graph.bincode", "path/to/graph.wal").unwrap();
+    /// g.add_edge(1, "Alice", "Bob", NO_PROPS, None).unwrap();
+    /// g.checkpoint("path/to/graph.bincode").unwrap();
+    /// ```
+    pub fn recover<P: AsRef<Path>, Q: AsRef<Path>>(
+        snapshot: P,
+        wal: Q,
+    ) -> Result<Self, GraphError> {
+        let g = if snapshot.as_ref().exists() {
+            Self::load_from_file(snapshot)?
+        } else {
+            Self::new()
+        };
+        g.0.replay_wal(&wal)?;
+        g.0.enable_wal(wal)?;
+        Ok(g)
+    }
+
     pub fn as_arc(&self) -> Arc<InternalGraph> {
         self.0.clone()
     }
@@ -161,7 +218,6 @@ mod db_tests {
     };
     use chrono::NaiveDateTime;
     use itertools::Itertools;
-    use quickcheck::Arbitrary;
     use std::collections::{HashMap, HashSet};
     use tempdir::TempDir;
 
@@ -171,7 +227,7 @@ mod db_tests {
 
         let expected_len = vs.iter().map(|(_, v)| v).sorted().dedup().count();
         for (t, v) in vs {
-            g.add_vertex(t, v, NO_PROPS)
+            g.add_vertex(t, v, NO_PROPS, None)
                 .map_err(|err| println!("{:?}", err))
                 .ok();
         }
@@ -185,7 +241,7 @@ mod db_tests {
 
         let expected_len = vs.iter().sorted().dedup().count();
         for (t, name) in vs.iter().enumerate() {
-            g.add_vertex(t as i64, name.clone(), NO_PROPS)
+            g.add_vertex(t as i64, name.clone(), NO_PROPS, None)
                 .map_err(|err| println!("{:?}", err))
                 .ok();
         }
@@ -392,7 +448,7 @@ mod db_tests {
         assert_eq!(g.latest_time(), None);
         assert_eq!(g.earliest_time(), None);
 
-        g.add_vertex(5, 1, NO_PROPS)
+        g.add_vertex(5, 1, NO_PROPS, None)
             .map_err(|err| println!("{:?}", err))
             .ok();
 
@@ -405,7 +461,7 @@ mod db_tests {
         assert_eq!(g.latest_time(), Some(10));
         assert_eq!(g.earliest_time(), Some(10));
 
-        g.add_vertex(5, 1, NO_PROPS)
+        g.add_vertex(5, 1, NO_PROPS, None)
             .map_err(|err| println!("{:?}", err))
             .ok();
         assert_eq!(g.latest_time(), Some(10));
@@ -434,7 +490,7 @@ mod db_tests {
         .unwrap();
         g.add_edge(0, 22, 33, NO_PROPS, None).unwrap();
         g.add_edge(0, 33, 11, NO_PROPS, None).unwrap();
-        g.add_vertex(0, 11, vec![("temp".to_string(), Prop::Bool(true))])
+        g.add_vertex(0, 11, vec![("temp".to_string(), Prop::Bool(true))], None)
             .unwrap();
         let v11 = g.vertex(11).unwrap();
         let v22 = g.vertex(22).unwrap();
@@ -481,7 +537,7 @@ mod db_tests {
     fn temporal_props_vertex() {
         let g = Graph::new();
 
-        g.add_vertex(0, 1, [("cool".to_string(), Prop::Bool(true))])
+        g.add_vertex(0, 1, [("cool".to_string(), Prop::Bool(true))], None)
             .unwrap();
 
         let v = g.vertex(1).unwrap();
@@ -490,7 +546,7 @@ mod db_tests {
         assert_eq!(actual, Some(Prop::Bool(true)));
 
         // we flip cool from true to false after t 3
-        g.add_vertex(3, 1, [("cool".to_string(), Prop::Bool(false))])
+        g.add_vertex(3, 1, [("cool".to_string(), Prop::Bool(false))], None)
             .unwrap();
 
         let wg = g.window(3, 15);
@@ -584,9 +640,9 @@ mod db_tests {
     fn test_add_vertex_with_strings() {
         let g = Graph::new();
 
-        g.add_vertex(0, "haaroon", NO_PROPS).unwrap();
-        g.add_vertex(1, "hamza", NO_PROPS).unwrap();
-        g.add_vertex(1, 831, NO_PROPS).unwrap();
+        g.add_vertex(0, "haaroon", NO_PROPS, None).unwrap();
+        g.add_vertex(1, "hamza", NO_PROPS, None).unwrap();
+        g.add_vertex(1, 831, NO_PROPS, None).unwrap();
 
         assert!(g.has_vertex(831));
         assert!(g.has_vertex("haaroon"));
@@ -797,16 +853,16 @@ mod db_tests {
     fn check_vertex_history() {
         let g = Graph::new();
 
-        g.add_vertex(1, 1, NO_PROPS).unwrap();
-        g.add_vertex(2, 1, NO_PROPS).unwrap();
-        g.add_vertex(3, 1, NO_PROPS).unwrap();
-        g.add_vertex(4, 1, NO_PROPS).unwrap();
-        g.add_vertex(8, 1, NO_PROPS).unwrap();
+        g.add_vertex(1, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(2, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(3, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(4, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(8, 1, NO_PROPS, None).unwrap();
 
-        g.add_vertex(4, "Lord Farquaad", NO_PROPS).unwrap();
-        g.add_vertex(6, "Lord Farquaad", NO_PROPS).unwrap();
-        g.add_vertex(7, "Lord Farquaad", NO_PROPS).unwrap();
-        g.add_vertex(8, "Lord Farquaad", NO_PROPS).unwrap();
+        g.add_vertex(4, "Lord Farquaad", NO_PROPS, None).unwrap();
+        g.add_vertex(6, "Lord Farquaad", NO_PROPS, None).unwrap();
+        g.add_vertex(7, "Lord Farquaad", NO_PROPS, None).unwrap();
+        g.add_vertex(8, "Lord Farquaad", NO_PROPS, None).unwrap();
 
         let times_of_one = g.vertex(1).unwrap().history();
         let times_of_farquaad = g.vertex("Lord Farquaad").unwrap().history();
@@ -877,21 +933,21 @@ mod db_tests {
     fn check_vertex_history_multiple_shards() {
         let g = Graph::new();
 
-        g.add_vertex(1, 1, NO_PROPS).unwrap();
-        g.add_vertex(2, 1, NO_PROPS).unwrap();
-        g.add_vertex(3, 1, NO_PROPS).unwrap();
-        g.add_vertex(4, 1, NO_PROPS).unwrap();
-        g.add_vertex(5, 2, NO_PROPS).unwrap();
-        g.add_vertex(6, 2, NO_PROPS).unwrap();
-        g.add_vertex(7, 2, NO_PROPS).unwrap();
-        g.add_vertex(8, 1, NO_PROPS).unwrap();
-        g.add_vertex(9, 2, NO_PROPS).unwrap();
-        g.add_vertex(10, 2, NO_PROPS).unwrap();
-
-        g.add_vertex(4, "Lord Farquaad", NO_PROPS).unwrap();
-        g.add_vertex(6, "Lord Farquaad", NO_PROPS).unwrap();
-        g.add_vertex(7, "Lord Farquaad", NO_PROPS).unwrap();
-        g.add_vertex(8, "Lord Farquaad", NO_PROPS).unwrap();
+        g.add_vertex(1, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(2, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(3, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(4, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(5, 2, NO_PROPS, None).unwrap();
+        g.add_vertex(6, 2, NO_PROPS, None).unwrap();
+        g.add_vertex(7, 2, NO_PROPS, None).unwrap();
+        g.add_vertex(8, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(9, 2, NO_PROPS, None).unwrap();
+        g.add_vertex(10, 2, NO_PROPS, None).unwrap();
+
+        g.add_vertex(4, "Lord Farquaad", NO_PROPS, None).unwrap();
+        g.add_vertex(6, "Lord Farquaad", NO_PROPS, None).unwrap();
+        g.add_vertex(7, "Lord Farquaad", NO_PROPS, None).unwrap();
+        g.add_vertex(8, "Lord Farquaad", NO_PROPS, None).unwrap();
 
         let times_of_one = g.vertex(1).unwrap().history();
         let times_of_farquaad = g.vertex("Lord Farquaad").unwrap().history();
@@ -929,7 +985,7 @@ mod db_tests {
         let latest_time = "2022-06-07 12:34:00".try_into_time().unwrap();
 
         let g = Graph::new();
-        g.add_vertex("2022-06-06T12:34:00.000", 0, NO_PROPS)
+        g.add_vertex("2022-06-06T12:34:00.000", 0, NO_PROPS, None)
             .unwrap();
         g.add_edge("2022-06-07T12:34:00", 1, 2, NO_PROPS, None)
             .unwrap();
@@ -939,7 +995,7 @@ mod db_tests {
         let g = Graph::new();
         let fmt = "%Y-%m-%d %H:%M";
 
-        g.add_vertex(CustomTime("2022-06-06 12:34", fmt), 0, NO_PROPS)
+        g.add_vertex(CustomTime("2022-06-06 12:34", fmt), 0, NO_PROPS, None)
             .unwrap();
         g.add_edge(CustomTime("2022-06-07 12:34", fmt), 1, 2, NO_PROPS, None)
             .unwrap();
@@ -1112,9 +1168,9 @@ mod db_tests {
     #[test]
     fn test_vertex_early_late_times() {
         let g = Graph::new();
-        g.add_vertex(1, 1, NO_PROPS).unwrap();
-        g.add_vertex(2, 1, NO_PROPS).unwrap();
-        g.add_vertex(3, 1, NO_PROPS).unwrap();
+        g.add_vertex(1, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(2, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(3, 1, NO_PROPS, None).unwrap();
 
         assert_eq!(g.vertex(1).unwrap().earliest_time(), Some(1));
         assert_eq!(g.vertex(1).unwrap().latest_time(), Some(3));
@@ -1126,9 +1182,9 @@ mod db_tests {
     #[test]
     fn test_vertex_ids() {
         let g = Graph::new();
-        g.add_vertex(1, 1, NO_PROPS).unwrap();
-        g.add_vertex(1, 2, NO_PROPS).unwrap();
-        g.add_vertex(2, 3, NO_PROPS).unwrap();
+        g.add_vertex(1, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(1, 2, NO_PROPS, None).unwrap();
+        g.add_vertex(2, 3, NO_PROPS, None).unwrap();
 
         assert_eq!(g.vertices().id().collect::<Vec<u64>>(), vec![1, 2, 3]);
 
@@ -1424,7 +1480,7 @@ mod db_tests {
     fn vertex_from_id_is_consistent(vertices: Vec<u64>) -> bool {
         let g = Graph::new();
         for v in vertices.iter() {
-            g.add_vertex(0, *v, NO_PROPS).unwrap();
+            g.add_vertex(0, *v, NO_PROPS, None).unwrap();
         }
         g.vertices()
             .name()
@@ -1521,21 +1577,4 @@ mod db_tests {
         );
         correct
     }
-
-    // non overlaping time intervals
-    #[derive(Clone, Debug)]
-    struct Intervals(Vec<(i64, i64)>);
-
-    impl Arbitrary for Intervals {
-        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
-            let mut some_nums = Vec::<i64>::arbitrary(g);
-            some_nums.sort();
-            let intervals = some_nums
-                .into_iter()
-                .tuple_windows()
-                .filter(|(a, b)| a != b)
-                .collect_vec();
-            Intervals(intervals)
-        }
-    }
 }
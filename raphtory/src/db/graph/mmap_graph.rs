@@ -0,0 +1,2120 @@
+//! A read-only graph stored as memory-mapped columns on disk.
+//!
+//! [`MmapGraph`] lays out vertices, adjacency lists, time indices and properties as column
+//! files in a directory, so graphs larger than RAM can be queried without loading them into
+//! memory. It implements the same internal view traits as the in-memory graph, hence all
+//! views and algorithms work on it unchanged. The graph has event semantics, graphs with
+//! deletion semantics cannot be written.
+//!
+//! The directory contains
+//!
+//! * `graph.meta` - counts, property and layer names and graph properties (bincode),
+//! * `vertex_gid` - the global id of each vertex and `gid_index` - `(gid, vid)` pairs sorted by gid,
+//! * `vertex_t`, `vertex_del` - the sorted addition and deletion timestamps of each vertex,
+//! * `vertex_layer_t` - the sorted timestamps at which a vertex was added to a layer, one row per
+//!   vertex and layer,
+//! * `adj_out`, `adj_in` - `(neighbour, edge)` pairs sorted by neighbour, one row per vertex and layer,
+//! * `edge_src`, `edge_dst` - the endpoints of each edge,
+//! * `edge_add`, `edge_del` - sorted `(t, event id)` pairs, one row per edge and layer,
+//! * `<kind>_<prop id>` - one column per property listed in `graph.meta`, see [`PropColumnKind`]
+//!   for the rows of each kind. A column holds the offsets of the values of each row, the
+//!   `(t, event id)` of each value for temporal properties and the values themselves. Values of
+//!   numeric, boolean and string properties are stored natively, other values and properties
+//!   whose type differs between vertices or edges are stored as bincode, one value at a time.
+//!   Vertex names are served from the column of the constant `_id` property.
+//!
+//! Structural and time queries are answered from the columns directly and reading a property
+//! only decodes the values of that property. Only the offsets are validated when the graph is
+//! opened, reading a corrupt value panics.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::db::graph::mmap_graph::MmapGraph;
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+//! g.add_edge(2, 2, 3, NO_PROPS, None).unwrap();
+//!
+//! let dir = tempdir::TempDir::new("mmap_graph").unwrap();
+//! let mg = MmapGraph::write(&g, dir.path()).unwrap();
+//! assert_eq!(mg.num_edges(), 2);
+//! assert_eq!(mg.window(2, 3).num_edges(), 1);
+//! ```
+
+use crate::{
+    core::{
+        entities::{
+            edges::{
+                edge_ref::EdgeRef,
+                edge_store::{EdgeLayer, EdgeStore},
+            },
+            graph::tgraph::TemporalGraph,
+            properties::{
+                graph_props::GraphProps,
+                props::{Meta, Props},
+                tprop::{LockedLayeredTProp, TProp},
+            },
+            vertices::{vertex_ref::VertexRef, vertex_store::VertexStore},
+            LayerIds, EID, VID,
+        },
+        storage::{
+            locked_view::LockedView,
+            mmap::{Column, ColumnWriter, OffsetColumn, OffsetColumnWriter},
+            timeindex::{AsTime, LayeredIndex, LockedLayeredIndex, TimeIndex, TimeIndexEntry},
+            ArcEntry,
+        },
+        utils::errors::GraphError,
+        Direction, Prop, PropUnwrap,
+    },
+    db::api::{
+        properties::internal::{
+            ConstPropertiesOps, Key, TemporalPropertiesOps, TemporalPropertyViewOps,
+        },
+        view::{
+            internal::{
+                Base, CoreDeletionOps, CoreGraphOps, DynamicGraph, EdgeFilter, EdgeFilterOps,
+                GraphOps, InternalMaterialize, IntoDynamic, LayerOps, MaterializedGraph, Static,
+                TimeSemantics,
+            },
+            BoxedIter, GraphViewOps, Layer,
+        },
+    },
+    db::graph::graph::{Graph, InternalGraph},
+};
+use itertools::Itertools;
+use parking_lot::RwLockReadGuard;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Formatter},
+    fs::{self, File},
+    io::{BufReader, BufWriter},
+    iter,
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+const FORMAT_VERSION: u32 = 1;
+const META_FILE: &str = "graph.meta";
+
+#[derive(Serialize)]
+struct GraphMetaRef<'a> {
+    version: u32,
+    little_endian: bool,
+    num_vertices: usize,
+    num_edges: usize,
+    num_layers: usize,
+    earliest_time: Option<i64>,
+    latest_time: Option<i64>,
+    vertex_meta: &'a Meta,
+    edge_meta: &'a Meta,
+    graph_props: &'a GraphProps,
+    prop_columns: Vec<PropColumnMeta>,
+}
+
+#[derive(Deserialize)]
+struct GraphMeta {
+    version: u32,
+    little_endian: bool,
+    num_vertices: usize,
+    num_edges: usize,
+    num_layers: usize,
+    earliest_time: Option<i64>,
+    latest_time: Option<i64>,
+    vertex_meta: Meta,
+    edge_meta: Meta,
+    graph_props: GraphProps,
+    prop_columns: Vec<PropColumnMeta>,
+}
+
+pub(crate) struct MmapStorage {
+    path: PathBuf,
+    meta: GraphMeta,
+    vertex_gid: Column<u64>,
+    gid_index: Column<[u64; 2]>,
+    vertex_t: OffsetColumn<i64>,
+    vertex_del: OffsetColumn<i64>,
+    vertex_layer_t: OffsetColumn<i64>,
+    adj_out: OffsetColumn<[u64; 2]>,
+    adj_in: OffsetColumn<[u64; 2]>,
+    edge_src: Column<u64>,
+    edge_dst: Column<u64>,
+    edge_add: OffsetColumn<[i64; 2]>,
+    edge_del: OffsetColumn<[i64; 2]>,
+    props: BTreeMap<(PropColumnKind, usize), PropColumn>,
+}
+
+/// A read-only graph backed by memory-mapped column files
+#[derive(Clone)]
+pub struct MmapGraph(Arc<MmapStorage>);
+
+impl Static for MmapGraph {}
+
+impl IntoDynamic for MmapGraph {
+    fn into_dynamic(self) -> DynamicGraph {
+        DynamicGraph::new(self)
+    }
+}
+
+impl Debug for MmapGraph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MmapGraph(path={}, num_vertices={}, num_edges={})",
+            self.0.path.display(),
+            self.num_vertices(),
+            self.num_edges()
+        )
+    }
+}
+
+impl MmapGraph {
+    /// Write the graph (or view) as column files into the directory `path` and open them.
+    /// Existing column files in the directory are overwritten.
+    ///
+    /// Returns [`GraphError::GraphLoadError`] for graphs with deletion semantics, as the
+    /// memory-mapped graph only supports event semantics.
+    pub fn write<G: GraphViewOps, P: AsRef<Path>>(graph: &G, path: P) -> Result<Self, GraphError> {
+        match graph.materialize()? {
+            MaterializedGraph::EventGraph(g) => write_columns(g.base().inner(), path.as_ref())?,
+            MaterializedGraph::PersistentGraph(_) => return Err(GraphError::GraphLoadError),
+        }
+        Self::load(path)
+    }
+
+    /// Open a graph previously written with [`MmapGraph::write`]
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, GraphError> {
+        let path = path.as_ref().to_path_buf();
+        let meta: GraphMeta =
+            bincode::deserialize_from(BufReader::new(File::open(path.join(META_FILE))?))?;
+        if meta.version != FORMAT_VERSION {
+            return Err(GraphError::UnsupportedFormatVersion {
+                version: meta.version,
+                supported: FORMAT_VERSION,
+            });
+        }
+        if meta.little_endian != cfg!(target_endian = "little") {
+            return Err(GraphError::GraphLoadError);
+        }
+        let props = meta
+            .prop_columns
+            .iter()
+            .map(|column| {
+                let rows = column.kind.num_rows(&meta);
+                let opened = PropColumn::open(&path, column, rows)?;
+                Ok(((column.kind, column.prop_id), opened))
+            })
+            .collect::<Result<_, GraphError>>()?;
+        let storage = MmapStorage {
+            vertex_gid: Column::open(path.join("vertex_gid.col"))?,
+            gid_index: Column::open(path.join("gid_index.col"))?,
+            vertex_t: OffsetColumn::open(&path, "vertex_t")?,
+            vertex_del: OffsetColumn::open(&path, "vertex_del")?,
+            vertex_layer_t: OffsetColumn::open(&path, "vertex_layer_t")?,
+            adj_out: OffsetColumn::open(&path, "adj_out")?,
+            adj_in: OffsetColumn::open(&path, "adj_in")?,
+            edge_src: Column::open(path.join("edge_src.col"))?,
+            edge_dst: Column::open(path.join("edge_dst.col"))?,
+            edge_add: OffsetColumn::open(&path, "edge_add")?,
+            edge_del: OffsetColumn::open(&path, "edge_del")?,
+            props,
+            path,
+            meta,
+        };
+        if !storage.has_consistent_lengths() {
+            return Err(GraphError::GraphLoadError);
+        }
+        Ok(Self(Arc::new(storage)))
+    }
+
+    /// The directory holding the column files
+    pub fn path(&self) -> &Path {
+        &self.0.path
+    }
+}
+
+fn write_columns<const N: usize>(g: &TemporalGraph<N>, dir: &Path) -> Result<(), GraphError> {
+    fs::create_dir_all(dir)?;
+    let num_layers = g.num_layers();
+    let num_vertices = g.internal_num_vertices();
+    let num_edges = g.storage.edges.len();
+
+    let nodes = g.storage.nodes.read_lock();
+    let mut vertex_gid = ColumnWriter::create(dir.join("vertex_gid.col"))?;
+    let mut vertex_t = OffsetColumnWriter::create(dir, "vertex_t")?;
+    let mut vertex_del = OffsetColumnWriter::create(dir, "vertex_del")?;
+    let mut vertex_layer_t = OffsetColumnWriter::create(dir, "vertex_layer_t")?;
+    let mut adj_out = OffsetColumnWriter::create(dir, "adj_out")?;
+    let mut adj_in = OffsetColumnWriter::create(dir, "adj_in")?;
+    let mut gid_index = Vec::with_capacity(num_vertices);
+    for vid in 0..num_vertices {
+        let v = nodes.get(vid);
+        vertex_gid.push(v.global_id())?;
+        gid_index.push([v.global_id(), vid as u64]);
+        vertex_t.push_iter(v.timestamps().iter().copied())?;
+        vertex_del.push_iter(v.deletions().iter().copied())?;
+        for layer in 0..num_layers {
+            vertex_layer_t.push_iter(
+                v.layer_timestamps(layer)
+                    .into_iter()
+                    .flat_map(|t| t.iter().copied()),
+            )?;
+            for (d, writer) in [(Direction::OUT, &mut adj_out), (Direction::IN, &mut adj_in)] {
+                writer.push_iter(v.layers.get(layer).into_iter().flat_map(|adj| {
+                    adj.iter(d)
+                        .map(|(n, e)| [usize::from(n) as u64, usize::from(e) as u64])
+                }))?;
+            }
+        }
+    }
+    vertex_gid.finish()?;
+    vertex_t.finish()?;
+    vertex_del.finish()?;
+    vertex_layer_t.finish()?;
+    adj_out.finish()?;
+    adj_in.finish()?;
+    gid_index.sort_unstable();
+    let mut gid_index_writer = ColumnWriter::create(dir.join("gid_index.col"))?;
+    gid_index_writer.push_slice(&gid_index)?;
+    gid_index_writer.finish()?;
+
+    let edges = g.storage.edges.read_lock();
+    let mut edge_src = ColumnWriter::create(dir.join("edge_src.col"))?;
+    let mut edge_dst = ColumnWriter::create(dir.join("edge_dst.col"))?;
+    let mut edge_add = OffsetColumnWriter::create(dir, "edge_add")?;
+    let mut edge_del = OffsetColumnWriter::create(dir, "edge_del")?;
+    for eid in 0..num_edges {
+        let e = edges.get(eid);
+        edge_src.push(usize::from(e.src()) as u64)?;
+        edge_dst.push(usize::from(e.dst()) as u64)?;
+        for layer in 0..num_layers {
+            for (t_index, writer) in [
+                (e.additions().get(layer), &mut edge_add),
+                (e.deletions().get(layer), &mut edge_del),
+            ] {
+                writer.push_iter(
+                    t_index
+                        .into_iter()
+                        .flat_map(|t| t.iter())
+                        .map(|t| [*t.t(), t.i() as i64]),
+                )?;
+            }
+        }
+    }
+    edge_src.finish()?;
+    edge_dst.finish()?;
+    edge_add.finish()?;
+    edge_del.finish()?;
+
+    let mut prop_columns = vec![];
+    let vertex_props = |row: usize| nodes.get(row).props.as_ref();
+    let vertex_layer_props = |row: usize| nodes.get(row / num_layers).layer_props(row % num_layers);
+    let edge_props = |row: usize| edges.get(row / num_layers).layer(row % num_layers)?.props();
+    write_prop_columns(
+        dir,
+        PropColumnKind::VertexConst,
+        num_vertices,
+        vertex_props,
+        &mut prop_columns,
+    )?;
+    write_prop_columns(
+        dir,
+        PropColumnKind::VertexTemporal,
+        num_vertices,
+        vertex_props,
+        &mut prop_columns,
+    )?;
+    write_prop_columns(
+        dir,
+        PropColumnKind::VertexLayerTemporal,
+        num_vertices * num_layers,
+        vertex_layer_props,
+        &mut prop_columns,
+    )?;
+    write_prop_columns(
+        dir,
+        PropColumnKind::EdgeConst,
+        num_edges * num_layers,
+        edge_props,
+        &mut prop_columns,
+    )?;
+    write_prop_columns(
+        dir,
+        PropColumnKind::EdgeTemporal,
+        num_edges * num_layers,
+        edge_props,
+        &mut prop_columns,
+    )?;
+
+    // the metadata is written last so an interrupted write cannot be loaded
+    let meta = GraphMetaRef {
+        version: FORMAT_VERSION,
+        little_endian: cfg!(target_endian = "little"),
+        num_vertices,
+        num_edges,
+        num_layers,
+        earliest_time: g.graph_earliest_time(),
+        latest_time: g.graph_latest_time(),
+        vertex_meta: &g.vertex_meta,
+        edge_meta: &g.edge_meta,
+        graph_props: &g.graph_props,
+        prop_columns,
+    };
+    let mut writer = BufWriter::new(File::create(dir.join(META_FILE))?);
+    bincode::serialize_into(&mut writer, &meta)?;
+    writer
+        .into_inner()
+        .map_err(|e| e.into_error())?
+        .sync_all()?;
+    Ok(())
+}
+
+/// The vertices or edges a property column belongs to
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum PropColumnKind {
+    /// Constant vertex properties, one row per vertex
+    VertexConst,
+    /// Temporal vertex properties set without a layer, one row per vertex
+    VertexTemporal,
+    /// Temporal vertex properties set in a layer, one row per vertex and layer
+    VertexLayerTemporal,
+    /// Constant edge properties, one row per edge and layer
+    EdgeConst,
+    /// Temporal edge properties, one row per edge and layer
+    EdgeTemporal,
+}
+
+impl PropColumnKind {
+    fn is_static(&self) -> bool {
+        matches!(
+            self,
+            PropColumnKind::VertexConst | PropColumnKind::EdgeConst
+        )
+    }
+
+    fn num_rows(&self, meta: &GraphMeta) -> usize {
+        match self {
+            PropColumnKind::VertexConst | PropColumnKind::VertexTemporal => meta.num_vertices,
+            PropColumnKind::VertexLayerTemporal => meta.num_vertices * meta.num_layers,
+            PropColumnKind::EdgeConst | PropColumnKind::EdgeTemporal => {
+                meta.num_edges * meta.num_layers
+            }
+        }
+    }
+
+    fn column_name(&self, prop_id: usize) -> String {
+        let kind = match self {
+            PropColumnKind::VertexConst => "vertex_const",
+            PropColumnKind::VertexTemporal => "vertex_temporal",
+            PropColumnKind::VertexLayerTemporal => "vertex_layer_temporal",
+            PropColumnKind::EdgeConst => "edge_const",
+            PropColumnKind::EdgeTemporal => "edge_temporal",
+        };
+        format!("{kind}_{prop_id}")
+    }
+}
+
+/// How the values of a property column are stored
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+enum PropType {
+    Str,
+    I32,
+    I64,
+    U32,
+    U64,
+    F32,
+    F64,
+    Bool,
+    /// Values of any type, each encoded with bincode
+    Encoded,
+}
+
+impl PropType {
+    fn of(prop: &TProp) -> Option<Self> {
+        match prop {
+            TProp::Empty => None,
+            TProp::Str(_) => Some(PropType::Str),
+            TProp::I32(_) => Some(PropType::I32),
+            TProp::I64(_) => Some(PropType::I64),
+            TProp::U32(_) => Some(PropType::U32),
+            TProp::U64(_) => Some(PropType::U64),
+            TProp::F32(_) => Some(PropType::F32),
+            TProp::F64(_) => Some(PropType::F64),
+            TProp::Bool(_) => Some(PropType::Bool),
+            TProp::DTime(_) | TProp::Graph(_) | TProp::List(_) | TProp::Map(_) => {
+                Some(PropType::Encoded)
+            }
+        }
+    }
+
+    fn of_const(prop: &Prop) -> Self {
+        match prop {
+            Prop::Str(_) => PropType::Str,
+            Prop::I32(_) => PropType::I32,
+            Prop::I64(_) => PropType::I64,
+            Prop::U32(_) => PropType::U32,
+            Prop::U64(_) => PropType::U64,
+            Prop::F32(_) => PropType::F32,
+            Prop::F64(_) => PropType::F64,
+            Prop::Bool(_) => PropType::Bool,
+            Prop::DTime(_) | Prop::Graph(_) | Prop::List(_) | Prop::Map(_) => PropType::Encoded,
+        }
+    }
+
+    /// The type of a column holding values of both types
+    fn merge(self, other: Self) -> Self {
+        if self == other {
+            self
+        } else {
+            PropType::Encoded
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct PropColumnMeta {
+    kind: PropColumnKind,
+    prop_id: usize,
+    dtype: PropType,
+}
+
+enum PropValues {
+    Str(OffsetColumn<u8>),
+    I32(Column<i32>),
+    I64(Column<i64>),
+    U32(Column<u32>),
+    U64(Column<u64>),
+    F32(Column<f32>),
+    F64(Column<f64>),
+    Bool(Column<u8>),
+    Encoded(OffsetColumn<u8>),
+}
+
+impl PropValues {
+    fn open(dir: &Path, name: &str, dtype: PropType) -> Result<Self, GraphError> {
+        let path = dir.join(format!("{name}.col"));
+        let values = match dtype {
+            PropType::Str => PropValues::Str(OffsetColumn::open(dir, name)?),
+            PropType::I32 => PropValues::I32(Column::open(path)?),
+            PropType::I64 => PropValues::I64(Column::open(path)?),
+            PropType::U32 => PropValues::U32(Column::open(path)?),
+            PropType::U64 => PropValues::U64(Column::open(path)?),
+            PropType::F32 => PropValues::F32(Column::open(path)?),
+            PropType::F64 => PropValues::F64(Column::open(path)?),
+            PropType::Bool => PropValues::Bool(Column::open(path)?),
+            PropType::Encoded => PropValues::Encoded(OffsetColumn::open(dir, name)?),
+        };
+        Ok(values)
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            PropValues::Str(values) | PropValues::Encoded(values) => values.len(),
+            PropValues::I32(values) => values.len(),
+            PropValues::I64(values) => values.len(),
+            PropValues::U32(values) => values.len(),
+            PropValues::U64(values) => values.len(),
+            PropValues::F32(values) => values.len(),
+            PropValues::F64(values) => values.len(),
+            PropValues::Bool(values) => values.len(),
+        }
+    }
+
+    fn get(&self, j: usize) -> Result<Prop, GraphError> {
+        let prop = match self {
+            PropValues::Str(values) => {
+                Prop::Str(String::from_utf8(values.get(j).to_vec()).map_err(|err| {
+                    GraphError::CorruptGraphFile(format!("invalid string ({err})"))
+                })?)
+            }
+            PropValues::I32(values) => Prop::I32(values.get(j)),
+            PropValues::I64(values) => Prop::I64(values.get(j)),
+            PropValues::U32(values) => Prop::U32(values.get(j)),
+            PropValues::U64(values) => Prop::U64(values.get(j)),
+            PropValues::F32(values) => Prop::F32(values.get(j)),
+            PropValues::F64(values) => Prop::F64(values.get(j)),
+            PropValues::Bool(values) => match values.get(j) {
+                0 => Prop::Bool(false),
+                1 => Prop::Bool(true),
+                b => return Err(GraphError::CorruptGraphFile(format!("invalid boolean {b}"))),
+            },
+            PropValues::Encoded(values) => bincode::deserialize(values.get(j))
+                .map_err(|err| GraphError::CorruptGraphFile(format!("invalid value ({err})")))?,
+        };
+        Ok(prop)
+    }
+}
+
+/// The values of one property, stored as `<name>_rows.off` holding `rows + 1` offsets into the
+/// values, `<name>_t.col` holding the `(t, event id)` of each value of a temporal property and
+/// the values in `<name>.col` (with their offsets in `<name>.off` for strings and encoded values)
+struct PropColumn {
+    name: String,
+    rows: Column<u64>,
+    times: Option<Column<[i64; 2]>>,
+    values: PropValues,
+}
+
+impl PropColumn {
+    /// Open the column and check that its offsets are consistent, the values are decoded on access
+    fn open(dir: &Path, meta: &PropColumnMeta, num_rows: usize) -> Result<Self, GraphError> {
+        let name = meta.kind.column_name(meta.prop_id);
+        let rows = Column::open(dir.join(format!("{name}_rows.off")))?;
+        let times = if meta.kind.is_static() {
+            None
+        } else {
+            Some(Column::open(dir.join(format!("{name}_t.col")))?)
+        };
+        let values = PropValues::open(dir, &name, meta.dtype)?;
+        let offsets = rows.as_slice();
+        let valid = offsets.len() == num_rows + 1
+            && offsets.first() == Some(&0)
+            && offsets.windows(2).all(|w| w[0] <= w[1])
+            && offsets.last().copied() == Some(values.len() as u64)
+            && times.as_ref().map_or(true, |t| t.len() == values.len());
+        if !valid {
+            return Err(GraphError::CorruptGraphFile(format!(
+                "offsets of property column {name} are inconsistent with its values"
+            )));
+        }
+        Ok(Self {
+            name,
+            rows,
+            times,
+            values,
+        })
+    }
+
+    #[inline]
+    fn range(&self, row: usize) -> Range<usize> {
+        self.rows.get(row) as usize..self.rows.get(row + 1) as usize
+    }
+
+    #[inline]
+    fn has_row(&self, row: usize) -> bool {
+        !self.range(row).is_empty()
+    }
+
+    /// Decode a single value, corrupt values are not recoverable from the read-only view apis
+    fn value(&self, j: usize) -> Prop {
+        self.values
+            .get(j)
+            .unwrap_or_else(|err| panic!("property column {}: {err}", self.name))
+    }
+
+    fn constant(&self, row: usize) -> Option<Prop> {
+        let range = self.range(row);
+        (!range.is_empty()).then(|| self.value(range.start))
+    }
+
+    fn times(&self) -> &[[i64; 2]] {
+        self.times.as_ref().map_or(&[], |t| t.as_slice())
+    }
+
+    fn entries(&self, row: usize) -> impl Iterator<Item = (TimeIndexEntry, Prop)> + '_ {
+        self.range(row).map(|j| {
+            let [t, i] = self.times()[j];
+            (TimeIndexEntry::new(t, i as usize), self.value(j))
+        })
+    }
+
+    fn temporal(&self, row: usize) -> Option<TProp> {
+        if !self.has_row(row) {
+            return None;
+        }
+        let mut prop = TProp::default();
+        for (t, value) in self.entries(row) {
+            prop.set(t, value)
+                .expect("values of a temporal property have a single type");
+        }
+        Some(prop)
+    }
+
+    fn temporal_values(
+        &self,
+        row: usize,
+        w: Option<Range<i64>>,
+    ) -> impl Iterator<Item = (i64, Prop)> + '_ {
+        let range = self.range(row);
+        let times = &self.times()[range.clone()];
+        let window = match w {
+            None => 0..times.len(),
+            Some(w) => window_range(times, w, |[t, _]| *t),
+        };
+        (range.start + window.start..range.start + window.end)
+            .map(|j| (self.times()[j][0], self.value(j)))
+    }
+}
+
+enum PropValuesWriter {
+    Str(OffsetColumnWriter<u8>),
+    I32(ColumnWriter<i32>),
+    I64(ColumnWriter<i64>),
+    U32(ColumnWriter<u32>),
+    U64(ColumnWriter<u64>),
+    F32(ColumnWriter<f32>),
+    F64(ColumnWriter<f64>),
+    Bool(ColumnWriter<u8>),
+    Encoded(OffsetColumnWriter<u8>),
+}
+
+impl PropValuesWriter {
+    fn create(dir: &Path, name: &str, dtype: PropType) -> Result<Self, GraphError> {
+        let path = dir.join(format!("{name}.col"));
+        let writer = match dtype {
+            PropType::Str => PropValuesWriter::Str(OffsetColumnWriter::create(dir, name)?),
+            PropType::I32 => PropValuesWriter::I32(ColumnWriter::create(path)?),
+            PropType::I64 => PropValuesWriter::I64(ColumnWriter::create(path)?),
+            PropType::U32 => PropValuesWriter::U32(ColumnWriter::create(path)?),
+            PropType::U64 => PropValuesWriter::U64(ColumnWriter::create(path)?),
+            PropType::F32 => PropValuesWriter::F32(ColumnWriter::create(path)?),
+            PropType::F64 => PropValuesWriter::F64(ColumnWriter::create(path)?),
+            PropType::Bool => PropValuesWriter::Bool(ColumnWriter::create(path)?),
+            PropType::Encoded => PropValuesWriter::Encoded(OffsetColumnWriter::create(dir, name)?),
+        };
+        Ok(writer)
+    }
+
+    fn push(&mut self, prop: &Prop) -> Result<(), GraphError> {
+        match (self, prop) {
+            (PropValuesWriter::Str(w), Prop::Str(v)) => w.push_row(v.as_bytes())?,
+            (PropValuesWriter::I32(w), Prop::I32(v)) => w.push(*v)?,
+            (PropValuesWriter::I64(w), Prop::I64(v)) => w.push(*v)?,
+            (PropValuesWriter::U32(w), Prop::U32(v)) => w.push(*v)?,
+            (PropValuesWriter::U64(w), Prop::U64(v)) => w.push(*v)?,
+            (PropValuesWriter::F32(w), Prop::F32(v)) => w.push(*v)?,
+            (PropValuesWriter::F64(w), Prop::F64(v)) => w.push(*v)?,
+            (PropValuesWriter::Bool(w), Prop::Bool(v)) => w.push(*v as u8)?,
+            (PropValuesWriter::Encoded(w), v) => w.push_row(&bincode::serialize(v)?)?,
+            _ => return Err(GraphError::IncorrectPropertyType),
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), GraphError> {
+        match self {
+            PropValuesWriter::Str(w) | PropValuesWriter::Encoded(w) => w.finish()?,
+            PropValuesWriter::I32(w) => w.finish()?,
+            PropValuesWriter::I64(w) => w.finish()?,
+            PropValuesWriter::U32(w) => w.finish()?,
+            PropValuesWriter::U64(w) => w.finish()?,
+            PropValuesWriter::F32(w) => w.finish()?,
+            PropValuesWriter::F64(w) => w.finish()?,
+            PropValuesWriter::Bool(w) => w.finish()?,
+        }
+        Ok(())
+    }
+}
+
+/// Write one column per property of the given kind, `props` returns the properties of a row
+fn write_prop_columns<'a>(
+    dir: &Path,
+    kind: PropColumnKind,
+    num_rows: usize,
+    props: impl Fn(usize) -> Option<&'a Props>,
+    columns: &mut Vec<PropColumnMeta>,
+) -> Result<(), GraphError> {
+    let mut dtypes: BTreeMap<usize, PropType> = BTreeMap::new();
+    let mut add_dtype = |prop_id: usize, dtype: PropType| {
+        dtypes
+            .entry(prop_id)
+            .and_modify(|d| *d = d.merge(dtype))
+            .or_insert(dtype);
+    };
+    for props in (0..num_rows).filter_map(&props) {
+        if kind.is_static() {
+            for prop_id in props.static_prop_ids() {
+                if let Some(prop) = props.static_prop(prop_id) {
+                    add_dtype(prop_id, PropType::of_const(prop));
+                }
+            }
+        } else {
+            for prop_id in props.temporal_prop_ids() {
+                if let Some(dtype) = props.temporal_prop(prop_id).and_then(PropType::of) {
+                    add_dtype(prop_id, dtype);
+                }
+            }
+        }
+    }
+
+    for (prop_id, dtype) in dtypes {
+        let name = kind.column_name(prop_id);
+        let mut rows: ColumnWriter<u64> =
+            ColumnWriter::create(dir.join(format!("{name}_rows.off")))?;
+        let mut times = if kind.is_static() {
+            None
+        } else {
+            Some(ColumnWriter::create(dir.join(format!("{name}_t.col")))?)
+        };
+        let mut values = PropValuesWriter::create(dir, &name, dtype)?;
+        rows.push(0)?;
+        let mut len = 0;
+        for props in (0..num_rows).map(&props) {
+            match &mut times {
+                None => {
+                    if let Some(prop) = props.and_then(|props| props.static_prop(prop_id)) {
+                        values.push(prop)?;
+                        len += 1;
+                    }
+                }
+                Some(times) => {
+                    for (t, prop) in props
+                        .and_then(|props| props.temporal_prop(prop_id))
+                        .into_iter()
+                        .flat_map(|prop| prop.iter_entries())
+                    {
+                        times.push([*t.t(), t.i() as i64])?;
+                        values.push(&prop)?;
+                        len += 1;
+                    }
+                }
+            }
+            rows.push(len)?;
+        }
+        rows.finish()?;
+        if let Some(times) = times {
+            times.finish()?;
+        }
+        values.finish()?;
+        columns.push(PropColumnMeta {
+            kind,
+            prop_id,
+            dtype,
+        });
+    }
+    Ok(())
+}
+
+fn time_index(entries: &[[i64; 2]]) -> TimeIndex<TimeIndexEntry> {
+    entries
+        .iter()
+        .map(|&[t, i]| TimeIndexEntry::new(t, i as usize))
+        .collect()
+}
+
+/// The positions of the entries of a sorted time column that fall inside the window
+fn window_range<T>(entries: &[T], w: Range<i64>, t: impl Fn(&T) -> i64) -> Range<usize> {
+    let start = entries.partition_point(|e| t(e) < w.start);
+    let end = entries.partition_point(|e| t(e) < w.end);
+    start..end.max(start)
+}
+
+fn window_slice<T>(entries: &[T], w: Range<i64>, t: impl Fn(&T) -> i64) -> &[T] {
+    &entries[window_range(entries, w, t)]
+}
+
+impl MmapStorage {
+    fn has_consistent_lengths(&self) -> bool {
+        let n = self.meta.num_vertices;
+        let m = self.meta.num_edges;
+        let l = self.meta.num_layers;
+        self.vertex_gid.len() == n
+            && self.gid_index.len() == n
+            && self.vertex_t.len() == n
+            && self.vertex_del.len() == n
+            && self.vertex_layer_t.len() == n * l
+            && self.adj_out.len() == n * l
+            && self.adj_in.len() == n * l
+            && self.edge_src.len() == m
+            && self.edge_dst.len() == m
+            && self.edge_add.len() == m * l
+            && self.edge_del.len() == m * l
+    }
+
+    #[inline]
+    fn num_layers(&self) -> usize {
+        self.meta.num_layers
+    }
+
+    fn layer_list(&self, layers: &LayerIds) -> Vec<usize> {
+        match layers {
+            LayerIds::None => vec![],
+            LayerIds::All => (0..self.num_layers()).collect(),
+            LayerIds::One(id) => (*id < self.num_layers())
+                .then_some(*id)
+                .into_iter()
+                .collect(),
+            LayerIds::Multiple(ids) => ids
+                .iter()
+                .copied()
+                .filter(|id| *id < self.num_layers())
+                .collect(),
+        }
+    }
+
+    fn resolve_vertex_ref(&self, v: VertexRef) -> Option<VID> {
+        match v {
+            VertexRef::Internal(vid) => Some(vid),
+            VertexRef::External(gid) => {
+                let index = self.gid_index.as_slice();
+                let i = index.binary_search_by_key(&gid, |[g, _]| *g).ok()?;
+                Some((index[i][1] as usize).into())
+            }
+        }
+    }
+
+    #[inline]
+    fn vertex_times(&self, v: VID) -> &[i64] {
+        self.vertex_t.get(v.into())
+    }
+
+    #[inline]
+    fn vertex_deletions(&self, v: VID) -> &[i64] {
+        self.vertex_del.get(v.into())
+    }
+
+    fn prop_column(&self, kind: PropColumnKind, prop_id: usize) -> Option<&PropColumn> {
+        self.props.get(&(kind, prop_id))
+    }
+
+    /// Sorted ids of the properties of the given kind that are set in any of the rows
+    fn prop_ids(
+        &self,
+        kind: PropColumnKind,
+        rows: impl Iterator<Item = usize> + Clone,
+    ) -> Vec<usize> {
+        self.props
+            .range((kind, 0)..=(kind, usize::MAX))
+            .filter(|(_, column)| rows.clone().any(|row| column.has_row(row)))
+            .map(|((_, prop_id), _)| *prop_id)
+            .collect()
+    }
+
+    fn edge_layer_rows(&self, e: EID, layers: &LayerIds) -> impl Iterator<Item = usize> + Clone {
+        let num_layers = self.num_layers();
+        self.layer_list(layers)
+            .into_iter()
+            .map(move |layer| usize::from(e) * num_layers + layer)
+    }
+
+    #[inline]
+    fn vertex_layer_times(&self, v: VID, layer: usize) -> &[i64] {
+        self.vertex_layer_t
+            .get(usize::from(v) * self.num_layers() + layer)
+    }
+
+    fn vertex_in_layer(&self, v: VID, layer: usize) -> bool {
+        !self.vertex_layer_times(v, layer).is_empty()
+            || !self.adj(v, layer, Direction::OUT).is_empty()
+            || !self.adj(v, layer, Direction::IN).is_empty()
+    }
+
+    /// Check if the vertex was added to or has edges in any of the `layers`
+    fn vertex_has_layer(&self, v: VID, layers: &LayerIds) -> bool {
+        match layers {
+            LayerIds::All => true,
+            _ => self
+                .layer_list(layers)
+                .into_iter()
+                .any(|layer| self.vertex_in_layer(v, layer)),
+        }
+    }
+
+    /// The temporal values of the property set without a layer or in one of the `layers`
+    fn vertex_prop_vec(
+        &self,
+        v: VID,
+        name: &str,
+        layers: &LayerIds,
+        w: Option<Range<i64>>,
+    ) -> Vec<(i64, Prop)> {
+        let Some(prop_id) = self.meta.vertex_meta.find_prop_id(name, false) else {
+            return vec![];
+        };
+        let layer_rows = self.layer_list(layers).into_iter().map(|layer| {
+            (
+                PropColumnKind::VertexLayerTemporal,
+                usize::from(v) * self.num_layers() + layer,
+            )
+        });
+        iter::once((PropColumnKind::VertexTemporal, usize::from(v)))
+            .chain(layer_rows)
+            .filter_map(|(kind, row)| {
+                let column = self.prop_column(kind, prop_id)?;
+                Some(column.temporal_values(row, w.clone()))
+            })
+            .kmerge_by(|(t1, _), (t2, _)| t1 < t2)
+            .collect()
+    }
+
+    /// A vertex store holding the time indices of the vertex only, adjacency and properties
+    /// are served from the columns
+    fn vertex_store(&self, v: VID) -> VertexStore {
+        VertexStore::from_parts(
+            self.vertex_gid.get(v.into()),
+            v,
+            self.vertex_times(v).iter().copied().collect(),
+            self.vertex_deletions(v).iter().copied().collect(),
+            None,
+            (0..self.num_layers())
+                .map(|layer| self.vertex_layer_times(v, layer).iter().copied().collect())
+                .collect(),
+            vec![],
+        )
+    }
+
+    #[inline]
+    fn adj(&self, v: VID, layer: usize, d: Direction) -> &[[u64; 2]] {
+        let row = usize::from(v) * self.num_layers() + layer;
+        match d {
+            Direction::OUT => self.adj_out.get(row),
+            Direction::IN => self.adj_in.get(row),
+            Direction::BOTH => unreachable!("adjacency is stored per direction"),
+        }
+    }
+
+    #[inline]
+    fn edge_additions(&self, e: EID, layer: usize) -> &[[i64; 2]] {
+        self.edge_add
+            .get(usize::from(e) * self.num_layers() + layer)
+    }
+
+    #[inline]
+    fn edge_deletions(&self, e: EID, layer: usize) -> &[[i64; 2]] {
+        self.edge_del
+            .get(usize::from(e) * self.num_layers() + layer)
+    }
+
+    fn edge_ref(&self, e: EID) -> EdgeRef {
+        EdgeRef::new_outgoing(
+            e,
+            (self.edge_src.get(e.into()) as usize).into(),
+            (self.edge_dst.get(e.into()) as usize).into(),
+        )
+    }
+
+    // an edge is in a layer if it has either deletions or additions in that layer
+    fn edge_has_layer(&self, e: EID, layers: &LayerIds) -> bool {
+        match layers {
+            LayerIds::All => true,
+            _ => self.layer_list(layers).into_iter().any(|layer| {
+                !self.edge_additions(e, layer).is_empty()
+                    || !self.edge_deletions(e, layer).is_empty()
+            }),
+        }
+    }
+
+    /// An edge store holding the time indices of the edge only, used by edge filters
+    fn edge_store(&self, e: EID) -> EdgeStore {
+        let edge = self.edge_ref(e);
+        EdgeStore::from_parts(
+            e,
+            edge.src(),
+            edge.dst(),
+            vec![],
+            (0..self.num_layers())
+                .map(|layer| time_index(self.edge_additions(e, layer)))
+                .collect(),
+            (0..self.num_layers())
+                .map(|layer| time_index(self.edge_deletions(e, layer)))
+                .collect(),
+        )
+    }
+
+    /// An edge store holding a single constant property of the edge only, used to combine the
+    /// values of multiple layers the same way as the in-memory graph
+    fn edge_const_prop_store(&self, e: EID, prop_id: usize) -> EdgeStore {
+        let edge = self.edge_ref(e);
+        let column = self.prop_column(PropColumnKind::EdgeConst, prop_id);
+        let layers = self
+            .edge_layer_rows(e, &LayerIds::All)
+            .map(|row| {
+                let mut layer = EdgeLayer::default();
+                if let Some(prop) = column.and_then(|column| column.constant(row)) {
+                    layer
+                        .add_static_prop(prop_id, prop)
+                        .expect("the layer has no properties yet");
+                }
+                layer
+            })
+            .collect();
+        EdgeStore::from_parts(e, edge.src(), edge.dst(), layers, vec![], vec![])
+    }
+
+    fn layered_index(
+        &self,
+        layer_ids: LayerIds,
+        entries: impl Fn(usize) -> TimeIndex<TimeIndexEntry>,
+    ) -> LockedLayeredIndex<'_, TimeIndexEntry> {
+        let t_index = (0..self.num_layers()).map(entries).collect();
+        LayeredIndex::new(layer_ids, LockedView::Owned(t_index))
+    }
+
+    /// Iterate over the additions of an edge in the given layers ordered by time
+    fn edge_events(
+        self: &Arc<Self>,
+        e: EID,
+        layer_ids: &LayerIds,
+        w: Option<Range<i64>>,
+    ) -> impl Iterator<Item = (usize, TimeIndexEntry)> + Send {
+        let iters = self.layer_list(layer_ids).into_iter().map(|layer| {
+            let g = self.clone();
+            let row = usize::from(e) * self.num_layers() + layer;
+            let offset = self.edge_add.range(row).start;
+            let entries = self.edge_add.get(row);
+            let range = match &w {
+                None => 0..entries.len(),
+                Some(w) => window_range(entries, w.clone(), |[t, _]| *t),
+            };
+            (offset + range.start..offset + range.end).map(move |j| {
+                let [t, i] = g.edge_add.value(j);
+                (layer, TimeIndexEntry::new(t, i as usize))
+            })
+        });
+        iters.kmerge_by(|a, b| a.1 < b.1)
+    }
+
+    fn edge_time_bound(
+        &self,
+        e: EID,
+        layer_ids: &LayerIds,
+        w: Option<Range<i64>>,
+        first: bool,
+    ) -> Option<i64> {
+        let times = self.layer_list(layer_ids).into_iter().flat_map(|layer| {
+            let entries = self.edge_additions(e, layer);
+            let entries = match &w {
+                None => entries,
+                Some(w) => window_slice(entries, w.clone(), |[t, _]| *t),
+            };
+            if first {
+                entries.first().map(|[t, _]| *t)
+            } else {
+                entries.last().map(|[t, _]| *t)
+            }
+        });
+        if first {
+            times.min()
+        } else {
+            times.max()
+        }
+    }
+
+    /// Edges of a vertex in one direction ordered by the remote vertex
+    fn edge_tuples(
+        self: &Arc<Self>,
+        v: VID,
+        d: Direction,
+        layers: &LayerIds,
+    ) -> Box<dyn Iterator<Item = EdgeRef> + Send> {
+        match d {
+            Direction::BOTH => Box::new(
+                self.edge_tuples(v, Direction::OUT, layers)
+                    .merge_by(self.edge_tuples(v, Direction::IN, layers), |e1, e2| {
+                        e1.remote() < e2.remote()
+                    }),
+            ),
+            _ => {
+                let iters = self.layer_list(layers).into_iter().map(|layer| {
+                    let g = self.clone();
+                    let len = self.adj(v, layer, d).len();
+                    (0..len).map(move |j| {
+                        let [n, e] = g.adj(v, layer, d)[j];
+                        let n = VID::from(n as usize);
+                        let e = EID::from(e as usize);
+                        match d {
+                            Direction::OUT => EdgeRef::new_outgoing(e, v, n),
+                            _ => EdgeRef::new_incoming(e, n, v),
+                        }
+                    })
+                });
+                Box::new(iters.kmerge_by(|e1, e2| e1.remote() < e2.remote()).dedup())
+            }
+        }
+    }
+}
+
+impl CoreGraphOps for MmapGraph {
+    fn unfiltered_num_vertices(&self) -> usize {
+        self.0.meta.num_vertices
+    }
+
+    fn unfiltered_num_edges(&self) -> usize {
+        self.0.meta.num_edges
+    }
+
+    fn get_layer_names_from_ids(&self, layer_ids: LayerIds) -> Vec<String> {
+        self.0.meta.edge_meta.layer_names(layer_ids)
+    }
+
+    fn vertex_id(&self, v: VID) -> u64 {
+        self.0.vertex_gid.get(v.into())
+    }
+
+    fn vertex_name(&self, v: VID) -> String {
+        self.0
+            .meta
+            .vertex_meta
+            .find_prop_id("_id", true)
+            .and_then(|id| {
+                self.0
+                    .prop_column(PropColumnKind::VertexConst, id)?
+                    .constant(v.into())
+            })
+            .into_str()
+            .unwrap_or_else(|| self.vertex_id(v).to_string())
+    }
+
+    fn edge_additions(
+        &self,
+        eref: EdgeRef,
+        layer_ids: LayerIds,
+    ) -> LockedLayeredIndex<'_, TimeIndexEntry> {
+        let layer_ids = layer_ids.constrain_from_edge(eref);
+        self.0.layered_index(layer_ids, |layer| {
+            time_index(self.0.edge_additions(eref.pid(), layer))
+        })
+    }
+
+    fn vertex_additions(&self, v: VID) -> LockedView<'_, TimeIndex<i64>> {
+        LockedView::Owned(self.0.vertex_times(v).iter().copied().collect())
+    }
+
+    fn internalise_vertex(&self, v: VertexRef) -> Option<VID> {
+        self.0.resolve_vertex_ref(v)
+    }
+
+    fn internalise_vertex_unchecked(&self, v: VertexRef) -> VID {
+        self.0.resolve_vertex_ref(v).unwrap()
+    }
+
+    fn static_prop_names(&self) -> Vec<String> {
+        self.0.meta.graph_props.static_prop_names().clone()
+    }
+
+    fn static_prop(&self, name: &str) -> Option<Prop> {
+        self.0.meta.graph_props.get_static(name)
+    }
+
+    fn temporal_prop_names(&self) -> Vec<String> {
+        self.0.meta.graph_props.temporal_prop_names().clone()
+    }
+
+    fn temporal_prop(&self, name: &str) -> Option<LockedView<'_, TProp>> {
+        self.0.meta.graph_props.get_temporal(name)
+    }
+
+    fn static_vertex_prop(&self, v: VID, name: &str) -> Option<Prop> {
+        let prop_id = self.0.meta.vertex_meta.find_prop_id(name, true)?;
+        self.0
+            .prop_column(PropColumnKind::VertexConst, prop_id)?
+            .constant(v.into())
+    }
+
+    fn static_vertex_prop_names<'a>(
+        &'a self,
+        v: VID,
+    ) -> Box<dyn Iterator<Item = LockedView<'a, String>> + 'a> {
+        let ids = self
+            .0
+            .prop_ids(PropColumnKind::VertexConst, iter::once(v.into()));
+        Box::new(
+            ids.into_iter()
+                .flat_map(|id| self.0.meta.vertex_meta.reverse_prop_id(id, true)),
+        )
+    }
+
+    fn temporal_vertex_prop(&self, v: VID, name: &str) -> Option<LockedView<'_, TProp>> {
+        let prop_id = self.0.meta.vertex_meta.find_prop_id(name, false)?;
+        let prop = self
+            .0
+            .prop_column(PropColumnKind::VertexTemporal, prop_id)?
+            .temporal(v.into())?;
+        Some(LockedView::Owned(prop))
+    }
+
+    fn temporal_vertex_layer_prop(
+        &self,
+        v: VID,
+        name: &str,
+        layer: usize,
+    ) -> Option<LockedView<'_, TProp>> {
+        if layer >= self.0.num_layers() {
+            return None;
+        }
+        let prop_id = self.0.meta.vertex_meta.find_prop_id(name, false)?;
+        let prop = self
+            .0
+            .prop_column(PropColumnKind::VertexLayerTemporal, prop_id)?
+            .temporal(usize::from(v) * self.0.num_layers() + layer)?;
+        Some(LockedView::Owned(prop))
+    }
+
+    fn temporal_vertex_prop_names<'a>(
+        &'a self,
+        v: VID,
+    ) -> Box<dyn Iterator<Item = LockedView<'a, String>> + 'a> {
+        let ids = self
+            .0
+            .prop_ids(PropColumnKind::VertexTemporal, iter::once(v.into()));
+        Box::new(
+            ids.into_iter()
+                .flat_map(|id| self.0.meta.vertex_meta.reverse_prop_id(id, false)),
+        )
+    }
+
+    fn all_vertex_prop_names(&self, is_static: bool) -> Vec<String> {
+        self.0.meta.vertex_meta.get_all_property_names(is_static)
+    }
+
+    fn all_edge_prop_names(&self, is_static: bool) -> Vec<String> {
+        self.0.meta.edge_meta.get_all_property_names(is_static)
+    }
+
+    fn static_edge_prop(&self, e: EdgeRef, name: &str, layer_ids: LayerIds) -> Option<Prop> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let prop_id = self.0.meta.edge_meta.find_prop_id(name, true)?;
+        self.0
+            .edge_const_prop_store(e.pid(), prop_id)
+            .static_property_layers(layer_ids, prop_id, &self.0.meta.edge_meta)
+    }
+
+    fn static_edge_prop_names<'a>(
+        &'a self,
+        e: EdgeRef,
+        layer_ids: LayerIds,
+    ) -> Box<dyn Iterator<Item = LockedView<'a, String>> + 'a> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let ids = self.0.prop_ids(
+            PropColumnKind::EdgeConst,
+            self.0.edge_layer_rows(e.pid(), &layer_ids),
+        );
+        Box::new(
+            ids.into_iter()
+                .flat_map(|id| self.0.meta.edge_meta.reverse_prop_id(id, true)),
+        )
+    }
+
+    fn temporal_edge_prop(
+        &self,
+        e: EdgeRef,
+        name: &str,
+        layer_ids: LayerIds,
+    ) -> Option<LockedLayeredTProp<'_>> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let prop_id = self.0.meta.edge_meta.find_prop_id(name, false)?;
+        let column = self.0.prop_column(PropColumnKind::EdgeTemporal, prop_id)?;
+        let props: Vec<_> = self
+            .0
+            .edge_layer_rows(e.pid(), &layer_ids)
+            .flat_map(|row| column.temporal(row))
+            .map(LockedView::Owned)
+            .collect();
+        (!props.is_empty()).then(|| LockedLayeredTProp::new(props))
+    }
+
+    fn temporal_edge_prop_names<'a>(
+        &'a self,
+        e: EdgeRef,
+        layer_ids: LayerIds,
+    ) -> Box<dyn Iterator<Item = LockedView<'a, String>> + 'a> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let ids = self.0.prop_ids(
+            PropColumnKind::EdgeTemporal,
+            self.0.edge_layer_rows(e.pid(), &layer_ids),
+        );
+        Box::new(
+            ids.into_iter()
+                .flat_map(|id| self.0.meta.edge_meta.reverse_prop_id(id, false)),
+        )
+    }
+
+    fn core_edges(&self) -> Box<dyn Iterator<Item = ArcEntry<EdgeStore>>> {
+        let g = self.0.clone();
+        Box::new((0..self.0.meta.num_edges).map(move |e| ArcEntry::owned(g.edge_store(e.into()))))
+    }
+
+    fn core_edge(&self, eid: EID) -> ArcEntry<EdgeStore> {
+        ArcEntry::owned(self.0.edge_store(eid))
+    }
+
+    fn core_vertices(&self) -> Box<dyn Iterator<Item = ArcEntry<VertexStore>>> {
+        let g = self.0.clone();
+        Box::new(
+            (0..self.0.meta.num_vertices).map(move |v| ArcEntry::owned(g.vertex_store(v.into()))),
+        )
+    }
+
+    fn core_vertex(&self, vid: VID) -> ArcEntry<VertexStore> {
+        ArcEntry::owned(self.0.vertex_store(vid))
+    }
+}
+
+impl CoreDeletionOps for MmapGraph {
+    fn edge_deletions(
+        &self,
+        eref: EdgeRef,
+        layer_ids: LayerIds,
+    ) -> LockedLayeredIndex<'_, TimeIndexEntry> {
+        let layer_ids = layer_ids.constrain_from_edge(eref);
+        self.0.layered_index(layer_ids, |layer| {
+            time_index(self.0.edge_deletions(eref.pid(), layer))
+        })
+    }
+
+    fn vertex_deletions(&self, v: VID) -> LockedView<'_, TimeIndex<i64>> {
+        LockedView::Owned(self.0.vertex_deletions(v).iter().copied().collect())
+    }
+}
+
+impl GraphOps for MmapGraph {
+    fn internal_vertex_ref(
+        &self,
+        v: VertexRef,
+        layer_ids: &LayerIds,
+        _filter: Option<&EdgeFilter>,
+    ) -> Option<VID> {
+        self.0
+            .resolve_vertex_ref(v)
+            .filter(|v| self.0.vertex_has_layer(*v, layer_ids))
+    }
+
+    fn find_edge_id(
+        &self,
+        e_id: EID,
+        layer_ids: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Option<EdgeRef> {
+        if usize::from(e_id) >= self.0.meta.num_edges {
+            return None;
+        }
+        filter
+            .map(|f| f(&self.0.edge_store(e_id), layer_ids))
+            .unwrap_or(true)
+            .then(|| self.0.edge_ref(e_id))
+    }
+
+    fn vertices_len(&self, layer_ids: LayerIds, filter: Option<&EdgeFilter>) -> usize {
+        match layer_ids {
+            LayerIds::All => self.0.meta.num_vertices,
+            _ => self.vertex_refs(layer_ids, filter).count(),
+        }
+    }
+
+    fn edges_len(&self, layers: LayerIds, filter: Option<&EdgeFilter>) -> usize {
+        let num_edges = self.0.meta.num_edges;
+        match filter {
+            None => match layers {
+                LayerIds::All => num_edges,
+                _ => (0..num_edges)
+                    .into_par_iter()
+                    .filter(|e| self.0.edge_has_layer((*e).into(), &layers))
+                    .count(),
+            },
+            Some(filter) => (0..num_edges)
+                .into_par_iter()
+                .filter(|e| filter(&self.0.edge_store((*e).into()), &layers))
+                .count(),
+        }
+    }
+
+    fn degree(
+        &self,
+        v: VID,
+        d: Direction,
+        layers: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> usize {
+        match filter {
+            None => match (d, self.0.layer_list(layers).as_slice()) {
+                (Direction::OUT | Direction::IN, [layer]) => self.0.adj(v, *layer, d).len(),
+                _ => self
+                    .0
+                    .edge_tuples(v, d, layers)
+                    .dedup_by(|e1, e2| e1.remote() == e2.remote())
+                    .count(),
+            },
+            Some(filter) => self
+                .0
+                .edge_tuples(v, d, layers)
+                .filter(|e| filter(&self.0.edge_store(e.pid()), layers))
+                .dedup_by(|e1, e2| e1.remote() == e2.remote())
+                .count(),
+        }
+    }
+
+    fn vertex_refs(
+        &self,
+        layers: LayerIds,
+        _filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = VID> + Send> {
+        let g = self.0.clone();
+        Box::new(
+            (0..self.0.meta.num_vertices)
+                .map(VID::from)
+                .filter(move |v| g.vertex_has_layer(*v, &layers)),
+        )
+    }
+
+    fn edge_ref(
+        &self,
+        src: VID,
+        dst: VID,
+        layer: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Option<EdgeRef> {
+        let dst_id = usize::from(dst) as u64;
+        self.0
+            .layer_list(layer)
+            .into_iter()
+            .find_map(|l| {
+                let adj = self.0.adj(src, l, Direction::OUT);
+                let i = adj.binary_search_by_key(&dst_id, |[n, _]| *n).ok()?;
+                Some(EID::from(adj[i][1] as usize))
+            })
+            .filter(|eid| {
+                filter
+                    .map(|f| f(&self.0.edge_store(*eid), layer))
+                    .unwrap_or(true)
+            })
+            .map(|e_id| EdgeRef::new_outgoing(e_id, src, dst))
+    }
+
+    fn edge_refs(
+        &self,
+        layers: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = EdgeRef> + Send> {
+        if matches!(layers, LayerIds::None) {
+            return Box::new(iter::empty());
+        }
+        let g = self.0.clone();
+        let filter = filter.cloned();
+        Box::new(
+            (0..self.0.meta.num_edges)
+                .map(EID::from)
+                .filter(move |e| match &filter {
+                    Some(f) => f(&g.edge_store(*e), &layers),
+                    None => g.edge_has_layer(*e, &layers),
+                })
+                .map({
+                    let g = self.0.clone();
+                    move |e| g.edge_ref(e)
+                }),
+        )
+    }
+
+    fn vertex_edges(
+        &self,
+        v: VID,
+        d: Direction,
+        layers: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = EdgeRef> + Send> {
+        match d {
+            Direction::BOTH => Box::new(
+                self.vertex_edges(v, Direction::IN, layers.clone(), filter)
+                    .merge(self.vertex_edges(v, Direction::OUT, layers, filter)),
+            ),
+            _ => {
+                let iter = self.0.edge_tuples(v, d, &layers);
+                match filter.cloned() {
+                    None => iter,
+                    Some(filter) => {
+                        let g = self.0.clone();
+                        Box::new(
+                            iter.filter(move |eref| filter(&g.edge_store(eref.pid()), &layers)),
+                        )
+                    }
+                }
+            }
+        }
+    }
+
+    fn neighbours(
+        &self,
+        v: VID,
+        d: Direction,
+        layers: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = VID> + Send> {
+        let iter = self.vertex_edges(v, d, layers, filter).map(|e| e.remote());
+        if matches!(d, Direction::BOTH) {
+            Box::new(iter.dedup())
+        } else {
+            Box::new(iter)
+        }
+    }
+}
+
+impl EdgeFilterOps for MmapGraph {
+    #[inline]
+    fn edge_filter(&self) -> Option<&EdgeFilter> {
+        None
+    }
+}
+
+impl LayerOps for MmapGraph {
+    fn layer_ids(&self) -> LayerIds {
+        LayerIds::All
+    }
+
+    fn layer_ids_from_names(&self, key: Layer) -> LayerIds {
+        self.0.meta.edge_meta.layer_ids(key)
+    }
+
+    fn edge_layer_ids(&self, e: &EdgeStore) -> LayerIds {
+        e.layer_ids()
+    }
+
+    fn vertex_layer_ids(&self, v: VID) -> LayerIds {
+        let layer_ids: Vec<_> = (0..self.0.num_layers())
+            .filter(|layer| self.0.vertex_in_layer(v, *layer))
+            .collect();
+        match layer_ids.len() {
+            0 => LayerIds::None,
+            1 => LayerIds::One(layer_ids[0]),
+            _ => LayerIds::Multiple(layer_ids.into()),
+        }
+    }
+}
+
+impl InternalMaterialize for MmapGraph {
+    fn new_base_graph(&self, graph: InternalGraph) -> MaterializedGraph {
+        MaterializedGraph::EventGraph(Graph::new_from_inner(Arc::new(graph)))
+    }
+
+    fn include_deletions(&self) -> bool {
+        false
+    }
+}
+
+impl TimeSemantics for MmapGraph {
+    fn vertex_earliest_time(&self, v: VID) -> Option<i64> {
+        self.0.vertex_times(v).first().copied()
+    }
+
+    fn vertex_latest_time(&self, v: VID) -> Option<i64> {
+        self.0.vertex_times(v).last().copied()
+    }
+
+    fn earliest_time_global(&self) -> Option<i64> {
+        self.0.meta.earliest_time
+    }
+
+    fn latest_time_global(&self) -> Option<i64> {
+        self.0.meta.latest_time
+    }
+
+    fn earliest_time_window(&self, t_start: i64, t_end: i64) -> Option<i64> {
+        (0..self.0.meta.num_vertices)
+            .into_par_iter()
+            .flat_map(|v| self.vertex_earliest_time_window(v.into(), t_start, t_end))
+            .min()
+    }
+
+    fn latest_time_window(&self, t_start: i64, t_end: i64) -> Option<i64> {
+        (0..self.0.meta.num_vertices)
+            .into_par_iter()
+            .flat_map(|v| self.vertex_latest_time_window(v.into(), t_start, t_end))
+            .max()
+    }
+
+    fn vertex_earliest_time_window(&self, v: VID, t_start: i64, t_end: i64) -> Option<i64> {
+        window_slice(self.0.vertex_times(v), t_start..t_end, |t| *t)
+            .first()
+            .copied()
+    }
+
+    fn vertex_latest_time_window(&self, v: VID, t_start: i64, t_end: i64) -> Option<i64> {
+        window_slice(self.0.vertex_times(v), t_start..t_end, |t| *t)
+            .last()
+            .copied()
+    }
+
+    fn include_vertex_window(
+        &self,
+        v: VID,
+        w: Range<i64>,
+        layer_ids: &LayerIds,
+        _edge_filter: Option<&EdgeFilter>,
+    ) -> bool {
+        !window_slice(self.0.vertex_times(v), w, |t| *t).is_empty()
+            && self.0.vertex_has_layer(v, layer_ids)
+    }
+
+    fn include_edge_window(&self, e: &EdgeStore, w: Range<i64>, layer_ids: &LayerIds) -> bool {
+        self.0.layer_list(layer_ids).into_iter().any(|layer| {
+            !window_slice(self.0.edge_additions(e.eid, layer), w.clone(), |[t, _]| *t).is_empty()
+        })
+    }
+
+    fn vertex_history(&self, v: VID) -> Vec<i64> {
+        self.0.vertex_times(v).to_vec()
+    }
+
+    fn vertex_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        window_slice(self.0.vertex_times(v), w, |t| *t).to_vec()
+    }
+
+    fn edge_exploded(&self, e: EdgeRef, layer_ids: LayerIds) -> BoxedIter<EdgeRef> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        Box::new(
+            self.0
+                .edge_events(e.pid(), &layer_ids, None)
+                .map(move |(l, t)| e.at(t).at_layer(l)),
+        )
+    }
+
+    fn edge_layers(&self, e: EdgeRef, layer_ids: LayerIds) -> BoxedIter<EdgeRef> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let layers = (0..self.0.num_layers())
+            .filter(|l| layer_ids.contains(l) && self.0.edge_has_layer(e.pid(), &LayerIds::One(*l)))
+            .collect_vec();
+        Box::new(layers.into_iter().map(move |l| e.at_layer(l)))
+    }
+
+    fn edge_window_exploded(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> BoxedIter<EdgeRef> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        Box::new(
+            self.0
+                .edge_events(e.pid(), &layer_ids, Some(w))
+                .map(move |(l, t)| e.at(t).at_layer(l)),
+        )
+    }
+
+    fn edge_window_layers(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> BoxedIter<EdgeRef> {
+        let in_window =
+            |entries: &[[i64; 2]]| !window_slice(entries, w.clone(), |[t, _]| *t).is_empty();
+        let layers = (0..self.0.num_layers())
+            .filter(|l| {
+                layer_ids.contains(l)
+                    && (in_window(self.0.edge_additions(e.pid(), *l))
+                        || in_window(self.0.edge_deletions(e.pid(), *l)))
+            })
+            .collect_vec();
+        Box::new(layers.into_iter().map(move |l| e.at_layer(l)))
+    }
+
+    fn edge_earliest_time(&self, e: EdgeRef, layer_ids: LayerIds) -> Option<i64> {
+        e.time_t().or_else(|| {
+            self.0
+                .edge_time_bound(e.pid(), &layer_ids.constrain_from_edge(e), None, true)
+        })
+    }
+
+    fn edge_earliest_time_window(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> Option<i64> {
+        e.time_t().or_else(|| {
+            self.0
+                .edge_time_bound(e.pid(), &layer_ids.constrain_from_edge(e), Some(w), true)
+        })
+    }
+
+    fn edge_latest_time(&self, e: EdgeRef, layer_ids: LayerIds) -> Option<i64> {
+        e.time_t().or_else(|| {
+            self.0
+                .edge_time_bound(e.pid(), &layer_ids.constrain_from_edge(e), None, false)
+        })
+    }
+
+    fn edge_latest_time_window(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> Option<i64> {
+        e.time_t().or_else(|| {
+            self.0
+                .edge_time_bound(e.pid(), &layer_ids.constrain_from_edge(e), Some(w), false)
+        })
+    }
+
+    fn edge_deletion_history(&self, e: EdgeRef, layer_ids: LayerIds) -> Vec<i64> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        self.0
+            .layer_list(&layer_ids)
+            .into_iter()
+            .map(|l| self.0.edge_deletions(e.pid(), l).iter().map(|[t, _]| *t))
+            .kmerge()
+            .dedup()
+            .collect()
+    }
+
+    fn edge_deletion_history_window(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> Vec<i64> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        self.0
+            .layer_list(&layer_ids)
+            .into_iter()
+            .map(|l| {
+                window_slice(self.0.edge_deletions(e.pid(), l), w.clone(), |[t, _]| *t)
+                    .iter()
+                    .map(|[t, _]| *t)
+            })
+            .kmerge()
+            .dedup()
+            .collect()
+    }
+
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.0.vertex_deletions(v).to_vec()
+    }
+
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        window_slice(self.0.vertex_deletions(v), w, |t| *t).to_vec()
+    }
+
+    fn temporal_prop_vec(&self, name: &str) -> Vec<(i64, Prop)> {
+        self.0
+            .meta
+            .graph_props
+            .get_temporal(name)
+            .map(|prop| prop.iter().collect())
+            .unwrap_or_default()
+    }
+
+    fn temporal_prop_vec_window(&self, name: &str, t_start: i64, t_end: i64) -> Vec<(i64, Prop)> {
+        self.0
+            .meta
+            .graph_props
+            .get_temporal(name)
+            .map(|prop| prop.iter_window(t_start..t_end).collect())
+            .unwrap_or_default()
+    }
+
+    fn temporal_vertex_prop_vec(
+        &self,
+        v: VID,
+        name: &str,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
+        self.0.vertex_prop_vec(v, name, &layer_ids, None)
+    }
+
+    fn temporal_vertex_prop_vec_window(
+        &self,
+        v: VID,
+        name: &str,
+        t_start: i64,
+        t_end: i64,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
+        self.0
+            .vertex_prop_vec(v, name, &layer_ids, Some(t_start..t_end))
+    }
+
+    fn temporal_edge_prop_vec_window(
+        &self,
+        e: EdgeRef,
+        name: &str,
+        t_start: i64,
+        t_end: i64,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
+        self.temporal_edge_prop(e, name, layer_ids)
+            .map(|p| match e.time() {
+                Some(t) => {
+                    if *t.t() >= t_start && *t.t() < t_end {
+                        p.at(&t).map(|v| vec![(*t.t(), v)]).unwrap_or_default()
+                    } else {
+                        vec![]
+                    }
+                }
+                None => p.iter_window(t_start..t_end).collect(),
+            })
+            .unwrap_or_default()
+    }
+
+    fn temporal_edge_prop_vec(
+        &self,
+        e: EdgeRef,
+        name: &str,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
+        self.temporal_edge_prop(e, name, layer_ids)
+            .map(|p| match e.time() {
+                Some(t) => p.at(&t).map(|v| vec![(*t.t(), v)]).unwrap_or_default(),
+                None => p.iter().collect(),
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl TemporalPropertyViewOps for MmapGraph {
+    fn temporal_value(&self, id: &Key) -> Option<Prop> {
+        self.0
+            .meta
+            .graph_props
+            .get_temporal(id)
+            .and_then(|prop| prop.last_before(i64::MAX).map(|(_, v)| v))
+    }
+
+    fn temporal_history(&self, id: &Key) -> Vec<i64> {
+        self.0
+            .meta
+            .graph_props
+            .get_temporal(id)
+            .map(|prop| prop.iter().map(|(t, _)| t).collect())
+            .unwrap_or_default()
+    }
+
+    fn temporal_values(&self, id: &Key) -> Vec<Prop> {
+        self.0
+            .meta
+            .graph_props
+            .get_temporal(id)
+            .map(|prop| prop.iter().map(|(_, v)| v).collect())
+            .unwrap_or_default()
+    }
+
+    fn temporal_value_at(&self, id: &Key, t: i64) -> Option<Prop> {
+        self.0
+            .meta
+            .graph_props
+            .get_temporal(id)
+            .and_then(|prop| prop.last_before(t.saturating_add(1)).map(|(_, v)| v))
+    }
+}
+
+impl TemporalPropertiesOps for MmapGraph {
+    fn temporal_property_keys<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = LockedView<'a, String>> + 'a> {
+        let guarded = self.0.meta.graph_props.temporal_prop_names();
+        Box::new((0..guarded.len()).map(move |i| {
+            RwLockReadGuard::map(RwLockReadGuard::rwlock(&guarded).read_recursive(), |v| {
+                &v[i]
+            })
+            .into()
+        }))
+    }
+
+    fn get_temporal_property(&self, key: &str) -> Option<Key> {
+        self.0
+            .meta
+            .graph_props
+            .get_temporal(key)
+            .map(|_| key.to_owned())
+    }
+}
+
+impl ConstPropertiesOps for MmapGraph {
+    fn const_property_keys<'a>(&'a self) -> Box<dyn Iterator<Item = LockedView<'a, String>> + 'a> {
+        let guarded = self.0.meta.graph_props.static_prop_names();
+        Box::new((0..guarded.len()).map(move |i| {
+            RwLockReadGuard::map(RwLockReadGuard::rwlock(&guarded).read_recursive(), |v| {
+                &v[i]
+            })
+            .into()
+        }))
+    }
+
+    fn get_const_property(&self, key: &str) -> Option<Prop> {
+        self.0.meta.graph_props.get_static(key)
+    }
+}
+
+#[cfg(test)]
+mod mmap_graph_test {
+    use super::{MmapGraph, PropColumnKind, META_FILE};
+    use crate::{
+        algorithms::pagerank::unweighted_page_rank,
+        core::utils::errors::GraphError,
+        db::graph::{graph::graph_equal, views::deletion_graph::GraphWithDeletions},
+        prelude::*,
+    };
+    use itertools::Itertools;
+    use std::fs;
+    use tempdir::TempDir;
+
+    fn test_graph() -> Graph {
+        let g = Graph::new();
+        g.add_vertex(0, "a", [("age", Prop::I64(5))], None).unwrap();
+        g.add_vertex_properties("a", [("kind", Prop::str("person"))])
+            .unwrap();
+        g.add_edge(1, "a", "b", [("weight", Prop::F64(1.5))], None)
+            .unwrap();
+        g.add_edge(2, "b", "c", NO_PROPS, Some("friends")).unwrap();
+        g.add_edge(3, "a", "b", [("weight", Prop::F64(2.5))], Some("friends"))
+            .unwrap();
+        g.add_edge(4, "c", "a", NO_PROPS, None).unwrap();
+        g.add_edge_properties("b", "c", [("since", Prop::I64(2))], Some("friends"))
+            .unwrap();
+        g.add_static_properties([("name", Prop::str("test"))])
+            .unwrap();
+        g.add_properties(5, [("size", Prop::I64(3))]).unwrap();
+        g
+    }
+
+    #[test]
+    fn round_trip_matches_in_memory_graph() {
+        let g = test_graph();
+        let dir = TempDir::new("mmap_graph").unwrap();
+        let mg = MmapGraph::write(&g, dir.path()).unwrap();
+
+        assert!(graph_equal(&g, &mg));
+        assert_eq!(mg.earliest_time(), g.earliest_time());
+        assert_eq!(mg.latest_time(), g.latest_time());
+        assert_eq!(mg.get_unique_layers(), g.get_unique_layers());
+        for v in g.vertices() {
+            let mv = mg.vertex(v.id()).unwrap();
+            assert_eq!(mv.name(), v.name());
+            assert_eq!(mv.history(), v.history());
+            assert_eq!(mv.out_degree(), v.out_degree());
+            assert_eq!(mv.in_degree(), v.in_degree());
+            assert_eq!(mv.degree(), v.degree());
+            assert_eq!(
+                mv.neighbours().id().collect_vec(),
+                v.neighbours().id().collect_vec()
+            );
+            assert_eq!(mv.properties().as_map(), v.properties().as_map());
+            assert_eq!(mv.layer_names(), v.layer_names());
+        }
+        for e in g.edges() {
+            let me = mg.edge(e.src().id(), e.dst().id()).unwrap();
+            assert_eq!(me.history(), e.history());
+            assert_eq!(me.layer_names(), e.layer_names());
+            assert_eq!(me.properties().as_map(), e.properties().as_map());
+        }
+        assert_eq!(mg.properties().as_map(), g.properties().as_map());
+    }
+
+    #[test]
+    fn views_work_on_mmap_graph() {
+        let g = test_graph();
+        let dir = TempDir::new("mmap_graph").unwrap();
+        let mg = MmapGraph::write(&g, dir.path()).unwrap();
+
+        assert!(graph_equal(&g.window(2, 4), &mg.window(2, 4)));
+        assert!(graph_equal(
+            &g.layer("friends").unwrap(),
+            &mg.layer("friends").unwrap()
+        ));
+        assert!(graph_equal(
+            &g.subgraph(["a", "b"]),
+            &mg.subgraph(["a", "b"])
+        ));
+        assert_eq!(mg.window(2, 4).edge("a", "b").unwrap().history(), vec![3]);
+        assert_eq!(
+            mg.edges()
+                .explode()
+                .map(|e| e.time().unwrap())
+                .collect_vec(),
+            g.edges().explode().map(|e| e.time().unwrap()).collect_vec()
+        );
+
+        let expected = unweighted_page_rank(&g, 20, None, None, true);
+        let actual = unweighted_page_rank(&mg, 20, None, None, true);
+        for (k, v) in expected.get_all() {
+            assert!((actual.get_all()[k] - v).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn reload_from_disk() {
+        let g = test_graph();
+        let dir = TempDir::new("mmap_graph").unwrap();
+        MmapGraph::write(&g, dir.path()).unwrap();
+
+        let mg = MmapGraph::load(dir.path()).unwrap();
+        assert!(graph_equal(&g, &mg));
+        assert!(graph_equal(
+            &mg.materialize().unwrap().into_events().unwrap(),
+            &g
+        ));
+    }
+
+    #[test]
+    fn missing_metadata_is_an_error() {
+        let g = test_graph();
+        let dir = TempDir::new("mmap_graph").unwrap();
+        MmapGraph::write(&g, dir.path()).unwrap();
+        fs::remove_file(dir.path().join(META_FILE)).unwrap();
+        assert!(MmapGraph::load(dir.path()).is_err());
+    }
+
+    #[test]
+    fn corrupt_property_offsets_are_an_error() {
+        let g = test_graph();
+        let dir = TempDir::new("mmap_graph").unwrap();
+        let mg = MmapGraph::write(&g, dir.path()).unwrap();
+        let prop_id = mg.0.meta.edge_meta.find_prop_id("weight", false).unwrap();
+        let path = dir.path().join(format!(
+            "{}_rows.off",
+            PropColumnKind::EdgeTemporal.column_name(prop_id)
+        ));
+        let len = fs::metadata(&path).unwrap().len() as usize;
+        fs::write(&path, vec![0xff; len]).unwrap();
+        assert!(matches!(
+            MmapGraph::load(dir.path()),
+            Err(GraphError::CorruptGraphFile(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid string")]
+    fn corrupt_property_values_fail_on_access() {
+        let g = test_graph();
+        let dir = TempDir::new("mmap_graph").unwrap();
+        let mg = MmapGraph::write(&g, dir.path()).unwrap();
+        let prop_id = mg.0.meta.vertex_meta.find_prop_id("_id", true).unwrap();
+        let path = dir.path().join(format!(
+            "{}.col",
+            PropColumnKind::VertexConst.column_name(prop_id)
+        ));
+        let len = fs::metadata(&path).unwrap().len() as usize;
+        fs::write(&path, vec![0xff; len]).unwrap();
+        let mg = MmapGraph::load(dir.path()).unwrap();
+        mg.vertex("a").unwrap().name();
+    }
+
+    #[test]
+    fn properties_with_mixed_types_round_trip() {
+        let g = test_graph();
+        g.add_vertex(6, "b", [("age", Prop::str("unknown"))], None)
+            .unwrap();
+        g.add_vertex_properties("b", [("kind", Prop::I64(1))])
+            .unwrap();
+        let dir = TempDir::new("mmap_graph").unwrap();
+        let mg = MmapGraph::write(&g, dir.path()).unwrap();
+        for v in g.vertices() {
+            let mv = mg.vertex(v.id()).unwrap();
+            assert_eq!(mv.properties().as_map(), v.properties().as_map());
+            assert_eq!(
+                mv.properties()
+                    .temporal()
+                    .get("age")
+                    .map(|p| p.iter().collect_vec()),
+                v.properties()
+                    .temporal()
+                    .get("age")
+                    .map(|p| p.iter().collect_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn persistent_graphs_are_rejected() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        g.delete_edge(2, 1, 2, None).unwrap();
+        let dir = TempDir::new("mmap_graph").unwrap();
+        assert!(matches!(
+            MmapGraph::write(&g, dir.path()),
+            Err(GraphError::GraphLoadError)
+        ));
+    }
+}
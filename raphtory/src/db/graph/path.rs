@@ -91,7 +91,10 @@ impl<G: GraphViewOps> PathFromGraph<G> {
 impl<G: GraphViewOps> VertexViewOps for PathFromGraph<G> {
     type Graph = G;
     type ValueType<T> = Box<dyn Iterator<Item = Box<dyn Iterator<Item = T> + Send>> + Send>;
-    type PathType<'a> = Self where Self: 'a;
+    type PathType<'a>
+        = Self
+    where
+        Self: 'a;
     type EList = Box<dyn Iterator<Item = Box<dyn Iterator<Item = EdgeView<G>> + Send>> + Send>;
 
     fn id(&self) -> Box<dyn Iterator<Item = Box<dyn Iterator<Item = u64> + Send>> + Send> {
@@ -102,6 +105,14 @@ impl<G: GraphViewOps> VertexViewOps for PathFromGraph<G> {
         Box::new(self.iter().map(|it| it.name()))
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<String>> {
+        Box::new(self.iter().map(|it| it.vertex_type()))
+    }
+
+    fn layer_names(&self) -> Self::ValueType<Vec<String>> {
+        Box::new(self.iter().map(|it| it.layer_names()))
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         Box::new(self.iter().map(|it| it.earliest_time()))
     }
@@ -278,7 +289,10 @@ impl<G: GraphViewOps> PathFromVertex<G> {
 impl<G: GraphViewOps> VertexViewOps for PathFromVertex<G> {
     type Graph = G;
     type ValueType<T> = BoxedIter<T>;
-    type PathType<'a> = Self where Self: 'a;
+    type PathType<'a>
+        = Self
+    where
+        Self: 'a;
     type EList = BoxedIter<EdgeView<G>>;
 
     fn id(&self) -> Self::ValueType<u64> {
@@ -289,6 +303,14 @@ impl<G: GraphViewOps> VertexViewOps for PathFromVertex<G> {
         self.iter().name()
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<String>> {
+        self.iter().vertex_type()
+    }
+
+    fn layer_names(&self) -> Self::ValueType<Vec<String>> {
+        self.iter().layer_names()
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.iter().earliest_time()
     }
@@ -82,7 +82,7 @@ impl<G: GraphViewOps> TemporalPropertiesOps for VertexView<G> {
     fn get_temporal_property(&self, key: &str) -> Option<Key> {
         (!self
             .graph
-            .temporal_vertex_prop_vec(self.vertex, key)
+            .temporal_vertex_prop_vec(self.vertex, key, self.graph.layer_ids())
             .is_empty())
         .then(|| key.to_owned())
     }
@@ -91,14 +91,14 @@ impl<G: GraphViewOps> TemporalPropertiesOps for VertexView<G> {
 impl<G: GraphViewOps> TemporalPropertyViewOps for VertexView<G> {
     fn temporal_value(&self, id: &String) -> Option<Prop> {
         self.graph
-            .temporal_vertex_prop_vec(self.vertex, id)
+            .temporal_vertex_prop_vec(self.vertex, id, self.graph.layer_ids())
             .last()
             .map(|(_, v)| v.to_owned())
     }
 
     fn temporal_history(&self, id: &String) -> Vec<i64> {
         self.graph
-            .temporal_vertex_prop_vec(self.vertex, id)
+            .temporal_vertex_prop_vec(self.vertex, id, self.graph.layer_ids())
             .into_iter()
             .map(|(t, _)| t)
             .collect()
@@ -106,7 +106,7 @@ impl<G: GraphViewOps> TemporalPropertyViewOps for VertexView<G> {
 
     fn temporal_values(&self, id: &String) -> Vec<Prop> {
         self.graph
-            .temporal_vertex_prop_vec(self.vertex, id)
+            .temporal_vertex_prop_vec(self.vertex, id, self.graph.layer_ids())
             .into_iter()
             .map(|(_, v)| v)
             .collect()
@@ -123,7 +123,12 @@ impl<G: GraphViewOps> TemporalPropertyViewOps for VertexView<G> {
 
 impl<G: GraphViewOps> ConstPropertiesOps for VertexView<G> {
     fn const_property_keys<'a>(&'a self) -> Box<dyn Iterator<Item = LockedView<'a, String>> + 'a> {
-        self.graph.static_vertex_prop_names(self.vertex)
+        // the vertex type is exposed through `vertex_type` only
+        Box::new(
+            self.graph
+                .static_vertex_prop_names(self.vertex)
+                .filter(|name| name.as_str() != "_type"),
+        )
     }
 
     fn const_property_values(&self) -> Vec<Prop> {
@@ -133,6 +138,9 @@ impl<G: GraphViewOps> ConstPropertiesOps for VertexView<G> {
     }
 
     fn get_const_property(&self, key: &str) -> Option<Prop> {
+        if key == "_type" {
+            return None;
+        }
         self.graph.static_vertex_prop(self.vertex, key)
     }
 }
@@ -143,7 +151,10 @@ impl<G: GraphViewOps> Static for VertexView<G> {}
 impl<G: GraphViewOps> VertexViewOps for VertexView<G> {
     type Graph = G;
     type ValueType<T> = T;
-    type PathType<'a> = PathFromVertex<G> where Self: 'a;
+    type PathType<'a>
+        = PathFromVertex<G>
+    where
+        Self: 'a;
     type EList = BoxedIter<EdgeView<G>>;
 
     fn id(&self) -> u64 {
@@ -154,6 +165,15 @@ impl<G: GraphViewOps> VertexViewOps for VertexView<G> {
         self.graph.vertex_name(self.vertex)
     }
 
+    fn vertex_type(&self) -> Option<String> {
+        self.graph.vertex_type(self.vertex)
+    }
+
+    fn layer_names(&self) -> Vec<String> {
+        let layer_ids = self.graph.vertex_layer_ids(self.vertex);
+        self.graph.get_layer_names_from_ids(layer_ids)
+    }
+
     fn earliest_time(&self) -> Option<i64> {
         self.graph.vertex_earliest_time(self.vertex)
     }
@@ -327,6 +347,14 @@ impl<G: GraphViewOps> VertexListOps for Box<dyn Iterator<Item = VertexView<G>> +
         Box::new(self.map(|v| v.name()))
     }
 
+    fn vertex_type(self) -> BoxedIter<Option<String>> {
+        Box::new(self.map(|v| v.vertex_type()))
+    }
+
+    fn layer_names(self) -> BoxedIter<Vec<String>> {
+        Box::new(self.map(|v| v.layer_names()))
+    }
+
     fn properties(self) -> BoxedIter<Properties<VertexView<G>>> {
         Box::new(self.map(move |v| v.properties()))
     }
@@ -403,6 +431,14 @@ impl<G: GraphViewOps> VertexListOps for BoxedIter<BoxedIter<VertexView<G>>> {
         Box::new(self.map(|it| it.name()))
     }
 
+    fn vertex_type(self) -> BoxedIter<Self::ValueType<Option<String>>> {
+        Box::new(self.map(|it| it.vertex_type()))
+    }
+
+    fn layer_names(self) -> BoxedIter<Self::ValueType<Vec<String>>> {
+        Box::new(self.map(|it| it.layer_names()))
+    }
+
     fn properties(self) -> BoxedIter<Self::ValueType<Properties<VertexView<G>>>> {
         Box::new(self.map(move |it| it.properties()))
     }
@@ -456,9 +492,9 @@ mod vertex_test {
     #[test]
     fn test_earliest_time() {
         let g = Graph::new();
-        g.add_vertex(0, 1, NO_PROPS).unwrap();
-        g.add_vertex(1, 1, NO_PROPS).unwrap();
-        g.add_vertex(2, 1, NO_PROPS).unwrap();
+        g.add_vertex(0, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(1, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(2, 1, NO_PROPS, None).unwrap();
         let mut view = g.at(1);
         assert_eq!(view.vertex(1).expect("v").earliest_time().unwrap(), 0);
         assert_eq!(view.vertex(1).expect("v").latest_time().unwrap(), 1);
@@ -472,8 +508,8 @@ mod vertex_test {
     fn test_properties() {
         let g = Graph::new();
         let props = [("test", "test")];
-        g.add_vertex(0, 1, NO_PROPS).unwrap();
-        g.add_vertex(2, 1, props).unwrap();
+        g.add_vertex(0, 1, NO_PROPS, None).unwrap();
+        g.add_vertex(2, 1, props, None).unwrap();
 
         let v1 = g.vertex(1).unwrap();
         let v1_w = g.window(0, 1).vertex(1).unwrap();
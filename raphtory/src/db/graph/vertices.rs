@@ -64,6 +64,15 @@ impl<G: GraphViewOps> VertexViewOps for Vertices<G> {
         self.iter().name()
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<String>> {
+        self.iter().vertex_type()
+    }
+
+    /// Returns an iterator over the layers of the vertices
+    fn layer_names(&self) -> Self::ValueType<Vec<String>> {
+        self.iter().layer_names()
+    }
+
     /// Returns an iterator over the vertices' earliest time
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.iter().earliest_time()
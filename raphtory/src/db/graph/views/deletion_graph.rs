@@ -111,8 +111,22 @@ impl GraphWithDeletions {
         };
 
         // None is less than any value (see test below)
-        (first_deletion < first_addition && first_deletion.filter(|v| *v >= t).is_some())
-            || last_addition_before_start > last_deletion_before_start
+        let alive = (first_deletion < first_addition
+            && first_deletion.filter(|v| *v >= t).is_some())
+            || last_addition_before_start > last_deletion_before_start;
+
+        // deleting a vertex closes all its edges until they are added again
+        alive
+            && self
+                .vertex_closing_time(e, last_addition_before_start.unwrap_or(i64::MIN)..t)
+                .is_none()
+    }
+
+    /// First deletion of either endpoint of `e` in window `w`
+    fn vertex_closing_time(&self, e: &EdgeStore, w: Range<i64>) -> Option<i64> {
+        let src_deletion = self.vertex_deletions(e.src()).range(w.clone()).first_t();
+        let dst_deletion = self.vertex_deletions(e.dst()).range(w).first_t();
+        src_deletion.into_iter().chain(dst_deletion).min()
     }
 
     fn vertex_alive_at(
@@ -122,15 +136,31 @@ impl GraphWithDeletions {
         layers: &LayerIds,
         edge_filter: Option<&EdgeFilter>,
     ) -> bool {
+        // as for edges, a vertex deleted before it was first added existed until the deletion
+        let first_deletion = v.deletions().first_t();
+        if first_deletion < v.timestamps().first_t()
+            && first_deletion.filter(|deletion| *deletion >= t).is_some()
+        {
+            return true;
+        }
+        // an explicit deletion means the vertex existed since its previous addition
+        let deleted_later = v
+            .timestamps()
+            .range(i64::MIN..t.saturating_add(1))
+            .last_t()
+            .and_then(|last_addition| v.deletions().range(last_addition..i64::MAX).first_t())
+            .filter(|deletion| *deletion >= t)
+            .is_some();
+        if deleted_later {
+            return true;
+        }
         let edges = self.graph.inner().storage.edges.read_lock();
         v.edge_tuples(layers, Direction::BOTH)
             .map(|eref| edges.get(eref.pid().into()))
-            .filter(|e| {
+            .any(|e| {
                 edge_filter.map(|f| f(e, layers)).unwrap_or(true)
                     && self.edge_alive_at(e, t, layers)
             })
-            .next()
-            .is_some()
     }
 
     pub fn new() -> Self {
@@ -155,7 +185,7 @@ impl GraphWithDeletions {
     /// use std::fs::File;
     /// use raphtory::prelude::*;
     /// let g = Graph::new();
-    /// g.add_vertex(1, 1, NO_PROPS).unwrap();
+    /// g.add_vertex(1, 1, NO_PROPS, None).unwrap();
     /// g.save_to_file("path_str").expect("failed to save file");
     /// ```
     pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
@@ -182,6 +212,52 @@ impl GraphWithDeletions {
         let g = MaterializedGraph::load_from_file(path)?;
         g.into_persistent().ok_or(GraphError::GraphLoadError)
     }
+
+    /// Record all subsequent mutations in the write-ahead log at `path`
+    ///
+    /// Entries are appended if the log already exists.
+    pub fn enable_wal<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        self.graph.enable_wal(path)
+    }
+
+    /// Stop recording mutations in the write-ahead log
+    pub fn disable_wal(&self) -> Result<(), GraphError> {
+        self.graph.disable_wal()
+    }
+
+    /// Flush the write-ahead log to disk
+    pub fn sync_wal(&self) -> Result<(), GraphError> {
+        self.graph.sync_wal()
+    }
+
+    /// Number of mutations recorded in the write-ahead log since the last checkpoint
+    pub fn wal_len(&self) -> usize {
+        self.graph.wal_len()
+    }
+
+    /// Write a snapshot of the graph to `path` and truncate the write-ahead log
+    pub fn checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        self.graph
+            .checkpoint(|| MaterializedGraph::from(self.clone()).save_snapshot(path))
+    }
+
+    /// Recover a graph from the snapshot at `snapshot` and the write-ahead log at `wal`
+    ///
+    /// The snapshot is loaded if it exists, all mutations in the log are replayed on top of it
+    /// and the log is attached to the recovered graph such that it keeps recording mutations.
+    pub fn recover<P: AsRef<Path>, Q: AsRef<Path>>(
+        snapshot: P,
+        wal: Q,
+    ) -> Result<Self, GraphError> {
+        let g = if snapshot.as_ref().exists() {
+            Self::load_from_file(snapshot)?
+        } else {
+            Self::new()
+        };
+        g.graph.replay_wal(&wal)?;
+        g.graph.enable_wal(wal)?;
+        Ok(g)
+    }
 }
 
 impl<G: GraphViewOps> PartialEq<G> for GraphWithDeletions {
@@ -261,7 +337,8 @@ impl TimeSemantics for GraphWithDeletions {
         edge_filter: Option<&EdgeFilter>,
     ) -> bool {
         let v = self.graph.inner().storage.get_node(v);
-        v.active(w.clone()) || self.vertex_alive_at(&v, w.start, layer_ids, edge_filter)
+        v.has_layer(layer_ids)
+            && (v.active(w.clone()) || self.vertex_alive_at(&v, w.start, layer_ids, edge_filter))
     }
 
     fn include_edge_window(&self, e: &EdgeStore, w: Range<i64>, layer_ids: &LayerIds) -> bool {
@@ -364,23 +441,35 @@ impl TimeSemantics for GraphWithDeletions {
     }
 
     fn edge_latest_time(&self, e: EdgeRef, layer_ids: LayerIds) -> Option<i64> {
+        let entry = self.core_edge(e.pid());
         match e.time().map(|ti| *ti.t()) {
             Some(t) => Some(min(
                 self.edge_additions(e, layer_ids.clone())
                     .range(t.saturating_add(1)..i64::MAX)
                     .first_t()
                     .unwrap_or(i64::MAX),
-                self.edge_deletions(e, layer_ids)
-                    .range(t.saturating_add(1)..i64::MAX)
-                    .first_t()
-                    .unwrap_or(i64::MAX),
+                min(
+                    self.edge_deletions(e, layer_ids)
+                        .range(t.saturating_add(1)..i64::MAX)
+                        .first_t()
+                        .unwrap_or(i64::MAX),
+                    self.vertex_closing_time(&entry, t.saturating_add(1)..i64::MAX)
+                        .unwrap_or(i64::MAX),
+                ),
             )),
             None => {
-                let entry = self.core_edge(e.pid());
                 if self.edge_alive_at(&entry, i64::MAX, &layer_ids) {
                     Some(i64::MAX)
                 } else {
-                    self.edge_deletions(e, layer_ids).last_t()
+                    let last_addition = self.edge_additions(e, layer_ids.clone()).last_t();
+                    let last_deletion = self.edge_deletions(e, layer_ids).last_t();
+                    match last_addition {
+                        // the edge was closed by a vertex deletion
+                        Some(t) if last_deletion < last_addition => {
+                            self.vertex_closing_time(&entry, t..i64::MAX)
+                        }
+                        _ => last_deletion,
+                    }
                 }
             }
         }
@@ -392,23 +481,41 @@ impl TimeSemantics for GraphWithDeletions {
         w: Range<i64>,
         layer_ids: LayerIds,
     ) -> Option<i64> {
+        let entry = self.core_edge(e.pid());
         match e.time().map(|ti| *ti.t()) {
             Some(t) => Some(min(
                 self.edge_additions(e, layer_ids.clone())
                     .range(t.saturating_add(1)..w.end)
                     .first_t()
                     .unwrap_or(w.end - 1),
-                self.edge_deletions(e, layer_ids)
-                    .range(t.saturating_add(1)..w.end)
-                    .first_t()
-                    .unwrap_or(w.end - 1),
+                min(
+                    self.edge_deletions(e, layer_ids)
+                        .range(t.saturating_add(1)..w.end)
+                        .first_t()
+                        .unwrap_or(w.end - 1),
+                    self.vertex_closing_time(&entry, t.saturating_add(1)..w.end)
+                        .unwrap_or(w.end - 1),
+                ),
             )),
             None => {
-                let entry = self.core_edge(e.pid());
                 if self.edge_alive_at(&entry, w.end - 1, &layer_ids) {
                     Some(w.end - 1)
                 } else {
-                    self.edge_deletions(e, layer_ids).range(w).last_t()
+                    let last_addition = self
+                        .edge_additions(e, layer_ids.clone())
+                        .range(i64::MIN..w.end)
+                        .last_t();
+                    let last_deletion = self
+                        .edge_deletions(e, layer_ids)
+                        .range(i64::MIN..w.end)
+                        .last_t();
+                    match last_addition {
+                        // the edge was closed by a vertex deletion
+                        Some(t) if last_deletion < last_addition => {
+                            self.vertex_closing_time(&entry, t..w.end)
+                        }
+                        _ => last_deletion.filter(|t| w.contains(t)),
+                    }
                 }
             }
         }
@@ -434,6 +541,14 @@ impl TimeSemantics for GraphWithDeletions {
             .collect()
     }
 
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.graph.vertex_deletion_history(v)
+    }
+
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.graph.vertex_deletion_history_window(v, w)
+    }
+
     fn temporal_prop_vec(&self, name: &str) -> Vec<(i64, Prop)> {
         self.graph.temporal_prop_vec(name)
     }
@@ -442,8 +557,13 @@ impl TimeSemantics for GraphWithDeletions {
         self.graph.temporal_prop_vec_window(name, t_start, t_end)
     }
 
-    fn temporal_vertex_prop_vec(&self, v: VID, name: &str) -> Vec<(i64, Prop)> {
-        self.graph.temporal_vertex_prop_vec(v, name)
+    fn temporal_vertex_prop_vec(
+        &self,
+        v: VID,
+        name: &str,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
+        self.graph.temporal_vertex_prop_vec(v, name, layer_ids)
     }
 
     fn temporal_vertex_prop_vec_window(
@@ -452,9 +572,10 @@ impl TimeSemantics for GraphWithDeletions {
         name: &str,
         t_start: i64,
         t_end: i64,
+        layer_ids: LayerIds,
     ) -> Vec<(i64, Prop)> {
         self.graph
-            .temporal_vertex_prop_vec_window(v, name, t_start, t_end)
+            .temporal_vertex_prop_vec_window(v, name, t_start, t_end, layer_ids)
     }
 
     fn temporal_edge_prop_vec_window(
@@ -577,4 +698,120 @@ mod test_deletions {
         assert_eq!(e.latest_time(), Some(10));
         assert_eq!(e.explode().latest_time().collect_vec(), vec![Some(10)]);
     }
+
+    #[test]
+    fn test_vertex_deletion_closes_edges() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        g.add_edge(1, 3, 1, NO_PROPS, Some("layer")).unwrap();
+        g.delete_vertex(5, 1).unwrap();
+
+        assert_eq!(g.window(2, 3).num_edges(), 2);
+        assert_eq!(g.window(2, 3).vertex(1).unwrap().degree(), 2);
+        assert_eq!(g.window(4, 6).num_edges(), 2);
+        assert!(g.window(6, 10).is_empty());
+        assert!(g.window(6, 10).vertex(1).is_none());
+
+        let e = g.edge(1, 2).unwrap();
+        assert_eq!(e.latest_time(), Some(5));
+        assert_eq!(e.explode().latest_time().collect_vec(), vec![Some(5)]);
+        assert_eq!(g.window(0, 3).edge(1, 2).unwrap().latest_time(), Some(2));
+        assert_eq!(g.window(0, 8).edge(1, 2).unwrap().latest_time(), Some(5));
+        assert_eq!(g.vertex(1).unwrap().history(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_vertex_deleted_before_added() {
+        let g = GraphWithDeletions::new();
+        g.delete_vertex(5, 1).unwrap();
+        g.add_vertex(7, 1, NO_PROPS, None).unwrap();
+        g.delete_vertex(3, 2).unwrap();
+
+        assert!(g.window(2, 3).has_vertex(1));
+        assert!(!g.window(6, 7).has_vertex(1));
+        assert!(g.window(7, 8).has_vertex(1));
+        assert_eq!(g.vertex(1).unwrap().history(), vec![7]);
+
+        // deleting a vertex that was never added does not add it
+        assert!(g.vertex(2).unwrap().history().is_empty());
+        assert!(!g.window(0, 10).has_vertex(2));
+    }
+
+    #[test]
+    fn test_vertex_readdition() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        g.delete_vertex(5, 1).unwrap();
+        g.add_vertex(8, 1, NO_PROPS, None).unwrap();
+        g.add_edge(10, 1, 2, NO_PROPS, None).unwrap();
+
+        assert!(g.window(6, 8).is_empty());
+        assert_eq!(g.window(8, 9).num_vertices(), 1);
+        assert_eq!(g.window(8, 9).num_edges(), 0);
+        assert_eq!(g.window(11, 12).num_edges(), 1);
+        assert_eq!(g.window(11, 12).vertex(2).unwrap().in_degree(), 1);
+        assert_eq!(g.edge(1, 2).unwrap().latest_time(), Some(i64::MAX));
+    }
+
+    #[test]
+    fn test_intervals() {
+        let g = GraphWithDeletions::new();
+        g.add_edge_interval(1, 5, 1, 2, [("weight", Prop::I64(1))], None)
+            .unwrap();
+        g.add_vertex_interval(2, 8, 3, NO_PROPS).unwrap();
+
+        assert_eq!(g.window(3, 4).num_edges(), 1);
+        assert_eq!(g.window(6, 7).num_edges(), 0);
+        assert_eq!(g.edge(1, 2).unwrap().latest_time(), Some(5));
+        assert!(g.window(6, 7).has_vertex(3));
+        assert!(!g.window(9, 10).has_vertex(3));
+
+        // invalid intervals do not modify the graph
+        assert!(g.add_edge_interval(7, 6, 4, 5, NO_PROPS, None).is_err());
+        assert!(g.add_vertex_interval(7, 6, 4, NO_PROPS).is_err());
+        assert!(!g.has_vertex(4));
+    }
+
+    #[test]
+    fn test_rejected_interval_is_not_written() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(1, 1, 2, [("weight", Prop::I64(1))], None)
+            .unwrap();
+        g.add_vertex(1, 3, [("age", Prop::I64(30))], None).unwrap();
+
+        assert!(g
+            .add_edge_interval(2, 5, 1, 2, [("weight", Prop::F64(1.0))], None)
+            .is_err());
+        assert!(g
+            .add_vertex_interval(2, 5, 3, [("age", Prop::str("thirty"))])
+            .is_err());
+        assert!(g
+            .add_vertex_interval(2, 5, 4, [("x", Prop::I64(1)), ("x", Prop::F64(1.0))])
+            .is_err());
+
+        assert_eq!(g.edge(1, 2).unwrap().history(), vec![1]);
+        assert_eq!(g.edge(1, 2).unwrap().latest_time(), Some(i64::MAX));
+        assert_eq!(g.vertex(3).unwrap().history(), vec![1]);
+        assert_eq!(g.latest_time(), Some(1));
+    }
+
+    #[test]
+    fn test_materialize_vertex_deletion() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        g.delete_vertex(5, 2).unwrap();
+
+        let gm = g.materialize().unwrap().into_persistent().unwrap();
+        assert_eq!(gm, g);
+        assert!(gm.window(6, 10).is_empty());
+
+        let gw = g
+            .window(3, 8)
+            .materialize()
+            .unwrap()
+            .into_persistent()
+            .unwrap();
+        assert_eq!(gw, g.window(3, 8));
+        assert!(gw.window(6, 8).is_empty());
+    }
 }
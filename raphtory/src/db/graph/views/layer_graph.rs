@@ -1,5 +1,5 @@
 use crate::{
-    core::entities::{edges::edge_store::EdgeStore, LayerIds},
+    core::entities::{edges::edge_store::EdgeStore, LayerIds, VID},
     db::api::{
         properties::internal::InheritPropertiesOps,
         view::{
@@ -115,6 +115,11 @@ impl<G: GraphViewOps> LayerOps for LayeredGraph<G> {
         let layer_ids = self.graph.edge_layer_ids(e);
         self.constrain(layer_ids)
     }
+
+    fn vertex_layer_ids(&self, v: VID) -> LayerIds {
+        let layer_ids = self.graph.vertex_layer_ids(v);
+        self.constrain(layer_ids)
+    }
 }
 
 #[cfg(test)]
@@ -177,4 +182,82 @@ mod test_layers {
         edges.sort();
         assert_eq!(edges, vec![(1, 2), (2, 3), (2, 4)]);
     }
+
+    #[test]
+    fn test_vertex_layers() {
+        let g = Graph::new();
+        g.add_edge(0, 1, 2, NO_PROPS, Some("layer1")).unwrap();
+        g.add_vertex_to_layer(1, 3, [("status", "away")], None, "layer2")
+            .unwrap();
+        g.add_vertex_to_layer(2, 1, [("status", "online")], None, "layer2")
+            .unwrap();
+        g.add_vertex(3, 1, [("age", 30)], None).unwrap();
+        g.add_vertex(4, 5, NO_PROPS, None).unwrap();
+
+        let layer1 = g.layer("layer1").unwrap();
+        assert_eq!(layer1.vertices().id().sorted().collect_vec(), vec![1, 2]);
+        assert_eq!(layer1.num_vertices(), 2);
+        assert!(!layer1.has_vertex(3));
+        assert!(!layer1.has_vertex(5));
+        let layer2 = g.layer("layer2").unwrap();
+        assert_eq!(layer2.vertices().id().sorted().collect_vec(), vec![1, 3]);
+        assert_eq!(g.num_vertices(), 4);
+
+        // properties set in a layer are only visible in views including the layer
+        let v = layer1.vertex(1).unwrap();
+        assert_eq!(v.properties().get("status"), None);
+        assert_eq!(v.properties().get("age"), Some(Prop::I32(30)));
+        let v = layer2.vertex(1).unwrap();
+        assert_eq!(v.properties().get("status"), Some(Prop::str("online")));
+        assert_eq!(
+            g.vertex(1).unwrap().properties().get("status"),
+            Some(Prop::str("online"))
+        );
+        assert_eq!(layer2.window(0, 1).vertex(3), None);
+        assert_eq!(
+            layer2
+                .window(0, 3)
+                .vertex(3)
+                .unwrap()
+                .properties()
+                .get("status"),
+            Some(Prop::str("away"))
+        );
+
+        assert_eq!(g.vertex(1).unwrap().layer_names(), vec!["layer1", "layer2"]);
+        assert_eq!(g.vertex(3).unwrap().layer_names(), vec!["layer2"]);
+        assert!(g.vertex(5).unwrap().layer_names().is_empty());
+        assert_eq!(layer1.vertex(1).unwrap().layer_names(), vec!["layer1"]);
+
+        let gm = layer2.materialize().unwrap().into_events().unwrap();
+        assert_eq!(gm.vertices().id().sorted().collect_vec(), vec![1, 3]);
+        assert_eq!(gm.vertex(3).unwrap().layer_names(), vec!["layer2"]);
+        assert_eq!(gm.layer("layer2").unwrap().num_vertices(), 2);
+    }
+
+    #[test]
+    fn test_layer_vertices_after_deserialise() {
+        let g = Graph::new();
+        g.add_edge(0, 1, 2, NO_PROPS, Some("layer1")).unwrap();
+        g.add_edge(0, 2, 2, NO_PROPS, Some("layer1")).unwrap();
+        g.add_vertex_to_layer(1, 3, NO_PROPS, None, "layer2")
+            .unwrap();
+        g.add_vertex_to_layer(2, 1, NO_PROPS, None, "layer2")
+            .unwrap();
+        g.add_vertex(3, 4, NO_PROPS, None).unwrap();
+
+        // the vertices of the layers are rebuilt after deserialisation
+        let g: Graph = bincode::deserialize(&bincode::serialize(&g).unwrap()).unwrap();
+        let both = g.layer(vec!["layer1", "layer2"]).unwrap();
+        assert_eq!(both.num_vertices(), 3);
+        assert_eq!(both.vertices().id().collect_vec(), vec![1, 2, 3]);
+        assert_eq!(g.layer("layer2").unwrap().num_vertices(), 2);
+
+        g.add_edge(4, 4, 5, NO_PROPS, Some("layer2")).unwrap();
+        assert_eq!(g.layer("layer1").unwrap().num_vertices(), 2);
+        assert_eq!(
+            g.layer("layer2").unwrap().vertices().id().collect_vec(),
+            vec![1, 3, 4, 5]
+        );
+    }
 }
@@ -320,6 +320,16 @@ impl<G: GraphViewOps> TimeSemantics for WindowedGraph<G> {
         )
     }
 
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.graph
+            .vertex_deletion_history_window(v, self.t_start..self.t_end)
+    }
+
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.graph
+            .vertex_deletion_history_window(v, self.actual_start(w.start)..self.actual_end(w.end))
+    }
+
     fn temporal_prop_vec(&self, name: &str) -> Vec<(i64, Prop)> {
         self.graph
             .temporal_prop_vec_window(name, self.t_start, self.t_end)
@@ -333,9 +343,14 @@ impl<G: GraphViewOps> TimeSemantics for WindowedGraph<G> {
         )
     }
 
-    fn temporal_vertex_prop_vec(&self, v: VID, name: &str) -> Vec<(i64, Prop)> {
+    fn temporal_vertex_prop_vec(
+        &self,
+        v: VID,
+        name: &str,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
         self.graph
-            .temporal_vertex_prop_vec_window(v, name, self.t_start, self.t_end)
+            .temporal_vertex_prop_vec_window(v, name, self.t_start, self.t_end, layer_ids)
     }
 
     fn temporal_vertex_prop_vec_window(
@@ -344,12 +359,14 @@ impl<G: GraphViewOps> TimeSemantics for WindowedGraph<G> {
         name: &str,
         t_start: i64,
         t_end: i64,
+        layer_ids: LayerIds,
     ) -> Vec<(i64, Prop)> {
         self.graph.temporal_vertex_prop_vec_window(
             v,
             name,
             self.actual_start(t_start),
             self.actual_end(t_end),
+            layer_ids,
         )
     }
 
@@ -761,7 +778,7 @@ mod views_test {
         let g = Graph::new();
 
         for (t, v) in &vs {
-            g.add_vertex(*t, *v, NO_PROPS)
+            g.add_vertex(*t, *v, NO_PROPS, None)
                 .map_err(|err| println!("{:?}", err))
                 .ok();
         }
@@ -786,7 +803,7 @@ mod views_test {
         let g = Graph::new();
 
         for (t, v) in &vs {
-            g.add_vertex(*t, *v, NO_PROPS)
+            g.add_vertex(*t, *v, NO_PROPS, None)
                 .map_err(|err| println!("{:?}", err))
                 .ok();
         }
@@ -987,6 +1004,7 @@ mod views_test {
             0,
             1,
             [("type", "wallet".into_prop()), ("cost", 99.5.into_prop())],
+            None,
         )
         .map_err(|err| println!("{:?}", err))
         .ok();
@@ -995,6 +1013,7 @@ mod views_test {
             -1,
             2,
             [("type", "wallet".into_prop()), ("cost", 10.0.into_prop())],
+            None,
         )
         .map_err(|err| println!("{:?}", err))
         .ok();
@@ -1003,6 +1022,7 @@ mod views_test {
             6,
             3,
             [("type", "wallet".into_prop()), ("cost", 76.2.into_prop())],
+            None,
         )
         .map_err(|err| println!("{:?}", err))
         .ok();
@@ -1027,6 +1047,7 @@ mod views_test {
             0,
             1,
             [("type", "wallet".into_prop()), ("cost", 99.5.into_prop())],
+            None,
         )
         .map_err(|err| println!("{:?}", err))
         .ok();
@@ -1035,6 +1056,7 @@ mod views_test {
             -1,
             2,
             [("type", "wallet".into_prop()), ("cost", 10.0.into_prop())],
+            None,
         )
         .map_err(|err| println!("{:?}", err))
         .ok();
@@ -1043,6 +1065,7 @@ mod views_test {
             6,
             3,
             [("type", "wallet".into_prop()), ("cost", 76.2.into_prop())],
+            None,
         )
         .map_err(|err| println!("{:?}", err))
         .ok();
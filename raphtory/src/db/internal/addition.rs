@@ -1,6 +1,9 @@
 use crate::{
     core::{
-        entities::{graph::tgraph::InnerTemporalGraph, EID, VID},
+        entities::{
+            graph::{tgraph::InnerTemporalGraph, wal::WalEntry},
+            EID, VID,
+        },
         storage::timeindex::TimeIndexEntry,
         utils::errors::GraphError,
     },
@@ -19,9 +22,46 @@ impl<const N: usize> InternalAdditionOps for InnerTemporalGraph<N> {
         t: TimeIndexEntry,
         v: u64,
         name: Option<&str>,
+        vertex_type: Option<&str>,
         props: Vec<(String, Prop)>,
+        layer: Option<&str>,
     ) -> Result<VID, GraphError> {
-        self.inner().add_vertex_internal(t, v, name, props)
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self
+                .inner()
+                .add_vertex_internal(t, v, name, vertex_type, props, layer),
+            Some(wal) => {
+                let logged_name = name.map(|n| n.to_owned());
+                let logged_props = props.clone();
+                let mut entries = vec![match layer {
+                    None => WalEntry::AddVertex {
+                        t,
+                        v,
+                        name: logged_name,
+                        props: logged_props,
+                    },
+                    Some(layer) => WalEntry::AddLayerVertex {
+                        t,
+                        v,
+                        name: logged_name,
+                        props: logged_props,
+                        layer: layer.to_owned(),
+                    },
+                }];
+                // the type is logged as a constant property to keep the log entries stable
+                if let Some(vertex_type) = vertex_type {
+                    entries.push(WalEntry::AddVertexProperties {
+                        v,
+                        props: vec![("_type".to_string(), Prop::str(vertex_type))],
+                    });
+                }
+                wal.log_then_apply(&entries, || {
+                    self.inner()
+                        .add_vertex_internal(t, v, name, vertex_type, props, layer)
+                })
+            }
+        }
     }
 
     fn internal_add_edge(
@@ -32,6 +72,19 @@ impl<const N: usize> InternalAdditionOps for InnerTemporalGraph<N> {
         props: Vec<(String, Prop)>,
         layer: Option<&str>,
     ) -> Result<EID, GraphError> {
-        self.inner().add_edge_internal(t, src, dst, props, layer)
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self.inner().add_edge_internal(t, src, dst, props, layer),
+            Some(wal) => wal.log_then_apply(
+                &[WalEntry::AddEdge {
+                    t,
+                    src,
+                    dst,
+                    props: props.clone(),
+                    layer: layer.map(|l| l.to_owned()),
+                }],
+                || self.inner().add_edge_internal(t, src, dst, props, layer),
+            ),
+        }
     }
 }
@@ -16,14 +16,16 @@ use crate::{
     db::api::view::internal::CoreGraphOps,
     prelude::Prop,
 };
-use itertools::Itertools;
-use std::{collections::HashMap, iter};
 
 impl<const N: usize> CoreGraphOps for InnerTemporalGraph<N> {
     fn unfiltered_num_vertices(&self) -> usize {
         self.inner().internal_num_vertices()
     }
 
+    fn unfiltered_num_edges(&self) -> usize {
+        self.inner().storage.edges.len()
+    }
+
     fn get_layer_names_from_ids(&self, layer_ids: LayerIds) -> Vec<String> {
         self.inner().layer_names(layer_ids)
     }
@@ -111,6 +113,18 @@ impl<const N: usize> CoreGraphOps for InnerTemporalGraph<N> {
         vertex.temporal_property(prop_id)
     }
 
+    fn temporal_vertex_layer_prop(
+        &self,
+        v: VID,
+        name: &str,
+        layer: usize,
+    ) -> Option<LockedView<'_, TProp>> {
+        let vertex = self.inner().vertex(v);
+        let prop_id = self.inner().vertex_find_prop(name, false)?;
+
+        vertex.layer_temporal_property(layer, prop_id)
+    }
+
     fn temporal_vertex_prop_names<'a>(
         &'a self,
         v: VID,
@@ -135,53 +149,7 @@ impl<const N: usize> CoreGraphOps for InnerTemporalGraph<N> {
         let layer_ids = layer_ids.constrain_from_edge(e);
         let entry = self.inner().edge_entry(e.pid());
         let prop_id = self.inner().edge_find_prop(name, true)?;
-        match layer_ids {
-            LayerIds::None => None,
-            LayerIds::All => {
-                if self.inner().num_layers() == 1 {
-                    // iterator has at most 1 element
-                    entry
-                        .layer_iter()
-                        .next()
-                        .and_then(|layer| layer.static_property(prop_id).cloned())
-                } else {
-                    let prop_map: HashMap<_, _> = entry
-                        .layer_iter()
-                        .enumerate()
-                        .flat_map(|(id, layer)| {
-                            layer
-                                .static_property(prop_id)
-                                .map(|p| (self.inner().get_layer_name(id), p.clone()))
-                        })
-                        .collect();
-                    if prop_map.is_empty() {
-                        None
-                    } else {
-                        Some(prop_map.into())
-                    }
-                }
-            }
-            LayerIds::One(id) => entry
-                .layer(id)
-                .and_then(|l| l.static_property(prop_id).cloned()),
-            LayerIds::Multiple(ids) => {
-                let prop_map: HashMap<_, _> = ids
-                    .iter()
-                    .flat_map(|&id| {
-                        entry.layer(id).and_then(|layer| {
-                            layer
-                                .static_property(prop_id)
-                                .map(|p| (self.inner().get_layer_name(id), p.clone()))
-                        })
-                    })
-                    .collect();
-                if prop_map.is_empty() {
-                    None
-                } else {
-                    Some(prop_map.into())
-                }
-            }
-        }
+        entry.static_property_layers(layer_ids, prop_id, &self.inner().edge_meta)
     }
 
     fn static_edge_prop_names<'a>(
@@ -191,32 +159,12 @@ impl<const N: usize> CoreGraphOps for InnerTemporalGraph<N> {
     ) -> Box<dyn Iterator<Item = LockedView<'a, String>> + 'a> {
         let layer_ids = layer_ids.constrain_from_edge(e);
         let entry = self.inner().edge_entry(e.pid());
-        match layer_ids {
-            LayerIds::None => Box::new(iter::empty()),
-            LayerIds::All => Box::new(
-                entry
-                    .layer_iter()
-                    .map(|l| l.static_prop_ids())
-                    .kmerge()
-                    .dedup()
-                    .flat_map(|id| self.inner().edge_reverse_prop_id(id, true)),
-            ),
-            LayerIds::One(id) => match entry.layer(id) {
-                Some(l) => Box::new(
-                    l.static_prop_ids()
-                        .into_iter()
-                        .flat_map(|id| self.inner().edge_reverse_prop_id(id, true)),
-                ),
-                None => Box::new(iter::empty()),
-            },
-            LayerIds::Multiple(ids) => Box::new(
-                ids.iter()
-                    .flat_map(|id| entry.layer(*id).map(|l| l.static_prop_ids()))
-                    .kmerge()
-                    .dedup()
-                    .flat_map(|id| self.inner().edge_reverse_prop_id(id, true)),
-            ),
-        }
+        Box::new(
+            entry
+                .static_prop_ids_layers(layer_ids)
+                .into_iter()
+                .flat_map(|id| self.inner().edge_reverse_prop_id(id, true)),
+        )
     }
 
     fn temporal_edge_prop(
@@ -238,28 +186,12 @@ impl<const N: usize> CoreGraphOps for InnerTemporalGraph<N> {
     ) -> Box<dyn Iterator<Item = LockedView<'a, String>> + 'a> {
         let layer_ids = layer_ids.constrain_from_edge(e);
         let entry = self.inner().edge_entry(e.pid());
-        match layer_ids {
-            LayerIds::None => Box::new(iter::empty()),
-            LayerIds::All => Box::new(
-                entry
-                    .temp_prop_ids(None)
-                    .into_iter()
-                    .flat_map(|id| self.inner().edge_reverse_prop_id(id, false)),
-            ),
-            LayerIds::One(id) => Box::new(
-                entry
-                    .temp_prop_ids(Some(id))
-                    .into_iter()
-                    .flat_map(|id| self.inner().edge_reverse_prop_id(id, false)),
-            ),
-            LayerIds::Multiple(ids) => Box::new(
-                ids.iter()
-                    .map(|id| entry.temp_prop_ids(Some(*id)))
-                    .kmerge()
-                    .dedup()
-                    .flat_map(|id| self.inner().edge_reverse_prop_id(id, false)),
-            ),
-        }
+        Box::new(
+            entry
+                .temp_prop_ids_layers(layer_ids)
+                .into_iter()
+                .flat_map(|id| self.inner().edge_reverse_prop_id(id, false)),
+        )
     }
 
     fn core_edges(&self) -> Box<dyn Iterator<Item = ArcEntry<EdgeStore>>> {
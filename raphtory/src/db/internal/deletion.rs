@@ -1,8 +1,16 @@
 use crate::{
     core::{
-        entities::{edges::edge_ref::EdgeRef, graph::tgraph::InnerTemporalGraph, LayerIds},
-        storage::timeindex::{LockedLayeredIndex, TimeIndexEntry},
+        entities::{
+            edges::edge_ref::EdgeRef,
+            graph::{tgraph::InnerTemporalGraph, wal::WalEntry},
+            LayerIds, EID, VID,
+        },
+        storage::{
+            locked_view::LockedView,
+            timeindex::{LockedLayeredIndex, TimeIndex, TimeIndexEntry},
+        },
         utils::errors::GraphError,
+        Prop,
     },
     db::api::{mutation::internal::InternalDeletionOps, view::internal::CoreDeletionOps},
 };
@@ -15,7 +23,94 @@ impl<const N: usize> InternalDeletionOps for InnerTemporalGraph<N> {
         dst: u64,
         layer: Option<&str>,
     ) -> Result<(), GraphError> {
-        self.inner().delete_edge(t, src, dst, layer)
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self.inner().delete_edge(t, src, dst, layer),
+            Some(wal) => wal.log_then_apply(
+                &[WalEntry::DeleteEdge {
+                    t,
+                    src,
+                    dst,
+                    layer: layer.map(|l| l.to_owned()),
+                }],
+                || self.inner().delete_edge(t, src, dst, layer),
+            ),
+        }
+    }
+
+    fn internal_delete_vertex(&self, t: TimeIndexEntry, v: u64) -> Result<(), GraphError> {
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self.inner().delete_vertex(t, v),
+            Some(wal) => wal.log_then_apply(&[WalEntry::DeleteVertex { t, v }], || {
+                self.inner().delete_vertex(t, v)
+            }),
+        }
+    }
+
+    fn internal_add_vertex_interval(
+        &self,
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        v: u64,
+        name: Option<&str>,
+        props: Vec<(String, Prop)>,
+    ) -> Result<VID, GraphError> {
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self
+                .inner()
+                .add_vertex_interval_internal(start, end, v, name, props),
+            Some(wal) => wal.log_then_apply(
+                &[WalEntry::AddVertexInterval {
+                    start,
+                    end,
+                    v,
+                    name: name.map(|n| n.to_owned()),
+                    props: props.clone(),
+                }],
+                || {
+                    self.inner()
+                        .add_vertex_interval_internal(start, end, v, name, props)
+                },
+            ),
+        }
+    }
+
+    fn internal_add_edge_interval(
+        &self,
+        start: TimeIndexEntry,
+        end: TimeIndexEntry,
+        src: u64,
+        src_name: Option<&str>,
+        dst: u64,
+        dst_name: Option<&str>,
+        props: Vec<(String, Prop)>,
+        layer: Option<&str>,
+    ) -> Result<EID, GraphError> {
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self
+                .inner()
+                .add_edge_interval_internal(start, end, src, src_name, dst, dst_name, props, layer),
+            Some(wal) => wal.log_then_apply(
+                &[WalEntry::AddEdgeInterval {
+                    start,
+                    end,
+                    src,
+                    src_name: src_name.map(|n| n.to_owned()),
+                    dst,
+                    dst_name: dst_name.map(|n| n.to_owned()),
+                    props: props.clone(),
+                    layer: layer.map(|l| l.to_owned()),
+                }],
+                || {
+                    self.inner().add_edge_interval_internal(
+                        start, end, src, src_name, dst, dst_name, props, layer,
+                    )
+                },
+            ),
+        }
     }
 }
 
@@ -28,4 +123,9 @@ impl<const N: usize> CoreDeletionOps for InnerTemporalGraph<N> {
         let edge = self.inner().edge(eref.pid());
         edge.deletions(layer_ids).unwrap()
     }
+
+    fn vertex_deletions(&self, v: VID) -> LockedView<'_, TimeIndex<i64>> {
+        let vertex = self.inner().vertex(v);
+        vertex.deletions().unwrap()
+    }
 }
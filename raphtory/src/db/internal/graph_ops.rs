@@ -17,16 +17,14 @@ impl<const N: usize> GraphOps for InnerTemporalGraph<N> {
     fn internal_vertex_ref(
         &self,
         v: VertexRef,
-        _layer_ids: &LayerIds,
+        layer_ids: &LayerIds,
         _filter: Option<&EdgeFilter>,
     ) -> Option<VID> {
-        match v {
-            VertexRef::Internal(l) => Some(l),
-            VertexRef::External(_) => {
-                let vid = self.inner().resolve_vertex_ref(v)?;
-                Some(vid)
-            }
-        }
+        let vid = match v {
+            VertexRef::Internal(l) => l,
+            VertexRef::External(_) => self.inner().resolve_vertex_ref(v)?,
+        };
+        self.inner().vertex_has_layer(vid, layer_ids).then_some(vid)
     }
 
     fn find_edge_id(
@@ -46,8 +44,11 @@ impl<const N: usize> GraphOps for InnerTemporalGraph<N> {
             .then(|| EdgeRef::new_outgoing(e_id, e.src(), e.dst()))
     }
 
-    fn vertices_len(&self, _layer_ids: LayerIds, _filter: Option<&EdgeFilter>) -> usize {
-        self.inner().internal_num_vertices()
+    fn vertices_len(&self, layer_ids: LayerIds, _filter: Option<&EdgeFilter>) -> usize {
+        match layer_ids {
+            LayerIds::All => self.inner().internal_num_vertices(),
+            _ => self.inner().num_layer_vertices(&layer_ids),
+        }
     }
 
     fn edges_len(&self, layers: LayerIds, filter: Option<&EdgeFilter>) -> usize {
@@ -67,10 +68,13 @@ impl<const N: usize> GraphOps for InnerTemporalGraph<N> {
 
     fn vertex_refs(
         &self,
-        _layers: LayerIds,
+        layers: LayerIds,
         _filter: Option<&EdgeFilter>,
     ) -> Box<dyn Iterator<Item = VID> + Send> {
-        Box::new(self.inner().vertex_ids())
+        match layers {
+            LayerIds::All => Box::new(self.inner().vertex_ids()),
+            _ => Box::new(self.inner().layer_vertex_ids(&layers)),
+        }
     }
 
     fn edge_ref(
@@ -1,5 +1,5 @@
 use crate::{
-    core::entities::{edges::edge_store::EdgeStore, LayerIds},
+    core::entities::{edges::edge_store::EdgeStore, LayerIds, VID},
     db::{api::view::internal::LayerOps, graph::graph::InternalGraph},
     prelude::Layer,
 };
@@ -16,4 +16,8 @@ impl LayerOps for InternalGraph {
     fn edge_layer_ids(&self, e: &EdgeStore) -> LayerIds {
         e.layer_ids()
     }
+
+    fn vertex_layer_ids(&self, v: VID) -> LayerIds {
+        self.inner().node_entry(v).layer_ids()
+    }
 }
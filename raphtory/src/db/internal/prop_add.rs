@@ -1,6 +1,7 @@
 use crate::{
     core::{
-        entities::graph::tgraph::InnerTemporalGraph, storage::timeindex::TimeIndexEntry,
+        entities::graph::{tgraph::InnerTemporalGraph, wal::WalEntry},
+        storage::timeindex::TimeIndexEntry,
         utils::errors::GraphError,
     },
     db::api::mutation::internal::InternalPropertyAdditionOps,
@@ -13,7 +14,17 @@ impl<const N: usize> InternalPropertyAdditionOps for InnerTemporalGraph<N> {
         v: u64,
         data: Vec<(String, Prop)>,
     ) -> Result<(), GraphError> {
-        self.inner().add_vertex_properties_internal(v, data)
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self.inner().add_vertex_properties_internal(v, data),
+            Some(wal) => wal.log_then_apply(
+                &[WalEntry::AddVertexProperties {
+                    v,
+                    props: data.clone(),
+                }],
+                || self.inner().add_vertex_properties_internal(v, data),
+            ),
+        }
     }
 
     fn internal_add_properties(
@@ -21,11 +32,30 @@ impl<const N: usize> InternalPropertyAdditionOps for InnerTemporalGraph<N> {
         t: TimeIndexEntry,
         props: Vec<(String, Prop)>,
     ) -> Result<(), GraphError> {
-        self.inner().add_property(t, props)
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self.inner().add_property(t, props),
+            Some(wal) => wal.log_then_apply(
+                &[WalEntry::AddProperties {
+                    t,
+                    props: props.clone(),
+                }],
+                || self.inner().add_property(t, props),
+            ),
+        }
     }
 
     fn internal_add_static_properties(&self, props: Vec<(String, Prop)>) -> Result<(), GraphError> {
-        self.inner().add_static_property(props)
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self.inner().add_static_property(props),
+            Some(wal) => wal.log_then_apply(
+                &[WalEntry::AddStaticProperties {
+                    props: props.clone(),
+                }],
+                || self.inner().add_static_property(props),
+            ),
+        }
     }
 
     fn internal_add_edge_properties(
@@ -35,7 +65,23 @@ impl<const N: usize> InternalPropertyAdditionOps for InnerTemporalGraph<N> {
         props: Vec<(String, Prop)>,
         layer: Option<&str>,
     ) -> Result<(), GraphError> {
-        self.inner()
-            .add_edge_properties_internal(src, dst, props, layer)
+        let wal = self.inner().wal.read();
+        match wal.as_ref() {
+            None => self
+                .inner()
+                .add_edge_properties_internal(src, dst, props, layer),
+            Some(wal) => wal.log_then_apply(
+                &[WalEntry::AddEdgeProperties {
+                    src,
+                    dst,
+                    props: props.clone(),
+                    layer: layer.map(|l| l.to_owned()),
+                }],
+                || {
+                    self.inner()
+                        .add_edge_properties_internal(src, dst, props, layer)
+                },
+            ),
+        }
     }
 }
@@ -85,10 +85,11 @@ impl<const N: usize> TimeSemantics for InnerTemporalGraph<N> {
         &self,
         v: VID,
         w: Range<i64>,
-        _layer_ids: &LayerIds,
+        layer_ids: &LayerIds,
         _edge_filter: Option<&EdgeFilter>,
     ) -> bool {
-        self.inner().node_entry(v).timestamps().active(w)
+        let node = self.inner().node_entry(v);
+        node.timestamps().active(w) && node.has_layer(layer_ids)
     }
 
     #[inline]
@@ -217,6 +218,18 @@ impl<const N: usize> TimeSemantics for InnerTemporalGraph<N> {
             .collect()
     }
 
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.vertex_deletions(v).iter_t().copied().collect()
+    }
+
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.vertex_deletions(v)
+            .range(w)
+            .iter_t()
+            .copied()
+            .collect()
+    }
+
     fn temporal_prop_vec(&self, name: &str) -> Vec<(i64, Prop)> {
         self.inner()
             .get_temporal_prop(name)
@@ -231,10 +244,15 @@ impl<const N: usize> TimeSemantics for InnerTemporalGraph<N> {
             .unwrap_or_default()
     }
 
-    fn temporal_vertex_prop_vec(&self, v: VID, name: &str) -> Vec<(i64, Prop)> {
+    fn temporal_vertex_prop_vec(
+        &self,
+        v: VID,
+        name: &str,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
         self.inner()
             .vertex(v)
-            .temporal_properties(name, None)
+            .temporal_properties(name, None, &layer_ids)
             .collect()
     }
 
@@ -244,10 +262,11 @@ impl<const N: usize> TimeSemantics for InnerTemporalGraph<N> {
         name: &str,
         t_start: i64,
         t_end: i64,
+        layer_ids: LayerIds,
     ) -> Vec<(i64, Prop)> {
         self.inner()
             .vertex(v)
-            .temporal_properties(name, Some(t_start..t_end))
+            .temporal_properties(name, Some(t_start..t_end), &layer_ids)
             .collect()
     }
 
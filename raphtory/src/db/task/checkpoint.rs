@@ -0,0 +1,274 @@
+//! Checkpoints of task runs
+//!
+//! A [`Checkpoint`] saves the shard, global and local states of a
+//! [`TaskRunner`](super::task_runner::TaskRunner) to a file at superstep boundaries. Running again
+//! with the same checkpoint resumes from the last saved superstep. The accumulators of the run need
+//! to be registered with [`Context::persist`] and the local state needs to be serializable.
+//!
+//! A saved checkpoint can also be loaded with [`Checkpoint::load`] to inspect the intermediate
+//! states.
+//!
+//! Like versioned graph files, a checkpoint file starts with [`CHECKPOINT_MAGIC`] followed by the
+//! format version as a little-endian `u32` such that files from other releases are rejected
+//! instead of being decoded into garbage.
+use super::{
+    context::{Context, GlobalState},
+    task_state::{Global, Shard},
+};
+use crate::{
+    core::{
+        state::{
+            compute_state::ComputeState, morcel_state::MorcelComputeState,
+            shuffle_state::ShuffleComputeState,
+        },
+        utils::errors::GraphError,
+    },
+    db::api::view::GraphViewOps,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fs::{self, File},
+    io::{BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// Magic bytes at the start of every checkpoint file
+pub const CHECKPOINT_MAGIC: &[u8; 8] = b"RAPHCKPT";
+
+/// The checkpoint format version written by this release
+pub const CHECKPOINT_VERSION: u32 = 1;
+
+/// Where and how often the states of a task run are saved
+#[derive(Clone, Debug)]
+pub struct Checkpoint {
+    path: PathBuf,
+    interval: usize,
+}
+
+/// The states of a task run saved in a checkpoint
+pub struct CheckpointState<CS: ComputeState, S> {
+    /// The superstep the run continues with
+    pub superstep: usize,
+    /// Whether the run had already finished
+    pub done: bool,
+    pub shard: Shard<CS>,
+    pub global: Global<CS>,
+    pub local: Vec<S>,
+}
+
+impl<CS: ComputeState, S> CheckpointState<CS, S> {
+    /// The global state as the result function of the run would see it at the checkpoint
+    pub fn global_state(&self) -> GlobalState<CS> {
+        GlobalState::new(self.global.clone(), self.superstep)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct EncodedMorcel {
+    morcel_size: usize,
+    states: Vec<(u32, Vec<u8>)>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EncodedState {
+    morcel_size: usize,
+    global: EncodedMorcel,
+    parts: Vec<EncodedMorcel>,
+    edge_morcel_size: usize,
+    edges: Vec<EncodedMorcel>,
+}
+
+/// The file contents, `L` is a slice of the local states when saving and a `Vec` when loading
+#[derive(Serialize, Deserialize)]
+struct CheckpointData<L> {
+    superstep: usize,
+    done: bool,
+    num_vertices: usize,
+    num_edges: usize,
+    shard: EncodedState,
+    global: EncodedState,
+    local: L,
+}
+
+fn encode_morcel<G: GraphViewOps, CS: ComputeState>(
+    ctx: &Context<G, CS>,
+    morcel: &MorcelComputeState<CS>,
+) -> Result<EncodedMorcel, GraphError> {
+    let mut states = morcel
+        .states
+        .iter()
+        .map(|(id, state)| Ok((*id, ctx.encode_state(*id, state)?)))
+        .collect::<Result<Vec<_>, GraphError>>()?;
+    states.sort_by_key(|(id, _)| *id);
+    Ok(EncodedMorcel {
+        morcel_size: morcel.morcel_size,
+        states,
+    })
+}
+
+fn decode_morcel<G: GraphViewOps, CS: ComputeState>(
+    ctx: &Context<G, CS>,
+    morcel: EncodedMorcel,
+) -> Result<MorcelComputeState<CS>, GraphError> {
+    let mut decoded = MorcelComputeState::new(morcel.morcel_size);
+    for (id, bytes) in morcel.states {
+        decoded.states.insert(id, ctx.decode_state(id, &bytes)?);
+    }
+    Ok(decoded)
+}
+
+fn encode_state<G: GraphViewOps, CS: ComputeState>(
+    ctx: &Context<G, CS>,
+    state: &ShuffleComputeState<CS>,
+) -> Result<EncodedState, GraphError> {
+    Ok(EncodedState {
+        morcel_size: state.morcel_size,
+        global: encode_morcel(ctx, &state.global)?,
+        parts: state
+            .parts
+            .iter()
+            .map(|part| encode_morcel(ctx, part))
+            .collect::<Result<_, _>>()?,
+        edge_morcel_size: state.edge_morcel_size,
+        edges: state
+            .edges
+            .iter()
+            .map(|part| encode_morcel(ctx, part))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn decode_state<G: GraphViewOps, CS: ComputeState>(
+    ctx: &Context<G, CS>,
+    state: EncodedState,
+) -> Result<ShuffleComputeState<CS>, GraphError> {
+    let mut decoded = ShuffleComputeState::global();
+    decoded.morcel_size = state.morcel_size;
+    decoded.global = decode_morcel(ctx, state.global)?;
+    decoded.parts = state
+        .parts
+        .into_iter()
+        .map(|part| decode_morcel(ctx, part))
+        .collect::<Result<_, _>>()?;
+    decoded.edge_morcel_size = state.edge_morcel_size;
+    decoded.edges = state
+        .edges
+        .into_iter()
+        .map(|part| decode_morcel(ctx, part))
+        .collect::<Result<_, _>>()?;
+    Ok(decoded)
+}
+
+impl Checkpoint {
+    /// A checkpoint saved to `path` after every superstep
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            interval: 1,
+        }
+    }
+
+    /// Only save the states every `supersteps` supersteps
+    pub fn every(mut self, supersteps: usize) -> Self {
+        self.interval = supersteps.max(1);
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether a checkpoint was saved
+    pub fn exists(&self) -> bool {
+        self.path.exists()
+    }
+
+    /// Deletes the saved checkpoint, if any
+    pub fn remove(&self) -> Result<(), GraphError> {
+        if self.exists() {
+            fs::remove_file(&self.path)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn is_due(&self, superstep: usize) -> bool {
+        superstep % self.interval == 0
+    }
+
+    /// Loads the saved states, the accumulators need to be registered with `ctx`
+    pub fn load<G: GraphViewOps, CS: ComputeState, S: DeserializeOwned>(
+        &self,
+        ctx: &Context<G, CS>,
+    ) -> Result<CheckpointState<CS, S>, GraphError> {
+        let mut reader = BufReader::new(File::open(&self.path)?);
+        read_header(&mut reader)?;
+        let data: CheckpointData<Vec<S>> = bincode::deserialize_from(reader)?;
+        let num_vertices = ctx.graph().unfiltered_num_vertices();
+        let num_edges = ctx.graph().unfiltered_num_edges();
+        if data.num_vertices != num_vertices || data.num_edges != num_edges {
+            return Err(GraphError::CheckpointError(format!(
+                "the checkpoint was saved for {} vertices and {} edges but the graph has {} and {}",
+                data.num_vertices, data.num_edges, num_vertices, num_edges
+            )));
+        }
+        Ok(CheckpointState {
+            superstep: data.superstep,
+            done: data.done,
+            shard: Shard::from_state(decode_state(ctx, data.shard)?),
+            global: Global::from_state(decode_state(ctx, data.global)?),
+            local: data.local,
+        })
+    }
+
+    /// Saves the states, replacing the previous checkpoint only once they are written completely
+    pub(crate) fn save<G: GraphViewOps, CS: ComputeState, S: Serialize>(
+        &self,
+        ctx: &Context<G, CS>,
+        done: bool,
+        shard: &Shard<CS>,
+        global: &Global<CS>,
+        local: &[S],
+    ) -> Result<(), GraphError> {
+        let data = CheckpointData {
+            superstep: ctx.ss(),
+            done,
+            num_vertices: ctx.graph().unfiltered_num_vertices(),
+            num_edges: ctx.graph().unfiltered_num_edges(),
+            shard: encode_state(ctx, shard.inner())?,
+            global: encode_state(ctx, global.inner())?,
+            local,
+        };
+        let tmp = self.path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&tmp)?);
+        writer.write_all(CHECKPOINT_MAGIC)?;
+        writer.write_all(&CHECKPOINT_VERSION.to_le_bytes())?;
+        bincode::serialize_into(&mut writer, &data)?;
+        // the data has to be on disk before the rename makes it the current checkpoint
+        let file = writer.into_inner().map_err(|err| err.into_error())?;
+        file.sync_all()?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Check the magic bytes and format version at the start of a checkpoint file
+fn read_header<R: Read>(reader: &mut R) -> Result<(), GraphError> {
+    let mut magic = [0u8; 8];
+    let mut version = [0u8; 4];
+    if reader.read_exact(&mut magic).is_err() || &magic != CHECKPOINT_MAGIC {
+        return Err(GraphError::CheckpointError(
+            "the file is not a checkpoint".to_string(),
+        ));
+    }
+    reader.read_exact(&mut version).map_err(|_| {
+        GraphError::CheckpointError("the file ends before the checkpoint version".to_string())
+    })?;
+    let version = u32::from_le_bytes(version);
+    if version == 0 || version > CHECKPOINT_VERSION {
+        return Err(GraphError::CheckpointError(format!(
+            "unsupported checkpoint version {version}, this release supports versions up to {}",
+            CHECKPOINT_VERSION
+        )));
+    }
+    Ok(())
+}
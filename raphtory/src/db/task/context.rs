@@ -1,16 +1,24 @@
 use super::task_state::{Global, Shard};
 use crate::{
-    core::state::{
-        accumulator_id::AccId, agg::Accumulator, compute_state::ComputeState,
-        shuffle_state::ShuffleComputeState, StateType,
+    core::{
+        state::{
+            accumulator_id::AccId, agg::Accumulator, compute_state::ComputeState,
+            shuffle_state::ShuffleComputeState, StateType,
+        },
+        utils::errors::GraphError,
     },
     db::api::view::GraphViewOps,
 };
-use std::sync::Arc;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::HashMap, sync::Arc};
 
 type MergeFn<CS> =
     Arc<dyn Fn(&mut ShuffleComputeState<CS>, &ShuffleComputeState<CS>, usize) + Send + Sync>;
 
+type EncodeFn<CS> = Arc<dyn Fn(&CS) -> Result<Vec<u8>, GraphError> + Send + Sync>;
+
+type DecodeFn<CS> = Arc<dyn Fn(&[u8]) -> Result<CS, GraphError> + Send + Sync>;
+
 pub struct Context<G, CS>
 where
     G: GraphViewOps,
@@ -20,6 +28,7 @@ where
     g: G,
     merge_fns: Vec<MergeFn<CS>>,
     resetable_states: Vec<u32>,
+    codecs: HashMap<u32, (EncodeFn<CS>, DecodeFn<CS>)>,
 }
 
 impl<G, CS> Context<G, CS>
@@ -39,6 +48,10 @@ where
         self.ss += 1;
     }
 
+    pub(crate) fn set_ss(&mut self, ss: usize) {
+        self.ss = ss;
+    }
+
     pub fn resetable_states(&self) -> &[u32] {
         &self.resetable_states
     }
@@ -121,6 +134,45 @@ where
         self.merge_fns.push(fn_merge);
         self.resetable_states.push(id.id());
     }
+
+    /// Registers the state of the accumulator to be saved in checkpoints
+    ///
+    /// All accumulators of a task run with
+    /// [`run_with_checkpoint`](super::task_runner::TaskRunner::run_with_checkpoint) need to be
+    /// registered.
+    pub fn persist<
+        A: StateType + Serialize + DeserializeOwned,
+        IN: 'static,
+        OUT: 'static,
+        ACC: Accumulator<A, IN, OUT>,
+    >(
+        &mut self,
+        id: AccId<A, IN, OUT, ACC>,
+    ) {
+        let encode: EncodeFn<CS> = Arc::new(|state| state.encode::<A>());
+        let decode: DecodeFn<CS> = Arc::new(|bytes| CS::decode::<A>(bytes));
+        self.codecs.insert(id.id(), (encode, decode));
+    }
+
+    pub(crate) fn encode_state(&self, id: u32, state: &CS) -> Result<Vec<u8>, GraphError> {
+        match self.codecs.get(&id) {
+            Some((encode, _)) => encode(state),
+            None => Err(not_persisted(id)),
+        }
+    }
+
+    pub(crate) fn decode_state(&self, id: u32, bytes: &[u8]) -> Result<CS, GraphError> {
+        match self.codecs.get(&id) {
+            Some((_, decode)) => decode(bytes),
+            None => Err(not_persisted(id)),
+        }
+    }
+}
+
+fn not_persisted(id: u32) -> GraphError {
+    GraphError::CheckpointError(format!(
+        "the state of accumulator {id} is not registered with Context::persist"
+    ))
 }
 
 impl<G: GraphViewOps, CS: ComputeState> From<&G> for Context<G, CS> {
@@ -130,6 +182,7 @@ impl<G: GraphViewOps, CS: ComputeState> From<&G> for Context<G, CS> {
             g: g.clone(),
             merge_fns: vec![],
             resetable_states: vec![],
+            codecs: HashMap::new(),
         }
     }
 }
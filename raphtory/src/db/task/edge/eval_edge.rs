@@ -1,7 +1,7 @@
 use crate::{
     core::{
         entities::{edges::edge_ref::EdgeRef, LayerIds, VID},
-        state::compute_state::ComputeState,
+        state::{accumulator_id::AccId, agg::Accumulator, compute_state::ComputeState, StateType},
         storage::locked_view::LockedView,
         Prop,
     },
@@ -47,6 +47,82 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> EvalEdgeView<'a, G, CS,
             _s: PhantomData,
         }
     }
+
+    fn eid(&self) -> usize {
+        self.ev.pid().into()
+    }
+
+    /// Accumulates `a` into the state of the edge
+    pub fn update<A: StateType, IN: 'static, OUT: 'static, ACC: Accumulator<A, IN, OUT>>(
+        &self,
+        id: &AccId<A, IN, OUT, ACC>,
+        a: IN,
+    ) {
+        self.vertex_state
+            .borrow_mut()
+            .shard_mut()
+            .accumulate_into_edge(self.ss, self.eid(), a, id);
+    }
+
+    pub fn global_update<A: StateType, IN: 'static, OUT: 'static, ACC: Accumulator<A, IN, OUT>>(
+        &self,
+        id: &AccId<A, IN, OUT, ACC>,
+        a: IN,
+    ) {
+        self.vertex_state
+            .borrow_mut()
+            .global_mut()
+            .accumulate_global(self.ss, a, id);
+    }
+
+    /// Read the current value of the edge state using the given accumulator.
+    /// Returns a default value if the value is not present.
+    pub fn read<A, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
+        &self,
+        agg_r: &AccId<A, IN, OUT, ACC>,
+    ) -> OUT
+    where
+        A: StateType,
+        OUT: std::fmt::Debug,
+    {
+        self.vertex_state
+            .borrow()
+            .shard()
+            .read_edge(self.ss, self.eid(), agg_r)
+            .unwrap_or(ACC::finish(&ACC::zero()))
+    }
+
+    /// Read the prev value of the edge state using the given accumulator.
+    /// Returns a default value if the value is not present.
+    pub fn read_prev<A, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
+        &self,
+        agg_r: &AccId<A, IN, OUT, ACC>,
+    ) -> OUT
+    where
+        A: StateType,
+        OUT: std::fmt::Debug,
+    {
+        self.vertex_state
+            .borrow()
+            .shard()
+            .read_edge(self.ss + 1, self.eid(), agg_r)
+            .unwrap_or(ACC::finish(&ACC::zero()))
+    }
+
+    /// Reads the global state for a given accumulator, returns None if the state does not exist
+    pub fn read_global_state<A, IN, OUT, ACC: Accumulator<A, IN, OUT>>(
+        &self,
+        agg: &AccId<A, IN, OUT, ACC>,
+    ) -> Option<OUT>
+    where
+        OUT: StateType,
+        A: StateType,
+    {
+        self.vertex_state
+            .borrow()
+            .global()
+            .read_global(self.ss, agg)
+    }
 }
 
 impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static>
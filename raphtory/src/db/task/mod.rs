@@ -2,8 +2,10 @@ use once_cell::sync::Lazy;
 use rayon::{ThreadPool, ThreadPoolBuilder};
 use std::sync::Arc;
 
+pub mod checkpoint;
 pub mod context;
 mod edge;
+pub mod pregel;
 pub mod task;
 pub mod task_runner;
 pub(crate) mod task_state;
@@ -41,16 +43,26 @@ pub fn custom_pool(n_threads: usize) -> Arc<ThreadPool> {
 #[cfg(test)]
 mod task_tests {
     use crate::{
-        core::state::{self, compute_state::ComputeStateVec},
-        db::api::mutation::AdditionOps,
+        algorithms::algorithm_result::AlgorithmResult,
+        core::{
+            state::{self, compute_state::ComputeStateVec},
+            utils::errors::GraphError,
+        },
+        db::{api::mutation::AdditionOps, graph::views::window_graph::WindowedGraph},
         prelude::*,
     };
 
     use super::{
+        checkpoint::{Checkpoint, CHECKPOINT_MAGIC, CHECKPOINT_VERSION},
         context::Context,
-        task::{ATask, Job, Step},
-        task_runner::TaskRunner,
+        task::{AEdgeTask, ATask, Job, Step},
+        task_runner::{SuperstepStats, TaskRunner},
     };
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+    use tempdir::TempDir;
 
     // count all the vertices with a global state
     #[test]
@@ -75,7 +87,7 @@ mod task_tests {
 
         let count = state::accumulator_id::accumulators::sum::<usize>(0);
 
-        ctx.global_agg(count.clone());
+        ctx.global_agg(count);
 
         let step1 = ATask::new(move |vv| {
             vv.global_update(&count, 1);
@@ -97,4 +109,279 @@ mod task_tests {
 
         assert_eq!(actual, 8);
     }
+
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        for (src, dst, ts) in [(1, 2, 1), (2, 3, 2), (3, 4, 3), (5, 4, 4)] {
+            graph.add_edge(ts, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    // every vertex counts the supersteps in its local state and sums its neighbours' counts in
+    // a shard accumulator, the global accumulator sums all counts
+    fn run_counts(
+        graph: &Graph,
+        steps: usize,
+        checkpoint: Option<&Checkpoint>,
+    ) -> (usize, HashMap<String, u64>, Vec<u64>) {
+        let mut ctx: Context<Graph, ComputeStateVec> = graph.into();
+        let total = state::accumulator_id::accumulators::sum::<usize>(0);
+        let neighbours = state::accumulator_id::accumulators::sum::<u64>(1);
+        ctx.global_agg(total);
+        ctx.agg(neighbours);
+        ctx.persist(total);
+        ctx.persist(neighbours);
+
+        let step = ATask::new(move |vv| {
+            let count = *vv.prev() + 1;
+            *vv.get_mut() = count;
+            vv.global_update(&total, 1);
+            for n in vv.neighbours() {
+                n.update(&neighbours, count);
+            }
+            Step::Continue
+        });
+
+        let mut runner = TaskRunner::new(ctx);
+        let f = |egs: super::context::GlobalState<ComputeStateVec>,
+                 ess: crate::core::state::shuffle_state::EvalShardState<Graph, ComputeStateVec>,
+                 _,
+                 local: &Vec<u64>| {
+            (
+                egs.finalize(&total),
+                ess.finalize(&neighbours, |c| c),
+                local.clone(),
+            )
+        };
+        match checkpoint {
+            Some(checkpoint) => runner
+                .run_with_checkpoint(
+                    vec![],
+                    vec![Job::new(step)],
+                    0u64,
+                    f,
+                    Some(2),
+                    steps,
+                    checkpoint,
+                )
+                .unwrap(),
+            None => runner.run(
+                vec![],
+                vec![Job::new(step)],
+                0u64,
+                f,
+                Some(2),
+                steps,
+                None,
+                None,
+            ),
+        }
+    }
+
+    #[test]
+    fn resume_from_checkpoint() {
+        let graph = graph();
+        let dir = TempDir::new("checkpoint").unwrap();
+        let checkpoint = Checkpoint::new(dir.path().join("counts")).every(2);
+
+        // the run saves the states before superstep 0, before superstep 2 and at the end
+        run_counts(&graph, 3, Some(&checkpoint));
+        let ctx: Context<Graph, ComputeStateVec> = (&graph).into();
+        let saved = checkpoint.load::<_, _, u64>(&ctx);
+        assert!(saved.is_err(), "the accumulators are not registered");
+
+        let mut ctx: Context<Graph, ComputeStateVec> = (&graph).into();
+        ctx.persist(state::accumulator_id::accumulators::sum::<usize>(0));
+        ctx.persist(state::accumulator_id::accumulators::sum::<u64>(1));
+        let saved = checkpoint.load::<_, _, u64>(&ctx).unwrap();
+        assert_eq!(saved.superstep, 3);
+        assert!(!saved.done);
+        assert_eq!(saved.local, vec![3; 5]);
+        assert_eq!(
+            saved
+                .global_state()
+                .finalize(&state::accumulator_id::accumulators::sum::<usize>(0)),
+            run_counts(&graph, 3, None).0
+        );
+
+        // resuming continues with superstep 3 and gives the same result as an uninterrupted run
+        let resumed = run_counts(&graph, 6, Some(&checkpoint));
+        assert_eq!(resumed, run_counts(&graph, 6, None));
+        assert_eq!(resumed.2, vec![6; 5]);
+        assert_eq!(checkpoint.load::<_, _, u64>(&ctx).unwrap().superstep, 6);
+
+        checkpoint.remove().unwrap();
+        assert!(!checkpoint.exists());
+    }
+
+    #[test]
+    fn checkpoint_requires_persisted_accumulators() {
+        let graph = graph();
+        let dir = TempDir::new("checkpoint").unwrap();
+        let checkpoint = Checkpoint::new(dir.path().join("counts"));
+
+        let mut ctx: Context<Graph, ComputeStateVec> = (&graph).into();
+        let count = state::accumulator_id::accumulators::sum::<usize>(0);
+        ctx.global_agg(count);
+        let step = ATask::new(move |vv| {
+            vv.global_update(&count, 1);
+            Step::Done
+        });
+        let result = TaskRunner::new(ctx).run_with_checkpoint(
+            vec![],
+            vec![Job::new(step)],
+            (),
+            |egs, _, _, _| egs.finalize(&count),
+            None,
+            1,
+            &checkpoint,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn checkpoint_rejects_foreign_files() {
+        let graph = graph();
+        let dir = TempDir::new("checkpoint").unwrap();
+        let checkpoint = Checkpoint::new(dir.path().join("counts"));
+        let mut ctx: Context<Graph, ComputeStateVec> = (&graph).into();
+        ctx.persist(state::accumulator_id::accumulators::sum::<usize>(0));
+        ctx.persist(state::accumulator_id::accumulators::sum::<u64>(1));
+
+        run_counts(&graph, 1, Some(&checkpoint));
+        let saved = std::fs::read(checkpoint.path()).unwrap();
+        assert_eq!(&saved[..8], CHECKPOINT_MAGIC);
+        assert!(checkpoint.load::<_, _, u64>(&ctx).is_ok());
+
+        // a newer version
+        let mut newer = saved.clone();
+        newer[8..12].copy_from_slice(&(CHECKPOINT_VERSION + 1).to_le_bytes());
+        std::fs::write(checkpoint.path(), newer).unwrap();
+        assert!(matches!(
+            checkpoint.load::<_, _, u64>(&ctx),
+            Err(GraphError::CheckpointError(_))
+        ));
+
+        // a file without the header
+        std::fs::write(checkpoint.path(), &saved[12..]).unwrap();
+        assert!(matches!(
+            checkpoint.load::<_, _, u64>(&ctx),
+            Err(GraphError::CheckpointError(_))
+        ));
+    }
+
+    #[test]
+    fn observer_reports_supersteps() {
+        let graph = graph();
+        let ctx: Context<Graph, ComputeStateVec> = (&graph).into();
+        let stats: Arc<Mutex<Vec<SuperstepStats>>> = Default::default();
+        let observed = stats.clone();
+
+        // vertex i stays active for i supersteps
+        let step = ATask::new(move |vv| {
+            let count = *vv.prev() + 1;
+            *vv.get_mut() = count;
+            match count < vv.id() {
+                true => Step::Continue,
+                false => Step::Done,
+            }
+        });
+        let mut runner = TaskRunner::new(ctx)
+            .with_observer(move |s: &SuperstepStats| observed.lock().unwrap().push(s.clone()));
+        runner.run(
+            vec![],
+            vec![Job::new(step)],
+            0u64,
+            |_, _, _, _| (),
+            None,
+            10,
+            None,
+            None,
+        );
+
+        let stats = stats.lock().unwrap();
+        let supersteps: Vec<_> = stats.iter().map(|s| (s.superstep, s.init)).collect();
+        assert_eq!(
+            supersteps,
+            vec![
+                (0, true),
+                (0, false),
+                (1, false),
+                (2, false),
+                (3, false),
+                (4, false)
+            ]
+        );
+        let active: Vec<_> = stats.iter().map(|s| s.active_vertices.clone()).collect();
+        assert_eq!(
+            active,
+            vec![vec![], vec![4], vec![3], vec![2], vec![1], vec![0]]
+        );
+    }
+
+    #[test]
+    fn edge_tasks() {
+        let graph = graph();
+        let run = |g: &WindowedGraph<Graph>| {
+            let mut ctx: Context<WindowedGraph<Graph>, ComputeStateVec> = g.into();
+            let score = state::accumulator_id::accumulators::sum::<u64>(0);
+            let in_edges = state::accumulator_id::accumulators::sum::<u64>(1);
+            let num_edges = state::accumulator_id::accumulators::sum::<usize>(2);
+            ctx.agg(score);
+            ctx.agg(in_edges);
+            ctx.global_agg(num_edges);
+
+            let degrees = ATask::new(|vv| {
+                *vv.get_mut() = vv.degree() as u64;
+                Step::Continue
+            });
+            // every edge scores the sum of the degrees of its vertices and counts itself at its
+            // destination
+            let scores = AEdgeTask::new(move |ev| {
+                ev.update(&score, *ev.src().prev() + *ev.dst().prev());
+                ev.dst().update(&in_edges, 1);
+                ev.global_update(&num_edges, 1);
+                Step::Done
+            });
+
+            TaskRunner::new(ctx).run(
+                vec![Job::new(degrees)],
+                vec![Job::edges(scores)],
+                0u64,
+                |egs, ess, _, _| {
+                    (
+                        AlgorithmResult::new(ess.finalize_edges(&score, |s| s)),
+                        ess.finalize(&in_edges, |c| c),
+                        egs.finalize(&num_edges),
+                    )
+                },
+                Some(2),
+                1,
+                None,
+                None,
+            )
+        };
+
+        let (scores, in_edges, num_edges) = run(&graph.window(i64::MIN, i64::MAX));
+        let expected: HashMap<(String, String), u64> = [
+            (("1", "2"), 3),
+            (("2", "3"), 4),
+            (("3", "4"), 4),
+            (("5", "4"), 3),
+        ]
+        .into_iter()
+        .map(|((src, dst), s)| ((src.to_string(), dst.to_string()), s))
+        .collect();
+        assert_eq!(scores.get_all(), &expected);
+        assert_eq!(in_edges["4"], 2);
+        assert_eq!(in_edges["1"], 0);
+        assert_eq!(num_edges, 4);
+
+        // only the edges of the view are scheduled
+        let (scores, _, num_edges) = run(&graph.window(1, 3));
+        assert_eq!(scores.get(&("2".to_string(), "3".to_string())), Some(&3));
+        assert_eq!(scores.get_all().len(), 2);
+        assert_eq!(num_edges, 2);
+    }
 }
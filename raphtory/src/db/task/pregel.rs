@@ -0,0 +1,352 @@
+//! Message passing on top of the [`TaskRunner`]
+//!
+//! [`run_pregel`] runs a vertex program in the style of Pregel, Giraph or GraphX. In every
+//! superstep each active vertex reads the messages sent to it in the previous superstep, updates
+//! its value and sends messages to other vertices. A vertex that calls
+//! [`vote_to_halt`](PregelVertex::vote_to_halt) is skipped in the following supersteps until it
+//! receives a message again. The run finishes when all vertices have halted and no messages are
+//! in flight, or after the maximum number of supersteps.
+//!
+//! Messages to the same vertex are combined with an [`Accumulator`], e.g.
+//! [`min`](crate::core::state::accumulator_id::accumulators::min) for shortest paths or
+//! [`hash_set`](crate::core::state::accumulator_id::accumulators::hash_set) to keep all distinct
+//! messages.
+//!
+//! # Examples
+//!
+//! Single source shortest paths from vertex 1:
+//!
+//! ```
+//! use raphtory::core::state::accumulator_id::accumulators;
+//! use raphtory::db::task::pregel::run_pregel;
+//! use raphtory::prelude::*;
+//!
+//! let graph = Graph::new();
+//! for (src, dst) in [(1, 2), (2, 3), (1, 3), (3, 4)] {
+//!     graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+//! }
+//!
+//! let distances = run_pregel(
+//!     &graph,
+//!     u64::MAX,
+//!     accumulators::min::<u64>(0),
+//!     |v| {
+//!         let candidate = match v.superstep() {
+//!             0 if v.vertex().id() == 1 => 0,
+//!             _ => v.messages().unwrap_or(u64::MAX),
+//!         };
+//!         if candidate < *v.value() {
+//!             *v.value_mut() = candidate;
+//!             v.send_message_to_out_neighbours(candidate + 1);
+//!         }
+//!         v.vote_to_halt();
+//!     },
+//!     10,
+//!     None,
+//! );
+//! assert_eq!(distances.get(&"3".to_string()), Some(&1));
+//! assert_eq!(distances.get(&"4".to_string()), Some(&2));
+//! ```
+use super::{
+    context::Context,
+    task::{ATask, Job, Step},
+    task_runner::TaskRunner,
+    vertex::eval_vertex::EvalVertexView,
+};
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::{
+        entities::{vertices::vertex_ref::VertexRef, VID},
+        state::{
+            accumulator_id::{accumulators, AccId},
+            agg::{Accumulator, SumDef},
+            compute_state::ComputeStateVec,
+            StateType,
+        },
+    },
+    db::api::view::{GraphViewOps, VertexViewOps},
+};
+use std::{collections::HashMap, fmt::Debug};
+
+// the combined messages and the number of messages received by a vertex
+const INBOX: u32 = 0;
+const MESSAGE_COUNT: u32 = 1;
+
+fn message_count() -> AccId<usize, usize, usize, SumDef<usize>> {
+    accumulators::sum::<usize>(MESSAGE_COUNT)
+}
+
+/// The local state of a vertex in a Pregel run
+#[derive(Clone, Debug)]
+pub struct PregelState<V> {
+    value: V,
+    halted: bool,
+}
+
+/// A vertex in a superstep of [`run_pregel`]
+pub struct PregelVertex<'a, 'b, G, V, A, IN, OUT, ACC>
+where
+    G: GraphViewOps,
+    V: 'static,
+    ACC: Accumulator<A, IN, OUT>,
+{
+    vv: &'b mut EvalVertexView<'a, G, ComputeStateVec, PregelState<V>>,
+    inbox: AccId<A, IN, OUT, ACC>,
+    sent: bool,
+}
+
+impl<'a, 'b, G, V, A, IN, OUT, ACC> PregelVertex<'a, 'b, G, V, A, IN, OUT, ACC>
+where
+    G: GraphViewOps,
+    V: 'static,
+    A: StateType,
+    IN: 'static,
+    OUT: Debug + 'static,
+    ACC: Accumulator<A, IN, OUT>,
+{
+    /// The view of the vertex, e.g. to access its id, edges and properties
+    pub fn vertex(&self) -> &EvalVertexView<'a, G, ComputeStateVec, PregelState<V>> {
+        self.vv
+    }
+
+    pub fn superstep(&self) -> usize {
+        self.vv.ss
+    }
+
+    pub fn value(&self) -> &V {
+        &self.vv.get().value
+    }
+
+    pub fn value_mut(&mut self) -> &mut V {
+        &mut self.vv.get_mut().value
+    }
+
+    /// The number of messages sent to the vertex in the previous superstep
+    pub fn num_messages(&self) -> usize {
+        self.vv.read_prev(&message_count())
+    }
+
+    /// The combined messages sent to the vertex in the previous superstep, `None` if there were
+    /// none
+    pub fn messages(&self) -> Option<OUT> {
+        (self.num_messages() > 0).then(|| self.vv.read_prev(&self.inbox))
+    }
+
+    /// Sends a message that is delivered in the next superstep, messages to vertices that are not
+    /// in the graph are dropped
+    pub fn send_message<T: Into<VertexRef>>(&mut self, to: T, msg: IN) {
+        let g = self.vv.graph;
+        if let Some(v) = g.internal_vertex_ref(to.into(), &g.layer_ids(), g.edge_filter()) {
+            self.deliver(v, msg);
+        }
+    }
+
+    /// Sends the message to all in- and out-neighbours
+    pub fn send_message_to_neighbours(&mut self, msg: IN)
+    where
+        IN: Clone,
+    {
+        let neighbours: Vec<VID> = self.vv.neighbours().into_iter().map(|n| n.vertex).collect();
+        for n in neighbours {
+            self.deliver(n, msg.clone());
+        }
+    }
+
+    /// Sends the message to all out-neighbours
+    pub fn send_message_to_out_neighbours(&mut self, msg: IN)
+    where
+        IN: Clone,
+    {
+        let neighbours: Vec<VID> = self
+            .vv
+            .out_neighbours()
+            .into_iter()
+            .map(|n| n.vertex)
+            .collect();
+        for n in neighbours {
+            self.deliver(n, msg.clone());
+        }
+    }
+
+    /// Skips the vertex in the following supersteps until it receives a message
+    pub fn vote_to_halt(&mut self) {
+        self.vv.get_mut().halted = true;
+    }
+
+    fn deliver(&mut self, v: VID, msg: IN) {
+        self.vv.update_vertex(v, &self.inbox, msg);
+        self.vv.update_vertex(v, &message_count(), 1);
+        self.sent = true;
+    }
+}
+
+/// Runs a vertex program with message passing
+///
+/// # Arguments
+///
+/// * `g` - A reference to the graph
+/// * `init` - The initial value of every vertex
+/// * `combiner` - The accumulator that combines the messages sent to a vertex, its id is not used
+/// * `compute` - The vertex program, called for every active vertex in every superstep
+/// * `max_supersteps` - The maximum number of supersteps to run
+/// * `threads` - Number of threads to use
+///
+/// # Returns
+///
+/// An AlgorithmResult with the final value of every vertex
+///
+pub fn run_pregel<G, V, A, IN, OUT, ACC, F>(
+    g: &G,
+    init: V,
+    combiner: AccId<A, IN, OUT, ACC>,
+    compute: F,
+    max_supersteps: usize,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, V>
+where
+    G: GraphViewOps,
+    V: Clone + Debug + Send + Sync + 'static,
+    A: StateType,
+    IN: 'static,
+    OUT: Debug + 'static,
+    ACC: Accumulator<A, IN, OUT>,
+    F: Fn(&mut PregelVertex<G, V, A, IN, OUT, ACC>) + Send + Sync + 'static,
+{
+    let mut ctx: Context<G, ComputeStateVec> = g.into();
+    let inbox = combiner.with_id(INBOX);
+    ctx.agg_reset(inbox);
+    ctx.agg_reset(message_count());
+
+    let step = ATask::new(
+        move |vv: &mut EvalVertexView<G, ComputeStateVec, PregelState<V>>| {
+            // halted vertices are reactivated by incoming messages
+            if vv.get().halted && vv.read_prev(&message_count()) == 0 {
+                return Step::Done;
+            }
+            vv.get_mut().halted = false;
+
+            let mut vertex = PregelVertex {
+                vv,
+                inbox,
+                sent: false,
+            };
+            compute(&mut vertex);
+
+            if !vertex.vv.get().halted || vertex.sent {
+                Step::Continue
+            } else {
+                Step::Done
+            }
+        },
+    );
+
+    let mut runner: TaskRunner<G, _> = TaskRunner::new(ctx);
+
+    let out: HashMap<VID, V> = runner.run(
+        vec![],
+        vec![Job::new(step)],
+        PregelState {
+            value: init,
+            halted: false,
+        },
+        |_, _, _, local| {
+            let layers = g.layer_ids();
+            let edge_filter = g.edge_filter();
+            local
+                .iter()
+                .enumerate()
+                .filter(|(v_ref, _)| {
+                    g.has_vertex_ref(VertexRef::Internal((*v_ref).into()), &layers, edge_filter)
+                })
+                .map(|(v_ref, state)| (v_ref.into(), state.value.clone()))
+                .collect::<HashMap<_, _>>()
+        },
+        threads,
+        max_supersteps,
+        None,
+        None,
+    );
+
+    AlgorithmResult::new(
+        out.into_iter()
+            .map(|(k, v)| (g.vertex_name(k), v))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod pregel_test {
+    use super::*;
+    use crate::{algorithms::connected_components::weakly_connected_components, prelude::*};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn connected_components_match_wcc() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (4, 3), (5, 6), (7, 7), (9, 8)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        let components = run_pregel(
+            &graph,
+            u64::MAX,
+            accumulators::min::<u64>(7),
+            |v| {
+                let id = v.vertex().id();
+                let candidate = v.messages().unwrap_or(id).min(id);
+                if candidate < *v.value() {
+                    *v.value_mut() = candidate;
+                    v.send_message_to_neighbours(candidate);
+                }
+                v.vote_to_halt();
+            },
+            100,
+            Some(2),
+        );
+        assert_eq!(
+            components.get_all(),
+            weakly_connected_components(&graph, 100, None).get_all()
+        );
+    }
+
+    #[test]
+    fn halted_vertices_wake_up_on_messages() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 4)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+
+        // vertex 1 starts a token that is passed along the path, every vertex collects the
+        // supersteps it received messages in
+        let received = run_pregel(
+            &graph,
+            vec![],
+            accumulators::sum::<usize>(0),
+            move |v| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                if let Some(hops) = v.messages() {
+                    let superstep = v.superstep();
+                    v.value_mut().push((superstep, hops));
+                    v.send_message_to_out_neighbours(hops + 1);
+                } else if v.superstep() == 0 && v.vertex().id() == 1 {
+                    v.send_message(2u64, 1);
+                    v.send_message(9u64, 1);
+                }
+                v.vote_to_halt();
+            },
+            100,
+            None,
+        );
+
+        assert_eq!(received.get(&"1".to_string()), Some(&vec![]));
+        assert_eq!(received.get(&"2".to_string()), Some(&vec![(1, 1)]));
+        assert_eq!(received.get(&"4".to_string()), Some(&vec![(3, 3)]));
+        // all vertices run in superstep 0, then only the one holding the token
+        assert_eq!(calls.load(Ordering::Relaxed), 4 + 3);
+    }
+}
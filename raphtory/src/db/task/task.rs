@@ -1,7 +1,10 @@
 use super::context::GlobalState;
 use crate::{
     core::state::compute_state::ComputeState,
-    db::{api::view::GraphViewOps, task::vertex::eval_vertex::EvalVertexView},
+    db::{
+        api::view::GraphViewOps,
+        task::{edge::eval_edge::EvalEdgeView, vertex::eval_vertex::EvalVertexView},
+    },
 };
 use std::marker::PhantomData;
 
@@ -13,6 +16,14 @@ where
     fn run(&self, vv: &mut EvalVertexView<G, CS, S>) -> Step;
 }
 
+pub trait EdgeTask<G, CS, S>
+where
+    G: GraphViewOps,
+    CS: ComputeState,
+{
+    fn run(&self, ev: &mut EvalEdgeView<G, CS, S>) -> Step;
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Step {
     Done,
@@ -31,10 +42,24 @@ where
     _s: PhantomData<S>,
 }
 
+pub struct AEdgeTask<G, CS, S, F>
+where
+    G: GraphViewOps,
+    CS: ComputeState,
+    F: Fn(&mut EvalEdgeView<G, CS, S>) -> Step,
+{
+    f: F,
+    _g: PhantomData<G>,
+    _cs: PhantomData<CS>,
+    _s: PhantomData<S>,
+}
+
 // determines if the task is executed for all vertices or only for updated vertices (vertices that had a state change since last sync)
 pub enum Job<G, CS: ComputeState, S> {
     Read(Box<dyn Task<G, CS, S> + Sync + Send>),
     Write(Box<dyn Task<G, CS, S> + Sync + Send>),
+    // runs for every edge of the graph, the edges only have accumulator states
+    Edge(Box<dyn EdgeTask<G, CS, S> + Sync + Send>),
     Check(Box<dyn Fn(&GlobalState<CS>) -> Step + Send + Sync + 'static>),
 }
 
@@ -46,6 +71,10 @@ impl<G: GraphViewOps, CS: ComputeState, S> Job<G, CS, S> {
     pub fn read_only<T: Task<G, CS, S> + Send + Sync + 'static>(t: T) -> Self {
         Self::Read(Box::new(t))
     }
+
+    pub fn edges<T: EdgeTask<G, CS, S> + Send + Sync + 'static>(t: T) -> Self {
+        Self::Edge(Box::new(t))
+    }
 }
 
 impl<G, CS, S, F> ATask<G, CS, S, F>
@@ -74,3 +103,30 @@ where
         (self.f)(vv)
     }
 }
+
+impl<G, CS, S, F> AEdgeTask<G, CS, S, F>
+where
+    G: GraphViewOps,
+    CS: ComputeState,
+    F: Fn(&mut EvalEdgeView<G, CS, S>) -> Step,
+{
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            _g: PhantomData,
+            _cs: PhantomData,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<G, CS, S, F> EdgeTask<G, CS, S> for AEdgeTask<G, CS, S, F>
+where
+    G: GraphViewOps,
+    CS: ComputeState,
+    F: Fn(&mut EvalEdgeView<G, CS, S>) -> Step,
+{
+    fn run(&self, ev: &mut EvalEdgeView<G, CS, S>) -> Step {
+        (self.f)(ev)
+    }
+}
@@ -1,37 +1,89 @@
 use super::{
+    checkpoint::{Checkpoint, CheckpointState},
     context::{Context, GlobalState},
     custom_pool,
-    task::{Job, Step, Task},
+    task::{EdgeTask, Job, Step, Task},
     task_state::{Global, Local2, Shard},
     POOL,
 };
 use crate::{
     core::{
-        entities::vertices::vertex_ref::VertexRef,
+        entities::{vertices::vertex_ref::VertexRef, EID},
         state::{
             compute_state::ComputeState,
             shuffle_state::{EvalLocalState, EvalShardState},
         },
+        utils::errors::GraphError,
     },
     db::{
         api::view::GraphViewOps,
-        task::vertex::{eval_vertex::EvalVertexView, eval_vertex_state::EVState},
+        task::{
+            edge::eval_edge::EvalEdgeView,
+            vertex::{eval_vertex::EvalVertexView, eval_vertex_state::EVState},
+        },
     },
 };
 use rayon::{prelude::*, ThreadPool};
+use serde::{de::DeserializeOwned, Serialize};
 use std::{
     borrow::Cow,
+    cell::RefCell,
     rc::Rc,
-    sync::atomic::{AtomicBool, Ordering},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    time::{Duration, Instant},
 };
 
+/// Statistics of a superstep reported to a [`TaskObserver`]
+#[derive(Clone, Debug)]
+pub struct SuperstepStats {
+    /// The superstep, the init tasks run before superstep 0 and are reported with `init` set
+    pub superstep: usize,
+    pub init: bool,
+    pub duration: Duration,
+    /// The number of vertices (or edges for edge tasks) that returned `Step::Continue` for each
+    /// task that ran
+    pub active_vertices: Vec<usize>,
+}
+
+/// A hook called by the [`TaskRunner`] after every superstep
+pub trait TaskObserver: Send + Sync {
+    fn superstep_finished(&self, stats: &SuperstepStats);
+}
+
+impl<F: Fn(&SuperstepStats) + Send + Sync> TaskObserver for F {
+    fn superstep_finished(&self, stats: &SuperstepStats) {
+        self(stats)
+    }
+}
+
 pub struct TaskRunner<G: GraphViewOps, CS: ComputeState> {
     pub(crate) ctx: Context<G, CS>,
+    observer: Option<Box<dyn TaskObserver>>,
 }
 
 impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
     pub fn new(ctx: Context<G, CS>) -> Self {
-        Self { ctx }
+        Self {
+            ctx,
+            observer: None,
+        }
+    }
+
+    /// Reports the statistics of every superstep to the observer
+    pub fn with_observer<O: TaskObserver + 'static>(mut self, observer: O) -> Self {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    fn observe(&self, superstep: usize, init: bool, start: Instant, active_vertices: Vec<usize>) {
+        if let Some(observer) = &self.observer {
+            observer.superstep_finished(&SuperstepStats {
+                superstep,
+                init,
+                duration: start.elapsed(),
+                active_vertices,
+            });
+        }
     }
 
     fn merge_states(
@@ -51,6 +103,7 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
         morcel: &mut [S],
         prev_local_state: &Vec<S>,
         atomic_done: &AtomicBool,
+        active: &AtomicUsize,
         morcel_size: usize,
         morcel_id: usize,
         task: &Box<dyn Task<G, CS, S> + Send + Sync>,
@@ -62,6 +115,7 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
         let g = self.ctx.graph();
 
         let mut done = true;
+        let mut num_active = 0;
 
         let vertex_state = EVState::rc_from(shard_state_view, global_state_view);
 
@@ -85,6 +139,7 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
                 match task.run(&mut vv) {
                     Step::Continue => {
                         done = false;
+                        num_active += 1;
                     }
                     Step::Done => {}
                 }
@@ -94,8 +149,67 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
 
         if !done {
             atomic_done.store(false, Ordering::Relaxed);
+            active.fetch_add(num_active, Ordering::Relaxed);
         }
 
+        Self::restore_states(shard_state, global_state, vertex_state)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn run_edge_task<S: 'static>(
+        &self,
+        shard_state: &Shard<CS>,
+        global_state: &Global<CS>,
+        prev_local_state: &Vec<S>,
+        atomic_done: &AtomicBool,
+        active: &AtomicUsize,
+        morcel_id: usize,
+        task: &(dyn EdgeTask<G, CS, S> + Send + Sync),
+    ) -> (Shard<CS>, Global<CS>) {
+        let edge_morcel_size = shard_state.inner().edge_morcel_size;
+        let num_edges = shard_state.inner().edges[morcel_id].morcel_size;
+
+        let shard_state_view = shard_state.as_cow();
+        let global_state_view = global_state.as_cow();
+
+        let g = self.ctx.graph();
+        let layer_ids = g.layer_ids();
+        let edge_filter = g.edge_filter();
+
+        let mut done = true;
+        let mut num_active = 0;
+
+        let vertex_state = EVState::rc_from(shard_state_view, global_state_view);
+
+        let local = Local2::new(prev_local_state);
+        let start = morcel_id * edge_morcel_size;
+        for e_id in start..start + num_edges {
+            if let Some(e) = g.find_edge_id(EID::from(e_id), &layer_ids, edge_filter) {
+                let mut ev = EvalEdgeView::new(self.ctx.ss(), e, &g, vertex_state.clone(), &local);
+
+                match task.run(&mut ev) {
+                    Step::Continue => {
+                        done = false;
+                        num_active += 1;
+                    }
+                    Step::Done => {}
+                }
+            }
+        }
+
+        if !done {
+            atomic_done.store(false, Ordering::Relaxed);
+            active.fetch_add(num_active, Ordering::Relaxed);
+        }
+
+        Self::restore_states(shard_state, global_state, vertex_state)
+    }
+
+    fn restore_states(
+        shard_state: &Shard<CS>,
+        global_state: &Global<CS>,
+        vertex_state: Rc<RefCell<EVState<CS>>>,
+    ) -> (Shard<CS>, Global<CS>) {
         let vertex_state: EVState<CS> = Rc::try_unwrap(vertex_state).unwrap().into_inner();
         let (shard_state_view, global_state_view) = vertex_state.restore_states();
 
@@ -128,15 +242,17 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
         global_state: Global<CS>,
         mut local_state: Vec<S>,
         prev_local_state: &Vec<S>,
-    ) -> (bool, Shard<CS>, Global<CS>, Vec<S>) {
+    ) -> (bool, Shard<CS>, Global<CS>, Vec<S>, Vec<usize>) {
         pool.install(move || {
             let mut new_shard_state = shard_state;
             let mut new_global_state = global_state;
 
             let mut done = false;
+            let mut active_vertices = vec![];
 
             for task in tasks.iter() {
                 let atomic_done = AtomicBool::new(true);
+                let active = AtomicUsize::new(0);
 
                 let updated_state: Option<(Shard<CS>, Global<CS>)> = match task {
                     Job::Write(task) => local_state
@@ -149,6 +265,7 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
                                 morcel,
                                 prev_local_state,
                                 &atomic_done,
+                                &active,
                                 morcel_size,
                                 morcel_id,
                                 task,
@@ -166,6 +283,7 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
                                     morcel,
                                     prev_local_state,
                                     &atomic_done,
+                                    &active,
                                     morcel_size,
                                     morcel_id,
                                     task,
@@ -173,6 +291,20 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
                             });
                         None
                     }
+                    Job::Edge(task) => (0..new_shard_state.inner().edges.len())
+                        .into_par_iter()
+                        .map(|morcel_id| {
+                            self.run_edge_task(
+                                &new_shard_state,
+                                &new_global_state,
+                                prev_local_state,
+                                &atomic_done,
+                                &active,
+                                morcel_id,
+                                task.as_ref(),
+                            )
+                        })
+                        .reduce_with(|a, b| self.merge_states(a, b)),
                     Job::Check(task) => {
                         match task(&GlobalState::new(new_global_state.clone(), self.ctx.ss())) {
                             Step::Continue => {
@@ -189,13 +321,23 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
                     new_global_state = global_state;
                 }
 
+                if !matches!(task, Job::Check(_)) {
+                    active_vertices.push(active.into_inner());
+                }
+
                 if atomic_done.load(Ordering::Relaxed) {
                     done = true;
                     break;
                 }
             }
 
-            (done, new_shard_state, new_global_state, local_state)
+            (
+                done,
+                new_shard_state,
+                new_global_state,
+                local_state,
+                active_vertices,
+            )
         })
     }
 
@@ -223,6 +365,86 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
         shard_initial_state: Option<Shard<CS>>,
         global_initial_state: Option<Global<CS>>,
     ) -> B {
+        self.run_from(
+            init_tasks,
+            tasks,
+            init,
+            f,
+            num_threads,
+            steps,
+            shard_initial_state,
+            global_initial_state,
+            None,
+            |_, _, _, _, _| Ok(()),
+        )
+        .expect("running without checkpoints does not fail")
+    }
+
+    /// Runs the tasks like [`run`](Self::run) while saving the states to the checkpoint
+    ///
+    /// If the checkpoint exists the run resumes from it instead of starting with the init tasks.
+    /// The accumulators need to be registered with [`Context::persist`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_with_checkpoint<
+        B: std::fmt::Debug,
+        F: FnOnce(GlobalState<CS>, EvalShardState<G, CS>, EvalLocalState<G, CS>, &Vec<S>) -> B
+            + std::marker::Copy,
+        S: Send + Sync + Clone + 'static + std::fmt::Debug + Serialize + DeserializeOwned,
+    >(
+        &mut self,
+        init_tasks: Vec<Job<G, CS, S>>,
+        tasks: Vec<Job<G, CS, S>>,
+        init: S,
+        f: F,
+        num_threads: Option<usize>,
+        steps: usize,
+        checkpoint: &Checkpoint,
+    ) -> Result<B, GraphError> {
+        let resume = match checkpoint.exists() {
+            true => Some(checkpoint.load(&self.ctx)?),
+            false => None,
+        };
+        self.run_from(
+            init_tasks,
+            tasks,
+            init,
+            f,
+            num_threads,
+            steps,
+            None,
+            None,
+            resume,
+            |ctx, done, shard_state, global_state, local_state| {
+                // the last superstep is always saved so a later run can continue with more steps
+                if done || ctx.ss() >= steps || checkpoint.is_due(ctx.ss()) {
+                    checkpoint.save(ctx, done, shard_state, global_state, local_state)
+                } else {
+                    Ok(())
+                }
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn run_from<
+        B: std::fmt::Debug,
+        F: FnOnce(GlobalState<CS>, EvalShardState<G, CS>, EvalLocalState<G, CS>, &Vec<S>) -> B
+            + std::marker::Copy,
+        S: Send + Sync + Clone + 'static + std::fmt::Debug,
+        SAVE: FnMut(&Context<G, CS>, bool, &Shard<CS>, &Global<CS>, &[S]) -> Result<(), GraphError>,
+    >(
+        &mut self,
+        init_tasks: Vec<Job<G, CS, S>>,
+        tasks: Vec<Job<G, CS, S>>,
+        init: S,
+        f: F,
+        num_threads: Option<usize>,
+        steps: usize,
+        shard_initial_state: Option<Shard<CS>>,
+        global_initial_state: Option<Global<CS>>,
+        resume: Option<CheckpointState<CS, S>>,
+        mut save: SAVE,
+    ) -> Result<B, GraphError> {
         let pool = num_threads
             .map(|nt| custom_pool(nt))
             .unwrap_or_else(|| POOL.clone());
@@ -231,30 +453,83 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
         let morcel_size = num_vertices.min(16_000);
         let num_chunks = (num_vertices + morcel_size - 1) / morcel_size;
 
-        let mut shard_state = shard_initial_state
-            .unwrap_or_else(|| Shard::new(num_vertices, num_chunks, morcel_size));
-
-        let mut global_state = global_initial_state.unwrap_or_else(|| Global::new());
-
-        let (mut cur_local_state, mut prev_local_state) = self.make_cur_and_prev_states::<S>(init);
-
-        let mut _done = false;
-
-        (_done, shard_state, global_state, cur_local_state) = self.run_task_list(
-            &init_tasks,
-            &pool,
-            morcel_size,
-            shard_state,
-            global_state,
-            cur_local_state,
-            &prev_local_state,
-        );
-
-        // To allow the init step to cache stuff we will copy everything from cur_local_state to prev_local_state
-        prev_local_state.clone_from_slice(&cur_local_state);
+        let num_edges = self.ctx.graph().unfiltered_num_edges();
+        let edge_morcel_size = num_edges.clamp(1, 16_000);
+        let num_edge_chunks = (num_edges + edge_morcel_size - 1) / edge_morcel_size;
+
+        let (
+            mut _done,
+            mut shard_state,
+            mut global_state,
+            mut cur_local_state,
+            mut prev_local_state,
+        ) = match resume {
+            Some(state) => {
+                // the local states are equal at superstep boundaries
+                self.ctx.set_ss(state.superstep);
+                (
+                    state.done,
+                    state.shard,
+                    state.global,
+                    state.local.clone(),
+                    state.local,
+                )
+            }
+            None => {
+                let shard_state = shard_initial_state.unwrap_or_else(|| {
+                    Shard::new(
+                        num_vertices,
+                        num_chunks,
+                        morcel_size,
+                        num_edges,
+                        num_edge_chunks,
+                        edge_morcel_size,
+                    )
+                });
+
+                let global_state = global_initial_state.unwrap_or_else(|| Global::new());
+
+                let (cur_local_state, mut prev_local_state) =
+                    self.make_cur_and_prev_states::<S>(init);
+
+                let start = Instant::now();
+                let (done, shard_state, global_state, cur_local_state, active) = self
+                    .run_task_list(
+                        &init_tasks,
+                        &pool,
+                        morcel_size,
+                        shard_state,
+                        global_state,
+                        cur_local_state,
+                        &prev_local_state,
+                    );
+                self.observe(self.ctx.ss(), true, start, active);
+
+                // To allow the init step to cache stuff we will copy everything from cur_local_state to prev_local_state
+                prev_local_state.clone_from_slice(&cur_local_state);
+
+                save(
+                    &self.ctx,
+                    done,
+                    &shard_state,
+                    &global_state,
+                    &cur_local_state,
+                )?;
+                (
+                    done,
+                    shard_state,
+                    global_state,
+                    cur_local_state,
+                    prev_local_state,
+                )
+            }
+        };
 
         while !_done && self.ctx.ss() < steps && tasks.len() > 0 {
-            (_done, shard_state, global_state, cur_local_state) = self.run_task_list(
+            let superstep = self.ctx.ss();
+            let start = Instant::now();
+            let active;
+            (_done, shard_state, global_state, cur_local_state, active) = self.run_task_list(
                 &tasks,
                 &pool,
                 morcel_size,
@@ -274,6 +549,15 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
 
             // Copy and reset the local states from the step that just ended
             self.ctx.increment_ss();
+
+            self.observe(superstep, false, start, active);
+            save(
+                &self.ctx,
+                _done,
+                &shard_state,
+                &global_state,
+                &cur_local_state,
+            )?;
         }
 
         let ss: usize = self.ctx.ss();
@@ -283,11 +567,11 @@ impl<G: GraphViewOps, CS: ComputeState> TaskRunner<G, CS> {
             prev_local_state
         };
 
-        f(
+        Ok(f(
             GlobalState::new(global_state, ss),
             EvalShardState::new(ss, self.ctx.graph(), shard_state),
             EvalLocalState::new(ss, self.ctx.graph(), vec![]),
             &last_local_state,
-        )
+        ))
     }
 }
@@ -27,12 +27,21 @@ impl<'a, S: 'static> Local2<'a, S> {
 }
 
 impl<CS: ComputeState> Shard<CS> {
-    pub(crate) fn new(total_len: usize, num_morcels: usize, morcel_size: usize) -> Self {
-        Self(Arc::new(ShuffleComputeState::new(
-            total_len,
-            num_morcels,
-            morcel_size,
-        )))
+    pub(crate) fn new(
+        total_len: usize,
+        num_morcels: usize,
+        morcel_size: usize,
+        num_edges: usize,
+        num_edge_morcels: usize,
+        edge_morcel_size: usize,
+    ) -> Self {
+        Self(Arc::new(
+            ShuffleComputeState::new(total_len, num_morcels, morcel_size).with_edges(
+                num_edges,
+                num_edge_morcels,
+                edge_morcel_size,
+            ),
+        ))
     }
 
     pub(crate) fn as_cow(&self) -> Cow<'_, ShuffleComputeState<CS>> {
@@ -36,8 +36,8 @@ use std::{
 };
 
 pub struct EvalVertexView<'a, G: GraphViewOps, CS: ComputeState, S: 'static> {
-    ss: usize,
-    vertex: VID,
+    pub(crate) ss: usize,
+    pub(crate) vertex: VID,
     pub(crate) graph: &'a G,
     local_state: Option<&'a mut S>,
     local_state_prev: &'a Local2<'a, S>,
@@ -97,6 +97,24 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S> EvalVertexView<'a, G, CS, S> {
             .accumulate_into(self.ss, self.pid(), a, id);
     }
 
+    /// Accumulates `a` into the state of another vertex
+    pub(crate) fn update_vertex<
+        A: StateType,
+        IN: 'static,
+        OUT: 'static,
+        ACC: Accumulator<A, IN, OUT>,
+    >(
+        &self,
+        v: VID,
+        id: &AccId<A, IN, OUT, ACC>,
+        a: IN,
+    ) {
+        self.vertex_state
+            .borrow_mut()
+            .shard_mut()
+            .accumulate_into(self.ss, v.into(), a, id);
+    }
+
     pub fn global_update<A: StateType, IN: 'static, OUT: 'static, ACC: Accumulator<A, IN, OUT>>(
         &self,
         id: &AccId<A, IN, OUT, ACC>,
@@ -304,7 +322,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
 {
     type Graph = G;
     type ValueType<T> = BoxedIter<T>;
-    type PathType<'b> = EvalPathFromVertex<'a, G, CS, S> where Self: 'b;
+    type PathType<'b>
+        = EvalPathFromVertex<'a, G, CS, S>
+    where
+        Self: 'b;
     type EList = BoxedIter<EdgeView<G>>;
 
     fn id(&self) -> Self::ValueType<u64> {
@@ -315,6 +336,14 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
         self.path.name()
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<String>> {
+        self.path.vertex_type()
+    }
+
+    fn layer_names(&self) -> Self::ValueType<Vec<String>> {
+        self.path.layer_names()
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.path.earliest_time()
     }
@@ -402,7 +431,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
 {
     type Graph = G;
     type ValueType<T> = T;
-    type PathType<'b> = EvalPathFromVertex<'a, G, CS, S> where Self: 'b;
+    type PathType<'b>
+        = EvalPathFromVertex<'a, G, CS, S>
+    where
+        Self: 'b;
     type EList = Box<dyn Iterator<Item = EvalEdgeView<'a, G, CS, S>> + 'a>;
 
     fn id(&self) -> Self::ValueType<u64> {
@@ -413,6 +445,15 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
         self.graph.vertex_name(self.vertex)
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<String>> {
+        self.graph.vertex_type(self.vertex)
+    }
+
+    fn layer_names(&self) -> Self::ValueType<Vec<String>> {
+        let layer_ids = self.graph.vertex_layer_ids(self.vertex);
+        self.graph.get_layer_names_from_ids(layer_ids)
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.graph.vertex_earliest_time(self.vertex)
     }
@@ -626,6 +667,14 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexListOps
         Box::new(self.map(|v| v.name()))
     }
 
+    fn vertex_type(self) -> Self::IterType<Option<String>> {
+        Box::new(self.map(|v| v.vertex_type()))
+    }
+
+    fn layer_names(self) -> Self::IterType<Vec<String>> {
+        Box::new(self.map(|v| v.layer_names()))
+    }
+
     fn properties(self) -> Self::IterType<Properties<VertexView<G>>> {
         Box::new(self.map(move |v| v.properties()))
     }
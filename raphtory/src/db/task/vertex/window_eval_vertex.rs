@@ -121,7 +121,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
 {
     type Graph = WindowedGraph<G>;
     type ValueType<T> = T;
-    type PathType<'b> = WindowEvalPathFromVertex<'a, G, CS, S> where Self: 'b;
+    type PathType<'b>
+        = WindowEvalPathFromVertex<'a, G, CS, S>
+    where
+        Self: 'b;
     type EList = Box<dyn Iterator<Item = WindowEvalEdgeView<'a, G, CS, S>> + 'a>;
 
     fn id(&self) -> Self::ValueType<u64> {
@@ -132,6 +135,15 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
         self.graph.vertex_name(self.vertex)
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<String>> {
+        self.graph.vertex_type(self.vertex)
+    }
+
+    fn layer_names(&self) -> Self::ValueType<Vec<String>> {
+        let layer_ids = self.graph.vertex_layer_ids(self.vertex);
+        self.graph.get_layer_names_from_ids(layer_ids)
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.graph
             .vertex_earliest_time_window(self.vertex, self.t_start, self.t_end)
@@ -472,7 +484,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
 
     type ValueType<T> = Box<dyn Iterator<Item = T> + 'a>;
 
-    type PathType<'b> = WindowEvalPathFromVertex<'a, G, CS, S> where Self: 'b;
+    type PathType<'b>
+        = WindowEvalPathFromVertex<'a, G, CS, S>
+    where
+        Self: 'b;
 
     type EList = Box<dyn Iterator<Item = WindowEvalEdgeView<'a, G, CS, S>> + 'a>;
 
@@ -484,6 +499,14 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
         self.path.name()
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<String>> {
+        self.path.vertex_type()
+    }
+
+    fn layer_names(&self) -> Self::ValueType<Vec<String>> {
+        self.path.layer_names()
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.path.earliest_time()
     }
@@ -588,6 +611,14 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexListOps
         Box::new(self.map(|v| v.name()))
     }
 
+    fn vertex_type(self) -> Self::IterType<Option<String>> {
+        Box::new(self.map(|v| v.vertex_type()))
+    }
+
+    fn layer_names(self) -> Self::IterType<Vec<String>> {
+        Box::new(self.map(|v| v.layer_names()))
+    }
+
     fn properties(self) -> Self::IterType<Properties<VertexView<Self::Graph>>> {
         Box::new(self.map(move |v| v.properties()))
     }
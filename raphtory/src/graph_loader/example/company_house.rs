@@ -77,6 +77,7 @@ pub fn company_house_graph(path: Option<String>) -> Graph {
                     NaiveDateTime::from_timestamp_opt(ts, 0).unwrap(),
                     owner.clone(),
                     NO_PROPS,
+                    None,
                 )
                 .expect("Failed to add vertex");
 
@@ -87,6 +88,7 @@ pub fn company_house_graph(path: Option<String>) -> Graph {
                     NaiveDateTime::from_timestamp_opt(ts, 0).unwrap(),
                     company.clone(),
                     NO_PROPS,
+                    None,
                 )
                 .expect("Failed to add vertex");
 
@@ -107,6 +109,7 @@ pub fn company_house_graph(path: Option<String>) -> Graph {
                     NaiveDateTime::from_timestamp_opt(ts, 0).unwrap(),
                     address.clone(),
                     NO_PROPS,
+                    None,
                 )
                 .expect("Failed to add vertex");
 
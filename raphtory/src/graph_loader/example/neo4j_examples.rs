@@ -22,13 +22,13 @@ fn load_movies(row: Row, graph: &rap::Graph) {
     let relation_type = relation.typ();
 
     graph
-        .add_vertex(actor_born, actor_name.clone(), NO_PROPS)
+        .add_vertex(actor_born, actor_name.clone(), NO_PROPS, None)
         .unwrap();
     graph
         .add_vertex_properties(actor_name.clone(), [("type", "actor")])
         .unwrap();
     graph
-        .add_vertex(film_release, film_title.clone(), NO_PROPS)
+        .add_vertex(film_release, film_title.clone(), NO_PROPS, None)
         .unwrap();
     graph
         .add_vertex_properties(
@@ -0,0 +1,356 @@
+//! Export graph views as [GEXF](https://gexf.net/) files, e.g. for Gephi.
+//!
+//! In [`GexfMode::Static`] every vertex and edge is written with the latest value of each of its
+//! properties. In [`GexfMode::Dynamic`] the history of vertices and edges is written as spells,
+//! one per update time, and each value of a temporal property is valid from the time it was set
+//! until the next change. Constant properties are written as static attributes in both modes.
+//! Vertices are identified by their name. Each layer of an edge is written as a separate edge
+//! labelled with the layer name.
+//!
+//! # Example
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graph_loader::export::gexf::{write_gexf, GexfMode};
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, "Alice", "Bob", [("weight", Prop::F64(1.0))], None).unwrap();
+//! g.add_edge(5, "Alice", "Bob", [("weight", Prop::F64(2.0))], None).unwrap();
+//!
+//! let dir = tempdir::TempDir::new("gexf").unwrap();
+//! write_gexf(&g, dir.path().join("graph.gexf"), GexfMode::Dynamic).unwrap();
+//! ```
+use crate::{
+    core::Prop,
+    db::api::{
+        properties::internal::PropertiesOps,
+        properties::Properties,
+        view::{EdgeViewOps, GraphViewOps, VertexViewOps},
+    },
+};
+use quick_xml::escape::escape;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// Whether to write the history of the graph or only its latest state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GexfMode {
+    Static,
+    Dynamic,
+}
+
+/// The GEXF type of `prop`, properties without a matching type are written as strings
+fn prop_type(prop: &Prop) -> &'static str {
+    match prop {
+        Prop::I32(_) => "integer",
+        Prop::I64(_) | Prop::U32(_) | Prop::U64(_) => "long",
+        Prop::F32(_) => "float",
+        Prop::F64(_) => "double",
+        Prop::Bool(_) => "boolean",
+        _ => "string",
+    }
+}
+
+/// The attribute values of a vertex or edge
+#[derive(Default)]
+struct Values {
+    /// Static values
+    fixed: Vec<(String, Prop)>,
+    /// Values with the time they were set and the time they were replaced
+    timed: Vec<(String, Prop, i64, Option<i64>)>,
+    history: Vec<i64>,
+}
+
+impl Values {
+    fn new<P: PropertiesOps + Clone>(
+        props: Properties<P>,
+        history: Vec<i64>,
+        mode: GexfMode,
+    ) -> Self {
+        let mut values = Values::default();
+        for (name, prop) in props.constant().iter() {
+            if name != "_id" && name != "_type" {
+                values.fixed.push((name, prop));
+            }
+        }
+        match mode {
+            GexfMode::Static => {
+                for (name, prop) in props.temporal().iter_latest() {
+                    values.fixed.push((name.to_string(), prop));
+                }
+            }
+            GexfMode::Dynamic => {
+                for (name, prop) in props.temporal().iter() {
+                    let updates: Vec<_> = prop.iter().collect();
+                    for (i, (t, value)) in updates.iter().enumerate() {
+                        let end = updates.get(i + 1).map(|(end, _)| *end);
+                        values
+                            .timed
+                            .push((name.to_string(), value.clone(), *t, end));
+                    }
+                }
+                values.history = history;
+                values.history.dedup();
+            }
+        }
+        values
+    }
+}
+
+/// Attribute declarations of one class of elements, sorted by name
+#[derive(Default)]
+struct Attributes {
+    fixed: BTreeMap<String, &'static str>,
+    timed: BTreeMap<String, &'static str>,
+    /// Attribute ids, assigned by [`Attributes::finish`]
+    fixed_ids: HashMap<String, usize>,
+    timed_ids: HashMap<String, usize>,
+}
+
+impl Attributes {
+    fn add(&mut self, values: &Values) {
+        for (name, prop) in &values.fixed {
+            self.fixed
+                .entry(name.clone())
+                .or_insert_with(|| prop_type(prop));
+        }
+        for (name, prop, _, _) in &values.timed {
+            self.timed
+                .entry(name.clone())
+                .or_insert_with(|| prop_type(prop));
+        }
+    }
+
+    /// Assign the attribute ids once all values are added
+    fn finish(mut self) -> Self {
+        self.fixed_ids = self
+            .fixed
+            .keys()
+            .enumerate()
+            .map(|(id, name)| (name.clone(), id))
+            .collect();
+        self.timed_ids = self
+            .timed
+            .keys()
+            .enumerate()
+            .map(|(id, name)| (name.clone(), self.fixed.len() + id))
+            .collect();
+        self
+    }
+
+    fn fixed_id(&self, name: &str) -> usize {
+        self.fixed_ids[name]
+    }
+
+    fn timed_id(&self, name: &str) -> usize {
+        self.timed_ids[name]
+    }
+
+    fn write<W: Write>(&self, out: &mut W, class: &str) -> io::Result<()> {
+        for (mode, attrs, offset) in [
+            ("static", &self.fixed, 0),
+            ("dynamic", &self.timed, self.fixed.len()),
+        ] {
+            if attrs.is_empty() {
+                continue;
+            }
+            writeln!(out, r#"    <attributes class="{}" mode="{}">"#, class, mode)?;
+            for (i, (name, dtype)) in attrs.iter().enumerate() {
+                writeln!(
+                    out,
+                    r#"      <attribute id="{}" title="{}" type="{}"/>"#,
+                    offset + i,
+                    escape(name),
+                    dtype
+                )?;
+            }
+            writeln!(out, "    </attributes>")?;
+        }
+        Ok(())
+    }
+
+    fn write_values<W: Write>(&self, out: &mut W, values: &Values) -> io::Result<()> {
+        if !values.fixed.is_empty() || !values.timed.is_empty() {
+            writeln!(out, "        <attvalues>")?;
+            for (name, prop) in &values.fixed {
+                writeln!(
+                    out,
+                    r#"          <attvalue for="{}" value="{}"/>"#,
+                    self.fixed_id(name),
+                    escape(&prop.to_string())
+                )?;
+            }
+            for (name, prop, start, end) in &values.timed {
+                write!(
+                    out,
+                    r#"          <attvalue for="{}" value="{}" start="{}""#,
+                    self.timed_id(name),
+                    escape(&prop.to_string()),
+                    start
+                )?;
+                match end {
+                    Some(end) => writeln!(out, r#" endopen="{}"/>"#, end)?,
+                    None => writeln!(out, "/>")?,
+                }
+            }
+            writeln!(out, "        </attvalues>")?;
+        }
+        if !values.history.is_empty() {
+            writeln!(out, "        <spells>")?;
+            for t in &values.history {
+                writeln!(out, r#"          <spell start="{}" end="{}"/>"#, t, t)?;
+            }
+            writeln!(out, "        </spells>")?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the view `g` to a GEXF file at `path`
+pub fn write_gexf<G: GraphViewOps, P: AsRef<Path>>(
+    g: &G,
+    path: P,
+    mode: GexfMode,
+) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    write_gexf_to(g, &mut out, mode)?;
+    out.flush()
+}
+
+/// Write the view `g` as GEXF to `out`
+pub fn write_gexf_to<G: GraphViewOps, W: Write>(
+    g: &G,
+    mut out: W,
+    mode: GexfMode,
+) -> io::Result<()> {
+    let mut vertex_attrs = Attributes::default();
+    let vertices: Vec<_> = g
+        .vertices()
+        .iter()
+        .map(|v| {
+            let values = Values::new(v.properties(), v.history(), mode);
+            vertex_attrs.add(&values);
+            (v.name(), values)
+        })
+        .collect();
+    let vertex_attrs = vertex_attrs.finish();
+
+    let mut edge_attrs = Attributes::default();
+    let mut edges = vec![];
+    for e in g.edges() {
+        for ee in e.explode_layers() {
+            let values = Values::new(ee.properties(), ee.history(), mode);
+            edge_attrs.add(&values);
+            let layer = ee.layer_names().into_iter().next();
+            edges.push((ee.src().name(), ee.dst().name(), layer, values));
+        }
+    }
+    let edge_attrs = edge_attrs.finish();
+
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<gexf xmlns="http://www.gexf.net/1.2draft" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://www.gexf.net/1.2draft http://www.gexf.net/1.2draft/gexf.xsd" version="1.2">"#
+    )?;
+    match mode {
+        GexfMode::Static => writeln!(out, r#"  <graph mode="static" defaultedgetype="directed">"#)?,
+        GexfMode::Dynamic => writeln!(
+            out,
+            r#"  <graph mode="dynamic" defaultedgetype="directed" timeformat="integer">"#
+        )?,
+    }
+    vertex_attrs.write(&mut out, "node")?;
+    edge_attrs.write(&mut out, "edge")?;
+    writeln!(out, "    <nodes>")?;
+    for (name, values) in vertices {
+        let name = escape(&name);
+        writeln!(out, r#"      <node id="{}" label="{}">"#, name, name)?;
+        vertex_attrs.write_values(&mut out, &values)?;
+        writeln!(out, "      </node>")?;
+    }
+    writeln!(out, "    </nodes>")?;
+    writeln!(out, "    <edges>")?;
+    for (id, (src, dst, layer, values)) in edges.into_iter().enumerate() {
+        write!(
+            out,
+            r#"      <edge id="{}" source="{}" target="{}""#,
+            id,
+            escape(&src),
+            escape(&dst)
+        )?;
+        if let Some(layer) = layer {
+            write!(out, r#" label="{}""#, escape(&layer))?;
+        }
+        writeln!(out, ">")?;
+        edge_attrs.write_values(&mut out, &values)?;
+        writeln!(out, "      </edge>")?;
+    }
+    writeln!(out, "    </edges>")?;
+    writeln!(out, "  </graph>")?;
+    writeln!(out, "</gexf>")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{write_gexf_to, GexfMode};
+    use crate::prelude::*;
+
+    fn gexf<G: GraphViewOps>(g: &G, mode: GexfMode) -> String {
+        let mut out = vec![];
+        write_gexf_to(g, &mut out, mode).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn static_gexf() {
+        let g = Graph::new();
+        g.add_vertex(1, "Alice", [("age", Prop::I64(30))], None)
+            .unwrap();
+        g.add_vertex(4, "Alice", [("age", Prop::I64(31))], None)
+            .unwrap();
+        g.add_edge(2, "Alice", "Bob & Co", NO_PROPS, Some("work"))
+            .unwrap();
+
+        let out = gexf(&g, GexfMode::Static);
+        assert!(out.contains(r#"<graph mode="static" defaultedgetype="directed">"#));
+        assert!(out.contains(r#"<attribute id="0" title="age" type="long"/>"#));
+        assert!(out.contains(r#"<attvalue for="0" value="31"/>"#));
+        assert!(!out.contains(r#"value="30""#));
+        assert!(out.contains(r#"<node id="Bob &amp; Co" label="Bob &amp; Co">"#));
+        assert!(out.contains(r#"<edge id="0" source="Alice" target="Bob &amp; Co" label="work">"#));
+        assert!(!out.contains("<spells>"));
+    }
+
+    #[test]
+    fn dynamic_gexf() {
+        let g = Graph::new();
+        g.add_edge(1, "Alice", "Bob", [("weight", Prop::F64(1.0))], None)
+            .unwrap();
+        g.add_edge(5, "Alice", "Bob", [("weight", Prop::F64(2.0))], None)
+            .unwrap();
+        g.add_edge(9, "Bob", "Carol", NO_PROPS, None).unwrap();
+        g.add_edge_properties("Alice", "Bob", [("kind", Prop::str("friend"))], None)
+            .unwrap();
+
+        let out = gexf(&g, GexfMode::Dynamic);
+        assert!(out.contains(r#"timeformat="integer""#));
+        assert!(out.contains(r#"<attributes class="edge" mode="static">"#));
+        assert!(out.contains(r#"<attribute id="0" title="kind" type="string"/>"#));
+        assert!(out.contains(r#"<attributes class="edge" mode="dynamic">"#));
+        assert!(out.contains(r#"<attribute id="1" title="weight" type="double"/>"#));
+        assert!(out.contains(r#"<attvalue for="0" value="friend"/>"#));
+        assert!(out.contains(r#"<attvalue for="1" value="1" start="1" endopen="5"/>"#));
+        assert!(out.contains(r#"<attvalue for="1" value="2" start="5"/>"#));
+        assert!(out.contains(r#"<spell start="9" end="9"/>"#));
+
+        // only the updates in the window are written
+        let out = gexf(&g.window(0, 3), GexfMode::Dynamic);
+        assert!(out.contains(r#"<attvalue for="1" value="1" start="1"/>"#));
+        assert!(!out.contains("Carol"));
+    }
+}
@@ -0,0 +1,190 @@
+//! Export graph views as [GraphML](http://graphml.graphdrawing.org/) files, e.g. for yEd.
+//!
+//! GraphML describes a static graph, so every vertex and edge is written with the latest value of
+//! each of its properties. Vertices are identified by their name. Each layer of an edge is written
+//! as a separate edge with the layer name stored in the reserved `_layer` attribute. The files can
+//! be read back with [`GraphMLLoader`](crate::graph_loader::source::graphml_loader::GraphMLLoader).
+//!
+//! # Example
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graph_loader::export::graphml::write_graphml;
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, "Alice", "Bob", [("weight", Prop::F64(1.0))], None).unwrap();
+//!
+//! let dir = tempdir::TempDir::new("graphml").unwrap();
+//! write_graphml(&g, dir.path().join("graph.graphml")).unwrap();
+//! ```
+use crate::{
+    core::Prop,
+    db::api::view::{EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use quick_xml::escape::escape;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// Name of the edge attribute holding the layer, reserved so it can't clash with edge properties
+pub const LAYER_ATTRIBUTE: &str = "_layer";
+
+/// The GraphML type of `prop`, properties without a matching type are written as strings
+fn prop_type(prop: &Prop) -> &'static str {
+    match prop {
+        Prop::I32(_) => "int",
+        Prop::I64(_) | Prop::U32(_) | Prop::U64(_) => "long",
+        Prop::F32(_) => "float",
+        Prop::F64(_) => "double",
+        Prop::Bool(_) => "boolean",
+        _ => "string",
+    }
+}
+
+/// Property keys of one kind of element, sorted by name
+struct Keys {
+    prefix: &'static str,
+    types: BTreeMap<String, &'static str>,
+    /// Key id of each property, assigned by [`Keys::finish`]
+    ids: HashMap<String, String>,
+}
+
+impl Keys {
+    fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            types: BTreeMap::new(),
+            ids: HashMap::new(),
+        }
+    }
+
+    fn add(&mut self, props: &[(String, Prop)]) {
+        for (name, prop) in props {
+            self.types
+                .entry(name.clone())
+                .or_insert_with(|| prop_type(prop));
+        }
+    }
+
+    /// Assign the key ids once all properties are added
+    fn finish(mut self) -> Self {
+        self.ids = self
+            .types
+            .keys()
+            .enumerate()
+            .map(|(index, name)| (name.clone(), format!("{}{}", self.prefix, index)))
+            .collect();
+        self
+    }
+
+    fn id(&self, name: &str) -> &str {
+        &self.ids[name]
+    }
+
+    fn write<W: Write>(&self, out: &mut W, element: &str) -> io::Result<()> {
+        for (name, dtype) in &self.types {
+            writeln!(
+                out,
+                r#"  <key id="{}" for="{}" attr.name="{}" attr.type="{}"/>"#,
+                self.id(name),
+                element,
+                escape(name),
+                dtype
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_data<W: Write>(&self, out: &mut W, props: &[(String, Prop)]) -> io::Result<()> {
+        for (name, prop) in props {
+            writeln!(
+                out,
+                r#"      <data key="{}">{}</data>"#,
+                self.id(name),
+                escape(&prop.to_string())
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the view `g` to a GraphML file at `path`
+pub fn write_graphml<G: GraphViewOps, P: AsRef<Path>>(g: &G, path: P) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    write_graphml_to(g, &mut out)?;
+    out.flush()
+}
+
+/// Write the view `g` as GraphML to `out`
+pub fn write_graphml_to<G: GraphViewOps, W: Write>(g: &G, mut out: W) -> io::Result<()> {
+    let mut vertex_keys = Keys::new("v");
+    let vertices: Vec<_> = g
+        .vertices()
+        .iter()
+        .map(|v| {
+            let props: Vec<_> = v
+                .properties()
+                .iter()
+                .filter(|(k, _)| k.as_str() != "_id" && k.as_str() != "_type")
+                .map(|(k, v)| (k.to_string(), v))
+                .collect();
+            vertex_keys.add(&props);
+            (v.name(), props)
+        })
+        .collect();
+    let vertex_keys = vertex_keys.finish();
+
+    let mut edge_keys = Keys::new("e");
+    let mut edges = vec![];
+    for e in g.edges() {
+        for ee in e.explode_layers() {
+            let props: Vec<_> = ee
+                .properties()
+                .iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect();
+            edge_keys.add(&props);
+            let layer = ee.layer_names().into_iter().next();
+            edges.push((ee.src().name(), ee.dst().name(), layer, props));
+        }
+    }
+    let edge_keys = edge_keys.finish();
+
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">"#
+    )?;
+    vertex_keys.write(&mut out, "node")?;
+    writeln!(
+        out,
+        r#"  <key id="layer" for="edge" attr.name="{}" attr.type="string"/>"#,
+        LAYER_ATTRIBUTE
+    )?;
+    edge_keys.write(&mut out, "edge")?;
+    writeln!(out, r#"  <graph id="G" edgedefault="directed">"#)?;
+    for (name, props) in vertices {
+        writeln!(out, r#"    <node id="{}">"#, escape(&name))?;
+        vertex_keys.write_data(&mut out, &props)?;
+        writeln!(out, "    </node>")?;
+    }
+    for (src, dst, layer, props) in edges {
+        writeln!(
+            out,
+            r#"    <edge source="{}" target="{}">"#,
+            escape(&src),
+            escape(&dst)
+        )?;
+        if let Some(layer) = layer {
+            writeln!(out, r#"      <data key="layer">{}</data>"#, escape(&layer))?;
+        }
+        edge_keys.write_data(&mut out, &props)?;
+        writeln!(out, "    </edge>")?;
+    }
+    writeln!(out, "  </graph>")?;
+    writeln!(out, "</graphml>")
+}
@@ -0,0 +1,4 @@
+//! Module for writing graphs to files that other tools can read.
+pub mod gexf;
+pub mod graphml;
+pub mod parquet;
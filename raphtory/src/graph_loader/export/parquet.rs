@@ -0,0 +1,389 @@
+//! Export graph views as Apache Parquet tables.
+//!
+//! [`write_edges`] writes one row per edge update with the columns `time`, `src`, `dst` and `layer`
+//! followed by one column per temporal edge property. [`write_vertices`] writes one row per vertex
+//! update with the columns `time` and `id` followed by one column per temporal vertex property.
+//! Vertices are identified by their name. The files can be read back with
+//! [`ParquetLoader`](crate::graph_loader::source::parquet_loader::ParquetLoader).
+//!
+//! Any view can be exported, e.g. a window or a layer of a graph, only the updates visible in the
+//! view are written.
+//!
+//! # Example
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graph_loader::export::parquet::{write_edges, write_vertices};
+//! use raphtory::graph_loader::source::parquet_loader::ParquetLoader;
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, "Alice", "Bob", [("weight", Prop::F64(1.0))], None).unwrap();
+//! g.add_edge(5, "Bob", "Carol", [("weight", Prop::F64(2.0))], Some("friends")).unwrap();
+//!
+//! let dir = tempdir::TempDir::new("parquet").unwrap();
+//! write_edges(&g.window(0, 3), dir.path().join("edges.parquet")).unwrap();
+//!
+//! let g2 = Graph::new();
+//! ParquetLoader::new(dir.path().join("edges.parquet"))
+//!     .set_layer_column("layer")
+//!     .load_edges_into_graph(&g2)
+//!     .unwrap();
+//! assert_eq!(g2.num_edges(), 1);
+//! assert!(g2.has_edge("Alice", "Bob", Layer::Default));
+//! ```
+use crate::{
+    core::Prop,
+    db::api::view::{EdgeViewOps, GraphViewOps, VertexViewOps},
+    graph_loader::source::parquet_loader::ParquetErr,
+};
+use arrow2::{
+    array::{
+        Array, MutableArray, MutableBooleanArray, MutablePrimitiveArray, MutableUtf8Array,
+        PrimitiveArray, Utf8Array,
+    },
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema, TimeUnit},
+    io::parquet::write::{
+        transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version,
+        WriteOptions,
+    },
+};
+use std::{collections::HashMap, fs::File, path::Path};
+
+/// Write all edge updates of the view `g` to a Parquet file at `path`
+pub fn write_edges<G: GraphViewOps, P: AsRef<Path>>(g: &G, path: P) -> Result<(), ParquetErr> {
+    let mut time = vec![];
+    let mut src = vec![];
+    let mut dst = vec![];
+    let mut layer = vec![];
+    let mut props = PropTable::default();
+    for e in g.edges() {
+        for ee in e.explode_layers() {
+            let layer_name = ee.layer_names().into_iter().next();
+            for ex in ee.explode() {
+                time.push(ex.time());
+                src.push(Some(ex.src().name()));
+                dst.push(Some(ex.dst().name()));
+                layer.push(layer_name.clone());
+                props.push_row(ex.properties().temporal().collect_properties())?;
+            }
+        }
+    }
+    let mut fields = vec![
+        Field::new("time", DataType::Int64, false),
+        Field::new("src", DataType::Utf8, false),
+        Field::new("dst", DataType::Utf8, false),
+        Field::new("layer", DataType::Utf8, true),
+    ];
+    let mut arrays: Vec<Box<dyn Array>> = vec![
+        PrimitiveArray::<i64>::from(time).boxed(),
+        Utf8Array::<i32>::from(src).boxed(),
+        Utf8Array::<i32>::from(dst).boxed(),
+        Utf8Array::<i32>::from(layer).boxed(),
+    ];
+    props.finish(&mut fields, &mut arrays)?;
+    write_chunk(path.as_ref(), Schema::from(fields), Chunk::new(arrays))
+}
+
+/// Write all vertex updates of the view `g` to a Parquet file at `path`
+pub fn write_vertices<G: GraphViewOps, P: AsRef<Path>>(g: &G, path: P) -> Result<(), ParquetErr> {
+    let mut time = vec![];
+    let mut id = vec![];
+    let mut props = PropTable::default();
+    for v in g.vertices().iter() {
+        let mut updates: HashMap<i64, Vec<(String, Prop)>> = HashMap::new();
+        for (name, prop) in v.properties().temporal().iter() {
+            for (t, value) in prop.iter() {
+                updates.entry(t).or_default().push((name.clone(), value));
+            }
+        }
+        for t in v.history() {
+            time.push(Some(t));
+            id.push(Some(v.name()));
+            props.push_row(updates.remove(&t).unwrap_or_default())?;
+        }
+    }
+    let mut fields = vec![
+        Field::new("time", DataType::Int64, false),
+        Field::new("id", DataType::Utf8, false),
+    ];
+    let mut arrays: Vec<Box<dyn Array>> = vec![
+        PrimitiveArray::<i64>::from(time).boxed(),
+        Utf8Array::<i32>::from(id).boxed(),
+    ];
+    props.finish(&mut fields, &mut arrays)?;
+    write_chunk(path.as_ref(), Schema::from(fields), Chunk::new(arrays))
+}
+
+pub(crate) fn write_chunk(
+    path: &Path,
+    schema: Schema,
+    chunk: Chunk<Box<dyn Array>>,
+) -> Result<(), ParquetErr> {
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Snappy,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+    let encodings = schema
+        .fields
+        .iter()
+        .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+        .collect();
+    let row_groups =
+        RowGroupIterator::try_new(vec![Ok(chunk)].into_iter(), &schema, options, encodings)?;
+    let mut writer = FileWriter::try_new(File::create(path)?, schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok(())
+}
+
+/// Property columns, typed by the first value seen for each property
+#[derive(Default)]
+struct PropTable {
+    rows: usize,
+    columns: Vec<(String, Box<dyn MutableArray>)>,
+    index: HashMap<String, usize>,
+}
+
+impl PropTable {
+    fn push_row(&mut self, props: Vec<(String, Prop)>) -> Result<(), ParquetErr> {
+        for (name, prop) in props {
+            let i = match self.index.get(&name) {
+                Some(i) => *i,
+                None => {
+                    let mut column = new_column(&name, &prop)?;
+                    for _ in 0..self.rows {
+                        column.push_null();
+                    }
+                    self.index.insert(name.clone(), self.columns.len());
+                    self.columns.push((name.clone(), column));
+                    self.columns.len() - 1
+                }
+            };
+            let (name, column) = &mut self.columns[i];
+            // a property may only be set once per row
+            if column.len() > self.rows {
+                continue;
+            }
+            push_value(name, column.as_mut(), prop)?;
+        }
+        self.rows += 1;
+        for (_, column) in self.columns.iter_mut() {
+            if column.len() < self.rows {
+                column.push_null();
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(
+        self,
+        fields: &mut Vec<Field>,
+        arrays: &mut Vec<Box<dyn Array>>,
+    ) -> Result<(), ParquetErr> {
+        for (name, mut column) in self.columns {
+            if fields.iter().any(|f| f.name == name) {
+                return Err(ParquetErr::SchemaError(format!(
+                    "property '{}' clashes with a key column",
+                    name
+                )));
+            }
+            fields.push(Field::new(name, column.data_type().clone(), true));
+            arrays.push(column.as_box());
+        }
+        Ok(())
+    }
+}
+
+fn new_column(name: &str, prop: &Prop) -> Result<Box<dyn MutableArray>, ParquetErr> {
+    Ok(match prop {
+        Prop::Str(_) => Box::new(MutableUtf8Array::<i32>::new()),
+        Prop::I32(_) => Box::new(MutablePrimitiveArray::<i32>::new()),
+        Prop::I64(_) => Box::new(MutablePrimitiveArray::<i64>::new()),
+        Prop::U32(_) => Box::new(MutablePrimitiveArray::<u32>::new()),
+        Prop::U64(_) => Box::new(MutablePrimitiveArray::<u64>::new()),
+        Prop::F32(_) => Box::new(MutablePrimitiveArray::<f32>::new()),
+        Prop::F64(_) => Box::new(MutablePrimitiveArray::<f64>::new()),
+        Prop::Bool(_) => Box::new(MutableBooleanArray::new()),
+        Prop::DTime(_) => Box::new(
+            MutablePrimitiveArray::<i64>::new()
+                .to(DataType::Timestamp(TimeUnit::Millisecond, None)),
+        ),
+        _ => {
+            return Err(ParquetErr::SchemaError(format!(
+                "property '{}' has type {} which cannot be exported",
+                name,
+                prop_type(prop)
+            )))
+        }
+    })
+}
+
+fn push_value(name: &str, column: &mut dyn MutableArray, prop: Prop) -> Result<(), ParquetErr> {
+    fn typed<A: 'static>(column: &mut dyn MutableArray) -> &mut A {
+        column.as_mut_any().downcast_mut::<A>().unwrap()
+    }
+    let dtype = column.data_type().clone();
+    match (prop, &dtype) {
+        (Prop::Str(v), DataType::Utf8) => typed::<MutableUtf8Array<i32>>(column).push(Some(v)),
+        (Prop::I32(v), DataType::Int32) => {
+            typed::<MutablePrimitiveArray<i32>>(column).push(Some(v))
+        }
+        (Prop::I64(v), DataType::Int64) => {
+            typed::<MutablePrimitiveArray<i64>>(column).push(Some(v))
+        }
+        (Prop::U32(v), DataType::UInt32) => {
+            typed::<MutablePrimitiveArray<u32>>(column).push(Some(v))
+        }
+        (Prop::U64(v), DataType::UInt64) => {
+            typed::<MutablePrimitiveArray<u64>>(column).push(Some(v))
+        }
+        (Prop::F32(v), DataType::Float32) => {
+            typed::<MutablePrimitiveArray<f32>>(column).push(Some(v))
+        }
+        (Prop::F64(v), DataType::Float64) => {
+            typed::<MutablePrimitiveArray<f64>>(column).push(Some(v))
+        }
+        (Prop::Bool(v), DataType::Boolean) => typed::<MutableBooleanArray>(column).push(Some(v)),
+        (Prop::DTime(v), DataType::Timestamp(_, _)) => {
+            typed::<MutablePrimitiveArray<i64>>(column).push(Some(v.timestamp_millis()))
+        }
+        (prop, dtype) => {
+            return Err(ParquetErr::SchemaError(format!(
+                "property '{}' has values of different types ({:?} and {})",
+                name,
+                dtype,
+                prop_type(&prop)
+            )))
+        }
+    }
+    Ok(())
+}
+
+fn prop_type(prop: &Prop) -> &'static str {
+    match prop {
+        Prop::Str(_) => "str",
+        Prop::I32(_) => "i32",
+        Prop::I64(_) => "i64",
+        Prop::U32(_) => "u32",
+        Prop::U64(_) => "u64",
+        Prop::F32(_) => "f32",
+        Prop::F64(_) => "f64",
+        Prop::Bool(_) => "bool",
+        Prop::List(_) => "list",
+        Prop::Map(_) => "map",
+        Prop::DTime(_) => "datetime",
+        Prop::Graph(_) => "graph",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{write_edges, write_vertices};
+    use crate::{
+        graph_loader::source::parquet_loader::{ParquetErr, ParquetLoader},
+        prelude::*,
+    };
+    use chrono::NaiveDateTime;
+    use std::sync::Arc;
+    use tempdir::TempDir;
+
+    fn round_trip<G: GraphViewOps>(g: &G) -> Graph {
+        let dir = TempDir::new("parquet").unwrap();
+        let edges = dir.path().join("edges.parquet");
+        let vertices = dir.path().join("vertices.parquet");
+        write_edges(g, &edges).unwrap();
+        write_vertices(g, &vertices).unwrap();
+        let g2 = Graph::new();
+        ParquetLoader::new(&edges)
+            .set_layer_column("layer")
+            .load_edges_into_graph(&g2)
+            .unwrap();
+        ParquetLoader::new(&vertices)
+            .load_vertices_into_graph(&g2)
+            .unwrap();
+        g2
+    }
+
+    #[test]
+    fn export_and_load() {
+        let dt = NaiveDateTime::from_timestamp_millis(1000).unwrap();
+        let g = Graph::new();
+        g.add_vertex(
+            0,
+            "Alice",
+            [("age", Prop::I64(30)), ("seen", Prop::DTime(dt))],
+            None,
+        )
+        .unwrap();
+        g.add_vertex(4, "Alice", [("age", Prop::I64(31))], None)
+            .unwrap();
+        g.add_edge(1, "Alice", "Bob", [("weight", Prop::F64(1.5))], None)
+            .unwrap();
+        g.add_edge(1, "Alice", "Bob", [("flag", Prop::Bool(true))], None)
+            .unwrap();
+        g.add_edge(
+            3,
+            "Bob",
+            "Carol",
+            [("count", Prop::U32(2))],
+            Some("friends"),
+        )
+        .unwrap();
+
+        let g2 = round_trip(&g);
+        assert_eq!(g2.num_vertices(), 3);
+        assert_eq!(g2.num_edges(), 2);
+        assert_eq!(g2.edge("Alice", "Bob").unwrap().explode().count(), 2);
+        assert_eq!(
+            g2.edge("Alice", "Bob").unwrap().properties().get("weight"),
+            Some(Prop::F64(1.5))
+        );
+        assert_eq!(g2.layer("friends").unwrap().num_edges(), 1);
+        let alice = g2.vertex("Alice").unwrap();
+        assert_eq!(alice.history(), vec![0, 1, 4]);
+        assert_eq!(
+            alice
+                .properties()
+                .temporal()
+                .get("age")
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![(0, Prop::I64(30)), (4, Prop::I64(31))]
+        );
+        assert_eq!(alice.properties().get("seen"), Some(Prop::DTime(dt)));
+    }
+
+    #[test]
+    fn export_views() {
+        let g = Graph::new();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        g.add_edge(5, 2, 3, NO_PROPS, Some("layer")).unwrap();
+        g.add_edge(6, 3, 4, NO_PROPS, None).unwrap();
+
+        let windowed = round_trip(&g.window(0, 6));
+        assert_eq!(windowed.num_edges(), 2);
+        assert_eq!(windowed.latest_time(), Some(5));
+
+        let layered = round_trip(&g.layer("layer").unwrap());
+        assert_eq!(layered.num_edges(), 1);
+        assert!(layered.has_edge(2, 3, "layer"));
+    }
+
+    #[test]
+    fn unsupported_properties() {
+        let g = Graph::new();
+        g.add_edge(1, 1, 2, [("list", Prop::List(Arc::new(vec![])))], None)
+            .unwrap();
+        let dir = TempDir::new("parquet").unwrap();
+        assert!(matches!(
+            write_edges(&g, dir.path().join("edges.parquet")),
+            Err(ParquetErr::SchemaError(_))
+        ));
+    }
+}
@@ -53,6 +53,7 @@
 //!         lotr.time,
 //!         lotr.src_id.clone(),
 //!         [("type".to_string(), Prop::Str("Character".to_string()))],
+//!         None,
 //!     )
 //!     .expect("Failed to add vertex");
 //!
@@ -60,6 +61,7 @@
 //!         lotr.time,
 //!         lotr.dst_id.clone(),
 //!         [("type".to_string(), Prop::Str("Character".to_string()))],
+//!         None,
 //!     )
 //!     .expect("Failed to add vertex");
 //!
@@ -104,6 +106,7 @@ use std::{
 use zip::read::ZipArchive;
 
 pub mod example;
+pub mod export;
 pub mod source;
 
 pub fn fetch_file(
@@ -220,12 +223,14 @@ mod graph_loader_test {
                         t,
                         src_id,
                         [("name".to_string(), Prop::Str("Character".to_string()))],
+                        None,
                     )
                     .unwrap();
                     g.add_vertex(
                         t,
                         dst_id,
                         [("name".to_string(), Prop::Str("Character".to_string()))],
+                        None,
                     )
                     .unwrap();
                     g.add_edge(
@@ -33,6 +33,7 @@
 //!              time,
 //!              src_id,
 //!              [("name".to_string(), Prop::Str("Character".to_string()))],
+//!              None,
 //!          )
 //!          .map_err(|err| println!("{:?}", err))
 //!          .ok();
@@ -40,6 +41,7 @@
 //!              time,
 //!              dst_id,
 //!              [("name".to_string(), Prop::Str("Character".to_string()))],
+//!              None,
 //!          )
 //!          .map_err(|err| println!("{:?}", err))
 //!          .ok();
@@ -59,13 +61,18 @@
 //!
 
 /// Module for loading CSV files into a graph.
+use crate::{
+    core::utils::errors::GraphError,
+    db::api::mutation::{internal::InternalDeletionOps, AdditionOps},
+    prelude::NO_PROPS,
+};
 use bzip2::read::BzDecoder;
 use csv::StringRecord;
 use flate2; // 1.0
 use flate2::read::GzDecoder;
 use rayon::prelude::*;
 use regex::Regex;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize};
 use std::{
     collections::VecDeque,
     error::Error,
@@ -83,6 +90,8 @@ pub enum CsvErr {
     IoError(io::Error),
     /// A CSV parsing error that occurred while parsing the CSV data.
     CsvError(csv::Error),
+    /// An error that occurred while adding the data to the graph.
+    GraphError(GraphError),
 }
 
 impl From<io::Error> for CsvErr {
@@ -97,6 +106,12 @@ impl From<csv::Error> for CsvErr {
     }
 }
 
+impl From<GraphError> for CsvErr {
+    fn from(value: GraphError) -> Self {
+        Self::GraphError(value)
+    }
+}
+
 impl Display for CsvErr {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self.source() {
@@ -111,10 +126,22 @@ impl Error for CsvErr {
         match self {
             CsvErr::IoError(error) => Some(error),
             CsvErr::CsvError(error) => Some(error),
+            CsvErr::GraphError(error) => Some(error),
         }
     }
 }
 
+/// A record of an edge that exists from `start` until it is deleted at `end`
+///
+/// A missing `end` means the edge is never deleted.
+#[derive(Deserialize, Debug)]
+pub struct EdgeInterval {
+    pub src: String,
+    pub dst: String,
+    pub start: i64,
+    pub end: Option<i64>,
+}
+
 /// A struct that defines the CSV loader with configurable options.
 #[derive(Debug)]
 pub struct CsvLoader {
@@ -350,6 +377,63 @@ impl CsvLoader {
         Ok(())
     }
 
+    /// Load edges with explicit lifetimes from all CSV files in the directory into a graph.
+    ///
+    /// Each record is deserialised as an [`EdgeInterval`], i.e., the columns are `src`, `dst`,
+    /// `start` and `end` (matched by name if the files have a header, by position otherwise).
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - A reference to the graph object where the data should be loaded.
+    /// * `layer` - The layer to add the edges to.
+    ///
+    /// # Errors
+    ///
+    /// An error of type CsvErr is returned if reading or parsing a file fails or if an interval is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use raphtory::db::graph::views::deletion_graph::GraphWithDeletions;
+    /// use raphtory::graph_loader::source::csv_loader::CsvLoader;
+    ///
+    /// let g = GraphWithDeletions::new();
+    /// CsvLoader::new("/path/to/intervals.csv")
+    ///     .set_header(true)
+    ///     .load_edge_intervals_into_graph(&g, None)
+    ///     .expect("Failed to load intervals");
+    /// ```
+    pub fn load_edge_intervals_into_graph<G>(
+        &self,
+        g: &G,
+        layer: Option<&str>,
+    ) -> Result<(), CsvErr>
+    where
+        G: AdditionOps + InternalDeletionOps + Sync,
+    {
+        let paths = self.files_vec()?;
+        paths.par_iter().try_for_each(|path| {
+            let mut csv_reader = self.csv_reader(path.clone())?;
+            for rec in csv_reader.deserialize::<EdgeInterval>() {
+                let interval = rec?;
+                match interval.end {
+                    Some(end) => g.add_edge_interval(
+                        interval.start,
+                        end,
+                        interval.src,
+                        interval.dst,
+                        NO_PROPS,
+                        layer,
+                    )?,
+                    None => {
+                        g.add_edge(interval.start, interval.src, interval.dst, NO_PROPS, layer)?
+                    }
+                };
+            }
+            Ok::<(), CsvErr>(())
+        })
+    }
+
     /// Loads a CSV file into a graph using the specified loader function.
     ///
     /// # Arguments
@@ -452,13 +536,17 @@ impl CsvLoader {
 #[cfg(test)]
 mod csv_loader_test {
     use crate::{
-        core::utils::hashing::calculate_hash, graph_loader::source::csv_loader::CsvLoader,
-        prelude::*,
+        core::utils::hashing::calculate_hash, db::graph::views::deletion_graph::GraphWithDeletions,
+        graph_loader::source::csv_loader::CsvLoader, prelude::*,
     };
     use csv::StringRecord;
     use regex::Regex;
     use serde::Deserialize;
-    use std::path::{Path, PathBuf};
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+    use tempdir::TempDir;
 
     #[test]
     fn regex_match() {
@@ -503,6 +591,7 @@ mod csv_loader_test {
                     time,
                     src_id,
                     [("name".to_string(), Prop::Str("Character".to_string()))],
+                    None,
                 )
                 .map_err(|err| println!("{:?}", err))
                 .ok();
@@ -510,6 +599,7 @@ mod csv_loader_test {
                     time,
                     dst_id,
                     [("name".to_string(), Prop::Str("Character".to_string()))],
+                    None,
                 )
                 .map_err(|err| println!("{:?}", err))
                 .ok();
@@ -548,6 +638,7 @@ mod csv_loader_test {
                     time,
                     src_id,
                     [("name".to_string(), Prop::Str("Character".to_string()))],
+                    None,
                 )
                 .map_err(|err| println!("{:?}", err))
                 .ok();
@@ -555,6 +646,7 @@ mod csv_loader_test {
                     time,
                     dst_id,
                     [("name".to_string(), Prop::Str("Character".to_string()))],
+                    None,
                 )
                 .map_err(|err| println!("{:?}", err))
                 .ok();
@@ -593,6 +685,36 @@ mod csv_loader_test {
         lotr_test_rec(g, csv_loader, has_header, delimiter, r);
     }
 
+    #[test]
+    fn test_load_edge_intervals() {
+        let dir = TempDir::new("csv_intervals").unwrap();
+        fs::write(
+            dir.path().join("intervals.csv"),
+            "src,dst,start,end\na,b,1,5\nb,c,2,\n",
+        )
+        .unwrap();
+
+        let g = GraphWithDeletions::new();
+        CsvLoader::new(dir.path())
+            .set_header(true)
+            .load_edge_intervals_into_graph(&g, None)
+            .unwrap();
+        assert!(g.window(3, 4).has_edge("a", "b", Layer::All));
+        assert!(!g.window(6, 7).has_edge("a", "b", Layer::All));
+        assert!(g.window(6, 7).has_edge("b", "c", Layer::All));
+
+        fs::write(
+            dir.path().join("intervals.csv"),
+            "src,dst,start,end\na,b,5,1\n",
+        )
+        .unwrap();
+        let g = GraphWithDeletions::new();
+        assert!(CsvLoader::new(dir.path())
+            .set_header(true)
+            .load_edge_intervals_into_graph(&g, None)
+            .is_err());
+    }
+
     #[test]
     #[should_panic]
     fn test_wrong_header_flag_file_with_header() {
@@ -0,0 +1,508 @@
+//! Module for loading [GraphML](http://graphml.graphdrawing.org/) files into a graph.
+//!
+//! GraphML graphs are static, so all vertices and edges are added at a single time (`0` unless
+//! configured otherwise) with their `data` values as temporal properties. Values are converted
+//! according to the `attr.type` of their key and missing values use the key's default. The layer of
+//! an edge is read from the `_layer` attribute written by
+//! [`write_graphml`](crate::graph_loader::export::graphml::write_graphml) if it exists, use
+//! [`GraphMLLoader::set_layer_attribute`] for files from other tools. Edges are always added from
+//! `source` to `target`, even if the graph is undirected. Nested graphs, hyperedges and ports are ignored.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use raphtory::graph_loader::source::graphml_loader::GraphMLLoader;
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! GraphMLLoader::new("/tmp/graph.graphml")
+//!     .set_time(1)
+//!     .load_into_graph(&g)
+//!     .expect("Failed to load graph");
+//! ```
+use crate::{
+    core::{utils::errors::GraphError, Prop},
+    db::api::mutation::AdditionOps,
+    graph_loader::export::graphml::LAYER_ATTRIBUTE,
+};
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+    fs::File,
+    io,
+    io::BufReader,
+    path::PathBuf,
+};
+
+#[derive(Debug)]
+pub enum GraphMLErr {
+    /// An IO error that occurred during file read.
+    IoError(io::Error),
+    /// The file is not valid XML.
+    XmlError(quick_xml::Error),
+    /// An error that occurred while adding the data to the graph.
+    GraphError(GraphError),
+    /// The file is not valid GraphML.
+    ParseError(String),
+}
+
+impl From<io::Error> for GraphMLErr {
+    fn from(value: io::Error) -> Self {
+        Self::IoError(value)
+    }
+}
+
+impl From<quick_xml::Error> for GraphMLErr {
+    fn from(value: quick_xml::Error) -> Self {
+        Self::XmlError(value)
+    }
+}
+
+impl From<quick_xml::events::attributes::AttrError> for GraphMLErr {
+    fn from(value: quick_xml::events::attributes::AttrError) -> Self {
+        Self::XmlError(value.into())
+    }
+}
+
+impl From<GraphError> for GraphMLErr {
+    fn from(value: GraphError) -> Self {
+        Self::GraphError(value)
+    }
+}
+
+impl Display for GraphMLErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphMLErr::ParseError(msg) => write!(f, "GraphML parse error: {}", msg),
+            _ => match self.source() {
+                Some(error) => write!(f, "GraphML loader failed with error: {}", error),
+                None => write!(f, "GraphML loader failed with unknown error"),
+            },
+        }
+    }
+}
+
+impl Error for GraphMLErr {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GraphMLErr::IoError(error) => Some(error),
+            GraphMLErr::XmlError(error) => Some(error),
+            GraphMLErr::GraphError(error) => Some(error),
+            GraphMLErr::ParseError(_) => None,
+        }
+    }
+}
+
+/// A struct that defines the GraphML loader with configurable options.
+#[derive(Debug)]
+pub struct GraphMLLoader {
+    /// Path of the GraphML file.
+    path: PathBuf,
+    /// Time of all vertex and edge updates
+    time: i64,
+    /// Name of the edge attribute holding the layer of each edge
+    layer_attr: String,
+}
+
+impl GraphMLLoader {
+    /// Creates a new `GraphMLLoader` for the file at `p`.
+    pub fn new<P: Into<PathBuf>>(p: P) -> Self {
+        Self {
+            path: p.into(),
+            time: 0,
+            layer_attr: LAYER_ATTRIBUTE.to_string(),
+        }
+    }
+
+    /// Sets the time at which the vertices and edges are added.
+    pub fn set_time(mut self, t: i64) -> Self {
+        self.time = t;
+        self
+    }
+
+    /// Reads the layer of each edge from the attribute `name`.
+    pub fn set_layer_attribute(mut self, name: &str) -> Self {
+        self.layer_attr = name.to_string();
+        self
+    }
+
+    /// Load the vertices and edges of the file into `g`.
+    pub fn load_into_graph<G: AdditionOps>(&self, g: &G) -> Result<(), GraphMLErr> {
+        let mut reader = Reader::from_reader(BufReader::new(File::open(&self.path)?));
+        let mut keys: HashMap<String, Key> = HashMap::new();
+        let mut element: Option<Element> = None;
+        let mut key: Option<String> = None;
+        let mut data: Option<(String, String)> = None;
+        let mut default: Option<String> = None;
+        let mut depth = 0;
+        let mut buf = vec![];
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => {
+                    match e.local_name().as_ref() {
+                        b"graph" => depth += 1,
+                        b"key" => key = Some(self.read_key(&e, &mut keys)?),
+                        b"default" if key.is_some() => default = Some(String::new()),
+                        b"node" | b"edge" if depth == 1 => element = Some(Element::new(&e)?),
+                        b"data" if element.is_some() => {
+                            data = Some((required_attr(&e, "key")?, String::new()))
+                        }
+                        _ => {}
+                    };
+                }
+                Event::Empty(e) => match e.local_name().as_ref() {
+                    b"key" => {
+                        self.read_key(&e, &mut keys)?;
+                    }
+                    b"node" | b"edge" if depth == 1 => {
+                        self.add(g, Element::new(&e)?, &keys)?;
+                    }
+                    _ => {}
+                },
+                Event::Text(e) => {
+                    let text = e.unescape()?;
+                    if let Some((_, value)) = &mut data {
+                        value.push_str(&text);
+                    } else if let Some(value) = &mut default {
+                        value.push_str(&text);
+                    }
+                }
+                Event::CData(e) => {
+                    let text = String::from_utf8_lossy(&e);
+                    if let Some((_, value)) = &mut data {
+                        value.push_str(&text);
+                    }
+                }
+                Event::End(e) => match e.local_name().as_ref() {
+                    b"graph" => depth -= 1,
+                    b"key" => key = None,
+                    b"default" => {
+                        if let (Some(id), Some(value)) = (&key, default.take()) {
+                            if let Some(key) = keys.get_mut(id) {
+                                key.default = Some(value);
+                            }
+                        }
+                    }
+                    b"data" => {
+                        if let (Some(element), Some(data)) = (&mut element, data.take()) {
+                            element.data.push(data);
+                        }
+                    }
+                    b"node" | b"edge" if depth == 1 => {
+                        if let Some(element) = element.take() {
+                            self.add(g, element, &keys)?;
+                        }
+                    }
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        Ok(())
+    }
+
+    fn read_key(
+        &self,
+        e: &BytesStart,
+        keys: &mut HashMap<String, Key>,
+    ) -> Result<String, GraphMLErr> {
+        let id = required_attr(e, "id")?;
+        let name = optional_attr(e, "attr.name")?.unwrap_or_else(|| id.clone());
+        let dtype = optional_attr(e, "attr.type")?.unwrap_or_else(|| "string".to_string());
+        let domain = optional_attr(e, "for")?.unwrap_or_else(|| "all".to_string());
+        keys.insert(
+            id.clone(),
+            Key {
+                name,
+                dtype,
+                domain,
+                default: None,
+            },
+        );
+        Ok(id)
+    }
+
+    fn add<G: AdditionOps>(
+        &self,
+        g: &G,
+        element: Element,
+        keys: &HashMap<String, Key>,
+    ) -> Result<(), GraphMLErr> {
+        let domain = match element.kind {
+            ElementKind::Node(_) => "node",
+            ElementKind::Edge(_, _) => "edge",
+        };
+        let mut layer = None;
+        let mut props = vec![];
+        let defaults = keys
+            .iter()
+            .filter(|(id, key)| {
+                key.default.is_some()
+                    && (key.domain == domain || key.domain == "all")
+                    && !element.data.iter().any(|(k, _)| k == *id)
+            })
+            .map(|(_, key)| (key, key.default.clone().unwrap()))
+            .collect::<Vec<_>>();
+        let data = element
+            .data
+            .into_iter()
+            .map(|(id, value)| {
+                keys.get(&id)
+                    .map(|key| (key, value))
+                    .ok_or_else(|| GraphMLErr::ParseError(format!("undefined key '{}'", id)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for (key, value) in data.into_iter().chain(defaults) {
+            if domain == "edge" && key.name == self.layer_attr {
+                layer = Some(value);
+            } else {
+                props.push((key.name.clone(), key.parse(&value)?));
+            }
+        }
+        match element.kind {
+            ElementKind::Node(id) => {
+                g.add_vertex(self.time, id.as_str(), props, None)?;
+            }
+            ElementKind::Edge(src, dst) => {
+                g.add_edge(
+                    self.time,
+                    src.as_str(),
+                    dst.as_str(),
+                    props,
+                    layer.as_deref(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A `key` declaration
+struct Key {
+    name: String,
+    dtype: String,
+    /// The kind of element the key applies to
+    domain: String,
+    default: Option<String>,
+}
+
+impl Key {
+    fn parse(&self, value: &str) -> Result<Prop, GraphMLErr> {
+        let err = |_| {
+            GraphMLErr::ParseError(format!(
+                "invalid value '{}' for attribute '{}' of type {}",
+                value, self.name, self.dtype
+            ))
+        };
+        let value = value.trim();
+        match self.dtype.as_str() {
+            "boolean" => match value.to_lowercase().as_str() {
+                "true" | "1" => Ok(Prop::Bool(true)),
+                "false" | "0" => Ok(Prop::Bool(false)),
+                _ => Err(err(())),
+            },
+            "int" => value.parse().map(Prop::I32).map_err(|_| err(())),
+            "long" => value.parse().map(Prop::I64).map_err(|_| err(())),
+            "float" => value.parse().map(Prop::F32).map_err(|_| err(())),
+            "double" => value.parse().map(Prop::F64).map_err(|_| err(())),
+            _ => Ok(Prop::str(value)),
+        }
+    }
+}
+
+enum ElementKind {
+    Node(String),
+    Edge(String, String),
+}
+
+/// A `node` or `edge` element and its `data` values
+struct Element {
+    kind: ElementKind,
+    data: Vec<(String, String)>,
+}
+
+impl Element {
+    fn new(e: &BytesStart) -> Result<Self, GraphMLErr> {
+        let kind = match e.local_name().as_ref() {
+            b"node" => ElementKind::Node(required_attr(e, "id")?),
+            _ => ElementKind::Edge(required_attr(e, "source")?, required_attr(e, "target")?),
+        };
+        Ok(Self { kind, data: vec![] })
+    }
+}
+
+fn optional_attr(e: &BytesStart, name: &str) -> Result<Option<String>, GraphMLErr> {
+    for attr in e.attributes() {
+        let attr = attr?;
+        if attr.key.as_ref() == name.as_bytes() {
+            return Ok(Some(attr.unescape_value()?.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+fn required_attr(e: &BytesStart, name: &str) -> Result<String, GraphMLErr> {
+    optional_attr(e, name)?.ok_or_else(|| {
+        GraphMLErr::ParseError(format!(
+            "missing attribute '{}' on element '{}'",
+            name,
+            String::from_utf8_lossy(e.local_name().as_ref())
+        ))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GraphMLErr, GraphMLLoader};
+    use crate::{graph_loader::export::graphml::write_graphml, prelude::*};
+    use tempdir::TempDir;
+
+    #[test]
+    fn load_graphml() {
+        let dir = TempDir::new("graphml").unwrap();
+        let path = dir.path().join("graph.graphml");
+        std::fs::write(
+            &path,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="color" attr.type="string">
+    <default>yellow</default>
+  </key>
+  <key id="d1" for="edge" attr.name="weight" attr.type="double"/>
+  <key id="d2" for="all" attr.name="flag" attr.type="boolean"/>
+  <graph id="G" edgedefault="undirected">
+    <node id="n0"><data key="d0">green &amp; blue</data></node>
+    <node id="n1"/>
+    <node id="n2"><data key="d2">true</data></node>
+    <edge source="n0" target="n1"><data key="d1">1.5</data></edge>
+    <edge id="e1" source="n1" target="n2"/>
+  </graph>
+</graphml>"#,
+        )
+        .unwrap();
+
+        let g = Graph::new();
+        GraphMLLoader::new(&path)
+            .set_time(3)
+            .load_into_graph(&g)
+            .unwrap();
+        assert_eq!(g.num_vertices(), 3);
+        assert_eq!(g.num_edges(), 2);
+        assert_eq!(g.earliest_time(), Some(3));
+        assert_eq!(
+            g.vertex("n0").unwrap().properties().get("color"),
+            Some(Prop::str("green & blue"))
+        );
+        assert_eq!(
+            g.vertex("n1").unwrap().properties().get("color"),
+            Some(Prop::str("yellow"))
+        );
+        assert_eq!(
+            g.vertex("n2").unwrap().properties().get("flag"),
+            Some(Prop::Bool(true))
+        );
+        assert_eq!(
+            g.edge("n0", "n1").unwrap().properties().get("weight"),
+            Some(Prop::F64(1.5))
+        );
+        assert!(g.has_edge("n1", "n2", Layer::All));
+    }
+
+    #[test]
+    fn round_trip() {
+        let g = Graph::new();
+        g.add_vertex(0, "Alice", [("age", Prop::I64(30))], None)
+            .unwrap();
+        g.add_edge(1, "Alice", "Bob", [("weight", Prop::F64(1.0))], None)
+            .unwrap();
+        g.add_edge(
+            2,
+            "Bob",
+            "<Carol>",
+            [("kind", Prop::str("a&b")), ("layer", Prop::I64(1))],
+            Some("friends"),
+        )
+        .unwrap();
+        g.add_edge(
+            3,
+            "Bob",
+            "<Carol>",
+            [("kind", Prop::str("c"))],
+            Some("work"),
+        )
+        .unwrap();
+
+        let dir = TempDir::new("graphml").unwrap();
+        let path = dir.path().join("graph.graphml");
+        write_graphml(&g, &path).unwrap();
+
+        let g2 = Graph::new();
+        GraphMLLoader::new(&path).load_into_graph(&g2).unwrap();
+        assert_eq!(g2.num_vertices(), 3);
+        assert_eq!(g2.num_edges(), 2);
+        assert_eq!(
+            g2.vertex("Alice").unwrap().properties().get("age"),
+            Some(Prop::I64(30))
+        );
+        assert!(g2.has_edge("Alice", "Bob", Layer::Default));
+        assert_eq!(
+            g2.edge("Alice", "Bob").unwrap().properties().get("weight"),
+            Some(Prop::F64(1.0))
+        );
+        for (layer, kind) in [("friends", "a&b"), ("work", "c")] {
+            assert_eq!(
+                g2.layer(layer)
+                    .and_then(|g| g.edge("Bob", "<Carol>"))
+                    .and_then(|e| e.properties().get("kind")),
+                Some(Prop::str(kind))
+            );
+        }
+        assert_eq!(
+            g2.layer("friends")
+                .and_then(|g| g.edge("Bob", "<Carol>"))
+                .and_then(|e| e.properties().get("layer")),
+            Some(Prop::I64(1))
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let dir = TempDir::new("graphml").unwrap();
+        let path = dir.path().join("graph.graphml");
+        std::fs::write(
+            &path,
+            r#"<graphml>
+  <key id="d0" for="node" attr.name="size" attr.type="int"/>
+  <graph edgedefault="directed"><node id="a"><data key="d0">big</data></node></graph>
+</graphml>"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            GraphMLLoader::new(&path).load_into_graph(&Graph::new()),
+            Err(GraphMLErr::ParseError(_))
+        ));
+
+        std::fs::write(
+            &path,
+            r#"<graphml><graph><node id="a"><data key="d0">1</data></node></graph></graphml>"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            GraphMLLoader::new(&path).load_into_graph(&Graph::new()),
+            Err(GraphMLErr::ParseError(_))
+        ));
+
+        std::fs::write(&path, r#"<graphml><graph><node id="a"></graph></graphml>"#).unwrap();
+        assert!(matches!(
+            GraphMLLoader::new(&path).load_into_graph(&Graph::new()),
+            Err(GraphMLErr::XmlError(_))
+        ));
+    }
+}
@@ -1,3 +1,5 @@
 pub mod csv_loader;
+pub mod graphml_loader;
 pub mod json_loader;
 pub mod neo4j_loader;
+pub mod parquet_loader;
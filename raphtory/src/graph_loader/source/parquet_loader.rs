@@ -0,0 +1,359 @@
+//! Module for loading Apache Parquet files into a graph.
+//!
+//! Each row of an edge table is one update of an edge, each row of a vertex table is one update of
+//! a vertex. All columns other than the id, time and layer columns are added as temporal properties,
+//! their arrow types are mapped to the matching [`Prop`](crate::core::Prop) variants (timestamps
+//! become [`Prop::DTime`](crate::core::Prop::DTime)). Null values are skipped. The rows are loaded
+//! with the [`df_loaders`](crate::arrow::df_loaders), the row groups of a file are added in
+//! parallel.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use raphtory::graph_loader::source::parquet_loader::ParquetLoader;
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! ParquetLoader::new("/tmp/edges.parquet")
+//!     .set_layer_column("layer")
+//!     .load_edges_into_graph(&g)
+//!     .expect("Failed to load edges");
+//! ParquetLoader::new("/tmp/vertices.parquet")
+//!     .load_vertices_into_graph(&g)
+//!     .expect("Failed to load vertices");
+//! ```
+use crate::{
+    arrow::{
+        dataframe::DFView,
+        df_loaders::{load_edges_from_df, load_vertices_from_df},
+    },
+    core::utils::errors::GraphError,
+    db::api::mutation::{AdditionOps, PropertyAdditionOps},
+};
+use arrow2::io::parquet::read;
+use regex::Regex;
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+    fs,
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug)]
+pub enum ParquetErr {
+    /// An IO error that occurred during file read or write.
+    IoError(io::Error),
+    /// An error that occurred while decoding or encoding the Parquet data.
+    ArrowError(arrow2::error::Error),
+    /// An error that occurred while adding the data to the graph.
+    GraphError(GraphError),
+    /// A property cannot be written to a Parquet column.
+    SchemaError(String),
+}
+
+impl From<io::Error> for ParquetErr {
+    fn from(value: io::Error) -> Self {
+        Self::IoError(value)
+    }
+}
+
+impl From<arrow2::error::Error> for ParquetErr {
+    fn from(value: arrow2::error::Error) -> Self {
+        Self::ArrowError(value)
+    }
+}
+
+impl From<GraphError> for ParquetErr {
+    fn from(value: GraphError) -> Self {
+        Self::GraphError(value)
+    }
+}
+
+impl Display for ParquetErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParquetErr::SchemaError(msg) => write!(f, "Parquet schema error: {}", msg),
+            _ => match self.source() {
+                Some(error) => write!(f, "Parquet loader failed with error: {}", error),
+                None => write!(f, "Parquet loader failed with unknown error"),
+            },
+        }
+    }
+}
+
+impl Error for ParquetErr {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParquetErr::IoError(error) => Some(error),
+            ParquetErr::ArrowError(error) => Some(error),
+            ParquetErr::GraphError(error) => Some(error),
+            ParquetErr::SchemaError(_) => None,
+        }
+    }
+}
+
+/// A struct that defines the Parquet loader with configurable options.
+#[derive(Debug)]
+pub struct ParquetLoader {
+    /// Path of the Parquet file or directory containing Parquet files.
+    path: PathBuf,
+    /// Optional regex filter to select specific Parquet files by name.
+    regex_filter: Option<Regex>,
+    /// Name of the time column
+    time: String,
+    /// Names of the source and destination columns of edge tables
+    src: String,
+    dst: String,
+    /// Name of the vertex id column of vertex tables
+    id: String,
+    /// Optional name of the column holding the layer of each edge
+    layer_column: Option<String>,
+    /// Layer for all edges if there is no layer column
+    layer: Option<String>,
+}
+
+impl ParquetLoader {
+    /// Creates a new `ParquetLoader` for the file or directory at `p`.
+    ///
+    /// The loader expects the columns `time`, `src` and `dst` for edges and `time` and `id` for
+    /// vertices unless configured otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use raphtory::graph_loader::source::parquet_loader::ParquetLoader;
+    /// let loader = ParquetLoader::new("/path/to/edges.parquet");
+    /// ```
+    pub fn new<P: Into<PathBuf>>(p: P) -> Self {
+        Self {
+            path: p.into(),
+            regex_filter: None,
+            time: "time".to_string(),
+            src: "src".to_string(),
+            dst: "dst".to_string(),
+            id: "id".to_string(),
+            layer_column: None,
+            layer: None,
+        }
+    }
+
+    /// Sets the name of the time column.
+    pub fn set_time_column(mut self, name: &str) -> Self {
+        self.time = name.to_string();
+        self
+    }
+
+    /// Sets the names of the source and destination columns of edge tables.
+    pub fn set_edge_columns(mut self, src: &str, dst: &str) -> Self {
+        self.src = src.to_string();
+        self.dst = dst.to_string();
+        self
+    }
+
+    /// Sets the name of the vertex id column of vertex tables.
+    pub fn set_id_column(mut self, name: &str) -> Self {
+        self.id = name.to_string();
+        self
+    }
+
+    /// Reads the layer of each edge from the column `name`.
+    pub fn set_layer_column(mut self, name: &str) -> Self {
+        self.layer_column = Some(name.to_string());
+        self
+    }
+
+    /// Adds all edges to `layer` (ignored if a layer column is set).
+    pub fn set_layer(mut self, layer: &str) -> Self {
+        self.layer = Some(layer.to_string());
+        self
+    }
+
+    /// Sets the regex filter to select specific Parquet files by name.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use regex::Regex;
+    /// use raphtory::graph_loader::source::parquet_loader::ParquetLoader;
+    ///
+    /// let loader = ParquetLoader::new("/path/to/parquet_files")
+    ///    .with_filter(Regex::new(r".*\.parquet$").unwrap());
+    /// ```
+    pub fn with_filter(mut self, r: Regex) -> Self {
+        self.regex_filter = Some(r);
+        self
+    }
+
+    /// All files under the path that match the filter, sorted by path
+    fn files_vec(&self) -> Result<Vec<PathBuf>, ParquetErr> {
+        let mut paths = vec![];
+        let mut queue = VecDeque::from([self.path.to_path_buf()]);
+        while let Some(path) = queue.pop_back() {
+            if fs::metadata(&path)?.is_dir() {
+                for entry in fs::read_dir(&path)? {
+                    queue.push_back(entry?.path());
+                }
+            } else if self
+                .regex_filter
+                .as_ref()
+                .map_or(true, |r| path.to_str().filter(|p| r.is_match(p)).is_some())
+            {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Load the edge table(s) into `g`, one edge update per row.
+    pub fn load_edges_into_graph<G: AdditionOps + PropertyAdditionOps + Sync>(
+        &self,
+        g: &G,
+    ) -> Result<(), ParquetErr> {
+        let layer = match self.layer_column {
+            Some(_) => None,
+            None => self.layer.as_deref(),
+        };
+        for path in self.files_vec()? {
+            let df = read_df(&path)?;
+            let props = prop_names(
+                &df,
+                &[&self.src, &self.dst, &self.time]
+                    .into_iter()
+                    .chain(self.layer_column.as_ref())
+                    .collect::<Vec<_>>(),
+            );
+            load_edges_from_df(
+                &df,
+                &self.src,
+                &self.dst,
+                &self.time,
+                Some(props),
+                None,
+                None,
+                layer,
+                self.layer_column.as_deref(),
+                g,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Load the vertex table(s) into `g`, one vertex update per row.
+    pub fn load_vertices_into_graph<G: AdditionOps + PropertyAdditionOps + Sync>(
+        &self,
+        g: &G,
+    ) -> Result<(), ParquetErr> {
+        for path in self.files_vec()? {
+            let df = read_df(&path)?;
+            let props = prop_names(&df, &[&self.id, &self.time]);
+            load_vertices_from_df(&df, &self.id, &self.time, Some(props), None, None, g)?;
+        }
+        Ok(())
+    }
+}
+
+fn read_df(path: &Path) -> Result<DFView, ParquetErr> {
+    let mut reader = File::open(path)?;
+    let metadata = read::read_metadata(&mut reader)?;
+    let schema = read::infer_schema(&metadata)?;
+    let chunks = read::FileReader::new(
+        reader,
+        metadata.row_groups,
+        schema.clone(),
+        None,
+        None,
+        None,
+    )
+    .collect::<Result<Vec<_>, _>>()?;
+    Ok(DFView::from_schema(&schema, chunks)?)
+}
+
+/// All remaining columns, which are added as properties
+fn prop_names<'a>(df: &'a DFView, exclude: &[&String]) -> Vec<&'a str> {
+    df.names()
+        .iter()
+        .filter(|name| !exclude.contains(name))
+        .map(|name| name.as_str())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ParquetErr, ParquetLoader};
+    use crate::{
+        core::utils::errors::GraphError, graph_loader::export::parquet::write_chunk, prelude::*,
+    };
+    use arrow2::{
+        array::{Array, PrimitiveArray, Utf8Array},
+        chunk::Chunk,
+        datatypes::{DataType, Field, Schema, TimeUnit},
+    };
+    use tempdir::TempDir;
+
+    #[test]
+    fn load_typed_columns() {
+        let dir = TempDir::new("parquet").unwrap();
+        let schema = Schema::from(vec![
+            Field::new("ts", DataType::Timestamp(TimeUnit::Second, None), false),
+            Field::new("from", DataType::UInt64, false),
+            Field::new("to", DataType::UInt64, false),
+            Field::new("weight", DataType::Int32, true),
+        ]);
+        let arrays: Vec<Box<dyn Array>> = vec![
+            PrimitiveArray::<i64>::from_vec(vec![1, 2, 3])
+                .to(DataType::Timestamp(TimeUnit::Second, None))
+                .boxed(),
+            PrimitiveArray::<u64>::from_vec(vec![1, 2, 3]).boxed(),
+            PrimitiveArray::<u64>::from_vec(vec![2, 3, 1]).boxed(),
+            PrimitiveArray::<i32>::from(vec![Some(5), None, Some(7)]).boxed(),
+        ];
+        write_chunk(&dir.path().join("a.parquet"), schema, Chunk::new(arrays)).unwrap();
+        std::fs::write(dir.path().join("ignored.txt"), "not parquet").unwrap();
+
+        let g = Graph::new();
+        ParquetLoader::new(dir.path())
+            .with_filter(regex::Regex::new(r"\.parquet$").unwrap())
+            .set_time_column("ts")
+            .set_edge_columns("from", "to")
+            .set_layer("layer")
+            .load_edges_into_graph(&g)
+            .unwrap();
+        assert_eq!(g.num_edges(), 3);
+        assert_eq!(g.earliest_time(), Some(1000));
+        assert!(g.has_edge(1, 2, "layer"));
+        assert_eq!(
+            g.edge(1, 2).unwrap().properties().get("weight"),
+            Some(Prop::I32(5))
+        );
+        assert_eq!(g.edge(2, 3).unwrap().properties().get("weight"), None);
+    }
+
+    #[test]
+    fn schema_errors() {
+        let dir = TempDir::new("parquet").unwrap();
+        let path = dir.path().join("a.parquet");
+        let schema = Schema::from(vec![
+            Field::new("time", DataType::Utf8, false),
+            Field::new("id", DataType::Utf8, false),
+        ]);
+        let arrays: Vec<Box<dyn Array>> = vec![
+            Utf8Array::<i32>::from_slice(["a"]).boxed(),
+            Utf8Array::<i32>::from_slice(["b"]).boxed(),
+        ];
+        write_chunk(&path, schema, Chunk::new(arrays)).unwrap();
+
+        let g = Graph::new();
+        assert!(matches!(
+            ParquetLoader::new(&path).load_vertices_into_graph(&g),
+            Err(ParquetErr::GraphError(GraphError::LoadFailure(_)))
+        ));
+        assert!(matches!(
+            ParquetLoader::new(&path).load_edges_into_graph(&g),
+            Err(ParquetErr::GraphError(GraphError::LoadFailure(_)))
+        ));
+    }
+}
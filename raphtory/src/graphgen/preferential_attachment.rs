@@ -65,7 +65,7 @@ pub fn ba_preferential_attachment(graph: &Graph, vertices_to_add: usize, edges_p
     while ids.len() < edges_per_step {
         max_id += 1;
         graph
-            .add_vertex(latest_time, max_id, NO_PROPS)
+            .add_vertex(latest_time, max_id, NO_PROPS, None)
             .map_err(|err| println!("{:?}", err))
             .ok();
         degrees.push(0);
@@ -134,7 +134,7 @@ mod preferential_attachment_tests {
         let graph = Graph::new();
         for i in 0..10 {
             graph
-                .add_vertex(i, i as u64, NO_PROPS)
+                .add_vertex(i, i as u64, NO_PROPS, None)
                 .map_err(|err| println!("{:?}", err))
                 .ok();
         }
@@ -54,7 +54,7 @@ pub fn random_attachment(graph: &Graph, vertices_to_add: usize, edges_per_step:
         max_id += 1;
         latest_time += 1;
         graph
-            .add_vertex(latest_time, max_id, NO_PROPS)
+            .add_vertex(latest_time, max_id, NO_PROPS, None)
             .map_err(|err| println!("{:?}", err))
             .ok();
         ids.push(max_id);
@@ -90,7 +90,7 @@ mod random_graph_test {
         let graph = Graph::new();
         for i in 0..10 {
             graph
-                .add_vertex(i, i as u64, NO_PROPS)
+                .add_vertex(i, i as u64, NO_PROPS, None)
                 .map_err(|err| println!("{:?}", err))
                 .ok();
         }
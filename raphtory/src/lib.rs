@@ -37,12 +37,14 @@
 //!   1,
 //!   "Gandalf",
 //!   [("type".to_string(), Prop::Str("Character".to_string()))],
+//!   None,
 //! );
 //!
 //! graph.add_vertex(
 //!   2,
 //!   "Frodo",
 //!   [("type".to_string(), Prop::Str("Character".to_string()))],
+//!   None,
 //! );
 //!
 //! graph.add_edge(
@@ -96,6 +98,9 @@ pub mod python;
 #[cfg(feature = "io")]
 pub mod graph_loader;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
 #[cfg(feature = "search")]
 pub mod search;
 
@@ -15,12 +15,9 @@ use crate::{
 };
 use pyo3::prelude::*;
 
-use crate::{
-    db::{
-        api::view::internal::{DynamicGraph, IntoDynamic},
-        graph::{edge::EdgeView, vertex::VertexView},
-    },
-    python::graph::pandas::{load_edges_props_from_df, load_vertex_props_from_df},
+use crate::db::{
+    api::view::internal::{DynamicGraph, IntoDynamic},
+    graph::{edge::EdgeView, vertex::VertexView},
 };
 use pyo3::types::IntoPyDict;
 use std::{
@@ -29,8 +26,9 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use super::pandas::{
-    load_edges_from_df, load_vertices_from_df, process_pandas_py_df, GraphLoadException,
+use super::pandas::{process_pandas_py_df, GraphLoadException};
+use crate::arrow::df_loaders::{
+    load_edges_from_df, load_edges_props_from_df, load_vertex_props_from_df, load_vertices_from_df,
 };
 
 /// A temporal graph.
@@ -124,18 +122,30 @@ impl PyGraph {
     ///    timestamp (int, str, or datetime(utc)): The timestamp of the vertex.
     ///    id (str or int): The id of the vertex.
     ///    properties (dict): The properties of the vertex.
+    ///    vertex_type (str): The type of the vertex, can only be set once.
+    ///    layer (str): The layer of the vertex, if set the vertex and its temporal properties are only visible in views including the layer.
     ///
     /// Returns:
     ///   None
-    #[pyo3(signature = (timestamp, id, properties=None))]
+    #[pyo3(signature = (timestamp, id, properties=None, vertex_type=None, layer=None))]
     pub fn add_vertex(
         &self,
         timestamp: PyTime,
         id: PyInputVertex,
         properties: Option<HashMap<String, Prop>>,
+        vertex_type: Option<&str>,
+        layer: Option<&str>,
     ) -> Result<VertexView<Graph>, GraphError> {
-        self.graph
-            .add_vertex(timestamp, id, properties.unwrap_or_default())
+        let properties = properties.unwrap_or_default();
+        match layer {
+            Some(layer) => {
+                self.graph
+                    .add_vertex_to_layer(timestamp, id, properties, vertex_type, layer)
+            }
+            None => self
+                .graph
+                .add_vertex(timestamp, id, properties, vertex_type),
+        }
     }
 
     /// add_vertex_properties(self, id: str | int, properties: dict) -> None
@@ -325,7 +335,6 @@ impl PyGraph {
             let df = process_pandas_py_df(vertices_df, py, size)?;
             load_vertices_from_df(
                 &df,
-                size,
                 vertex_col,
                 time_col,
                 props,
@@ -366,7 +375,6 @@ impl PyGraph {
             let df = process_pandas_py_df(edge_df, py, size)?;
             load_edges_from_df(
                 &df,
-                size,
                 src_col,
                 dst_col,
                 time_col,
@@ -403,15 +411,8 @@ impl PyGraph {
                 )?
                 .extract()?;
             let df = process_pandas_py_df(vertices_df, py, size)?;
-            load_vertex_props_from_df(
-                &df,
-                size,
-                vertex_col,
-                const_props,
-                shared_const_props,
-                graph,
-            )
-            .map_err(|e| GraphLoadException::new_err(format!("{:?}", e)))?;
+            load_vertex_props_from_df(&df, vertex_col, const_props, shared_const_props, graph)
+                .map_err(|e| GraphLoadException::new_err(format!("{:?}", e)))?;
 
             Ok::<(), PyErr>(())
         })
@@ -442,7 +443,6 @@ impl PyGraph {
             let df = process_pandas_py_df(edge_df, py, size)?;
             load_edges_props_from_df(
                 &df,
-                size,
                 src_col,
                 dst_col,
                 const_props,
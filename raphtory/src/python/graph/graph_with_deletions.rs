@@ -6,6 +6,7 @@
 //! It is a wrapper around a set of shards, which are the actual graph data structures.
 //! In Python, this class wraps around the rust graph.
 use crate::{
+    arrow::df_loaders::{load_edge_intervals_from_df, load_edges_from_df},
     core::{utils::errors::GraphError, Prop},
     db::{
         api::mutation::{AdditionOps, PropertyAdditionOps},
@@ -13,11 +14,14 @@ use crate::{
     },
     prelude::DeletionOps,
     python::{
-        graph::views::graph_view::PyGraphView,
+        graph::{
+            pandas::{process_pandas_py_df, GraphLoadException},
+            views::graph_view::PyGraphView,
+        },
         utils::{PyInputVertex, PyTime},
     },
 };
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::IntoPyDict};
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter},
@@ -91,18 +95,52 @@ impl PyGraphWithDeletions {
     ///    timestamp (int, str, or datetime(utc)): The timestamp of the vertex.
     ///    id (str or int): The id of the vertex.
     ///    properties (dict): The properties of the vertex.
+    ///    vertex_type (str): The type of the vertex, can only be set once.
+    ///    layer (str): The layer of the vertex, if set the vertex and its temporal properties are only visible in views including the layer.
     ///
     /// Returns:
     ///   None
-    #[pyo3(signature = (timestamp, id, properties=None))]
+    #[pyo3(signature = (timestamp, id, properties=None, vertex_type=None, layer=None))]
     pub fn add_vertex(
         &self,
         timestamp: PyTime,
         id: PyInputVertex,
         properties: Option<HashMap<String, Prop>>,
+        vertex_type: Option<&str>,
+        layer: Option<&str>,
+    ) -> Result<VertexView<GraphWithDeletions>, GraphError> {
+        let properties = properties.unwrap_or_default();
+        match layer {
+            Some(layer) => {
+                self.graph
+                    .add_vertex_to_layer(timestamp, id, properties, vertex_type, layer)
+            }
+            None => self
+                .graph
+                .add_vertex(timestamp, id, properties, vertex_type),
+        }
+    }
+
+    /// Adds a new vertex which exists from `start` until it is deleted at `end`.
+    ///
+    /// Arguments:
+    ///    start (int, str, or datetime(utc)): The time the vertex is added.
+    ///    end (int, str, or datetime(utc)): The time the vertex is deleted.
+    ///    id (str or int): The id of the vertex.
+    ///    properties (dict): The properties of the vertex.
+    ///
+    /// Returns:
+    ///   None
+    #[pyo3(signature = (start, end, id, properties=None))]
+    pub fn add_vertex_interval(
+        &self,
+        start: PyTime,
+        end: PyTime,
+        id: PyInputVertex,
+        properties: Option<HashMap<String, Prop>>,
     ) -> Result<VertexView<GraphWithDeletions>, GraphError> {
         self.graph
-            .add_vertex(timestamp, id, properties.unwrap_or_default())
+            .add_vertex_interval(start, end, id, properties.unwrap_or_default())
     }
 
     /// Adds properties to an existing vertex.
@@ -172,6 +210,32 @@ impl PyGraphWithDeletions {
             .add_edge(timestamp, src, dst, properties.unwrap_or_default(), layer)
     }
 
+    /// Adds a new edge which exists from `start` until it is deleted at `end`.
+    ///
+    /// Arguments:
+    ///    start (int, str, or datetime(utc)): The time the edge is added.
+    ///    end (int, str, or datetime(utc)): The time the edge is deleted.
+    ///    src (str or int): The id of the source vertex.
+    ///    dst (str or int): The id of the destination vertex.
+    ///    properties (dict): The properties of the edge, as a dict of string and properties
+    ///    layer (str): The layer of the edge.
+    ///
+    /// Returns:
+    ///   None
+    #[pyo3(signature = (start, end, src, dst, properties=None, layer=None))]
+    pub fn add_edge_interval(
+        &self,
+        start: PyTime,
+        end: PyTime,
+        src: PyInputVertex,
+        dst: PyInputVertex,
+        properties: Option<HashMap<String, Prop>>,
+        layer: Option<&str>,
+    ) -> Result<EdgeView<GraphWithDeletions>, GraphError> {
+        self.graph
+            .add_edge_interval(start, end, src, dst, properties.unwrap_or_default(), layer)
+    }
+
     /// Deletes an edge given the timestamp, src and dst vertices and layer (optional)
     ///
     /// Arguments:
@@ -192,6 +256,18 @@ impl PyGraphWithDeletions {
         self.graph.delete_edge(timestamp, src, dst, layer)
     }
 
+    /// Deletes a vertex given the timestamp and the vertex, all edges of the vertex are closed at the same time
+    ///
+    /// Arguments:
+    ///   timestamp (int): The timestamp of the deletion.
+    ///   id (str or int): The id of the vertex.
+    ///
+    /// Returns:
+    ///  None or a GraphError if the vertex could not be deleted
+    pub fn delete_vertex(&self, timestamp: PyTime, id: PyInputVertex) -> Result<(), GraphError> {
+        self.graph.delete_vertex(timestamp, id)
+    }
+
     /// Adds properties to an existing edge.
     ///
     /// Arguments:
@@ -213,6 +289,82 @@ impl PyGraphWithDeletions {
         self.graph.add_edge_properties(src, dst, properties, layer)
     }
 
+    /// Load edges from a Pandas DataFrame, rows with an end time are deleted again at that time.
+    ///
+    /// Arguments:
+    ///     edge_df (pandas.DataFrame): The DataFrame containing the edges.
+    ///     src_col (str): The column name for the source vertex ids.
+    ///     dst_col (str): The column name for the destination vertex ids.
+    ///     time_col (str): The column name for the start times.
+    ///     end_col (str): The column name for the end times (optional, missing values mean the edge is never deleted).
+    ///     props (List<str>): List of edge property column names (optional).
+    ///     const_props (List<str>): List of constant edge property column names (optional).
+    ///     shared_const_props (dict): Constant properties shared by all edges (optional).
+    ///     layer (str): The edge layer name (optional).
+    ///     layer_in_df (str): The column name for the edge layer (optional).
+    ///
+    /// Returns:
+    ///     None
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (edge_df, src_col = "source", dst_col = "destination", time_col = "time", end_col = None, props = None, const_props = None, shared_const_props = None, layer = None, layer_in_df = None))]
+    fn load_edges_from_pandas(
+        &self,
+        edge_df: &PyAny,
+        src_col: &str,
+        dst_col: &str,
+        time_col: &str,
+        end_col: Option<&str>,
+        props: Option<Vec<&str>>,
+        const_props: Option<Vec<&str>>,
+        shared_const_props: Option<HashMap<String, Prop>>,
+        layer: Option<&str>,
+        layer_in_df: Option<&str>,
+    ) -> Result<(), GraphError> {
+        let graph = &self.graph;
+        Python::with_gil(|py| {
+            let size: usize = py
+                .eval(
+                    "index.__len__()",
+                    Some([("index", edge_df.getattr("index")?)].into_py_dict(py)),
+                    None,
+                )?
+                .extract()?;
+            let df = process_pandas_py_df(edge_df, py, size)?;
+            match end_col {
+                Some(end_col) => load_edge_intervals_from_df(
+                    &df,
+                    src_col,
+                    dst_col,
+                    time_col,
+                    end_col,
+                    props,
+                    const_props,
+                    shared_const_props,
+                    layer,
+                    layer_in_df,
+                    graph,
+                ),
+                None => load_edges_from_df(
+                    &df,
+                    src_col,
+                    dst_col,
+                    time_col,
+                    props,
+                    const_props,
+                    shared_const_props,
+                    layer,
+                    layer_in_df,
+                    graph,
+                ),
+            }
+            .map_err(|e| GraphLoadException::new_err(format!("{:?}", e)))?;
+
+            Ok::<(), PyErr>(())
+        })
+        .map_err(|e| GraphError::LoadFailure(format!("Failed to load graph {e:?}")))?;
+        Ok(())
+    }
+
     //******  Saving And Loading  ******//
 
     // Alternative constructors are tricky, see: https://gist.github.com/redshiftzero/648e4feeff3843ffd9924f13625f839c
@@ -123,6 +123,22 @@ impl PyVertex {
         self.vertex.name()
     }
 
+    /// Returns the type of the vertex.
+    ///
+    /// Returns:
+    ///  The type of the vertex as a string or None if the vertex has no type.
+    pub fn vertex_type(&self) -> Option<String> {
+        self.vertex.vertex_type()
+    }
+
+    /// Returns the names of the layers the vertex was added to or has edges in.
+    ///
+    /// Returns:
+    ///  A list of layer names.
+    pub fn layer_names(&self) -> Vec<String> {
+        self.vertex.layer_names()
+    }
+
     /// Returns the earliest time that the vertex exists.
     ///
     /// Arguments:
@@ -363,6 +363,17 @@ impl PyGraphView {
         self.graph.subgraph(vertices)
     }
 
+    /// Returns a subgraph containing only the vertices with one of the given types
+    ///
+    /// Arguments:
+    ///   * `vertex_types`: the types to keep
+    ///
+    /// Returns:
+    ///    GraphView - Returns the subgraph
+    fn type_filter(&self, vertex_types: Vec<String>) -> VertexSubgraph<DynamicGraph> {
+        self.graph.type_filter(vertex_types)
+    }
+
     /// Returns a graph clone
     ///
     /// Arguments:
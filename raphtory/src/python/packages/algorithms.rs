@@ -19,22 +19,28 @@ use crate::{
         hits::hits as hits_rs,
         local_clustering_coefficient::local_clustering_coefficient as local_clustering_coefficient_rs,
         local_triangle_count::local_triangle_count as local_triangle_count_rs,
-        motifs::three_node_temporal_motifs::{
-            global_temporal_three_node_motif as global_temporal_three_node_motif_rs,
-            temporal_three_node_motif as local_three_node_rs,
+        motifs::{
+            temporal_motif_matcher::{
+                count_temporal_motif, temporal_motif_instances as temporal_motif_instances_rs,
+                Comparison, TemporalMotif,
+            },
+            three_node_temporal_motifs::{
+                global_temporal_three_node_motif as global_temporal_three_node_motif_rs,
+                temporal_three_node_motif as local_three_node_rs,
+            },
         },
-        pagerank::unweighted_page_rank,
+        pagerank::weighted_page_rank,
         reciprocity::{
             all_local_reciprocity as all_local_reciprocity_rs,
             global_reciprocity as global_reciprocity_rs,
         },
         temporal_reachability::temporally_reachable_nodes as temporal_reachability_rs,
     },
-    core::entities::vertices::vertex_ref::VertexRef,
+    core::{entities::vertices::vertex_ref::VertexRef, Prop},
     python::{graph::views::graph_view::PyGraphView, utils::PyInputVertex},
 };
 use ordered_float::OrderedFloat;
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
 
 /// Local triangle count - calculates the number of triangles (a cycle of length 3) a vertex participates in.
 ///
@@ -72,10 +78,35 @@ pub fn weakly_connected_components(
     connected_components::weakly_connected_components(&g.graph, iter_count, None)
 }
 
+/// Strongly connected components -- partitions the graph into node sets which are mutually reachable by a directed path
+///
+/// This function assigns a component id to each vertex such that vertices with the same component id are mutually reachable
+/// by a directed path.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     temporal (bool) : If True, paths must follow edge updates with strictly increasing timestamps and vertices are in the same
+/// component if they are linked by a chain of vertex pairs that temporally reach each other. Defaults to False.
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult object with string keys and integer values mapping vertex names to their component ids.
+#[pyfunction]
+#[pyo3(signature = (g, temporal=false))]
+pub fn strongly_connected_components(
+    g: &PyGraphView,
+    temporal: bool,
+) -> AlgorithmResult<String, u64> {
+    if temporal {
+        connected_components::temporal_strongly_connected_components(&g.graph)
+    } else {
+        connected_components::strongly_connected_components(&g.graph)
+    }
+}
+
 /// Pagerank -- pagerank centrality value of the vertices in a graph
 ///
 /// This function calculates the Pagerank value of each vertex in a graph. See https://en.wikipedia.org/wiki/PageRank for more information on PageRank centrality.
-/// A default damping factor of 0.85 is used. This is an iterative algorithm which terminates if the sum of the absolute difference in pagerank values between iterations
+/// This is an iterative algorithm which terminates if the sum of the absolute difference in pagerank values between iterations
 /// is less than the max diff value given.
 ///
 /// Arguments:
@@ -83,17 +114,32 @@ pub fn weakly_connected_components(
 ///     iter_count (int) : Maximum number of iterations to run. Note that this will terminate early if convergence is reached.
 ///     max_diff (float) : Optional parameter providing an alternative stopping condition. The algorithm will terminate if the sum of the absolute difference in pagerank values between iterations
 /// is less than the max diff value given.
+///     weight (str) : Optional name of the edge property holding the edge weights, the values are summed over the history of each edge in the graph view, falling back to the constant property. Defaults to a weight of 1 for every edge.
+///     damping_factor (float) : The probability of following an edge instead of teleporting. Defaults to 0.85.
+///     personalization (dict) : Optional mapping from seed vertex names or ids to their teleport values. Defaults to teleporting uniformly to all vertices.
 ///
 /// Returns:
 ///     AlgorithmResult : AlgorithmResult with string keys and float values mapping vertex names to their pagerank value.
 #[pyfunction]
-#[pyo3(signature = (g, iter_count=20, max_diff=None))]
+#[pyo3(signature = (g, iter_count=20, max_diff=None, weight=None, damping_factor=0.85, personalization=None))]
 pub fn pagerank(
     g: &PyGraphView,
     iter_count: usize,
     max_diff: Option<f64>,
+    weight: Option<String>,
+    damping_factor: f64,
+    personalization: Option<HashMap<PyInputVertex, f64>>,
 ) -> AlgorithmResult<String, OrderedFloat<f64>> {
-    unweighted_page_rank(&g.graph, iter_count, None, max_diff, true)
+    weighted_page_rank(
+        &g.graph,
+        iter_count,
+        None,
+        max_diff,
+        true,
+        weight,
+        Some(damping_factor),
+        personalization.map(|seeds| seeds.into_iter().collect()),
+    )
 }
 
 /// Temporally reachable nodes -- the nodes that are reachable by a time respecting path followed out from a set of seed nodes at a starting time.
@@ -353,6 +399,81 @@ pub fn local_temporal_three_node_motifs(
     local_three_node_rs(&g.graph, delta, None)
 }
 
+fn build_motif(
+    edges: Vec<(usize, usize)>,
+    delta: i64,
+    layers: Option<Vec<Option<String>>>,
+    filters: Option<Vec<Vec<(String, String, Prop)>>>,
+) -> PyResult<TemporalMotif> {
+    let layers = layers.unwrap_or_else(|| vec![None; edges.len()]);
+    let filters = filters.unwrap_or_else(|| vec![vec![]; edges.len()]);
+    if layers.len() != edges.len() || filters.len() != edges.len() {
+        return Err(PyValueError::new_err(
+            "layers and filters need one entry per motif edge",
+        ));
+    }
+    let mut motif = TemporalMotif::new(delta);
+    for (((src, dst), layer), edge_filters) in edges.into_iter().zip(layers).zip(filters) {
+        motif = motif.layered_edge(src, dst, layer.as_deref());
+        for (name, comparison, value) in edge_filters {
+            let comparison: Comparison = comparison.parse().map_err(PyValueError::new_err)?;
+            motif = motif.filter(&name, comparison, value);
+        }
+    }
+    Ok(motif)
+}
+
+/// Counts the instances of a user defined temporal motif.
+///
+/// A motif is an ordered list of edges between vertex variables numbered from 0. An instance maps the variables to distinct vertices
+/// and the motif edges to edge updates between them with strictly increasing timestamps, the last update happening at most delta after the first.
+///
+/// Arguments:
+///     g (raphtory graph) : A directed raphtory graph
+///     edges (list) : The motif edges as (src, dst) pairs of variables, e.g. [(0, 1), (1, 2), (2, 0)] for a cyclic triangle
+///     delta (int) : Maximum time difference between the first and last edge of the motif
+///     layers (list) : Optional layer for each motif edge, None matches all layers
+///     filters (list) : Optional list of (property, comparison, value) conditions for each motif edge, comparison is one of
+/// "==", "!=", "<", "<=", ">" and ">=". The properties are evaluated at the time of the update.
+///     threads (int) : Number of threads to use
+///
+/// Returns:
+///     int : The number of instances of the motif
+#[pyfunction]
+#[pyo3(signature = (g, edges, delta, layers=None, filters=None, threads=None))]
+pub fn temporal_motif_count(
+    g: &PyGraphView,
+    edges: Vec<(usize, usize)>,
+    delta: i64,
+    layers: Option<Vec<Option<String>>>,
+    filters: Option<Vec<Vec<(String, String, Prop)>>>,
+    threads: Option<usize>,
+) -> PyResult<usize> {
+    let motif = build_motif(edges, delta, layers, filters)?;
+    Ok(count_temporal_motif(&g.graph, &motif, threads))
+}
+
+/// Enumerates the instances of a user defined temporal motif, see temporal_motif_count for the arguments.
+///
+/// Returns:
+///     list : The instances sorted by time as tuples of the vertex names mapped to the variables and the timestamps of the matched updates
+#[pyfunction]
+#[pyo3(signature = (g, edges, delta, layers=None, filters=None, threads=None))]
+pub fn temporal_motif_instances(
+    g: &PyGraphView,
+    edges: Vec<(usize, usize)>,
+    delta: i64,
+    layers: Option<Vec<Option<String>>>,
+    filters: Option<Vec<Vec<(String, String, Prop)>>>,
+    threads: Option<usize>,
+) -> PyResult<Vec<(Vec<String>, Vec<i64>)>> {
+    let motif = build_motif(edges, delta, layers, filters)?;
+    Ok(temporal_motif_instances_rs(&g.graph, &motif, threads)
+        .into_iter()
+        .map(|instance| (instance.vertices, instance.times))
+        .collect())
+}
+
 /// HITS (Hubs and Authority) Algorithm:
 /// AuthScore of a vertex (A) = Sum of HubScore of all vertices pointing at vertex (A) from previous iteration /
 ///     Sum of HubScore of all vertices in the current iteration
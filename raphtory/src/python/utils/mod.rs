@@ -133,7 +133,7 @@ impl TryFrom<PyInterval> for Interval {
 
 /// A trait for vertices that can be used as input for the graph.
 /// This allows us to add vertices with different types of ids, either strings or ints.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PyInputVertex {
     id: u64,
     name: Option<String>,
@@ -616,7 +616,9 @@ impl<G: GraphViewOps + InternalAdditionOps> InternalAdditionOps for IndexedGraph
         t: TimeIndexEntry,
         v: u64,
         name: Option<&str>,
+        vertex_type: Option<&str>,
         props: Vec<(String, Prop)>,
+        layer: Option<&str>,
     ) -> Result<VID, GraphError> {
         let mut document = Document::new();
         // add time to the document
@@ -638,7 +640,9 @@ impl<G: GraphViewOps + InternalAdditionOps> InternalAdditionOps for IndexedGraph
             }
         }
         // add the vertex id to the document
-        let v_id = self.graph.internal_add_vertex(t, v, name, props)?;
+        let v_id = self
+            .graph
+            .internal_add_vertex(t, v, name, vertex_type, props, layer)?;
         // get the field from the index
         let vertex_id = self.vertex_index.schema().get_field(fields::VERTEX_ID)?;
         let vertex_id_rev = self
@@ -691,6 +695,7 @@ mod test {
                     ("age".to_string(), Prop::U64(42)),
                     ("balance".to_string(), Prop::I64(-1234)),
                 ],
+                None,
             )
             .expect("failed to add vertex");
 
@@ -733,7 +738,12 @@ mod test {
         let graph = Graph::new();
 
         graph
-            .add_vertex(1, "Gandalf", [("kind".to_string(), Prop::str("Wizard"))])
+            .add_vertex(
+                1,
+                "Gandalf",
+                [("kind".to_string(), Prop::str("Wizard"))],
+                None,
+            )
             .expect("add vertex failed");
 
         graph
@@ -744,31 +754,62 @@ mod test {
                     ("kind".to_string(), Prop::str("Hobbit")),
                     ("has_ring".to_string(), Prop::str("yes")),
                 ],
+                None,
             )
             .expect("add vertex failed");
 
         graph
-            .add_vertex(2, "Merry", [("kind".to_string(), Prop::str("Hobbit"))])
+            .add_vertex(
+                2,
+                "Merry",
+                [("kind".to_string(), Prop::str("Hobbit"))],
+                None,
+            )
             .expect("add vertex failed");
 
         graph
-            .add_vertex(4, "Gollum", [("kind".to_string(), Prop::str("Creature"))])
+            .add_vertex(
+                4,
+                "Gollum",
+                [("kind".to_string(), Prop::str("Creature"))],
+                None,
+            )
             .expect("add vertex failed");
 
         graph
-            .add_vertex(9, "Gollum", [("has_ring".to_string(), Prop::str("yes"))])
+            .add_vertex(
+                9,
+                "Gollum",
+                [("has_ring".to_string(), Prop::str("yes"))],
+                None,
+            )
             .expect("add vertex failed");
 
         graph
-            .add_vertex(9, "Frodo", [("has_ring".to_string(), Prop::str("no"))])
+            .add_vertex(
+                9,
+                "Frodo",
+                [("has_ring".to_string(), Prop::str("no"))],
+                None,
+            )
             .expect("add vertex failed");
 
         graph
-            .add_vertex(10, "Frodo", [("has_ring".to_string(), Prop::str("yes"))])
+            .add_vertex(
+                10,
+                "Frodo",
+                [("has_ring".to_string(), Prop::str("yes"))],
+                None,
+            )
             .expect("add vertex failed");
 
         graph
-            .add_vertex(10, "Gollum", [("has_ring".to_string(), Prop::str("no"))])
+            .add_vertex(
+                10,
+                "Gollum",
+                [("has_ring".to_string(), Prop::str("no"))],
+                None,
+            )
             .expect("add vertex failed");
 
         let indexed_graph: IndexedGraph<Graph> =
@@ -814,7 +855,7 @@ mod test {
         let graph = IndexedGraph::new(Graph::new(), NO_PROPS, NO_PROPS);
 
         graph
-            .add_vertex(1, "Gandalf", NO_PROPS)
+            .add_vertex(1, "Gandalf", NO_PROPS, None)
             .expect("add vertex failed");
 
         graph.reload().expect("reload failed");
@@ -838,6 +879,7 @@ mod test {
                 1,
                 "Bilbo",
                 [("description".to_string(), Prop::str("A hobbit"))],
+                None,
             )
             .expect("add vertex failed");
 
@@ -846,6 +888,7 @@ mod test {
                 2,
                 "Gandalf",
                 [("description".to_string(), Prop::str("A wizard"))],
+                None,
             )
             .expect("add vertex failed");
 
@@ -875,6 +918,7 @@ mod test {
                 1,
                 "Gandalf",
                 [("description".to_string(), Prop::str("The wizard"))],
+                None,
             )
             .expect("add vertex failed");
 
@@ -883,6 +927,7 @@ mod test {
                 2,
                 "Saruman",
                 [("description".to_string(), Prop::str("Another wizard"))],
+                None,
             )
             .expect("add vertex failed");
 